@@ -17,38 +17,47 @@
 
 //! Utilities to assist with reading and writing Arrow data as Flight messages
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
 use crate::{FlightData, SchemaResult};
 
+use arrow::array::ArrayRef;
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::{ArrowError, Result};
-use arrow::ipc::{convert, reader, writer, writer::IpcWriteOptions};
+use arrow::ipc::{
+    convert, reader, writer,
+    writer::{DictionaryTracker, EncodedData, IpcWriteOptions},
+};
 use arrow::record_batch::RecordBatch;
 
-/// Convert a `RecordBatch` to `FlightData` by converting the header and body to bytes
-///
-/// Note: This implicitly uses the default `IpcWriteOptions`. To configure options,
-/// use `flight_data_from_arrow_batch()`
-impl From<&RecordBatch> for FlightData {
-    fn from(batch: &RecordBatch) -> Self {
-        let options = IpcWriteOptions::default();
-        flight_data_from_arrow_batch(batch, &options)
+/// Convert encoded IPC data to `FlightData`, using the IPC message as the header
+impl From<EncodedData> for FlightData {
+    fn from(data: EncodedData) -> Self {
+        FlightData {
+            flight_descriptor: None,
+            app_metadata: vec![],
+            data_header: data.ipc_message,
+            data_body: data.arrow_data,
+        }
     }
 }
 
 /// Convert a `RecordBatch` to `FlightData` by converting the header and body to bytes
+///
+/// Returns a message for each dictionary used by the batch that `dictionary_tracker`
+/// has not seen yet, which must be sent before the message of the batch itself. Use
+/// the same tracker for all the batches sent in a stream, so that each dictionary is
+/// only sent once.
 pub fn flight_data_from_arrow_batch(
     batch: &RecordBatch,
+    dictionary_tracker: &mut DictionaryTracker,
     options: &IpcWriteOptions,
-) -> FlightData {
-    let data = writer::record_batch_to_bytes(batch, &options);
-    FlightData {
-        flight_descriptor: None,
-        app_metadata: vec![],
-        data_header: data.ipc_message,
-        data_body: data.arrow_data,
-    }
+) -> Result<(Vec<FlightData>, FlightData)> {
+    let (encoded_dictionaries, encoded_batch) =
+        writer::encoded_batch(batch, dictionary_tracker, options)?;
+    let dictionaries = encoded_dictionaries.into_iter().map(Into::into).collect();
+    Ok((dictionaries, encoded_batch.into()))
 }
 
 /// Convert a `Schema` to `SchemaResult` by converting to an IPC message
@@ -126,13 +135,16 @@ impl TryFrom<&SchemaResult> for Schema {
 }
 
 /// Convert a FlightData message to a RecordBatch
+///
+/// The values of dictionary columns are looked up in `dictionaries_by_id`, which holds
+/// the dictionaries read from earlier messages with `flight_data_to_dictionary`.
 pub fn flight_data_to_arrow_batch(
     data: &FlightData,
     schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
 ) -> Option<Result<RecordBatch>> {
     // check that the data_header is a record batch message
     let message = arrow::ipc::get_root_as_message(&data.data_header[..]);
-    let dictionaries_by_field = Vec::new();
 
     message
         .header_as_record_batch()
@@ -148,10 +160,89 @@ pub fn flight_data_to_arrow_batch(
                     &data.data_body,
                     batch,
                     schema,
-                    &dictionaries_by_field,
+                    dictionaries_by_id,
                 ))
             },
         )
 }
 
+/// Read the dictionary of a FlightData message into `dictionaries_by_id`
+///
+/// Returns an error if the `FlightData` header is not a dictionary batch of a
+/// dictionary in `schema`
+pub fn flight_data_to_dictionary(
+    data: &FlightData,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+) -> Result<()> {
+    // check that the data_header is a dictionary batch message
+    let message = arrow::ipc::get_root_as_message(&data.data_header[..]);
+    let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+        ArrowError::ParseError(
+            "Unable to convert flight data header to a dictionary batch".to_string(),
+        )
+    })?;
+    reader::read_dictionary_batch(&data.data_body, batch, schema, dictionaries_by_id)
+}
+
 // TODO: add more explicit conversion that exposes flight descriptor and metadata options
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use arrow::array::{Array, DictionaryArray, Int32Array};
+    use arrow::datatypes::{DataType, Field, Int8Type};
+
+    #[test]
+    fn test_dictionary_round_trip() {
+        let dictionary: DictionaryArray<Int8Type> =
+            vec!["a", "b", "a", "c"].into_iter().collect();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dict("dict", dictionary.data_type().clone(), false, 0, false),
+            Field::new("int", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(dictionary) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            ],
+        )
+        .unwrap();
+
+        let mut tracker = DictionaryTracker::new(false);
+        let options = IpcWriteOptions::default();
+        let (dictionaries, data) =
+            flight_data_from_arrow_batch(&batch, &mut tracker, &options).unwrap();
+        assert_eq!(1, dictionaries.len());
+
+        // the dictionary is only sent once
+        let (resent, _) =
+            flight_data_from_arrow_batch(&batch, &mut tracker, &options).unwrap();
+        assert!(resent.is_empty());
+
+        // the batch cannot be read without its dictionary
+        assert!(
+            flight_data_to_arrow_batch(&data, schema.clone(), &HashMap::new())
+                .unwrap()
+                .is_err()
+        );
+        assert!(flight_data_to_dictionary(&data, &schema, &mut HashMap::new()).is_err());
+
+        let mut dictionaries_by_id = HashMap::new();
+        for dictionary in &dictionaries {
+            flight_data_to_dictionary(dictionary, &schema, &mut dictionaries_by_id)
+                .unwrap();
+        }
+        let read = flight_data_to_arrow_batch(&data, schema, &dictionaries_by_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.num_rows(), read.num_rows());
+        for (read, written) in read.columns().iter().zip(batch.columns()) {
+            assert!(read.equals(written.as_ref()));
+        }
+    }
+}
@@ -36,6 +36,7 @@ use crate::datatypes::*;
 use crate::memory;
 use crate::{
     error::{ArrowError, Result},
+    ffi::{self, FFI_ArrowArray, FFI_ArrowSchema},
     util::bit_util,
 };
 
@@ -225,11 +226,43 @@ pub trait Array: fmt::Debug + Send + Sync + ArrayEqual + JsonEqual {
 
     /// Returns the total number of bytes of memory occupied physically by this array.
     fn get_array_memory_size(&self) -> usize;
+
+    /// Exports this array through the C Data Interface into the structs pointed to by
+    /// `array` and `schema`, without copying its buffers. The consumer is responsible
+    /// for releasing them.
+    ///
+    /// # Safety
+    ///
+    /// Both pointers must be valid for writes. Their previous contents are overwritten
+    /// without being released.
+    unsafe fn to_raw(
+        &self,
+        array: *mut FFI_ArrowArray,
+        schema: *mut FFI_ArrowSchema,
+    ) -> Result<()> {
+        ffi::ArrowArray::try_new(self.data_ref())?.into_raw(array, schema);
+        Ok(())
+    }
 }
 
 /// A reference-counted reference to a generic `Array`.
 pub type ArrayRef = Arc<Array>;
 
+/// Constructs an array from the structs of the C Data Interface pointed to by `array`
+/// and `schema`, without copying its buffers. The structs are moved into the array,
+/// and are marked as released.
+///
+/// # Safety
+///
+/// Both pointers must point to valid structs, e.g. ones filled by a producer.
+pub unsafe fn make_array_from_raw(
+    array: *mut FFI_ArrowArray,
+    schema: *mut FFI_ArrowSchema,
+) -> Result<ArrayRef> {
+    let array = ffi::ArrowArray::try_from_raw(array, schema)?;
+    Ok(make_array(array.into_data()?))
+}
+
 /// Constructs an array using the input `data`.
 /// Returns a reference-counted `Array` instance.
 pub fn make_array(data: ArrayDataRef) -> ArrayRef {
@@ -243,7 +276,7 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
         DataType::UInt16 => Arc::new(UInt16Array::from(data)) as ArrayRef,
         DataType::UInt32 => Arc::new(UInt32Array::from(data)) as ArrayRef,
         DataType::UInt64 => Arc::new(UInt64Array::from(data)) as ArrayRef,
-        DataType::Float16 => panic!("Float16 datatype not supported"),
+        DataType::Float16 => Arc::new(Float16Array::from(data)) as ArrayRef,
         DataType::Float32 => Arc::new(Float32Array::from(data)) as ArrayRef,
         DataType::Float64 => Arc::new(Float64Array::from(data)) as ArrayRef,
         DataType::Date32(DateUnit::Day) => Arc::new(Date32Array::from(data)) as ArrayRef,
@@ -297,6 +330,8 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
         DataType::FixedSizeBinary(_) => {
             Arc::new(FixedSizeBinaryArray::from(data)) as ArrayRef
         }
+        DataType::Decimal(_, _) => Arc::new(DecimalArray::from(data)) as ArrayRef,
+        DataType::Map(_, _) => Arc::new(MapArray::from(data)) as ArrayRef,
         DataType::Utf8 => Arc::new(StringArray::from(data)) as ArrayRef,
         DataType::LargeUtf8 => Arc::new(LargeStringArray::from(data)) as ArrayRef,
         DataType::List(_) => Arc::new(ListArray::from(data)) as ArrayRef,
@@ -338,6 +373,22 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
     }
 }
 
+/// Creates an array of type `data_type` where all `length` slots are null.
+///
+/// # Example
+///
+/// ```
+/// use arrow::array::{new_null_array, Array};
+/// use arrow::datatypes::DataType;
+///
+/// let array = new_null_array(&DataType::Utf8, 3);
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.null_count(), 3);
+/// ```
+pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
+    make_array(Arc::new(ArrayData::new_null(data_type, length)))
+}
+
 /// Creates a zero-copy slice of the array's data.
 ///
 /// # Panics
@@ -730,6 +781,7 @@ def_numeric_from_vec!(UInt8Type);
 def_numeric_from_vec!(UInt16Type);
 def_numeric_from_vec!(UInt32Type);
 def_numeric_from_vec!(UInt64Type);
+def_numeric_from_vec!(Float16Type);
 def_numeric_from_vec!(Float32Type);
 def_numeric_from_vec!(Float64Type);
 
@@ -1059,6 +1111,155 @@ pub type ListArray = GenericListArray<i32>;
 /// type whose memory offsets between elements are represented by a i64.
 pub type LargeListArray = GenericListArray<i64>;
 
+/// A nested array type where each element is a variable-sized collection of key-value
+/// pairs. It is laid out as a `ListArray` of `entries` structs, whose first child holds
+/// the keys and whose second child holds the values.
+pub struct MapArray {
+    data: ArrayDataRef,
+    entries: ArrayRef,
+    keys: ArrayRef,
+    values: ArrayRef,
+    value_offsets: RawPtrBox<i32>,
+}
+
+impl MapArray {
+    /// Returns a reference to the `entries` struct array of this map.
+    pub fn entries(&self) -> ArrayRef {
+        self.entries.clone()
+    }
+
+    /// Returns a reference to the keys of this map.
+    pub fn keys(&self) -> ArrayRef {
+        self.keys.clone()
+    }
+
+    /// Returns a reference to the values of this map.
+    pub fn values(&self) -> ArrayRef {
+        self.values.clone()
+    }
+
+    /// Returns a clone of the key type of this map.
+    pub fn key_type(&self) -> DataType {
+        self.keys.data_ref().data_type().clone()
+    }
+
+    /// Returns a clone of the value type of this map.
+    pub fn value_type(&self) -> DataType {
+        self.values.data_ref().data_type().clone()
+    }
+
+    /// Returns ith value of this map array as a `StructArray` of key-value pairs.
+    pub fn value(&self, i: usize) -> ArrayRef {
+        self.entries
+            .slice(self.value_offset(i) as usize, self.value_length(i) as usize)
+    }
+
+    /// Returns the offset for value at index `i`.
+    ///
+    /// Note this doesn't do any bound checking, for performance reason.
+    #[inline]
+    pub fn value_offset(&self, i: usize) -> i32 {
+        self.value_offset_at(self.data.offset() + i)
+    }
+
+    /// Returns the number of entries of the map at index `i`.
+    ///
+    /// Note this doesn't do any bound checking, for performance reason.
+    #[inline]
+    pub fn value_length(&self, mut i: usize) -> i32 {
+        i += self.data.offset();
+        self.value_offset_at(i + 1) - self.value_offset_at(i)
+    }
+
+    #[inline]
+    fn value_offset_at(&self, i: usize) -> i32 {
+        unsafe { *self.value_offsets.get().add(i) }
+    }
+}
+
+impl From<ArrayDataRef> for MapArray {
+    fn from(data: ArrayDataRef) -> Self {
+        assert_eq!(
+            data.buffers().len(),
+            1,
+            "MapArray data should contain a single buffer only (value offsets)"
+        );
+        assert_eq!(
+            data.child_data().len(),
+            1,
+            "MapArray should contain a single child array (entries array)"
+        );
+        let entries = make_array(data.child_data()[0].clone());
+        let (keys, values) = {
+            let entries = entries
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .expect("MapArray entries should be a struct array");
+            assert_eq!(
+                entries.num_columns(),
+                2,
+                "MapArray entries should contain two children (keys and values)"
+            );
+            (entries.column(0).clone(), entries.column(1).clone())
+        };
+        let raw_value_offsets = data.buffers()[0].raw_data();
+        let value_offsets: *const i32 = as_aligned_pointer(raw_value_offsets);
+        unsafe {
+            assert!(
+                *value_offsets.offset(0) == 0,
+                "offsets do not start at zero"
+            );
+        }
+        Self {
+            data,
+            entries,
+            keys,
+            values,
+            value_offsets: RawPtrBox::new(value_offsets),
+        }
+    }
+}
+
+impl Array for MapArray {
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn data(&self) -> ArrayDataRef {
+        self.data.clone()
+    }
+
+    fn data_ref(&self) -> &ArrayDataRef {
+        &self.data
+    }
+
+    /// Returns the total number of bytes of memory occupied by the buffers owned by this [MapArray].
+    fn get_buffer_memory_size(&self) -> usize {
+        self.data.get_buffer_memory_size()
+    }
+
+    /// Returns the total number of bytes of memory occupied physically by this [MapArray].
+    fn get_array_memory_size(&self) -> usize {
+        self.data.get_array_memory_size() + mem::size_of_val(self)
+    }
+}
+
+impl fmt::Debug for MapArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MapArray\n[\n")?;
+        print_long_array(self, f, |array, index, f| {
+            fmt::Debug::fmt(&array.value(index), f)
+        })?;
+        write!(f, "]")
+    }
+}
+
+impl ListArrayOps<i32> for MapArray {
+    fn value_offset_at(&self, i: usize) -> i32 {
+        self.value_offset_at(i)
+    }
+}
+
 /// A list array where each element is a fixed-size sequence of values with the same
 /// type whose maximum length is represented by a i32.
 pub struct FixedSizeListArray {
@@ -1862,6 +2063,195 @@ impl Array for FixedSizeBinaryArray {
     }
 }
 
+/// An array where each element is a 128-bit decimal with a fixed precision and
+/// scale.
+///
+/// Values are stored as 16-byte little-endian two's complement integers.
+pub struct DecimalArray {
+    data: ArrayDataRef,
+    value_data: RawPtrBox<u8>,
+    precision: usize,
+    scale: usize,
+    length: i32,
+}
+
+impl DecimalArray {
+    /// Returns the element at index `i` as an `i128`.
+    pub fn value(&self, i: usize) -> i128 {
+        assert!(i < self.data.len(), "DecimalArray out of bounds access");
+        let offset = i.checked_add(self.data.offset()).unwrap();
+        let raw_val = unsafe {
+            let pos = self.value_offset_at(offset);
+            std::slice::from_raw_parts(
+                self.value_data.get().offset(pos as isize),
+                (self.value_offset_at(offset + 1) - pos) as usize,
+            )
+        };
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(raw_val);
+        i128::from_le_bytes(bytes)
+    }
+
+    /// Returns the element at index `i` formatted as a decimal string,
+    /// e.g. `123.45` for the value `12345` with a scale of 2.
+    pub fn value_as_string(&self, i: usize) -> String {
+        format_decimal_str(self.value(i), self.scale)
+    }
+
+    /// Returns the offset for the element at index `i`.
+    ///
+    /// Note this doesn't do any bound checking, for performance reason.
+    #[inline]
+    pub fn value_offset(&self, i: usize) -> i32 {
+        self.value_offset_at(self.data.offset() + i)
+    }
+
+    /// Returns the length for an element.
+    ///
+    /// All elements have the same length as the array is a fixed size.
+    #[inline]
+    pub fn value_length(&self) -> i32 {
+        self.length
+    }
+
+    /// Returns a clone of the value data buffer
+    pub fn value_data(&self) -> Buffer {
+        self.data.buffers()[0].clone()
+    }
+
+    #[inline]
+    fn value_offset_at(&self, i: usize) -> i32 {
+        self.length * i as i32
+    }
+
+    /// Creates a `DecimalArray` from a `FixedSizeList<u8>` array of 16 byte values
+    pub fn from_fixed_size_list_array(
+        v: FixedSizeListArray,
+        precision: usize,
+        scale: usize,
+    ) -> Self {
+        assert_eq!(
+            v.data_ref().child_data()[0].child_data().len(),
+            0,
+            "DecimalArray can only be created from list array of u8 values \
+             (i.e. FixedSizeList<PrimitiveArray<u8>>)."
+        );
+        assert_eq!(
+            v.data_ref().child_data()[0].data_type(),
+            &DataType::UInt8,
+            "DecimalArray can only be created from FixedSizeList<u8> arrays, mismatched data types."
+        );
+        assert_eq!(
+            v.value_length(),
+            16,
+            "DecimalArray can only be created from FixedSizeList<u8> arrays with a value length of 16."
+        );
+
+        let mut builder = ArrayData::builder(DataType::Decimal(precision, scale))
+            .len(v.len())
+            .add_buffer(v.data_ref().child_data()[0].buffers()[0].clone());
+        if let Some(bitmap) = v.data_ref().null_bitmap() {
+            builder = builder
+                .null_count(v.data_ref().null_count())
+                .null_bit_buffer(bitmap.bits.clone())
+        }
+
+        let data = builder.build();
+        Self::from(data)
+    }
+
+    /// Returns the decimal precision of this array
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Returns the decimal scale of this array
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+}
+
+/// Formats an unscaled decimal `value` with `scale` digits after the decimal point.
+pub(crate) fn format_decimal_str(value: i128, scale: usize) -> String {
+    let digits = value.abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = if digits.len() <= scale {
+        format!("{}{}", "0".repeat(scale - digits.len() + 1), digits)
+    } else {
+        digits
+    };
+    let (integral, fractional) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integral, fractional)
+}
+
+impl ListArrayOps<i32> for DecimalArray {
+    fn value_offset_at(&self, i: usize) -> i32 {
+        self.value_offset_at(i)
+    }
+}
+
+impl From<ArrayDataRef> for DecimalArray {
+    fn from(data: ArrayDataRef) -> Self {
+        assert_eq!(
+            data.buffers().len(),
+            1,
+            "DecimalArray data should contain 1 buffer only (values)"
+        );
+        let value_data = data.buffers()[0].raw_data();
+        let (precision, scale) = match data.data_type() {
+            DataType::Decimal(precision, scale) => (*precision, *scale),
+            _ => panic!("Expected data type to be Decimal"),
+        };
+        let length = 16;
+        Self {
+            data,
+            value_data: RawPtrBox::new(value_data),
+            precision,
+            scale,
+            length,
+        }
+    }
+}
+
+impl fmt::Debug for DecimalArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DecimalArray<{}, {}>\n[\n", self.precision, self.scale)?;
+        print_long_array(self, f, |array, index, f| {
+            let formatted_decimal = array.value_as_string(index);
+
+            write!(f, "{}", formatted_decimal)
+        })?;
+        write!(f, "]")
+    }
+}
+
+impl Array for DecimalArray {
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn data(&self) -> ArrayDataRef {
+        self.data.clone()
+    }
+
+    fn data_ref(&self) -> &ArrayDataRef {
+        &self.data
+    }
+
+    /// Returns the total number of bytes of memory occupied by the buffers owned by this [DecimalArray].
+    fn get_buffer_memory_size(&self) -> usize {
+        self.data.get_buffer_memory_size()
+    }
+
+    /// Returns the total number of bytes of memory occupied physically by this [DecimalArray].
+    fn get_array_memory_size(&self) -> usize {
+        self.data.get_array_memory_size() + mem::size_of_val(self)
+    }
+}
+
 /// A nested array type where each child (called *field*) is represented by a separate
 /// array.
 pub struct StructArray {
@@ -2983,6 +3373,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_map_array() {
+        // Construct the keys and values of the entries
+        let keys_data = ArrayData::builder(DataType::Int32)
+            .len(8)
+            .add_buffer(Buffer::from(&[0, 1, 2, 3, 4, 5, 6, 7].to_byte_slice()))
+            .build();
+        let values_data = ArrayData::builder(DataType::UInt32)
+            .len(8)
+            .add_buffer(Buffer::from(
+                &[0u32, 10, 20, 30, 40, 50, 60, 70].to_byte_slice(),
+            ))
+            .build();
+
+        // Construct a buffer for value offsets, for the nested array:
+        //  [{0: 0, 1: 10, 2: 20}, {3: 30, 4: 40, 5: 50}, {6: 60, 7: 70}]
+        let entry_offsets = Buffer::from(&[0, 3, 6, 8].to_byte_slice());
+
+        let keys_field = Field::new("key", DataType::Int32, false);
+        let values_field = Field::new("value", DataType::UInt32, true);
+        let entry_struct = StructArray::from(vec![
+            (keys_field.clone(), make_array(keys_data.clone())),
+            (values_field.clone(), make_array(values_data.clone())),
+        ]);
+
+        // Construct a map array from the above two
+        let map_data_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![keys_field, values_field]),
+                false,
+            )),
+            false,
+        );
+        let map_data = ArrayData::builder(map_data_type.clone())
+            .len(3)
+            .add_buffer(entry_offsets.clone())
+            .add_child_data(entry_struct.data())
+            .build();
+        let map_array = MapArray::from(map_data);
+
+        assert_eq!(keys_data, map_array.keys().data());
+        assert_eq!(values_data, map_array.values().data());
+        assert_eq!(DataType::Int32, map_array.key_type());
+        assert_eq!(DataType::UInt32, map_array.value_type());
+        assert_eq!(3, map_array.len());
+        assert_eq!(0, map_array.null_count());
+        assert_eq!(6, map_array.value_offset(2));
+        assert_eq!(2, map_array.value_length(2));
+
+        let first = map_array.value(0);
+        let first = first.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(3, first.len());
+        assert_eq!(
+            &Int32Array::from(vec![0, 1, 2]),
+            first
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+        );
+        assert_eq!(
+            &UInt32Array::from(vec![0, 10, 20]),
+            first
+                .column(1)
+                .as_any()
+                .downcast_ref::<UInt32Array>()
+                .unwrap()
+        );
+        for i in 0..3 {
+            assert!(map_array.is_valid(i));
+            assert!(!map_array.is_null(i));
+        }
+
+        // Now test with a non-zero offset
+        let map_data = ArrayData::builder(map_data_type)
+            .len(2)
+            .offset(1)
+            .add_buffer(entry_offsets)
+            .add_child_data(entry_struct.data())
+            .build();
+        let map_array = MapArray::from(map_data);
+
+        assert_eq!(2, map_array.len());
+        assert_eq!(6, map_array.value_offset(1));
+        assert_eq!(2, map_array.value_length(1));
+
+        let first = map_array.value(0);
+        let first = first.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(
+            &Int32Array::from(vec![3, 4, 5]),
+            first
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "MapArray entries should contain two children (keys and values)"
+    )]
+    fn test_map_array_invalid_entries() {
+        let keys_data = ArrayData::builder(DataType::Int32)
+            .len(2)
+            .add_buffer(Buffer::from(&[0, 1].to_byte_slice()))
+            .build();
+        let entry_struct = StructArray::from(vec![(
+            Field::new("key", DataType::Int32, false),
+            make_array(keys_data),
+        )]);
+        let map_data = ArrayData::builder(DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                entry_struct.data_type().clone(),
+                false,
+            )),
+            false,
+        ))
+        .len(1)
+        .add_buffer(Buffer::from(&[0, 2].to_byte_slice()))
+        .add_child_data(entry_struct.data())
+        .build();
+        MapArray::from(map_data);
+    }
+
     #[test]
     fn test_large_list_array() {
         // Construct a value array
@@ -3852,6 +4369,57 @@ mod tests {
         FixedSizeBinaryArray::from(list_array);
     }
 
+    #[test]
+    fn test_decimal_array() {
+        // 8_887_000_000 and -8_887_000_000 as little-endian i128
+        let values: [u8; 32] = [
+            192, 219, 180, 17, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 36, 75, 238, 253,
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+        ];
+        let array_data = ArrayData::builder(DataType::Decimal(23, 6))
+            .len(2)
+            .add_buffer(Buffer::from(&values[..]))
+            .build();
+        let decimal_array = DecimalArray::from(array_data);
+        assert_eq!(8_887_000_000, decimal_array.value(0));
+        assert_eq!(-8_887_000_000, decimal_array.value(1));
+        assert_eq!("8887.000000", decimal_array.value_as_string(0));
+        assert_eq!("-8887.000000", decimal_array.value_as_string(1));
+        assert_eq!(16, decimal_array.value_length());
+        assert_eq!(23, decimal_array.precision());
+        assert_eq!(6, decimal_array.scale());
+    }
+
+    #[test]
+    fn test_decimal_array_value_as_string() {
+        let mut builder = DecimalBuilder::new(5, 6, 3);
+        for value in &[123456, -123456, 5, -5, 0] {
+            builder.append_value(*value).unwrap();
+        }
+        let arr = builder.finish();
+        assert_eq!("123.456", arr.value_as_string(0));
+        assert_eq!("-123.456", arr.value_as_string(1));
+        assert_eq!("0.005", arr.value_as_string(2));
+        assert_eq!("-0.005", arr.value_as_string(3));
+        assert_eq!("0.000", arr.value_as_string(4));
+    }
+
+    #[test]
+    fn test_decimal_array_fmt_debug() {
+        let values: Vec<i128> = vec![8887000000, -8887000000];
+        let mut decimal_builder = DecimalBuilder::new(3, 23, 6);
+
+        values.iter().for_each(|&value| {
+            decimal_builder.append_value(value).unwrap();
+        });
+        decimal_builder.append_null().unwrap();
+        let arr = decimal_builder.finish();
+        assert_eq!(
+            "DecimalArray<23, 6>\n[\n  8887.000000,\n  -8887.000000,\n  null,\n]",
+            format!("{:?}", arr)
+        );
+    }
+
     #[test]
     #[should_panic(expected = "BinaryArray out of bounds access")]
     fn test_binary_array_get_value_index_out_of_bound() {
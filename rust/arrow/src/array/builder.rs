@@ -32,6 +32,7 @@ use crate::array::*;
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::memory::MemoryPool;
 use crate::util::bit_util;
 
 ///  Converts a `MutableBuffer` to a `BufferBuilder<T>`.
@@ -255,21 +256,7 @@ pub trait BufferBuilderTrait<T: ArrowPrimitiveType> {
 impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
     #[inline]
     fn new(capacity: usize) -> Self {
-        let buffer = if T::DATA_TYPE == DataType::Boolean {
-            let byte_capacity = bit_util::ceil(capacity, 8);
-            let actual_capacity = bit_util::round_upto_multiple_of_64(byte_capacity);
-            let mut buffer = MutableBuffer::new(actual_capacity);
-            buffer.set_null_bits(0, actual_capacity);
-            buffer
-        } else {
-            MutableBuffer::new(capacity * mem::size_of::<T::Native>())
-        };
-
-        Self {
-            buffer,
-            len: 0,
-            _marker: PhantomData,
-        }
+        Self::from_empty_buffer(MutableBuffer::new(Self::byte_capacity(capacity)))
     }
 
     fn len(&self) -> usize {
@@ -382,12 +369,14 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
             // `append` does not update the buffer's `len` so do it before `freeze` is called.
             let new_buffer_len = bit_util::ceil(self.len, 8);
             debug_assert!(new_buffer_len >= self.buffer.len());
-            let mut buf = std::mem::replace(&mut self.buffer, MutableBuffer::new(0));
+            let empty = self.buffer.new_empty_like();
+            let mut buf = std::mem::replace(&mut self.buffer, empty);
             self.len = 0;
             buf.resize(new_buffer_len).unwrap();
             buf.freeze()
         } else {
-            let buf = std::mem::replace(&mut self.buffer, MutableBuffer::new(0));
+            let empty = self.buffer.new_empty_like();
+            let buf = std::mem::replace(&mut self.buffer, empty);
             self.len = 0;
             buf.freeze()
         }
@@ -395,6 +384,38 @@ impl<T: ArrowPrimitiveType> BufferBuilderTrait<T> for BufferBuilder<T> {
 }
 
 impl<T: ArrowPrimitiveType> BufferBuilder<T> {
+    /// Creates a new builder with initial capacity for _at least_ `capacity`
+    /// elements, whose memory is allocated from `pool`.
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(capacity: usize, pool: Arc<MemoryPool>) -> Result<Self> {
+        let buffer = MutableBuffer::try_new_in(Self::byte_capacity(capacity), pool)?;
+        Ok(Self::from_empty_buffer(buffer))
+    }
+
+    /// Returns the number of bytes needed to hold `capacity` elements
+    fn byte_capacity(capacity: usize) -> usize {
+        if T::DATA_TYPE == DataType::Boolean {
+            let byte_capacity = bit_util::ceil(capacity, 8);
+            bit_util::round_upto_multiple_of_64(byte_capacity)
+        } else {
+            capacity * mem::size_of::<T::Native>()
+        }
+    }
+
+    /// Creates a new builder writing into the empty `buffer`
+    fn from_empty_buffer(mut buffer: MutableBuffer) -> Self {
+        if T::DATA_TYPE == DataType::Boolean {
+            let capacity = buffer.capacity();
+            buffer.set_null_bits(0, capacity);
+        }
+        Self {
+            buffer,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
     /// Writes a byte slice to the underlying buffer and updates the `len`, i.e. the
     /// number array elements in the builder.  Also, converts the `io::Result`
     /// required by the `Write` trait to the Arrow `Result` type.
@@ -559,6 +580,16 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         }
     }
 
+    /// Creates a new primitive array builder whose memory is allocated from `pool`
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(capacity: usize, pool: Arc<MemoryPool>) -> Result<Self> {
+        Ok(Self {
+            values_builder: BufferBuilder::<T>::try_new_in(capacity, pool.clone())?,
+            bitmap_builder: BooleanBufferBuilder::try_new_in(capacity, pool)?,
+        })
+    }
+
     /// Returns the capacity of this builder measured in slots of type `T`
     pub fn capacity(&self) -> usize {
         self.values_builder.capacity()
@@ -676,6 +707,27 @@ impl<T: ArrayBuilder> ListBuilder<T> {
             len: 0,
         }
     }
+
+    /// Creates a new `ListArrayBuilder` from a given values array builder, whose
+    /// offsets and null bitmap are allocated from `pool`
+    /// `capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        values_builder: T,
+        capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let mut offsets_builder =
+            Int32BufferBuilder::try_new_in(capacity + 1, pool.clone())?;
+        offsets_builder.append(0)?;
+        Ok(Self {
+            offsets_builder,
+            bitmap_builder: BooleanBufferBuilder::try_new_in(capacity, pool)?,
+            values_builder,
+            len: 0,
+        })
+    }
 }
 
 impl<T: ArrayBuilder> ArrayBuilder for ListBuilder<T>
@@ -881,6 +933,27 @@ impl<T: ArrayBuilder> LargeListBuilder<T> {
             len: 0,
         }
     }
+
+    /// Creates a new `LargeListArrayBuilder` from a given values array builder, whose
+    /// offsets and null bitmap are allocated from `pool`
+    /// `capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        values_builder: T,
+        capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let mut offsets_builder =
+            Int64BufferBuilder::try_new_in(capacity + 1, pool.clone())?;
+        offsets_builder.append(0)?;
+        Ok(Self {
+            offsets_builder,
+            bitmap_builder: BooleanBufferBuilder::try_new_in(capacity, pool)?,
+            values_builder,
+            len: 0,
+        })
+    }
 }
 
 impl<T: ArrayBuilder> ArrayBuilder for LargeListBuilder<T>
@@ -1089,6 +1162,26 @@ impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
             list_len: length,
         }
     }
+
+    /// Creates a new `FixedSizeListBuilder` from a given values array builder, whose
+    /// null bitmap is allocated from `pool`
+    /// `length` is the number of values within each array
+    /// `capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        values_builder: T,
+        length: i32,
+        capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        Ok(Self {
+            bitmap_builder: BooleanBufferBuilder::try_new_in(capacity, pool)?,
+            values_builder,
+            len: 0,
+            list_len: length,
+        })
+    }
 }
 
 impl<T: ArrayBuilder> ArrayBuilder for FixedSizeListBuilder<T>
@@ -1244,6 +1337,301 @@ where
     }
 }
 
+/// The names of the child fields of a `MapArray` produced by `MapBuilder`
+#[derive(Debug, Clone)]
+pub struct MapFieldNames {
+    /// Name of the `entries` struct field
+    pub entry: String,
+    /// Name of the key field within `entries`
+    pub key: String,
+    /// Name of the value field within `entries`
+    pub value: String,
+}
+
+impl Default for MapFieldNames {
+    fn default() -> Self {
+        Self {
+            entry: "entries".to_string(),
+            key: "key".to_string(),
+            value: "value".to_string(),
+        }
+    }
+}
+
+///  Array builder for `MapArray`
+#[derive(Debug)]
+pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
+    offsets_builder: Int32BufferBuilder,
+    bitmap_builder: BooleanBufferBuilder,
+    field_names: MapFieldNames,
+    key_builder: K,
+    value_builder: V,
+    len: usize,
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
+    /// Creates a new `MapBuilder` from the given key and value array builders.
+    ///
+    /// If `field_names` is `None`, the child fields are named `entries`, `key` and
+    /// `value`.
+    pub fn new(
+        field_names: Option<MapFieldNames>,
+        key_builder: K,
+        value_builder: V,
+    ) -> Self {
+        let capacity = key_builder.len();
+        Self::with_capacity(field_names, key_builder, value_builder, capacity)
+    }
+
+    /// Creates a new `MapBuilder` from the given key and value array builders.
+    /// `capacity` is the number of maps to pre-allocate space for in this builder
+    pub fn with_capacity(
+        field_names: Option<MapFieldNames>,
+        key_builder: K,
+        value_builder: V,
+        capacity: usize,
+    ) -> Self {
+        let mut offsets_builder = Int32BufferBuilder::new(capacity + 1);
+        offsets_builder.append(0).unwrap();
+        Self {
+            offsets_builder,
+            bitmap_builder: BooleanBufferBuilder::new(capacity),
+            field_names: field_names.unwrap_or_default(),
+            key_builder,
+            value_builder,
+            len: 0,
+        }
+    }
+
+    /// Creates a new `MapBuilder` from the given key and value array builders, whose
+    /// offsets and null bitmap are allocated from `pool`
+    /// `capacity` is the number of maps to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        field_names: Option<MapFieldNames>,
+        key_builder: K,
+        value_builder: V,
+        capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let mut offsets_builder =
+            Int32BufferBuilder::try_new_in(capacity + 1, pool.clone())?;
+        offsets_builder.append(0)?;
+        Ok(Self {
+            offsets_builder,
+            bitmap_builder: BooleanBufferBuilder::try_new_in(capacity, pool)?,
+            field_names: field_names.unwrap_or_default(),
+            key_builder,
+            value_builder,
+            len: 0,
+        })
+    }
+
+    /// Returns the `entries` field of the map type built by this builder
+    fn entries_field(&self) -> Field {
+        Field::new(
+            &self.field_names.entry,
+            DataType::Struct(vec![
+                Field::new(&self.field_names.key, self.key_builder.data_type(), false),
+                Field::new(
+                    &self.field_names.value,
+                    self.value_builder.data_type(),
+                    true,
+                ),
+            ]),
+            false,
+        )
+    }
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V>
+where
+    K: 'static,
+    V: 'static,
+{
+    /// Returns the builder as a non-mutable `Any` reference.
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    /// Appends data from other arrays into the builder
+    ///
+    /// This is most useful when concatenating arrays of the same type into a builder.
+    fn append_data(&mut self, data: &[ArrayDataRef]) -> Result<()> {
+        // validate arraydata and reserve memory
+        let mut total_len = 0;
+        for array in data {
+            if array.data_type() != &self.data_type() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Cannot append data to builder if data types are different"
+                        .to_string(),
+                ));
+            }
+            if array.buffers().len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Map arrays should have 1 buffer".to_string(),
+                ));
+            }
+            if array.child_data().len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Map arrays should have 1 child_data element".to_string(),
+                ));
+            }
+            total_len += array.len();
+        }
+        // reserve memory
+        self.offsets_builder.reserve(total_len)?;
+        self.bitmap_builder.reserve(total_len)?;
+
+        // peek into buffer to get last appended offset
+        let mut cum_offset = {
+            let buffer = self.offsets_builder.buffer.data();
+            let len = self.offsets_builder.len();
+            let (start, end) = ((len - 1) * 4, len * 4);
+            i32::from_le_bytes(buffer[start..end].try_into().unwrap())
+        };
+        for array in data {
+            let len = array.len();
+            if len == 0 {
+                continue;
+            }
+            let offset = array.offset();
+
+            // `typed_data` is unsafe, however this call is safe as `MapArray` has i32 offsets
+            let offsets = unsafe {
+                &array.buffers()[0].typed_data::<i32>()[offset..(len + offset) + 1]
+            };
+            let offset_at_len = offsets[offsets.len() - 1] as usize;
+            let first_offset = offsets[0] as usize;
+            // slice the entries to account for offsets, which also slices its children
+            let entries = make_array(array.child_data()[0].clone())
+                .slice(first_offset, offset_at_len - first_offset);
+            let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+            self.key_builder.append_data(&[entries.column(0).data()])?;
+            self.value_builder
+                .append_data(&[entries.column(1).data()])?;
+            let adjusted_offsets: Vec<i32> = offsets
+                .windows(2)
+                .map(|w| {
+                    let curr_offset = w[1] - w[0] + cum_offset;
+                    cum_offset = curr_offset;
+                    curr_offset
+                })
+                .collect();
+            self.offsets_builder
+                .append_slice(adjusted_offsets.as_slice())?;
+
+            for i in 0..len {
+                // account for offset as `ArrayData` does not
+                self.bitmap_builder.append(array.is_valid(offset + i))?;
+            }
+        }
+
+        self.len += total_len;
+        Ok(())
+    }
+
+    /// Returns the data type of the builder
+    ///
+    /// This is used for validating array data types in `append_data`
+    fn data_type(&self) -> DataType {
+        DataType::Map(Box::new(self.entries_field()), false)
+    }
+
+    /// Returns the builder as a mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<Any> {
+        self
+    }
+
+    /// Returns the number of array slots in the builder
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the number of array slots is zero
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+}
+
+impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V>
+where
+    K: 'static,
+    V: 'static,
+{
+    /// Returns the key array builder as a mutable reference.
+    pub fn keys(&mut self) -> &mut K {
+        &mut self.key_builder
+    }
+
+    /// Returns the value array builder as a mutable reference.
+    pub fn values(&mut self) -> &mut V {
+        &mut self.value_builder
+    }
+
+    /// Finish the current map array slot
+    ///
+    /// Returns an error if the key and value builders have different lengths.
+    pub fn append(&mut self, is_valid: bool) -> Result<()> {
+        if self.key_builder.len() != self.value_builder.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot append to a map builder when its keys and values have unequal lengths of {} and {}",
+                self.key_builder.len(),
+                self.value_builder.len()
+            )));
+        }
+        self.offsets_builder.append(self.key_builder.len() as i32)?;
+        self.bitmap_builder.append(is_valid)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Builds the `MapArray` and reset this builder.
+    pub fn finish(&mut self) -> MapArray {
+        let len = self.len();
+        self.len = 0;
+        let entries_field = self.entries_field();
+        let (key_field, value_field) = match entries_field.data_type() {
+            DataType::Struct(fields) => (fields[0].clone(), fields[1].clone()),
+            _ => unreachable!("Map entries should be a struct"),
+        };
+
+        let keys_arr = self.key_builder.finish();
+        assert_eq!(
+            keys_arr.null_count(),
+            0,
+            "Keys of a MapArray must not be null"
+        );
+        let values_arr = self.value_builder.finish();
+        let entries =
+            StructArray::from(vec![(key_field, keys_arr), (value_field, values_arr)]);
+
+        let offset_buffer = self.offsets_builder.finish();
+        let null_bit_buffer = self.bitmap_builder.finish();
+        self.offsets_builder.append(0).unwrap();
+        let data = ArrayData::builder(DataType::Map(Box::new(entries_field), false))
+            .len(len)
+            .null_count(len - bit_util::count_set_bits(null_bit_buffer.data()))
+            .add_buffer(offset_buffer)
+            .add_child_data(entries.data())
+            .null_bit_buffer(null_bit_buffer)
+            .build();
+
+        MapArray::from(data)
+    }
+}
+
 ///  Array builder for `BinaryArray`
 #[derive(Debug)]
 pub struct BinaryBuilder {
@@ -1270,6 +1658,13 @@ pub struct FixedSizeBinaryBuilder {
     builder: FixedSizeListBuilder<UInt8Builder>,
 }
 
+#[derive(Debug)]
+pub struct DecimalBuilder {
+    builder: FixedSizeListBuilder<UInt8Builder>,
+    precision: usize,
+    scale: usize,
+}
+
 impl ArrayBuilder for BinaryBuilder {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &Any {
@@ -1641,6 +2036,89 @@ impl ArrayBuilder for FixedSizeBinaryBuilder {
     }
 }
 
+impl ArrayBuilder for DecimalBuilder {
+    /// Returns the builder as a non-mutable `Any` reference.
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    /// Appends data from other arrays into the builder
+    ///
+    /// This is most useful when concatenating arrays of the same type into a builder.
+    fn append_data(&mut self, data: &[ArrayDataRef]) -> Result<()> {
+        // validate arraydata and reserve memory
+        for array in data {
+            if array.data_type() != &self.data_type() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Cannot append data to builder if data types are different"
+                        .to_string(),
+                ));
+            }
+            if array.buffers().len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Decimal arrays should have 1 buffer".to_string(),
+                ));
+            }
+        }
+        for array in data {
+            // convert decimal to FixedSizeList<u8> to reuse list's append
+            let int_data = &array.buffers()[0];
+            let int_data = Arc::new(ArrayData::new(
+                DataType::UInt8,
+                int_data.len(),
+                None,
+                None,
+                0,
+                vec![int_data.clone()],
+                vec![],
+            )) as ArrayDataRef;
+            let list_data = Arc::new(ArrayData::new(
+                DataType::FixedSizeList(Box::new(DataType::UInt8), self.builder.list_len),
+                array.len(),
+                None,
+                array.null_buffer().cloned(),
+                array.offset(),
+                vec![],
+                vec![int_data],
+            ));
+            self.builder.append_data(&[list_data])?;
+        }
+        Ok(())
+    }
+
+    /// Returns the data type of the builder
+    ///
+    /// This is used for validating array data types in `append_data`
+    fn data_type(&self) -> DataType {
+        DataType::Decimal(self.precision, self.scale)
+    }
+
+    /// Returns the builder as a mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<Any> {
+        self
+    }
+
+    /// Returns the number of array slots in the builder
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    /// Returns whether the number of array slots is zero
+    fn is_empty(&self) -> bool {
+        self.builder.is_empty()
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+}
+
 impl BinaryBuilder {
     /// Creates a new `BinaryBuilder`, `capacity` is the number of bytes in the values
     /// array
@@ -1651,6 +2129,26 @@ impl BinaryBuilder {
         }
     }
 
+    /// Creates a new `BinaryBuilder` whose memory is allocated from `pool`,
+    /// `data_capacity` is the number of bytes of binary data to pre-allocate space for in this builder
+    /// `item_capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        item_capacity: usize,
+        data_capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(data_capacity, pool.clone())?;
+        Ok(Self {
+            builder: ListBuilder::try_with_capacity_in(
+                values_builder,
+                item_capacity,
+                pool,
+            )?,
+        })
+    }
+
     /// Appends a single byte value into the builder's values array.
     ///
     /// Note, when appending individual byte values you must call `append` to delimit each
@@ -1696,6 +2194,26 @@ impl LargeBinaryBuilder {
         }
     }
 
+    /// Creates a new `LargeBinaryBuilder` whose memory is allocated from `pool`,
+    /// `data_capacity` is the number of bytes of binary data to pre-allocate space for in this builder
+    /// `item_capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        item_capacity: usize,
+        data_capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(data_capacity, pool.clone())?;
+        Ok(Self {
+            builder: LargeListBuilder::try_with_capacity_in(
+                values_builder,
+                item_capacity,
+                pool,
+            )?,
+        })
+    }
+
     /// Appends a single byte value into the builder's values array.
     ///
     /// Note, when appending individual byte values you must call `append` to delimit each
@@ -1751,6 +2269,26 @@ impl StringBuilder {
         }
     }
 
+    /// Creates a new `StringBuilder` whose memory is allocated from `pool`,
+    /// `data_capacity` is the number of bytes of string data to pre-allocate space for in this builder
+    /// `item_capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        item_capacity: usize,
+        data_capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(data_capacity, pool.clone())?;
+        Ok(Self {
+            builder: ListBuilder::try_with_capacity_in(
+                values_builder,
+                item_capacity,
+                pool,
+            )?,
+        })
+    }
+
     /// Appends a string into the builder.
     ///
     /// Automatically calls the `append` method to delimit the string appended in as a
@@ -1797,6 +2335,26 @@ impl LargeStringBuilder {
         }
     }
 
+    /// Creates a new `LargeStringBuilder` whose memory is allocated from `pool`,
+    /// `data_capacity` is the number of bytes of string data to pre-allocate space for in this builder
+    /// `item_capacity` is the number of items to pre-allocate space for in this builder
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_with_capacity_in(
+        item_capacity: usize,
+        data_capacity: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(data_capacity, pool.clone())?;
+        Ok(Self {
+            builder: LargeListBuilder::try_with_capacity_in(
+                values_builder,
+                item_capacity,
+                pool,
+            )?,
+        })
+    }
+
     /// Appends a string into the builder.
     ///
     /// Automatically calls the `append` method to delimit the string appended in as a
@@ -1833,6 +2391,26 @@ impl FixedSizeBinaryBuilder {
         }
     }
 
+    /// Creates a new `FixedSizeBinaryBuilder` whose memory is allocated from `pool`,
+    /// `capacity` is the number of bytes in the values array
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(
+        capacity: usize,
+        byte_width: i32,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(capacity, pool.clone())?;
+        Ok(Self {
+            builder: FixedSizeListBuilder::try_with_capacity_in(
+                values_builder,
+                byte_width,
+                0,
+                pool,
+            )?,
+        })
+    }
+
     /// Appends a byte slice into the builder.
     ///
     /// Automatically calls the `append` method to delimit the slice appended in as a
@@ -1860,6 +2438,68 @@ impl FixedSizeBinaryBuilder {
     }
 }
 
+impl DecimalBuilder {
+    /// Creates a new `DecimalBuilder`, `capacity` is the number of values the
+    /// builder is expected to hold
+    pub fn new(capacity: usize, precision: usize, scale: usize) -> Self {
+        let values_builder = UInt8Builder::new(capacity * 16);
+        let byte_width = 16;
+        Self {
+            builder: FixedSizeListBuilder::new(values_builder, byte_width),
+            precision,
+            scale,
+        }
+    }
+
+    /// Creates a new `DecimalBuilder` whose memory is allocated from `pool`,
+    /// `capacity` is the number of values the builder is expected to hold
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(
+        capacity: usize,
+        precision: usize,
+        scale: usize,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = UInt8Builder::try_new_in(capacity * 16, pool.clone())?;
+        Ok(Self {
+            builder: FixedSizeListBuilder::try_with_capacity_in(
+                values_builder,
+                16,
+                capacity,
+                pool,
+            )?,
+            precision,
+            scale,
+        })
+    }
+
+    /// Appends an unscaled `i128` value into the builder.
+    ///
+    /// Automatically calls the `append` method to delimit the value appended in as a
+    /// distinct array element.
+    pub fn append_value(&mut self, value: i128) -> Result<()> {
+        self.builder.values().append_slice(&value.to_le_bytes())?;
+        self.builder.append(true)
+    }
+
+    /// Append a null value to the array.
+    pub fn append_null(&mut self) -> Result<()> {
+        let length: usize = self.builder.value_length() as usize;
+        self.builder.values().append_slice(&vec![0u8; length][..])?;
+        self.builder.append(false)
+    }
+
+    /// Builds the `DecimalArray` and reset this builder.
+    pub fn finish(&mut self) -> DecimalArray {
+        DecimalArray::from_fixed_size_list_array(
+            self.builder.finish(),
+            self.precision,
+            self.scale,
+        )
+    }
+}
+
 /// Array builder for Struct types.
 ///
 /// Note that callers should make sure that methods of all the child field builders are
@@ -1997,12 +2637,16 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<ArrayBuilder> {
         DataType::UInt16 => Box::new(UInt16Builder::new(capacity)),
         DataType::UInt32 => Box::new(UInt32Builder::new(capacity)),
         DataType::UInt64 => Box::new(UInt64Builder::new(capacity)),
+        DataType::Float16 => Box::new(Float16Builder::new(capacity)),
         DataType::Float32 => Box::new(Float32Builder::new(capacity)),
         DataType::Float64 => Box::new(Float64Builder::new(capacity)),
         DataType::Binary => Box::new(BinaryBuilder::new(capacity)),
         DataType::FixedSizeBinary(len) => {
             Box::new(FixedSizeBinaryBuilder::new(capacity, *len))
         }
+        DataType::Decimal(precision, scale) => {
+            Box::new(DecimalBuilder::new(capacity, *precision, *scale))
+        }
         DataType::Utf8 => Box::new(StringBuilder::new(capacity)),
         DataType::Date32(DateUnit::Day) => Box::new(Date32Builder::new(capacity)),
         DataType::Date64(DateUnit::Millisecond) => Box::new(Date64Builder::new(capacity)),
@@ -2057,6 +2701,29 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<ArrayBuilder> {
 
 impl StructBuilder {
     pub fn new(fields: Vec<Field>, builders: Vec<Box<ArrayBuilder>>) -> Self {
+        Self::with_bitmap_builder(fields, builders, BooleanBufferBuilder::new(0))
+    }
+
+    /// Creates a new `StructBuilder` whose null bitmap is allocated from `pool`.
+    ///
+    /// The memory of the fields is allocated by their `builders`, which can be created
+    /// from `pool` with their own `_in` constructors.
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(
+        fields: Vec<Field>,
+        builders: Vec<Box<ArrayBuilder>>,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let bitmap_builder = BooleanBufferBuilder::try_new_in(0, pool)?;
+        Ok(Self::with_bitmap_builder(fields, builders, bitmap_builder))
+    }
+
+    fn with_bitmap_builder(
+        fields: Vec<Field>,
+        builders: Vec<Box<ArrayBuilder>>,
+        bitmap_builder: BooleanBufferBuilder,
+    ) -> Self {
         let mut field_anys = Vec::with_capacity(builders.len());
         let mut field_builders = Vec::with_capacity(builders.len());
 
@@ -2076,7 +2743,7 @@ impl StructBuilder {
             fields,
             field_anys,
             field_builders,
-            bitmap_builder: BooleanBufferBuilder::new(0),
+            bitmap_builder,
             len: 0,
         }
     }
@@ -2167,6 +2834,10 @@ where
     V: ArrowPrimitiveType,
 {
     /// Creates a new `PrimitiveDictionaryBuilder` from a keys builder and a value builder.
+    ///
+    /// The keys and values are allocated by their builders, which can be created from
+    /// a `MemoryPool` with `PrimitiveBuilder::try_new_in`. The hash map of the values
+    /// is not allocated from the pool.
     pub fn new(
         keys_builder: PrimitiveBuilder<K>,
         values_builder: PrimitiveBuilder<V>,
@@ -2284,6 +2955,11 @@ where
     K: ArrowDictionaryKeyType,
 {
     /// Creates a new `StringDictionaryBuilder` from a keys builder and a value builder.
+    ///
+    /// The keys and values are allocated by their builders, which can be created from
+    /// a `MemoryPool` with `PrimitiveBuilder::try_new_in` and
+    /// `StringBuilder::try_with_capacity_in`. The hash map of the values is not
+    /// allocated from the pool.
     pub fn new(keys_builder: PrimitiveBuilder<K>, values_builder: StringBuilder) -> Self {
         Self {
             keys_builder,
@@ -2319,10 +2995,38 @@ where
     pub fn new_with_dictionary(
         keys_builder: PrimitiveBuilder<K>,
         dictionary_values: &StringArray,
+    ) -> Result<Self> {
+        let values_builder = StringBuilder::with_capacity(
+            dictionary_values.len(),
+            dictionary_values.value_data().len(),
+        );
+        Self::with_dictionary(keys_builder, values_builder, dictionary_values)
+    }
+
+    /// Creates a new `StringDictionaryBuilder` from a keys builder and a dictionary
+    /// which is initialized with the given values, like `new_with_dictionary`, but
+    /// allocates the values of the dictionary from `pool`.
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_with_dictionary_in(
+        keys_builder: PrimitiveBuilder<K>,
+        dictionary_values: &StringArray,
+        pool: Arc<MemoryPool>,
+    ) -> Result<Self> {
+        let values_builder = StringBuilder::try_with_capacity_in(
+            dictionary_values.len(),
+            dictionary_values.value_data().len(),
+            pool,
+        )?;
+        Self::with_dictionary(keys_builder, values_builder, dictionary_values)
+    }
+
+    fn with_dictionary(
+        keys_builder: PrimitiveBuilder<K>,
+        mut values_builder: StringBuilder,
+        dictionary_values: &StringArray,
     ) -> Result<Self> {
         let dict_len = dictionary_values.len();
-        let mut values_builder =
-            StringBuilder::with_capacity(dict_len, dictionary_values.value_data().len());
         let mut map: HashMap<Box<[u8]>, K::Native> = HashMap::with_capacity(dict_len);
         for i in 0..dict_len {
             if dictionary_values.is_valid(i) {
@@ -2798,6 +3502,121 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_array_builders_in_pool() {
+        use crate::memory::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::new());
+        let mut builder = Int32Builder::try_new_in(10, pool.clone()).unwrap();
+        builder.append_slice(&[1, 2, 3]).unwrap();
+        builder.append_null().unwrap();
+        let array = builder.finish();
+        assert_eq!(4, array.len());
+        assert_eq!(1, array.null_count());
+        assert_eq!(128, pool.bytes_allocated());
+
+        let mut builder =
+            StringBuilder::try_with_capacity_in(2, 64, pool.clone()).unwrap();
+        builder.append_value("hello").unwrap();
+        builder.append_value("arrow").unwrap();
+        let strings = builder.finish();
+        assert_eq!("arrow", strings.value(1));
+        assert_eq!(128 + 4 * 64, pool.bytes_allocated());
+
+        drop(builder);
+        drop(array);
+        drop(strings);
+        assert_eq!(0, pool.bytes_allocated());
+        assert_eq!(128 + 4 * 64, pool.peak_bytes_allocated());
+
+        let pool = Arc::new(TrackingMemoryPool::with_limit(128));
+        assert!(Int64Builder::try_new_in(16, pool.clone()).is_err());
+        let mut builder = Int64Builder::try_new_in(8, pool).unwrap();
+        builder.append_slice(&[1; 8]).unwrap();
+        assert!(builder.append_value(9).is_err());
+    }
+
+    #[test]
+    fn test_nested_builders_in_pool() {
+        use crate::memory::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::new());
+        let binary = {
+            let mut builder =
+                FixedSizeBinaryBuilder::try_new_in(64, 2, pool.clone()).unwrap();
+            builder.append_value(b"ab").unwrap();
+            builder.append_null().unwrap();
+            builder.finish()
+        };
+        assert_eq!(b"ab", binary.value(0));
+        assert!(binary.is_null(1));
+        assert!(pool.bytes_allocated() > 0);
+
+        let decimals = {
+            let mut builder = DecimalBuilder::try_new_in(4, 10, 2, pool.clone()).unwrap();
+            builder.append_value(12345).unwrap();
+            builder.finish()
+        };
+        assert_eq!(12345, decimals.value(0));
+
+        let map = {
+            let keys = StringBuilder::try_with_capacity_in(4, 64, pool.clone()).unwrap();
+            let values = Int32Builder::try_new_in(4, pool.clone()).unwrap();
+            let mut builder =
+                MapBuilder::try_with_capacity_in(None, keys, values, 4, pool.clone())
+                    .unwrap();
+            builder.keys().append_value("a").unwrap();
+            builder.values().append_value(1).unwrap();
+            builder.append(true).unwrap();
+            builder.finish()
+        };
+        assert_eq!(1, map.len());
+
+        let structs = {
+            let fields = vec![Field::new("x", DataType::Int32, true)];
+            let builders: Vec<Box<ArrayBuilder>> =
+                vec![Box::new(Int32Builder::try_new_in(4, pool.clone()).unwrap())];
+            let mut builder =
+                StructBuilder::try_new_in(fields, builders, pool.clone()).unwrap();
+            builder
+                .field_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(1)
+                .unwrap();
+            builder.append(true).unwrap();
+            builder.finish()
+        };
+        assert_eq!(1, structs.len());
+
+        let dictionary = {
+            let values = StringArray::from(vec!["a", "b"]);
+            let keys = PrimitiveBuilder::<Int8Type>::try_new_in(4, pool.clone()).unwrap();
+            let mut builder = StringDictionaryBuilder::try_new_with_dictionary_in(
+                keys,
+                &values,
+                pool.clone(),
+            )
+            .unwrap();
+            builder.append("b").unwrap();
+            builder.finish()
+        };
+        assert_eq!(1, dictionary.len());
+
+        drop(binary);
+        drop(decimals);
+        drop(map);
+        drop(structs);
+        drop(dictionary);
+        assert_eq!(0, pool.bytes_allocated());
+
+        let pool = Arc::new(TrackingMemoryPool::with_limit(64));
+        assert!(DecimalBuilder::try_new_in(8, 10, 2, pool.clone()).is_err());
+        assert!(FixedSizeBinaryBuilder::try_new_in(128, 2, pool.clone()).is_err());
+        let keys = Int32Builder::new(0);
+        let values = Int32Builder::new(0);
+        assert!(MapBuilder::try_with_capacity_in(None, keys, values, 16, pool).is_err());
+    }
+
     #[test]
     fn test_list_array_builder() {
         let values_builder = Int32Builder::new(10);
@@ -2982,6 +3801,66 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_map_array_builder() {
+        let string_builder = StringBuilder::new(4);
+        let int_builder = Int32Builder::new(4);
+
+        let mut builder = MapBuilder::new(None, string_builder, int_builder);
+
+        builder.keys().append_value("joe").unwrap();
+        builder.values().append_value(1).unwrap();
+        builder.append(true).unwrap();
+
+        builder.keys().append_value("blogs").unwrap();
+        builder.values().append_value(2).unwrap();
+        builder.keys().append_value("foo").unwrap();
+        builder.values().append_null().unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+
+        let map_array = builder.finish();
+        assert_eq!(0, builder.len());
+
+        assert_eq!(4, map_array.len());
+        assert_eq!(1, map_array.null_count());
+        assert!(map_array.is_null(2));
+        assert_eq!(1, map_array.value_offset(1));
+        assert_eq!(2, map_array.value_length(1));
+        assert_eq!(0, map_array.value_length(3));
+        assert_eq!(DataType::Utf8, map_array.key_type());
+        assert_eq!(DataType::Int32, map_array.value_type());
+
+        let keys = map_array.keys();
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(&StringArray::from(vec!["joe", "blogs", "foo"]), keys);
+        let values = map_array.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![Some(1), Some(2), None]), values);
+
+        let expected_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        );
+        assert_eq!(&expected_type, map_array.data_type());
+    }
+
+    #[test]
+    fn test_map_array_builder_unequal_lengths() {
+        let mut builder =
+            MapBuilder::new(None, Int32Builder::new(2), Int32Builder::new(2));
+        builder.keys().append_value(1).unwrap();
+        assert!(builder.append(true).is_err());
+    }
+
     #[test]
     fn test_fixed_size_list_array_builder_empty() {
         let values_builder = Int32Array::builder(5);
@@ -3165,6 +4044,24 @@ mod tests {
         assert_eq!(5, fixed_size_binary_array.value_length());
     }
 
+    #[test]
+    fn test_decimal_builder() {
+        let mut builder = DecimalBuilder::new(30, 23, 6);
+
+        builder.append_value(8_887_000_000).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-8_887_000_000).unwrap();
+        let decimal_array: DecimalArray = builder.finish();
+
+        assert_eq!(&DataType::Decimal(23, 6), decimal_array.data_type());
+        assert_eq!(3, decimal_array.len());
+        assert_eq!(1, decimal_array.null_count());
+        assert_eq!(32, decimal_array.value_offset(2));
+        assert_eq!(16, decimal_array.value_length());
+        assert_eq!(8_887_000_000, decimal_array.value(0));
+        assert_eq!(-8_887_000_000, decimal_array.value(2));
+    }
+
     #[test]
     fn test_string_array_builder_finish() {
         let mut builder = StringBuilder::new(10);
@@ -3686,6 +4583,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_map_append() -> Result<()> {
+        let mut builder =
+            MapBuilder::new(None, Int32Builder::new(8), Int64Builder::new(8));
+        builder.keys().append_slice(&[1, 2])?;
+        builder.values().append_slice(&[10, 20])?;
+        builder.append(true)?;
+
+        let mut a_builder =
+            MapBuilder::new(None, Int32Builder::new(8), Int64Builder::new(8));
+        a_builder.keys().append_slice(&[3])?;
+        a_builder.values().append_slice(&[30])?;
+        a_builder.append(true)?;
+        a_builder.append(false)?;
+        a_builder.keys().append_slice(&[4, 5])?;
+        a_builder.values().append_slice(&[40, 50])?;
+        a_builder.append(true)?;
+        let a = a_builder.finish();
+
+        // append array
+        builder.append_data(&[a.data(), a.slice(1, 2).data()])?;
+        let finished = builder.finish();
+
+        assert_eq!(6, finished.len());
+        assert_eq!(2, finished.null_count());
+        assert_eq!(
+            Buffer::from(&[0, 2, 3, 3, 5, 5, 7].to_byte_slice()).data(),
+            finished.data().buffers()[0].data()
+        );
+        assert!(Int32Array::from(vec![1, 2, 3, 4, 5, 4, 5]).equals(&*finished.keys()));
+        assert!(Int64Array::from(vec![10, 20, 30, 40, 50, 40, 50])
+            .equals(&*finished.values()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_nulls_append() -> Result<()> {
         let int_builder = Int64Builder::new(32);
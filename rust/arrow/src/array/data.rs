@@ -22,8 +22,9 @@ use std::mem;
 use std::sync::Arc;
 
 use crate::bitmap::Bitmap;
-use crate::buffer::Buffer;
-use crate::datatypes::DataType;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::{ArrowNativeType, DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
 
 /// An generic representation of Arrow array data which encapsulates common attributes and
@@ -96,6 +97,84 @@ impl ArrayData {
         }
     }
 
+    /// Creates array data of type `data_type` where all `len` slots are null.
+    ///
+    /// Values buffers are zero-initialized, and nested types get children of
+    /// null values.
+    pub fn new_null(data_type: &DataType, len: usize) -> Self {
+        let zeroed = |bytes: usize| -> Buffer {
+            MutableBuffer::new(bytes).with_bitset(bytes, false).freeze()
+        };
+        let null_bitmap = Some(zeroed(bit_util::ceil(len, 8)));
+        let (buffers, child_data) = match data_type {
+            DataType::Null => {
+                return ArrayData::new(DataType::Null, len, None, None, 0, vec![], vec![])
+            }
+            DataType::Boolean => (vec![zeroed(bit_util::ceil(len, 8))], vec![]),
+            DataType::Utf8 | DataType::Binary => (
+                vec![zeroed((len + 1) * mem::size_of::<i32>()), zeroed(0)],
+                vec![],
+            ),
+            DataType::LargeUtf8 | DataType::LargeBinary => (
+                vec![zeroed((len + 1) * mem::size_of::<i64>()), zeroed(0)],
+                vec![],
+            ),
+            DataType::List(value_type) => (
+                vec![zeroed((len + 1) * mem::size_of::<i32>())],
+                vec![Arc::new(ArrayData::new_null(value_type, 0))],
+            ),
+            DataType::LargeList(value_type) => (
+                vec![zeroed((len + 1) * mem::size_of::<i64>())],
+                vec![Arc::new(ArrayData::new_null(value_type, 0))],
+            ),
+            DataType::Map(field, _) => (
+                vec![zeroed((len + 1) * mem::size_of::<i32>())],
+                vec![Arc::new(ArrayData::new_null(field.data_type(), 0))],
+            ),
+            DataType::FixedSizeList(value_type, size) => (
+                vec![],
+                vec![Arc::new(ArrayData::new_null(
+                    value_type,
+                    len * *size as usize,
+                ))],
+            ),
+            DataType::Struct(fields) => (
+                vec![],
+                fields
+                    .iter()
+                    .map(|field| Arc::new(ArrayData::new_null(field.data_type(), len)))
+                    .collect(),
+            ),
+            DataType::Union(fields) => (
+                vec![zeroed(len)],
+                fields
+                    .iter()
+                    .map(|field| Arc::new(ArrayData::new_null(field.data_type(), len)))
+                    .collect(),
+            ),
+            DataType::Dictionary(key_type, value_type) => {
+                let key_width = fixed_byte_width(key_type).unwrap_or(0);
+                (
+                    vec![zeroed(len * key_width)],
+                    vec![Arc::new(ArrayData::new_null(value_type, 0))],
+                )
+            }
+            data_type => {
+                let width = fixed_byte_width(data_type).unwrap_or(0);
+                (vec![zeroed(len * width)], vec![])
+            }
+        };
+        ArrayData::new(
+            data_type.clone(),
+            len,
+            Some(len),
+            null_bitmap,
+            0,
+            buffers,
+            child_data,
+        )
+    }
+
     /// Returns a builder to construct a `ArrayData` instance.
     #[inline]
     pub const fn builder(data_type: DataType) -> ArrayDataBuilder {
@@ -207,6 +286,341 @@ impl ArrayData {
 
         size
     }
+
+    /// Validates that the buffers and children of this array data are consistent with
+    /// its data type, length and offset, so that accessing it through an array cannot
+    /// read out of bounds or produce invalid values.
+    ///
+    /// This checks that:
+    /// * the null bitmap, buffers and children are large enough,
+    /// * the offsets of `Utf8`, `Binary`, `List` and `Map` data (and their large
+    ///   variants) are monotonically increasing and within the bounds of their values,
+    /// * the values of `Utf8` data are valid UTF-8,
+    /// * dictionary keys are within the bounds of the dictionary values,
+    /// * union type ids point to existing children, and dense union offsets to
+    ///   existing values within them,
+    ///
+    /// and validates the children recursively.
+    ///
+    /// The cost of this is linear in the size of the data, it is meant to be used on
+    /// data from untrusted sources, e.g. IPC messages from external producers.
+    pub fn validate_full(&self) -> Result<()> {
+        let end = self.offset.checked_add(self.len).ok_or_else(|| {
+            self.invalid(format!(
+                "offset {} plus length {} overflows",
+                self.offset, self.len
+            ))
+        })?;
+        if self.null_count > self.len {
+            return Err(self.invalid(format!(
+                "null count {} is greater than the length {}",
+                self.null_count, self.len
+            )));
+        }
+        if let Some(bitmap) = &self.null_bitmap {
+            let bitmap = bitmap.buffer_ref();
+            if bitmap.len() < bit_util::ceil(end, 8) {
+                return Err(self.invalid(format!(
+                    "null bitmap of {} bytes is too small for {} slots",
+                    bitmap.len(),
+                    end
+                )));
+            }
+            let null_count = self.len
+                - bit_util::count_set_bits_offset(bitmap.data(), self.offset, self.len);
+            if null_count != self.null_count {
+                return Err(self.invalid(format!(
+                    "null count {} does not match the {} nulls of the null bitmap",
+                    self.null_count, null_count
+                )));
+            }
+        }
+
+        match &self.data_type {
+            DataType::Null => {
+                self.check_layout(0, 0)?;
+                Ok(())
+            }
+            DataType::Boolean => {
+                self.check_layout(1, 0)?;
+                self.check_buffer_len(0, bit_util::ceil(end, 8), 1)
+            }
+            DataType::Utf8 => self.validate_variable_size::<i32>(true),
+            DataType::LargeUtf8 => self.validate_variable_size::<i64>(true),
+            DataType::Binary => self.validate_variable_size::<i32>(false),
+            DataType::LargeBinary => self.validate_variable_size::<i64>(false),
+            DataType::List(_) | DataType::Map(_, _) => self.validate_list::<i32>(),
+            DataType::LargeList(_) => self.validate_list::<i64>(),
+            DataType::FixedSizeList(_, size) => {
+                self.check_layout(0, 1)?;
+                let len = end.checked_mul(*size as usize).ok_or_else(|| {
+                    self.invalid("the length of the child overflows".to_string())
+                })?;
+                self.check_child_len(0, len)?;
+                self.child_data[0].validate_full()
+            }
+            DataType::Struct(fields) => {
+                self.check_layout(0, fields.len())?;
+                for (i, child) in self.child_data.iter().enumerate() {
+                    self.check_child_len(i, end)?;
+                    child.validate_full()?;
+                }
+                Ok(())
+            }
+            DataType::Union(fields) => self.validate_union(fields.len()),
+            DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+                DataType::Int8 => self.validate_dictionary::<i8>(),
+                DataType::Int16 => self.validate_dictionary::<i16>(),
+                DataType::Int32 => self.validate_dictionary::<i32>(),
+                DataType::Int64 => self.validate_dictionary::<i64>(),
+                DataType::UInt8 => self.validate_dictionary::<u8>(),
+                DataType::UInt16 => self.validate_dictionary::<u16>(),
+                DataType::UInt32 => self.validate_dictionary::<u32>(),
+                DataType::UInt64 => self.validate_dictionary::<u64>(),
+                key_type => {
+                    Err(self.invalid(format!("{:?} is not a valid key type", key_type)))
+                }
+            },
+            data_type => {
+                let width = fixed_byte_width(data_type).ok_or_else(|| {
+                    self.invalid("the data type is not supported".to_string())
+                })?;
+                self.check_layout(1, 0)?;
+                self.check_buffer_len(0, end, width)
+            }
+        }
+    }
+
+    /// Returns an error describing why this array data is invalid
+    fn invalid(&self, reason: String) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid {:?} array data: {}",
+            self.data_type, reason
+        ))
+    }
+
+    /// Checks that this array data has the number of buffers and children of its type
+    fn check_layout(&self, buffers: usize, children: usize) -> Result<()> {
+        if self.buffers.len() != buffers {
+            return Err(self.invalid(format!(
+                "expected {} buffers, found {}",
+                buffers,
+                self.buffers.len()
+            )));
+        }
+        if self.child_data.len() != children {
+            return Err(self.invalid(format!(
+                "expected {} children, found {}",
+                children,
+                self.child_data.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the buffer at index `i` holds at least `slots` values of `width` bytes
+    fn check_buffer_len(&self, i: usize, slots: usize, width: usize) -> Result<()> {
+        let len = slots
+            .checked_mul(width)
+            .ok_or_else(|| self.invalid(format!("the size of buffer {} overflows", i)))?;
+        if self.buffers[i].len() < len {
+            return Err(self.invalid(format!(
+                "buffer {} of {} bytes is too small, {} bytes are required",
+                i,
+                self.buffers[i].len(),
+                len
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the child at index `i` holds at least `len` slots
+    fn check_child_len(&self, i: usize, len: usize) -> Result<()> {
+        if self.child_data[i].len() < len {
+            return Err(self.invalid(format!(
+                "child {} of length {} is too short, a length of {} is required",
+                i,
+                self.child_data[i].len(),
+                len
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the offsets in the first buffer and returns them as `(start, end)` ranges,
+    /// each of them within `[0, values_len]`
+    fn checked_offsets<O: ArrowNativeType>(
+        &self,
+        values_len: usize,
+    ) -> Result<Vec<(usize, usize)>> {
+        let end = self.offset + self.len;
+        self.check_buffer_len(0, end + 1, mem::size_of::<O>())?;
+        let offset_at = |i: usize| {
+            let offset: O = unsafe { read_unaligned(&self.buffers[0], i) };
+            offset
+                .to_usize()
+                .filter(|offset| *offset <= values_len)
+                .ok_or_else(|| {
+                    self.invalid(format!(
+                        "offset {:?} at slot {} is not within [0, {}]",
+                        offset, i, values_len
+                    ))
+                })
+        };
+
+        let mut ranges = Vec::with_capacity(self.len);
+        let mut start = offset_at(self.offset)?;
+        for i in self.offset..end {
+            let next = offset_at(i + 1)?;
+            if next < start {
+                return Err(self.invalid(format!(
+                    "offsets are decreasing from {} to {} at slot {}",
+                    start, next, i
+                )));
+            }
+            ranges.push((start, next));
+            start = next;
+        }
+        Ok(ranges)
+    }
+
+    fn validate_variable_size<O: ArrowNativeType>(&self, utf8: bool) -> Result<()> {
+        self.check_layout(2, 0)?;
+        let values = self.buffers[1].data();
+        let ranges = self.checked_offsets::<O>(values.len())?;
+        if utf8 {
+            for (i, (start, end)) in ranges.into_iter().enumerate() {
+                std::str::from_utf8(&values[start..end]).map_err(|e| {
+                    self.invalid(format!(
+                        "the value at slot {} is not valid UTF-8: {}",
+                        self.offset + i,
+                        e
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_list<O: ArrowNativeType>(&self) -> Result<()> {
+        self.check_layout(1, 1)?;
+        self.checked_offsets::<O>(self.child_data[0].len())?;
+        self.child_data[0].validate_full()
+    }
+
+    fn validate_dictionary<K: ArrowNativeType>(&self) -> Result<()> {
+        self.check_layout(1, 1)?;
+        let end = self.offset + self.len;
+        self.check_buffer_len(0, end, mem::size_of::<K>())?;
+        let values_len = self.child_data[0].len();
+        for i in self.offset..end {
+            if !self.is_valid(i) {
+                continue;
+            }
+            let key: K = unsafe { read_unaligned(&self.buffers[0], i) };
+            if key.to_usize().filter(|key| *key < values_len).is_none() {
+                return Err(self.invalid(format!(
+                    "key {:?} at slot {} is not within [0, {})",
+                    key, i, values_len
+                )));
+            }
+        }
+        self.child_data[0].validate_full()
+    }
+
+    /// Validates a union, whose values are located by their position in sparse unions,
+    /// or by an offset per non-null slot in dense unions
+    fn validate_union(&self, num_fields: usize) -> Result<()> {
+        let dense = self.buffers.len() == 2;
+        if !dense {
+            self.check_layout(1, num_fields)?;
+        } else {
+            self.check_layout(2, num_fields)?;
+        }
+        let end = self.offset + self.len;
+        self.check_buffer_len(0, end, 1)?;
+
+        let mut valid_slots = if dense {
+            match &self.null_bitmap {
+                Some(bitmap) => bit_util::count_set_bits_offset(
+                    bitmap.buffer_ref().data(),
+                    0,
+                    self.offset,
+                ),
+                None => self.offset,
+            }
+        } else {
+            0
+        };
+        let type_ids = &self.buffers[0].data()[self.offset..end];
+        for (i, type_id) in (self.offset..end).zip(type_ids) {
+            if !self.is_valid(i) {
+                continue;
+            }
+            let type_id = *type_id as i8;
+            if type_id < 0 || type_id as usize >= num_fields {
+                return Err(self.invalid(format!(
+                    "type id {} at slot {} is not within [0, {})",
+                    type_id, i, num_fields
+                )));
+            }
+            if dense {
+                self.check_buffer_len(1, valid_slots + 1, mem::size_of::<i32>())?;
+                let offset: i32 =
+                    unsafe { read_unaligned(&self.buffers[1], valid_slots) };
+                let child_len = self.child_data[type_id as usize].len();
+                if offset < 0 || offset as usize >= child_len {
+                    return Err(self.invalid(format!(
+                        "offset {} at slot {} is not within [0, {})",
+                        offset, i, child_len
+                    )));
+                }
+                valid_slots += 1;
+            }
+        }
+
+        for (i, child) in self.child_data.iter().enumerate() {
+            if !dense {
+                self.check_child_len(i, end)?;
+            }
+            child.validate_full()?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the width in bytes of the values of a fixed-width data type
+fn fixed_byte_width(data_type: &DataType) -> Option<usize> {
+    Some(match data_type {
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        DataType::FixedSizeBinary(size) => *size as usize,
+        _ => return None,
+    })
+}
+
+/// Reads the `i`-th value of type `T` of `buffer`, which may not be aligned for `T`
+///
+/// # Safety
+///
+/// `buffer` must hold at least `i + 1` values of type `T`
+unsafe fn read_unaligned<T: ArrowNativeType>(buffer: &Buffer, i: usize) -> T {
+    std::ptr::read_unaligned((buffer.raw_data() as *const T).add(i))
 }
 
 impl PartialEq for ArrayData {
@@ -354,6 +768,7 @@ mod tests {
     use std::sync::Arc;
 
     use crate::buffer::Buffer;
+    use crate::datatypes::{Field, ToByteSlice};
     use crate::util::bit_util;
 
     #[test]
@@ -435,4 +850,162 @@ mod tests {
         assert!(arr_data.null_buffer().is_some());
         assert_eq!(&bit_v, arr_data.null_buffer().unwrap().data());
     }
+
+    fn assert_invalid(data: ArrayDataRef, message: &str) {
+        let error = data.validate_full().unwrap_err().to_string();
+        assert!(error.contains(message), "{}", error);
+    }
+
+    #[test]
+    fn test_validate_full() {
+        use crate::array::*;
+
+        let strings = StringArray::from(vec![Some("hello"), None, Some("arrow")]);
+        strings.data_ref().validate_full().unwrap();
+        strings.slice(1, 2).data_ref().validate_full().unwrap();
+
+        let mut builder = ListBuilder::new(Int32Builder::new(4));
+        builder.values().append_slice(&[1, 2, 3]).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.finish().data_ref().validate_full().unwrap();
+
+        let mut builder = StringDictionaryBuilder::new(
+            PrimitiveBuilder::<Int8Type>::new(4),
+            StringBuilder::new(4),
+        );
+        builder.append("a").unwrap();
+        builder.append_null().unwrap();
+        builder.append("b").unwrap();
+        builder.finish().data_ref().validate_full().unwrap();
+
+        let struct_array = StructArray::from(vec![(
+            Field::new("a", DataType::Boolean, false),
+            Arc::new(BooleanArray::from(vec![true, false])) as ArrayRef,
+        )]);
+        struct_array.data_ref().validate_full().unwrap();
+
+        let mut builder = UnionBuilder::new_dense(3);
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 3.0).unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        builder.build().unwrap().data_ref().validate_full().unwrap();
+    }
+
+    #[test]
+    fn test_new_null() {
+        let data_types = vec![
+            DataType::Null,
+            DataType::Boolean,
+            DataType::Int16,
+            DataType::Float64,
+            DataType::Decimal(10, 2),
+            DataType::Utf8,
+            DataType::LargeBinary,
+            DataType::List(Box::new(DataType::Int32)),
+            DataType::FixedSizeList(Box::new(DataType::Utf8), 3),
+            DataType::Struct(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::LargeList(Box::new(DataType::Utf8)), true),
+            ]),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+        ];
+        for data_type in data_types {
+            let data = ArrayData::new_null(&data_type, 5);
+            assert_eq!(&data_type, data.data_type());
+            assert_eq!(5, data.len());
+            // like `NullArray`, null data has no null bitmap
+            if data_type != DataType::Null {
+                assert_eq!(5, data.null_count());
+            }
+            data.validate_full().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_validate_full_invalid() {
+        let offsets = Buffer::from(&[0i32, 3, 1].to_byte_slice());
+        let data = ArrayData::builder(DataType::Binary)
+            .len(2)
+            .add_buffer(offsets)
+            .add_buffer(Buffer::from(b"abc"))
+            .build();
+        assert_invalid(data, "offsets are decreasing from 3 to 1 at slot 1");
+
+        let offsets = Buffer::from(&[0i64, 4].to_byte_slice());
+        let data = ArrayData::builder(DataType::LargeUtf8)
+            .len(1)
+            .add_buffer(offsets)
+            .add_buffer(Buffer::from(b"abc"))
+            .build();
+        assert_invalid(data, "offset 4 at slot 1 is not within [0, 3]");
+
+        let offsets = Buffer::from(&[0i32, 2].to_byte_slice());
+        let data = ArrayData::builder(DataType::Utf8)
+            .len(1)
+            .add_buffer(offsets)
+            .add_buffer(Buffer::from(&[0xc3, 0x28]))
+            .build();
+        assert_invalid(data, "the value at slot 0 is not valid UTF-8");
+
+        let data = ArrayData::builder(DataType::Int64)
+            .len(3)
+            .add_buffer(Buffer::from(&[1i64, 2].to_byte_slice()))
+            .build();
+        assert_invalid(
+            data,
+            "buffer 0 of 16 bytes is too small, 24 bytes are required",
+        );
+
+        let data = ArrayData::builder(DataType::Int32)
+            .len(2)
+            .null_count(2)
+            .null_bit_buffer(Buffer::from([0b01]))
+            .add_buffer(Buffer::from(&[1i32, 2].to_byte_slice()))
+            .build();
+        assert_invalid(data, "null count 2 does not match the 1 nulls");
+
+        let values = ArrayData::builder(DataType::Int32)
+            .len(2)
+            .add_buffer(Buffer::from(&[1i32, 2].to_byte_slice()))
+            .build();
+        let data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(1)
+            .add_buffer(Buffer::from(&[0i32, 3].to_byte_slice()))
+            .add_child_data(values.clone())
+            .build();
+        assert_invalid(data, "offset 3 at slot 1 is not within [0, 2]");
+
+        let data = ArrayData::builder(DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::Int32),
+        ))
+        .len(2)
+        .add_buffer(Buffer::from(&[1i16, -1].to_byte_slice()))
+        .add_child_data(values.clone())
+        .build();
+        assert_invalid(data, "key -1 at slot 1 is not within [0, 2)");
+
+        let data = ArrayData::builder(DataType::Struct(vec![Field::new(
+            "a",
+            DataType::Int32,
+            false,
+        )]))
+        .len(2)
+        .offset(1)
+        .add_child_data(values.clone())
+        .build();
+        assert_invalid(data, "child 0 of length 2 is too short");
+
+        let data = ArrayData::builder(DataType::Union(vec![Field::new(
+            "a",
+            DataType::Int32,
+            false,
+        )]))
+        .len(2)
+        .add_buffer(Buffer::from(&[0i8, 1].to_byte_slice()))
+        .add_child_data(values)
+        .build();
+        assert_invalid(data, "type id 1 at slot 1 is not within [0, 1)");
+    }
 }
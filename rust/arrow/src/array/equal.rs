@@ -156,6 +156,12 @@ impl PartialEq for FixedSizeBinaryArray {
     }
 }
 
+impl PartialEq for DecimalArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait> ArrayEqual for GenericListArray<OffsetSize> {
     fn equals(&self, other: &dyn Array) -> bool {
         if !base_equal(&self.data(), &other.data()) {
@@ -235,6 +241,76 @@ impl<OffsetSize: OffsetSizeTrait> ArrayEqual for GenericListArray<OffsetSize> {
     }
 }
 
+impl ArrayEqual for MapArray {
+    fn equals(&self, other: &dyn Array) -> bool {
+        if !base_equal(&self.data(), &other.data()) {
+            return false;
+        }
+
+        let other = other.as_any().downcast_ref::<MapArray>().unwrap();
+
+        if !value_offset_equal(self, other) {
+            return false;
+        }
+
+        self.entries().range_equals(
+            &*other.entries(),
+            self.value_offset(0) as usize,
+            self.value_offset(self.len()) as usize,
+            other.value_offset(0) as usize,
+        )
+    }
+
+    fn range_equals(
+        &self,
+        other: &dyn Array,
+        start_idx: usize,
+        end_idx: usize,
+        other_start_idx: usize,
+    ) -> bool {
+        assert!(other_start_idx + (end_idx - start_idx) <= other.len());
+
+        let other = other.as_any().downcast_ref::<MapArray>().unwrap();
+
+        let mut j = other_start_idx;
+        for i in start_idx..end_idx {
+            let is_null = self.is_null(i);
+            let other_is_null = other.is_null(j);
+
+            if is_null != other_is_null {
+                return false;
+            }
+
+            if is_null {
+                j += 1;
+                continue;
+            }
+
+            let start_offset = self.value_offset(i) as usize;
+            let end_offset = self.value_offset(i + 1) as usize;
+            let other_start_offset = other.value_offset(j) as usize;
+            let other_end_offset = other.value_offset(j + 1) as usize;
+
+            if end_offset - start_offset != other_end_offset - other_start_offset {
+                return false;
+            }
+
+            if !self.entries().range_equals(
+                &*other.entries(),
+                start_offset,
+                end_offset,
+                other_start_offset,
+            ) {
+                return false;
+            }
+
+            j += 1;
+        }
+
+        true
+    }
+}
+
 impl<T: ArrowPrimitiveType> ArrayEqual for DictionaryArray<T> {
     fn equals(&self, other: &dyn Array) -> bool {
         self.range_equals(other, 0, self.len(), 0)
@@ -553,6 +629,47 @@ impl<OffsetSize: StringOffsetSizeTrait> ArrayEqual for GenericStringArray<Offset
     }
 }
 
+impl ArrayEqual for DecimalArray {
+    fn equals(&self, other: &dyn Array) -> bool {
+        if !base_equal(&self.data(), &other.data()) {
+            return false;
+        }
+
+        let other = other.as_any().downcast_ref::<DecimalArray>().unwrap();
+
+        (0..self.len()).all(|i| self.is_null(i) || self.value(i) == other.value(i))
+    }
+
+    fn range_equals(
+        &self,
+        other: &dyn Array,
+        start_idx: usize,
+        end_idx: usize,
+        other_start_idx: usize,
+    ) -> bool {
+        assert!(other_start_idx + (end_idx - start_idx) <= other.len());
+        let other = other.as_any().downcast_ref::<DecimalArray>().unwrap();
+
+        let mut j = other_start_idx;
+        for i in start_idx..end_idx {
+            let is_null = self.is_null(i);
+            let other_is_null = other.is_null(j);
+
+            if is_null != other_is_null {
+                return false;
+            }
+
+            if !is_null && self.value(i) != other.value(j) {
+                return false;
+            }
+
+            j += 1;
+        }
+
+        true
+    }
+}
+
 impl ArrayEqual for FixedSizeBinaryArray {
     fn equals(&self, other: &dyn Array) -> bool {
         if !base_equal(&self.data(), &other.data()) {
@@ -908,6 +1025,38 @@ impl<OffsetSize: OffsetSizeTrait> PartialEq<GenericListArray<OffsetSize>> for Va
     }
 }
 
+impl JsonEqual for MapArray {
+    fn equals_json(&self, json: &[&Value]) -> bool {
+        if self.len() != json.len() {
+            return false;
+        }
+
+        (0..self.len()).all(|i| match json[i] {
+            Value::Array(v) => self.is_valid(i) && self.value(i).equals_json_values(v),
+            Value::Null => self.is_null(i) || self.value_length(i) == 0,
+            _ => false,
+        })
+    }
+}
+
+impl PartialEq<Value> for MapArray {
+    fn eq(&self, json: &Value) -> bool {
+        match json {
+            Value::Array(json_array) => self.equals_json_values(json_array),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<MapArray> for Value {
+    fn eq(&self, arrow: &MapArray) -> bool {
+        match self {
+            Value::Array(json_array) => arrow.equals_json_values(json_array),
+            _ => false,
+        }
+    }
+}
+
 impl<T: ArrowPrimitiveType> JsonEqual for DictionaryArray<T> {
     fn equals_json(&self, json: &[&Value]) -> bool {
         self.keys().zip(json.iter()).all(|aj| match aj {
@@ -1138,6 +1287,42 @@ impl PartialEq<FixedSizeBinaryArray> for Value {
     }
 }
 
+impl JsonEqual for DecimalArray {
+    fn equals_json(&self, json: &[&Value]) -> bool {
+        if self.len() != json.len() {
+            return false;
+        }
+
+        (0..self.len()).all(|i| match json[i] {
+            // decimals are represented by the string of their unscaled value
+            JString(s) => {
+                self.is_valid(i)
+                    && s.parse::<i128>().map_or(false, |v| v == self.value(i))
+            }
+            JNull => self.is_null(i),
+            _ => false,
+        })
+    }
+}
+
+impl PartialEq<Value> for DecimalArray {
+    fn eq(&self, json: &Value) -> bool {
+        match json {
+            Value::Array(json_array) => self.equals_json_values(&json_array),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<DecimalArray> for Value {
+    fn eq(&self, arrow: &DecimalArray) -> bool {
+        match self {
+            Value::Array(json_array) => arrow.equals_json_values(&json_array),
+            _ => false,
+        }
+    }
+}
+
 impl JsonEqual for UnionArray {
     fn equals_json(&self, _json: &[&Value]) -> bool {
         unimplemented!(
@@ -2028,6 +2213,74 @@ mod tests {
         assert!(json_array.ne(&arrow_array));
     }
 
+    #[test]
+    fn test_decimal_equal() {
+        let mut builder = DecimalBuilder::new(5, 10, 2);
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-678).unwrap();
+        let a = builder.finish();
+
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-678).unwrap();
+        let b = builder.finish();
+
+        assert!(a.equals(&b));
+        assert!(b.equals(&a));
+
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(678).unwrap();
+        let c = builder.finish();
+
+        assert!(!a.equals(&c));
+        assert!(a.range_equals(&c, 0, 2, 0));
+        assert!(!a.range_equals(&c, 0, 3, 0));
+    }
+
+    #[test]
+    fn test_decimal_json_equal() {
+        // Test the equal case
+        let mut builder = DecimalBuilder::new(5, 23, 6);
+        builder.append_value(8_887_000_000).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-8_887_000_000).unwrap();
+        let arrow_array: DecimalArray = builder.finish();
+        let json_array: Value = serde_json::from_str(
+            r#"
+            [
+                "8887000000",
+                null,
+                "-8887000000"
+            ]
+        "#,
+        )
+        .unwrap();
+        assert!(arrow_array.eq(&json_array));
+        assert!(json_array.eq(&arrow_array));
+
+        // Test unequal case
+        builder.append_value(8_887_000_000).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(55).unwrap();
+        let arrow_array: DecimalArray = builder.finish();
+        assert!(arrow_array.ne(&json_array));
+        assert!(json_array.ne(&arrow_array));
+
+        // Test incorrect type case
+        let json_array: Value = serde_json::from_str(
+            r#"
+            {
+                "a": 1
+            }
+        "#,
+        )
+        .unwrap();
+        assert!(arrow_array.ne(&json_array));
+        assert!(json_array.ne(&arrow_array));
+    }
+
     #[test]
     fn test_struct_json_equal() {
         let strings: ArrayRef = Arc::new(StringArray::from(vec![
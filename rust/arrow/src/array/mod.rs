@@ -103,6 +103,7 @@ pub use self::data::ArrayDataBuilder;
 pub use self::data::ArrayDataRef;
 
 pub use self::array::BinaryArray;
+pub use self::array::DecimalArray;
 pub use self::array::DictionaryArray;
 pub use self::array::FixedSizeBinaryArray;
 pub use self::array::FixedSizeListArray;
@@ -110,6 +111,7 @@ pub use self::array::LargeBinaryArray;
 pub use self::array::LargeListArray;
 pub use self::array::LargeStringArray;
 pub use self::array::ListArray;
+pub use self::array::MapArray;
 pub use self::array::PrimitiveArray;
 pub use self::array::StringArray;
 pub use self::array::StructArray;
@@ -117,6 +119,8 @@ pub use self::null::NullArray;
 pub use self::union::UnionArray;
 
 pub use self::array::make_array;
+pub use self::array::make_array_from_raw;
+pub use self::array::new_null_array;
 
 pub type BooleanArray = PrimitiveArray<BooleanType>;
 pub type Int8Array = PrimitiveArray<Int8Type>;
@@ -127,6 +131,7 @@ pub type UInt8Array = PrimitiveArray<UInt8Type>;
 pub type UInt16Array = PrimitiveArray<UInt16Type>;
 pub type UInt32Array = PrimitiveArray<UInt32Type>;
 pub type UInt64Array = PrimitiveArray<UInt64Type>;
+pub type Float16Array = PrimitiveArray<Float16Type>;
 pub type Float32Array = PrimitiveArray<Float32Type>;
 pub type Float64Array = PrimitiveArray<Float64Type>;
 
@@ -176,6 +181,7 @@ pub type UInt8BufferBuilder = BufferBuilder<UInt8Type>;
 pub type UInt16BufferBuilder = BufferBuilder<UInt16Type>;
 pub type UInt32BufferBuilder = BufferBuilder<UInt32Type>;
 pub type UInt64BufferBuilder = BufferBuilder<UInt64Type>;
+pub type Float16BufferBuilder = BufferBuilder<Float16Type>;
 pub type Float32BufferBuilder = BufferBuilder<Float32Type>;
 pub type Float64BufferBuilder = BufferBuilder<Float64Type>;
 
@@ -198,12 +204,15 @@ pub type DurationNanosecondBufferBuilder = BufferBuilder<DurationNanosecondType>
 
 pub use self::builder::ArrayBuilder;
 pub use self::builder::BinaryBuilder;
+pub use self::builder::DecimalBuilder;
 pub use self::builder::FixedSizeBinaryBuilder;
 pub use self::builder::FixedSizeListBuilder;
 pub use self::builder::LargeBinaryBuilder;
 pub use self::builder::LargeListBuilder;
 pub use self::builder::LargeStringBuilder;
 pub use self::builder::ListBuilder;
+pub use self::builder::MapBuilder;
+pub use self::builder::MapFieldNames;
 pub use self::builder::PrimitiveBuilder;
 pub use self::builder::PrimitiveDictionaryBuilder;
 pub use self::builder::StringBuilder;
@@ -220,6 +229,7 @@ pub type UInt8Builder = PrimitiveBuilder<UInt8Type>;
 pub type UInt16Builder = PrimitiveBuilder<UInt16Type>;
 pub type UInt32Builder = PrimitiveBuilder<UInt32Type>;
 pub type UInt64Builder = PrimitiveBuilder<UInt64Type>;
+pub type Float16Builder = PrimitiveBuilder<Float16Type>;
 pub type Float32Builder = PrimitiveBuilder<Float32Type>;
 pub type Float64Builder = PrimitiveBuilder<Float64Type>;
 
@@ -458,6 +458,7 @@ impl FieldData {
             DataType::UInt16 => self.append_null::<UInt16Type>()?,
             DataType::UInt32 => self.append_null::<UInt32Type>()?,
             DataType::UInt64 => self.append_null::<UInt64Type>()?,
+            DataType::Float16 => self.append_null::<Float16Type>()?,
             DataType::Float32 => self.append_null::<Float32Type>()?,
             DataType::Float64 => self.append_null::<Float64Type>()?,
             _ => unreachable!("All cases of types that satisfy the trait bounds over T are covered above."),
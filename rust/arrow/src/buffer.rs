@@ -21,6 +21,7 @@
 #[cfg(feature = "simd")]
 use packed_simd::u8x64;
 
+use std::any::Any;
 use std::cmp;
 use std::convert::AsRef;
 use std::fmt::{Debug, Formatter};
@@ -33,15 +34,20 @@ use std::sync::Arc;
 
 use crate::datatypes::ArrowNativeType;
 use crate::error::{ArrowError, Result};
-use crate::memory;
+use crate::ffi;
+use crate::memory::{self, MemoryPool};
 use crate::util::bit_chunk_iterator::BitChunks;
 use crate::util::bit_util;
 use crate::util::bit_util::ceil;
+use crate::util::cpu;
 #[cfg(feature = "simd")]
 use std::borrow::BorrowMut;
 
 /// Buffer is a contiguous memory region of fixed size and is aligned at a 64-byte
 /// boundary. Buffer is immutable.
+///
+/// A buffer may also wrap memory owned elsewhere, see `Buffer::from_owner` and
+/// `Buffer::from_custom_allocation`, in which case it is only aligned as its owner made it.
 #[derive(Clone, PartialEq, Debug)]
 pub struct Buffer {
     /// Reference-counted pointer to the internal byte buffer.
@@ -60,8 +66,8 @@ struct BufferData {
     /// unoccupied region.
     len: usize,
 
-    /// Whether this piece of memory is owned by this object
-    owned: bool,
+    /// How this piece of memory is released when this object is dropped
+    deallocation: Deallocation,
 
     /// The capacity (num of bytes) of the buffer
     /// Invariant: len <= capacity
@@ -78,11 +84,45 @@ impl PartialEq for BufferData {
     }
 }
 
+/// Describes how the memory of a `BufferData` is released
+enum Deallocation {
+    /// The memory was allocated by `memory::allocate_aligned` and is owned by the buffer
+    Native,
+    /// The memory is not owned by the buffer and is never released by it
+    Unowned,
+    /// The memory is owned by another object, e.g. a `Vec<u8>` or an array imported
+    /// through the C Data Interface, which is dropped along with the buffer
+    Custom(Box<Any + Send>),
+    /// The memory was allocated from a `MemoryPool` and is returned to it
+    Pool(Arc<MemoryPool>),
+}
+
+/// Runs a deallocation closure when dropped
+struct Deallocator(Option<Box<FnOnce() + Send>>);
+
+impl Drop for Deallocator {
+    fn drop(&mut self) {
+        if let Some(deallocate) = self.0.take() {
+            deallocate()
+        }
+    }
+}
+
 /// Release the underlying memory when the current buffer goes out of scope
 impl Drop for BufferData {
     fn drop(&mut self) {
-        if self.is_allocated() && self.owned {
-            unsafe { memory::free_aligned(self.ptr as *mut u8, self.capacity) };
+        if self.is_allocated() {
+            match self.deallocation {
+                Deallocation::Native => unsafe {
+                    memory::free_aligned(self.ptr as *mut u8, self.capacity)
+                },
+                Deallocation::Pool(ref pool) if self.capacity != 0 => unsafe {
+                    pool.free(self.ptr as *mut u8, self.capacity)
+                },
+                Deallocation::Pool(_)
+                | Deallocation::Unowned
+                | Deallocation::Custom(_) => {}
+            }
         }
     }
 }
@@ -141,7 +181,7 @@ impl Buffer {
     /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
     /// bytes. If the `ptr` and `capacity` come from a `Buffer`, then this is guaranteed.
     pub unsafe fn from_raw_parts(ptr: *const u8, len: usize, capacity: usize) -> Self {
-        Buffer::build_with_arguments(ptr, len, capacity, true)
+        Buffer::build_with_arguments(ptr, len, capacity, Deallocation::Native)
     }
 
     /// Creates a buffer from an existing memory region (must already be byte-aligned), this
@@ -158,7 +198,84 @@ impl Buffer {
     /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
     /// bytes. If the `ptr` and `capacity` come from a `Buffer`, then this is guaranteed.
     pub unsafe fn from_unowned(ptr: *const u8, len: usize, capacity: usize) -> Self {
-        Buffer::build_with_arguments(ptr, len, capacity, false)
+        Buffer::build_with_arguments(ptr, len, capacity, Deallocation::Unowned)
+    }
+
+    /// Creates a buffer sharing the memory of `owner`, such as a `Vec<u8>` or a memory
+    /// mapped region, without copying it. `owner` is dropped once this `Buffer` and all
+    /// its slices are dropped.
+    ///
+    /// Unlike the other constructors, the memory is not required to be aligned at a 64-byte
+    /// boundary, but only as demanded by the values it holds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::from_owner(vec![1u8, 2, 3, 4]);
+    /// assert_eq!(buffer.slice(2).data(), &[3, 4]);
+    /// ```
+    pub fn from_owner<T: AsRef<[u8]> + Send + 'static>(owner: T) -> Self {
+        // the owner is boxed first, so that moving it afterwards cannot move the memory
+        let owner = Box::new(owner);
+        let (ptr, len) = {
+            let data = (*owner).as_ref();
+            (data.as_ptr(), data.len())
+        };
+        unsafe { Buffer::build_with_owner(ptr, len, owner) }
+    }
+
+    /// Creates a buffer from a memory region allocated outside of Arrow, e.g. by a foreign
+    /// allocator. `deallocate` is called once this `Buffer` and all its slices are
+    /// dropped, and is responsible for releasing the memory.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
+    /// bytes until `deallocate` is called.
+    pub unsafe fn from_custom_allocation<F: FnOnce() + Send + 'static>(
+        ptr: *const u8,
+        len: usize,
+        deallocate: F,
+    ) -> Self {
+        let deallocator = Deallocator(Some(Box::new(deallocate)));
+        Buffer::build_with_owner(ptr, len, Box::new(deallocator))
+    }
+
+    /// Creates a buffer from a memory region owned by an array imported through the
+    /// C Data Interface. The array is released once this `Buffer` and all its slices
+    /// are dropped.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
+    /// bytes for as long as `owner` is not released.
+    pub(crate) unsafe fn from_ffi(
+        ptr: *const u8,
+        len: usize,
+        owner: Arc<ffi::FFI_ArrowArray>,
+    ) -> Self {
+        Buffer::build_with_owner(ptr, len, Box::new(owner))
+    }
+
+    /// Creates a buffer from a memory region owned by `owner`, which is dropped along
+    /// with the last reference to the buffer.
+    unsafe fn build_with_owner(
+        ptr: *const u8,
+        len: usize,
+        owner: Box<Any + Send>,
+    ) -> Self {
+        let buf_data = BufferData {
+            ptr,
+            len,
+            capacity: len,
+            deallocation: Deallocation::Custom(owner),
+        };
+        Buffer {
+            data: Arc::new(buf_data),
+            offset: 0,
+        }
     }
 
     /// Creates a buffer from an existing memory region (must already be byte-aligned).
@@ -168,8 +285,7 @@ impl Buffer {
     /// * `ptr` - Pointer to raw parts
     /// * `len` - Length of raw parts in bytes
     /// * `capacity` - Total allocated memory for the pointer `ptr`, in **bytes**
-    /// * `owned` - Whether the raw parts is owned by this `Buffer`. If true, this `Buffer` will
-    /// free this memory when dropped, otherwise it will skip freeing the raw parts.
+    /// * `deallocation` - How the raw parts are released when this `Buffer` is dropped.
     ///
     /// # Safety
     ///
@@ -179,7 +295,7 @@ impl Buffer {
         ptr: *const u8,
         len: usize,
         capacity: usize,
-        owned: bool,
+        deallocation: Deallocation,
     ) -> Self {
         assert!(
             memory::is_aligned(ptr, memory::ALIGNMENT),
@@ -189,7 +305,7 @@ impl Buffer {
             ptr,
             len,
             capacity,
-            owned,
+            deallocation,
         };
         Buffer {
             data: Arc::new(buf_data),
@@ -288,7 +404,7 @@ impl<T: AsRef<[u8]>> From<T> for Buffer {
         let buffer = memory::allocate_aligned(capacity);
         unsafe {
             memory::memcpy(buffer, slice.as_ptr(), len);
-            Buffer::build_with_arguments(buffer, len, capacity, true)
+            Buffer::build_with_arguments(buffer, len, capacity, Deallocation::Native)
         }
     }
 }
@@ -299,6 +415,7 @@ impl<T: AsRef<[u8]>> From<T> for Buffer {
 /// Contrary to the non-simd version `bitwise_bin_op_helper`, the offset and length is specified in bytes
 /// and this version does not support operations starting at arbitrary bit offsets.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn bitwise_bin_op_simd_helper<F_SIMD, F_SCALAR>(
     left: &Buffer,
     left_offset: usize,
@@ -348,6 +465,7 @@ where
 /// Contrary to the non-simd version `bitwise_unary_op_helper`, the offset and length is specified in bytes
 /// and this version does not support operations starting at arbitrary bit offsets.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn bitwise_unary_op_simd_helper<F_SIMD, F_SCALAR>(
     left: &Buffer,
     left_offset: usize,
@@ -387,6 +505,7 @@ where
 
 /// Apply a bitwise operation `op` to two inputs and return the result as a Buffer.
 /// The inputs are treated as bitmaps, meaning that offsets and length are specified in number of bits.
+#[inline(always)]
 fn bitwise_bin_op_helper<F>(
     left: &Buffer,
     left_offset_in_bits: usize,
@@ -424,6 +543,7 @@ where
 
 /// Apply a bitwise operation `op` to one input and return the result as a Buffer.
 /// The input is treated as a bitmap, meaning that offset and length are specified in number of bits.
+#[inline(always)]
 fn bitwise_unary_op_helper<F>(
     left: &Buffer,
     offset_in_bits: usize,
@@ -469,27 +589,31 @@ pub(super) fn buffer_bin_and(
         && right_offset_in_bits % 8 == 0
         && len_in_bits % 8 == 0
     {
-        return bitwise_bin_op_simd_helper(
-            &left,
-            left_offset_in_bits / 8,
-            &right,
-            right_offset_in_bits / 8,
-            len_in_bits / 8,
-            |a, b| a & b,
-            |a, b| a & b,
-        );
+        return cpu::dispatch(|| {
+            bitwise_bin_op_simd_helper(
+                &left,
+                left_offset_in_bits / 8,
+                &right,
+                right_offset_in_bits / 8,
+                len_in_bits / 8,
+                |a, b| a & b,
+                |a, b| a & b,
+            )
+        });
     }
     // Default implementation
     #[allow(unreachable_code)]
     {
-        bitwise_bin_op_helper(
-            &left,
-            left_offset_in_bits,
-            right,
-            right_offset_in_bits,
-            len_in_bits,
-            |a, b| a & b,
-        )
+        cpu::dispatch(|| {
+            bitwise_bin_op_helper(
+                &left,
+                left_offset_in_bits,
+                right,
+                right_offset_in_bits,
+                len_in_bits,
+                |a, b| a & b,
+            )
+        })
     }
 }
 
@@ -506,27 +630,31 @@ pub(super) fn buffer_bin_or(
         && right_offset_in_bits % 8 == 0
         && len_in_bits % 8 == 0
     {
-        return bitwise_bin_op_simd_helper(
-            &left,
-            left_offset_in_bits / 8,
-            &right,
-            right_offset_in_bits / 8,
-            len_in_bits / 8,
-            |a, b| a | b,
-            |a, b| a | b,
-        );
+        return cpu::dispatch(|| {
+            bitwise_bin_op_simd_helper(
+                &left,
+                left_offset_in_bits / 8,
+                &right,
+                right_offset_in_bits / 8,
+                len_in_bits / 8,
+                |a, b| a | b,
+                |a, b| a | b,
+            )
+        });
     }
     // Default implementation
     #[allow(unreachable_code)]
     {
-        bitwise_bin_op_helper(
-            &left,
-            left_offset_in_bits,
-            right,
-            right_offset_in_bits,
-            len_in_bits,
-            |a, b| a | b,
-        )
+        cpu::dispatch(|| {
+            bitwise_bin_op_helper(
+                &left,
+                left_offset_in_bits,
+                right,
+                right_offset_in_bits,
+                len_in_bits,
+                |a, b| a | b,
+            )
+        })
     }
 }
 
@@ -538,18 +666,22 @@ pub(super) fn buffer_unary_not(
     // SIMD implementation if available and byte-aligned
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
     if offset_in_bits % 8 == 0 && len_in_bits % 8 == 0 {
-        return bitwise_unary_op_simd_helper(
-            &left,
-            offset_in_bits / 8,
-            len_in_bits / 8,
-            |a| !a,
-            |a| !a,
-        );
+        return cpu::dispatch(|| {
+            bitwise_unary_op_simd_helper(
+                &left,
+                offset_in_bits / 8,
+                len_in_bits / 8,
+                |a| !a,
+                |a| !a,
+            )
+        });
     }
     // Default implementation
     #[allow(unreachable_code)]
     {
-        bitwise_unary_op_helper(&left, offset_in_bits, len_in_bits, |a| !a)
+        cpu::dispatch(|| {
+            bitwise_unary_op_helper(&left, offset_in_bits, len_in_bits, |a| !a)
+        })
     }
 }
 
@@ -598,11 +730,17 @@ unsafe impl Send for Buffer {}
 
 /// Similar to `Buffer`, but is growable and can be mutated. A mutable buffer can be
 /// converted into a immutable buffer via the `freeze` method.
+///
+/// The memory of a mutable buffer comes from the system allocator, unless it was
+/// created with `MutableBuffer::try_new_in`, in which case it is allocated from and
+/// returned to the given `MemoryPool`, including after the buffer is frozen.
 #[derive(Debug)]
 pub struct MutableBuffer {
     data: *mut u8,
     len: usize,
     capacity: usize,
+    /// The pool the memory is allocated from, if not the system allocator
+    pool: Option<Arc<MemoryPool>>,
 }
 
 impl MutableBuffer {
@@ -614,9 +752,60 @@ impl MutableBuffer {
             data: ptr,
             len: 0,
             capacity: new_capacity,
+            pool: None,
+        }
+    }
+
+    /// Allocate a new mutable buffer with initial capacity to be `capacity`, from
+    /// `pool`. Any later growth of the buffer is also allocated from `pool`.
+    ///
+    /// Returns an error if `pool` refuses the allocation.
+    pub fn try_new_in(capacity: usize, pool: Arc<MemoryPool>) -> Result<Self> {
+        let mut buffer = Self {
+            data: memory::allocate_aligned(0),
+            len: 0,
+            capacity: 0,
+            pool: Some(pool),
+        };
+        buffer.reallocate(bit_util::round_upto_multiple_of_64(capacity))?;
+        Ok(buffer)
+    }
+
+    /// Returns the pool this buffer is allocated from, or `None` if it is allocated
+    /// from the system allocator.
+    pub fn pool(&self) -> Option<&Arc<MemoryPool>> {
+        self.pool.as_ref()
+    }
+
+    /// Returns a new empty buffer allocating from the same pool as this one.
+    pub(crate) fn new_empty_like(&self) -> Self {
+        Self {
+            data: memory::allocate_aligned(0),
+            len: 0,
+            capacity: 0,
+            pool: self.pool.clone(),
         }
     }
 
+    /// Moves the data of this buffer to a region of `new_capacity` bytes.
+    fn reallocate(&mut self, new_capacity: usize) -> Result<()> {
+        let new_data = match self.pool {
+            None => unsafe { memory::reallocate(self.data, self.capacity, new_capacity) },
+            Some(_) if new_capacity == self.capacity => self.data,
+            Some(ref pool) if self.capacity == 0 => pool.allocate(new_capacity)?,
+            Some(ref pool) if new_capacity == 0 => {
+                unsafe { pool.free(self.data, self.capacity) };
+                memory::allocate_aligned(0)
+            }
+            Some(ref pool) => unsafe {
+                pool.reallocate(self.data, self.capacity, new_capacity)?
+            },
+        };
+        self.data = new_data;
+        self.capacity = new_capacity;
+        Ok(())
+    }
+
     /// Set the bits in the range of `[0, end)` to 0 (if `val` is false), or 1 (if `val`
     /// is true). Also extend the length of this buffer to be `end`.
     ///
@@ -653,10 +842,7 @@ impl MutableBuffer {
         if capacity > self.capacity {
             let new_capacity = bit_util::round_upto_multiple_of_64(capacity);
             let new_capacity = cmp::max(new_capacity, self.capacity * 2);
-            let new_data =
-                unsafe { memory::reallocate(self.data, self.capacity, new_capacity) };
-            self.data = new_data as *mut u8;
-            self.capacity = new_capacity;
+            self.reallocate(new_capacity)?;
         }
         Ok(self.capacity)
     }
@@ -674,10 +860,7 @@ impl MutableBuffer {
         } else {
             let new_capacity = bit_util::round_upto_multiple_of_64(new_len);
             if new_capacity < self.capacity {
-                let new_data =
-                    unsafe { memory::reallocate(self.data, self.capacity, new_capacity) };
-                self.data = new_data as *mut u8;
-                self.capacity = new_capacity;
+                self.reallocate(new_capacity)?;
             }
         }
         self.len = new_len;
@@ -740,12 +923,16 @@ impl MutableBuffer {
     }
 
     /// Freezes this buffer and return an immutable version of it.
-    pub fn freeze(self) -> Buffer {
+    pub fn freeze(mut self) -> Buffer {
+        let deallocation = match self.pool.take() {
+            Some(pool) => Deallocation::Pool(pool),
+            None => Deallocation::Native,
+        };
         let buffer_data = BufferData {
             ptr: self.data,
             len: self.len,
             capacity: self.capacity,
-            owned: true,
+            deallocation,
         };
         std::mem::forget(self);
         Buffer {
@@ -786,8 +973,14 @@ impl MutableBuffer {
 
 impl Drop for MutableBuffer {
     fn drop(&mut self) {
-        if !self.data.is_null() {
-            unsafe { memory::free_aligned(self.data, self.capacity) };
+        match self.pool {
+            None if !self.data.is_null() => unsafe {
+                memory::free_aligned(self.data, self.capacity)
+            },
+            Some(ref pool) if self.capacity != 0 => unsafe {
+                pool.free(self.data, self.capacity)
+            },
+            _ => {}
         }
     }
 }
@@ -832,7 +1025,8 @@ mod tests {
     use std::thread;
 
     use super::*;
-    use crate::datatypes::ToByteSlice;
+    use crate::array::{Array, ArrayData, Int32Array};
+    use crate::datatypes::{DataType, ToByteSlice};
 
     #[test]
     fn test_buffer_data_equality() {
@@ -877,6 +1071,92 @@ mod tests {
         assert_eq!([0, 1, 2, 3, 4], buf.data());
     }
 
+    #[test]
+    fn test_from_owner() {
+        let vec = vec![0u8, 1, 2, 3, 4];
+        let ptr = vec.as_ptr();
+        let buf = Buffer::from_owner(vec);
+        assert_eq!(5, buf.len());
+        assert_eq!(5, buf.capacity());
+        // the memory of the vector is shared, not copied
+        assert_eq!(ptr, buf.raw_data());
+        assert_eq!([0, 1, 2, 3, 4], buf.data());
+        assert_eq!([3, 4], buf.slice(3).data());
+    }
+
+    #[test]
+    fn test_from_custom_allocation() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let deallocated = Arc::new(AtomicBool::new(false));
+        let vec = vec![1i32, 2, 3, 4];
+        let buf = unsafe {
+            let flag = deallocated.clone();
+            Buffer::from_custom_allocation(
+                vec.as_ptr() as *const u8,
+                vec.len() * mem::size_of::<i32>(),
+                move || {
+                    drop(vec);
+                    flag.store(true, Ordering::SeqCst);
+                },
+            )
+        };
+
+        // the memory is only released once all the arrays and slices using it are dropped
+        let array = Int32Array::from(
+            ArrayData::builder(DataType::Int32)
+                .len(3)
+                .add_buffer(buf.slice(4))
+                .build(),
+        );
+        let sliced = array.slice(1, 2);
+        drop(buf);
+        drop(array);
+        assert!(!deallocated.load(Ordering::SeqCst));
+        {
+            let values = sliced.as_any().downcast_ref::<Int32Array>().unwrap();
+            assert_eq!(3, values.value(0));
+            assert_eq!(4, values.value(1));
+        }
+        drop(sliced);
+        assert!(deallocated.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_mutable_buffer_in_pool() {
+        let pool = Arc::new(memory::TrackingMemoryPool::with_limit(256));
+        let mut buf = MutableBuffer::try_new_in(100, pool.clone()).unwrap();
+        assert_eq!(128, buf.capacity());
+        assert_eq!(128, pool.bytes_allocated());
+
+        buf.write_all(&[1; 100]).unwrap();
+        buf.reserve(200).unwrap();
+        assert_eq!(256, pool.bytes_allocated());
+        assert!(buf.reserve(300).is_err());
+        assert_eq!(256, buf.capacity());
+        assert!(MutableBuffer::try_new_in(1, pool.clone()).is_err());
+
+        buf.resize(10).unwrap();
+        assert_eq!(64, pool.bytes_allocated());
+        assert_eq!(256, pool.peak_bytes_allocated());
+
+        // the memory is returned to the pool once the frozen buffer is dropped
+        let frozen = buf.freeze();
+        let slice = frozen.slice(2);
+        drop(frozen);
+        assert_eq!(&[1; 8], slice.data());
+        assert_eq!(64, pool.bytes_allocated());
+        drop(slice);
+        assert_eq!(0, pool.bytes_allocated());
+
+        let mut buf = MutableBuffer::try_new_in(0, pool.clone()).unwrap();
+        assert_eq!(0, pool.bytes_allocated());
+        buf.resize(10).unwrap();
+        buf.resize(0).unwrap();
+        drop(buf);
+        assert_eq!(0, pool.bytes_allocated());
+    }
+
     #[test]
     fn test_copy() {
         let buf = Buffer::from(&[0, 1, 2, 3, 4]);
@@ -1119,4 +1399,66 @@ mod tests {
         check_as_typed_data!(&[1f32, 3f32, 6f32], f32);
         check_as_typed_data!(&[1f64, 3f64, 6f64], f64);
     }
+
+    #[test]
+    fn test_bitwise_ops_simd_levels() {
+        use crate::util::cpu::{with_simd_level, SimdLevel};
+
+        let left: Vec<u8> = (0..200u32).map(|i| (i * 37 % 251) as u8).collect();
+        let right: Vec<u8> = (0..200u32).map(|i| (i * 91 % 241) as u8).collect();
+        let left_buffer = Buffer::from(&left);
+        let right_buffer = Buffer::from(&right);
+
+        // byte-aligned and unaligned offsets, lengths not a multiple of 64
+        for &(left_offset, right_offset, len) in
+            &[(0, 0, 1600), (8, 16, 1000), (3, 11, 1203), (5, 0, 70)]
+        {
+            // every level is checked against results computed bit by bit
+            let expected = |op: fn(bool, bool) -> bool| {
+                (0..len)
+                    .map(|i| {
+                        op(
+                            bit_util::get_bit(&left, left_offset + i),
+                            bit_util::get_bit(&right, right_offset + i),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let expected = vec![
+                expected(|a, b| a & b),
+                expected(|a, b| a | b),
+                expected(|a, _| !a),
+            ];
+            for level in SimdLevel::supported() {
+                let results = with_simd_level(level, || {
+                    vec![
+                        buffer_bin_and(
+                            &left_buffer,
+                            left_offset,
+                            &right_buffer,
+                            right_offset,
+                            len,
+                        ),
+                        buffer_bin_or(
+                            &left_buffer,
+                            left_offset,
+                            &right_buffer,
+                            right_offset,
+                            len,
+                        ),
+                        buffer_unary_not(&left_buffer, left_offset, len),
+                    ]
+                });
+                let results = results
+                    .iter()
+                    .map(|result| {
+                        (0..len)
+                            .map(|i| bit_util::get_bit(result.data(), i))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(expected, results, "{:?}", level);
+            }
+        }
+    }
 }
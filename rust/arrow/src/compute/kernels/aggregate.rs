@@ -21,6 +21,7 @@ use std::ops::Add;
 
 use crate::array::{Array, GenericStringArray, PrimitiveArray, StringOffsetSizeTrait};
 use crate::datatypes::ArrowNumericType;
+use crate::util::cpu;
 
 /// Helper macro to perform min/max of strings
 fn min_max_string<T: StringOffsetSizeTrait, F: Fn(&str, &str) -> bool>(
@@ -61,7 +62,7 @@ pub fn min<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T: ArrowNumericType,
 {
-    min_max_helper(array, |a, b| a > b)
+    cpu::dispatch(|| min_max_helper(array, |a, b| a > b))
 }
 
 /// Returns the maximum value in the array, according to the natural order.
@@ -69,7 +70,7 @@ pub fn max<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T: ArrowNumericType,
 {
-    min_max_helper(array, |a, b| a < b)
+    cpu::dispatch(|| min_max_helper(array, |a, b| a < b))
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
@@ -87,6 +88,7 @@ pub fn min_string<T: StringOffsetSizeTrait>(
 }
 
 /// Helper function to perform min/max lambda function on values from a numeric array.
+#[inline(always)]
 fn min_max_helper<T, F>(array: &PrimitiveArray<T>, cmp: F) -> Option<T::Native>
 where
     T: ArrowNumericType,
@@ -125,7 +127,6 @@ where
 /// Returns the sum of values in the array.
 ///
 /// Returns `None` if the array is empty or only contains null values.
-#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd")))]
 pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T: ArrowNumericType,
@@ -137,16 +138,23 @@ where
         return None;
     }
 
+    Some(cpu::dispatch(|| sum_values(array)))
+}
+
+/// Sums the valid values of an array
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd")))]
+#[inline(always)]
+fn sum_values<T>(array: &PrimitiveArray<T>) -> T::Native
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
     let data: &[T::Native] = array.value_slice(0, array.len());
 
     match array.data().null_buffer() {
-        None => {
-            let sum = data.iter().fold(T::default_value(), |accumulator, value| {
-                accumulator + *value
-            });
-
-            Some(sum)
-        }
+        None => data.iter().fold(T::default_value(), |accumulator, value| {
+            accumulator + *value
+        }),
         Some(buffer) => {
             let mut sum = T::default_value();
             let data_chunks = data.chunks_exact(64);
@@ -171,25 +179,18 @@ where
                 }
             });
 
-            Some(sum)
+            sum
         }
     }
 }
 
-/// Returns the sum of values in the array.
-///
-/// Returns `None` if the array is empty or only contains null values.
+/// Sums the valid values of an array using SIMD
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-pub fn sum<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
+#[inline(always)]
+fn sum_values<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> T::Native
 where
     T::Native: Add<Output = T::Native>,
 {
-    let null_count = array.null_count();
-
-    if null_count == array.len() {
-        return None;
-    }
-
     let data: &[T::Native] = array.value_slice(0, array.len());
 
     let mut vector_sum = T::init(T::default_value());
@@ -254,9 +255,7 @@ where
         .iter()
         .for_each(|lane| total_sum = total_sum + *lane);
 
-    total_sum = total_sum + remainder_sum;
-
-    Some(total_sum)
+    total_sum + remainder_sum
 }
 
 #[cfg(test)]
@@ -329,4 +328,54 @@ mod tests {
         assert_eq!(Some("a"), min_string(&a));
         assert_eq!(Some("b"), max_string(&a));
     }
+
+    #[test]
+    fn test_primitive_array_aggregate_simd_levels() {
+        use crate::datatypes::Int64Type;
+        use crate::util::cpu::{with_simd_level, SimdLevel};
+
+        let values: Vec<Option<i64>> = (0..1000)
+            .map(|i| {
+                if i % 13 == 0 {
+                    None
+                } else {
+                    Some(i * 7 % 331 - 150)
+                }
+            })
+            .collect();
+        let valid_values = values.iter().flatten().copied().collect::<Vec<_>>();
+        let with_nulls = Int64Array::from(values.clone());
+        let without_nulls = Int64Array::from(valid_values.clone());
+        // a sliced array exercises non-zero offsets
+        let sliced = with_nulls.slice(7, 900);
+        let sliced = as_primitive_array::<Int64Type>(&sliced);
+        let sliced_values = values[7..907].iter().flatten().copied().collect::<Vec<_>>();
+
+        // every level is checked against results computed value by value
+        let expected = vec![
+            Some(valid_values.iter().sum::<i64>()),
+            Some(valid_values.iter().sum::<i64>()),
+            valid_values.iter().min().copied(),
+            valid_values.iter().max().copied(),
+            valid_values.iter().min().copied(),
+            valid_values.iter().max().copied(),
+            Some(sliced_values.iter().sum::<i64>()),
+            sliced_values.iter().min().copied(),
+        ];
+        for level in SimdLevel::supported() {
+            let results = with_simd_level(level, || {
+                vec![
+                    sum(&with_nulls),
+                    sum(&without_nulls),
+                    min(&with_nulls),
+                    max(&with_nulls),
+                    min(&without_nulls),
+                    max(&without_nulls),
+                    sum(sliced),
+                    min(sliced),
+                ]
+            });
+            assert_eq!(expected, results, "{:?}", level);
+        }
+    }
 }
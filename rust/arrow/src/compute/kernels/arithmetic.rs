@@ -17,10 +17,10 @@
 
 //! Defines basic arithmetic kernels for `PrimitiveArrays`.
 //!
-//! These kernels can leverage SIMD if available on your system.  Currently no runtime
-//! detection is provided, you should enable the specific SIMD intrinsics using
-//! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
-//! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
+//! These kernels leverage SIMD if available on your system. They are compiled for
+//! several instruction sets and the best one supported by the CPU is selected at
+//! runtime, see [`cpu`](crate::util::cpu). With the `simd` feature, they use explicit
+//! `packed_simd` vectors, and otherwise loops that the compiler vectorizes.
 
 #[cfg(feature = "simd")]
 use std::mem;
@@ -42,7 +42,7 @@ use crate::compute::util::simd_load_set_invalid;
 use crate::datatypes;
 use crate::datatypes::ToByteSlice;
 use crate::error::{ArrowError, Result};
-use crate::{array::*, util::bit_util};
+use crate::{array::*, util::bit_util, util::cpu};
 
 /// Helper function to perform math lambda function on values from two arrays. If either
 /// left or right value is null then the output value is also null, so `1 + null` is
@@ -69,9 +69,13 @@ where
     let null_bit_buffer =
         combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
 
-    let values = (0..left.len())
-        .map(|i| op(left.value(i), right.value(i)))
-        .collect::<Vec<T::Native>>();
+    let values = cpu::dispatch(|| {
+        binary_values(
+            left.value_slice(0, left.len()),
+            right.value_slice(0, right.len()),
+            op,
+        )
+    });
 
     let data = ArrayData::new(
         T::DATA_TYPE,
@@ -85,6 +89,15 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
+/// Applies `op` to each pair of values
+#[inline(always)]
+fn binary_values<T: Copy, F: Fn(T, T) -> T>(left: &[T], right: &[T], op: F) -> Vec<T> {
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| op(*l, *r))
+        .collect()
+}
+
 /// Helper function to divide two arrays.
 ///
 /// # Errors
@@ -92,6 +105,7 @@ where
 /// This function errors if:
 /// * the arrays have different lengths
 /// * a division by zero is found
+#[inline(always)]
 fn math_divide<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -152,6 +166,7 @@ where
 
 /// SIMD vectorized version of `math_op` above.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn simd_math_op<T, F>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -208,6 +223,7 @@ where
 /// is a need to handle situations where a divide by `0` occurs.  This is complicated by `NULL`
 /// slots and padding.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn simd_divide<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -277,7 +293,7 @@ where
         + Zero,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_math_op(&left, &right, |a, b| a + b);
+    return cpu::dispatch(|| simd_math_op(&left, &right, |a, b| a + b));
 
     #[allow(unreachable_code)]
     math_op(left, right, |a, b| a + b)
@@ -298,7 +314,7 @@ where
         + Zero,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_math_op(&left, &right, |a, b| a - b);
+    return cpu::dispatch(|| simd_math_op(&left, &right, |a, b| a - b));
 
     #[allow(unreachable_code)]
     math_op(left, right, |a, b| a - b)
@@ -319,7 +335,7 @@ where
         + Zero,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_math_op(&left, &right, |a, b| a * b);
+    return cpu::dispatch(|| simd_math_op(&left, &right, |a, b| a * b));
 
     #[allow(unreachable_code)]
     math_op(left, right, |a, b| a * b)
@@ -342,16 +358,17 @@ where
         + One,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_divide(&left, &right);
+    return cpu::dispatch(|| simd_divide(&left, &right));
 
     #[allow(unreachable_code)]
-    math_divide(&left, &right)
+    cpu::dispatch(|| math_divide(&left, &right))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::Int32Array;
+    use crate::array::{Float16Array, Int32Array};
+    use crate::datatypes::f16;
 
     #[test]
     fn test_primitive_array_add() {
@@ -365,6 +382,21 @@ mod tests {
         assert_eq!(17, c.value(4));
     }
 
+    #[test]
+    fn test_float16_array_arithmetic() {
+        let a = Float16Array::from(vec![Some(f16::from_f32(1.5)), None, Some(f16::ONE)]);
+        let b = Float16Array::from(vec![f16::from_f32(2.0); 3]);
+        let c = add(&a, &b).unwrap();
+        assert_eq!(f16::from_f32(3.5), c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(f16::from_f32(3.0), c.value(2));
+        let c = multiply(&a, &b).unwrap();
+        assert_eq!(f16::from_f32(3.0), c.value(0));
+        let c = divide(&a, &b).unwrap();
+        assert_eq!(f16::from_f32(0.75), c.value(0));
+        assert_eq!(f16::from_f32(0.5), c.value(2));
+    }
+
     #[test]
     fn test_primitive_array_add_sliced() {
         let a = Int32Array::from(vec![0, 0, 0, 5, 6, 7, 8, 9, 0]);
@@ -548,4 +580,54 @@ mod tests {
         assert_eq!(true, c.is_null(3));
         assert_eq!(13, c.value(2));
     }
+
+    #[test]
+    fn test_primitive_array_arithmetic_simd_levels() {
+        use crate::util::cpu::{with_simd_level, SimdLevel};
+
+        let a_values: Vec<Option<i32>> = (0..100)
+            .map(|i| if i % 9 == 0 { None } else { Some(i * 3 - 50) })
+            .collect();
+        let b_values: Vec<Option<i32>> = (0..100).map(|i| Some(i % 17 + 1)).collect();
+        let a = Int32Array::from(a_values.clone());
+        let b = Int32Array::from(b_values.clone());
+        // a sliced array exercises non-zero offsets
+        let a_slice = a.slice(5, 90);
+        let a_slice = a_slice.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b_slice = b.slice(3, 90);
+        let b_slice = b_slice.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        // every level is checked against results computed value by value
+        let expected = |a: &[Option<i32>], b: &[Option<i32>], op: fn(i32, i32) -> i32| {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| Some(op((*a)?, (*b)?)))
+                .collect::<Vec<_>>()
+        };
+        let expected = vec![
+            expected(&a_values, &b_values, |a, b| a + b),
+            expected(&a_values, &b_values, |a, b| a - b),
+            expected(&a_values, &b_values, |a, b| a * b),
+            expected(&a_values, &b_values, |a, b| a / b),
+            expected(&a_values[5..95], &b_values[3..93], |a, b| a - b),
+            expected(&a_values[5..95], &b_values[3..93], |a, b| a / b),
+        ];
+        for level in SimdLevel::supported() {
+            let results = with_simd_level(level, || {
+                vec![
+                    add(&a, &b).unwrap(),
+                    subtract(&a, &b).unwrap(),
+                    multiply(&a, &b).unwrap(),
+                    divide(&a, &b).unwrap(),
+                    subtract(a_slice, b_slice).unwrap(),
+                    divide(a_slice, b_slice).unwrap(),
+                ]
+            });
+            let results = results
+                .iter()
+                .map(|result| result.iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(expected, results, "{:?}", level);
+        }
+    }
 }
@@ -17,10 +17,10 @@
 
 //! Defines boolean kernels on Arrow `BooleanArray`'s, e.g. `AND`, `OR` and `NOT`.
 //!
-//! These kernels can leverage SIMD if available on your system.  Currently no runtime
-//! detection is provided, you should enable the specific SIMD intrinsics using
-//! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
-//! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
+//! These kernels leverage SIMD if available on your system. They are compiled for
+//! several instruction sets and the best one supported by the CPU is selected at
+//! runtime, see [`cpu`](crate::util::cpu). With the `simd` feature, they use explicit
+//! `packed_simd` vectors, and otherwise loops that the compiler vectorizes.
 
 use std::sync::Arc;
 
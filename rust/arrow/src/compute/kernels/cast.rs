@@ -55,6 +55,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 
     match (from_type, to_type) {
+        // these date types have no array implementation
+        (Null, Date32(DateUnit::Millisecond)) | (Null, Date64(DateUnit::Day)) => false,
+        (Null, _) => true,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => can_cast_types(list_from, list_to),
@@ -66,8 +69,14 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
 
+        (Decimal(_, _), Decimal(_, _)) => true,
+        (Decimal(_, _), _) => DataType::is_numeric(to_type) || to_type == &Utf8,
+        (_, Decimal(_, _)) => DataType::is_numeric(from_type) || from_type == &Utf8,
+
         (_, Boolean) => DataType::is_numeric(from_type),
         (Boolean, _) => DataType::is_numeric(to_type) || to_type == &Utf8,
+        (Utf8, LargeUtf8) => true,
+        (Binary, LargeBinary) => true,
         (Utf8, _) => DataType::is_numeric(to_type),
         (_, Utf8) => DataType::is_numeric(from_type) || from_type == &Binary,
 
@@ -152,6 +161,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int64, Float32) => true,
         (Int64, Float64) => true,
 
+        (Float16, Float32) => true,
+        (Float16, Float64) => true,
+
         (Float32, UInt8) => true,
         (Float32, UInt16) => true,
         (Float32, UInt32) => true,
@@ -160,6 +172,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float32, Int16) => true,
         (Float32, Int32) => true,
         (Float32, Int64) => true,
+        (Float32, Float16) => true,
         (Float32, Float64) => true,
 
         (Float64, UInt8) => true,
@@ -170,6 +183,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int16) => true,
         (Float64, Int32) => true,
         (Float64, Int64) => true,
+        (Float64, Float16) => true,
         (Float64, Float32) => true,
         // end numeric casts
 
@@ -200,7 +214,6 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Date32(_)) => true,
         (Timestamp(_, _), Date64(_)) => true,
         // date64 to timestamp might not make sense,
-        (Null, Int32) => true,
         (_, _) => false,
     }
 }
@@ -213,12 +226,18 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Null to any type: an array of nulls is created
+/// * Utf8 to LargeUtf8 and Binary to LargeBinary: offsets are widened
 /// * List to List: the underlying data type is cast
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Numeric and Utf8 to Decimal: values that don't fit in the target precision
+///   return null, excess fractional digits are truncated (floats are rounded)
+/// * Decimal to integer: the fractional part is truncated, values that don't fit
+///   return null
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -234,6 +253,13 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         return Ok(array.clone());
     }
     match (from_type, to_type) {
+        (Null, Date32(DateUnit::Millisecond)) | (Null, Date64(DateUnit::Day)) => {
+            Err(ArrowError::ComputeError(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            )))
+        }
+        (Null, _) => Ok(new_null_array(to_type, array.len())),
         (Struct(_), _) => Err(ArrowError::ComputeError(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -315,6 +341,55 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 from_type, to_type,
             ))),
         },
+        (Decimal(_, _), Decimal(precision, scale)) => {
+            cast_decimal_to_decimal(array, *precision, *scale)
+        }
+        (Decimal(_, _), _) => match to_type {
+            UInt8 => cast_decimal_to_numeric::<UInt8Type>(array),
+            UInt16 => cast_decimal_to_numeric::<UInt16Type>(array),
+            UInt32 => cast_decimal_to_numeric::<UInt32Type>(array),
+            UInt64 => cast_decimal_to_numeric::<UInt64Type>(array),
+            Int8 => cast_decimal_to_numeric::<Int8Type>(array),
+            Int16 => cast_decimal_to_numeric::<Int16Type>(array),
+            Int32 => cast_decimal_to_numeric::<Int32Type>(array),
+            Int64 => cast_decimal_to_numeric::<Int64Type>(array),
+            Float32 => cast_decimal_to_numeric::<Float32Type>(array),
+            Float64 => cast_decimal_to_numeric::<Float64Type>(array),
+            Utf8 => {
+                let from = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+                let mut b = StringBuilder::new(array.len());
+                for i in 0..array.len() {
+                    if array.is_null(i) {
+                        b.append_null()?;
+                    } else {
+                        b.append_value(&from.value_as_string(i))?;
+                    }
+                }
+
+                Ok(Arc::new(b.finish()) as ArrayRef)
+            }
+            _ => Err(ArrowError::ComputeError(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
+        (_, Decimal(precision, scale)) => match from_type {
+            UInt8 => cast_numeric_to_decimal::<UInt8Type>(array, *precision, *scale),
+            UInt16 => cast_numeric_to_decimal::<UInt16Type>(array, *precision, *scale),
+            UInt32 => cast_numeric_to_decimal::<UInt32Type>(array, *precision, *scale),
+            UInt64 => cast_numeric_to_decimal::<UInt64Type>(array, *precision, *scale),
+            Int8 => cast_numeric_to_decimal::<Int8Type>(array, *precision, *scale),
+            Int16 => cast_numeric_to_decimal::<Int16Type>(array, *precision, *scale),
+            Int32 => cast_numeric_to_decimal::<Int32Type>(array, *precision, *scale),
+            Int64 => cast_numeric_to_decimal::<Int64Type>(array, *precision, *scale),
+            Float32 => cast_numeric_to_decimal::<Float32Type>(array, *precision, *scale),
+            Float64 => cast_numeric_to_decimal::<Float64Type>(array, *precision, *scale),
+            Utf8 => cast_string_to_decimal(array, *precision, *scale),
+            _ => Err(ArrowError::ComputeError(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
         (_, Boolean) => match from_type {
             UInt8 => cast_numeric_to_bool::<UInt8Type>(array),
             UInt16 => cast_numeric_to_bool::<UInt16Type>(array),
@@ -364,6 +439,24 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 from_type, to_type,
             ))),
         },
+        (Utf8, LargeUtf8) => {
+            let from = array.as_any().downcast_ref::<StringArray>().unwrap();
+            let values = from.iter().collect::<Vec<_>>();
+            Ok(Arc::new(LargeStringArray::from(values)) as ArrayRef)
+        }
+        (Binary, LargeBinary) => {
+            let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            let values = (0..from.len())
+                .map(|i| {
+                    if from.is_null(i) {
+                        None
+                    } else {
+                        Some(from.value(i))
+                    }
+                })
+                .collect::<Vec<_>>();
+            Ok(Arc::new(LargeBinaryArray::from(values)) as ArrayRef)
+        }
         (Utf8, _) => match to_type {
             UInt8 => cast_string_to_numeric::<UInt8Type>(array),
             UInt16 => cast_string_to_numeric::<UInt16Type>(array),
@@ -494,6 +587,9 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         (Int64, Float32) => cast_numeric_arrays::<Int64Type, Float32Type>(array),
         (Int64, Float64) => cast_numeric_arrays::<Int64Type, Float64Type>(array),
 
+        (Float16, Float32) => cast_numeric_arrays::<Float16Type, Float32Type>(array),
+        (Float16, Float64) => cast_numeric_arrays::<Float16Type, Float64Type>(array),
+
         (Float32, UInt8) => cast_numeric_arrays::<Float32Type, UInt8Type>(array),
         (Float32, UInt16) => cast_numeric_arrays::<Float32Type, UInt16Type>(array),
         (Float32, UInt32) => cast_numeric_arrays::<Float32Type, UInt32Type>(array),
@@ -502,6 +598,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         (Float32, Int16) => cast_numeric_arrays::<Float32Type, Int16Type>(array),
         (Float32, Int32) => cast_numeric_arrays::<Float32Type, Int32Type>(array),
         (Float32, Int64) => cast_numeric_arrays::<Float32Type, Int64Type>(array),
+        (Float32, Float16) => cast_numeric_arrays::<Float32Type, Float16Type>(array),
         (Float32, Float64) => cast_numeric_arrays::<Float32Type, Float64Type>(array),
 
         (Float64, UInt8) => cast_numeric_arrays::<Float64Type, UInt8Type>(array),
@@ -512,6 +609,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         (Float64, Int16) => cast_numeric_arrays::<Float64Type, Int16Type>(array),
         (Float64, Int32) => cast_numeric_arrays::<Float64Type, Int32Type>(array),
         (Float64, Int64) => cast_numeric_arrays::<Float64Type, Int64Type>(array),
+        (Float64, Float16) => cast_numeric_arrays::<Float64Type, Float16Type>(array),
         (Float64, Float32) => cast_numeric_arrays::<Float64Type, Float32Type>(array),
         // end numeric casts
 
@@ -751,8 +849,6 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         // date64 to timestamp might not make sense,
 
         // null to primitive/flat types
-        (Null, Int32) => Ok(Arc::new(Int32Array::from(vec![None; array.len()]))),
-
         (_, _) => Err(ArrowError::ComputeError(format!(
             "Casting from {:?} to {:?} not supported",
             from_type, to_type,
@@ -894,6 +990,183 @@ where
         .collect()
 }
 
+/// Returns `10^exponent`, or `None` if it does not fit in an `i128`
+fn decimal_scale_factor(exponent: usize) -> Option<i128> {
+    10_i128.checked_pow(exponent as u32)
+}
+
+/// Returns whether an unscaled decimal `value` has no more than `precision` digits
+fn decimal_fits_precision(value: i128, precision: usize) -> bool {
+    match decimal_scale_factor(precision) {
+        Some(max) => value > -max && value < max,
+        // every i128 value fits in a precision of 39 digits or more
+        None => true,
+    }
+}
+
+/// Returns whether the primitive type is a floating point type
+fn is_floating_point<T: ArrowPrimitiveType>() -> bool {
+    match T::DATA_TYPE {
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => true,
+        _ => false,
+    }
+}
+
+/// Cast numeric types to Decimal
+fn cast_numeric_to_decimal<FROM>(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: num::NumCast,
+{
+    let from = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    let factor = decimal_scale_factor(scale).ok_or_else(|| {
+        ArrowError::ComputeError(format!("Decimal scale {} is too large", scale))
+    })?;
+    let is_float = is_floating_point::<FROM>();
+
+    let mut b = DecimalBuilder::new(from.len(), precision, scale);
+    for i in 0..from.len() {
+        let value = if from.is_null(i) {
+            None
+        } else if is_float {
+            num::cast::cast::<FROM::Native, f64>(from.value(i))
+                .and_then(|v| num::cast::cast::<f64, i128>((v * factor as f64).round()))
+        } else {
+            num::cast::cast::<FROM::Native, i128>(from.value(i))
+                .and_then(|v| v.checked_mul(factor))
+        };
+        match value {
+            Some(v) if decimal_fits_precision(v, precision) => b.append_value(v)?,
+            _ => b.append_null()?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast Decimal to numeric types
+fn cast_decimal_to_numeric<TO>(array: &ArrayRef) -> Result<ArrayRef>
+where
+    TO: ArrowNumericType,
+    TO::Native: num::NumCast,
+{
+    let from = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let factor = decimal_scale_factor(from.scale()).ok_or_else(|| {
+        ArrowError::ComputeError(format!("Decimal scale {} is too large", from.scale()))
+    })?;
+    let is_float = is_floating_point::<TO>();
+
+    let array: PrimitiveArray<TO> = (0..from.len())
+        .map(|i| {
+            if from.is_null(i) {
+                None
+            } else if is_float {
+                num::cast::cast::<f64, TO::Native>(from.value(i) as f64 / factor as f64)
+            } else {
+                num::cast::cast::<i128, TO::Native>(from.value(i) / factor)
+            }
+        })
+        .collect();
+
+    Ok(Arc::new(array) as ArrayRef)
+}
+
+/// Cast Decimal to a Decimal of a different precision and/or scale
+fn cast_decimal_to_decimal(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+) -> Result<ArrayRef> {
+    let from = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let (multiply, factor) = if scale >= from.scale() {
+        (true, decimal_scale_factor(scale - from.scale()))
+    } else {
+        (false, decimal_scale_factor(from.scale() - scale))
+    };
+
+    let mut b = DecimalBuilder::new(from.len(), precision, scale);
+    for i in 0..from.len() {
+        let value = if from.is_null(i) {
+            None
+        } else if multiply {
+            factor.and_then(|f| from.value(i).checked_mul(f))
+        } else {
+            // the value has fewer digits than the factor, only zero remains
+            Some(factor.map_or(0, |f| from.value(i) / f))
+        };
+        match value {
+            Some(v) if decimal_fits_precision(v, precision) => b.append_value(v)?,
+            _ => b.append_null()?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast Utf8 to Decimal
+///
+/// Strings that are not valid decimal numbers, or that don't fit in the precision,
+/// return null.
+fn cast_string_to_decimal(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+) -> Result<ArrayRef> {
+    let from = array.as_any().downcast_ref::<StringArray>().unwrap();
+
+    let mut b = DecimalBuilder::new(from.len(), precision, scale);
+    for i in 0..from.len() {
+        let value = if from.is_null(i) {
+            None
+        } else {
+            parse_decimal(from.value(i), scale)
+        };
+        match value {
+            Some(v) if decimal_fits_precision(v, precision) => b.append_value(v)?,
+            _ => b.append_null()?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Parse a decimal string such as `-12.345` into an unscaled value with `scale`
+/// fractional digits, truncating any excess fractional digits.
+fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, s) = if s.starts_with('-') {
+        (true, &s[1..])
+    } else if s.starts_with('+') {
+        (false, &s[1..])
+    } else {
+        (false, s)
+    };
+    let mut parts = s.splitn(2, '.');
+    let integral = parts.next().unwrap_or("");
+    let fractional = parts.next().unwrap_or("");
+    if (integral.is_empty() && fractional.is_empty())
+        || !integral.bytes().all(|c| c.is_ascii_digit())
+        || !fractional.bytes().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut value: i128 = 0;
+    let fractional_digits = fractional.bytes().chain(std::iter::repeat(b'0'));
+    for c in integral.bytes().chain(fractional_digits.take(scale)) {
+        value = value.checked_mul(10)?.checked_add((c - b'0') as i128)?;
+    }
+
+    Some(if negative { -value } else { value })
+}
+
 /// Cast numeric types to Boolean
 ///
 /// Any zero value returns `false` while non-zero returns `true`
@@ -1171,6 +1444,33 @@ mod tests {
         assert!(9.0 - c.value(4) < f64::EPSILON);
     }
 
+    #[test]
+    fn test_cast_f16_to_from_float() {
+        let a = Float16Array::from(vec![Some(f16::from_f32(1.5)), None, Some(f16::MAX)]);
+        let array = Arc::new(a) as ArrayRef;
+        assert_eq!("1.5", array_value_to_string(&array, 0).unwrap());
+        let b = cast(&array, &DataType::Float32).unwrap();
+        let c = b.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(1.5, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(65504.0, c.value(2));
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(1.5, c.value(0));
+        assert!(c.is_null(1));
+
+        // values are rounded to the nearest half-precision float
+        let array = Arc::new(Float64Array::from(vec![0.1, -2.25])) as ArrayRef;
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let c = b.as_any().downcast_ref::<Float16Array>().unwrap();
+        assert_eq!(f16::from_f64(0.1), c.value(0));
+        assert_eq!(f16::from_f32(-2.25), c.value(1));
+        let array = Arc::new(Float32Array::from(vec![3.0])) as ArrayRef;
+        let b = cast(&array, &DataType::Float16).unwrap();
+        let c = b.as_any().downcast_ref::<Float16Array>().unwrap();
+        assert_eq!(f16::from_f32(3.0), c.value(0));
+    }
+
     #[test]
     fn test_cast_i32_to_u8() {
         let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
@@ -1308,6 +1608,115 @@ mod tests {
         assert_eq!(false, c.is_valid(2));
     }
 
+    #[test]
+    fn test_cast_numeric_to_decimal() {
+        let a = Int32Array::from(vec![Some(5), None, Some(-12), Some(1_000_000)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(500, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!(-1200, c.value(2));
+        // 1_000_000.00 does not fit in a precision of 5
+        assert_eq!(false, c.is_valid(3));
+
+        let a = Float64Array::from(vec![1.005, -2.5, std::f64::NAN]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(10, 3)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(1005, c.value(0));
+        assert_eq!(-2500, c.value(1));
+        assert_eq!(false, c.is_valid(2));
+    }
+
+    #[test]
+    fn test_cast_decimal_to_numeric() {
+        let mut builder = DecimalBuilder::new(3, 10, 2);
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-199).unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(123, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!(-1, c.value(2));
+
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(123, c.value(0));
+        assert_eq!(false, c.is_valid(2));
+
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert!((123.45 - c.value(0)).abs() < f64::EPSILON);
+        assert!((-1.99 - c.value(2)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_cast_decimal_to_decimal() {
+        let mut builder = DecimalBuilder::new(3, 5, 2);
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-199).unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let b = cast(&array, &DataType::Decimal(10, 4)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(1234500, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!(-19900, c.value(2));
+
+        let b = cast(&array, &DataType::Decimal(3, 0)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(123, c.value(0));
+        assert_eq!(-1, c.value(2));
+
+        let b = cast(&array, &DataType::Decimal(2, 1)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(false, c.is_valid(0));
+        assert_eq!(-19, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_decimal() {
+        let a = StringArray::from(vec![
+            Some("123.45"),
+            Some("-0.5"),
+            Some("7"),
+            Some("1.239"),
+            Some("seven"),
+            Some("123456"),
+            None,
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let c = b.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(12345, c.value(0));
+        assert_eq!(-50, c.value(1));
+        assert_eq!(700, c.value(2));
+        assert_eq!(123, c.value(3));
+        assert_eq!(false, c.is_valid(4));
+        assert_eq!(false, c.is_valid(5));
+        assert_eq!(false, c.is_valid(6));
+    }
+
+    #[test]
+    fn test_cast_decimal_to_utf8() {
+        let mut builder = DecimalBuilder::new(3, 5, 2);
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-5).unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("123.45", c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!("-0.05", c.value(2));
+    }
+
     #[test]
     fn test_cast_bool_to_i32() {
         let a = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -2743,6 +3152,7 @@ mod tests {
             Arc::new(make_large_list_array()),
             Arc::new(make_fixed_size_list_array()),
             Arc::new(make_fixed_size_binary_array()),
+            Arc::new(make_decimal_array()),
             Arc::new(StructArray::from(vec![
                 (
                     Field::new("a", DataType::Boolean, false),
@@ -2767,6 +3177,10 @@ mod tests {
             Arc::new(UInt16Array::from(vec![1, 2])),
             Arc::new(UInt32Array::from(vec![1, 2])),
             Arc::new(UInt64Array::from(vec![1, 2])),
+            Arc::new(Float16Array::from(vec![
+                f16::from_f32(1.0),
+                f16::from_f32(2.0),
+            ])),
             Arc::new(Float32Array::from(vec![1.0, 2.0])),
             Arc::new(Float64Array::from(vec![1.0, 2.0])),
             Arc::new(TimestampSecondArray::from_vec(vec![1000, 2000], None)),
@@ -2874,6 +3288,13 @@ mod tests {
         FixedSizeBinaryArray::from(array_data)
     }
 
+    fn make_decimal_array() -> DecimalArray {
+        let mut builder = DecimalBuilder::new(2, 10, 2);
+        builder.append_value(123).unwrap();
+        builder.append_value(-456).unwrap();
+        builder.finish()
+    }
+
     fn make_union_array() -> UnionArray {
         let mut builder = UnionBuilder::new_dense(7);
         builder.append::<Int32Type>("a", 1).unwrap();
@@ -2946,6 +3367,7 @@ mod tests {
             Interval(IntervalUnit::DayTime),
             Binary,
             FixedSizeBinary(10),
+            Decimal(10, 2),
             LargeBinary,
             Utf8,
             LargeUtf8,
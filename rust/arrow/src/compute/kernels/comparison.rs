@@ -17,10 +17,10 @@
 
 //! Defines basic comparison kernels for `PrimitiveArrays`.
 //!
-//! These kernels can leverage SIMD if available on your system.  Currently no runtime
-//! detection is provided, you should enable the specific SIMD intrinsics using
-//! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
-//! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
+//! These kernels leverage SIMD if available on your system. They are compiled for
+//! several instruction sets and the best one supported by the CPU is selected at
+//! runtime, see [`cpu`](crate::util::cpu). With the `simd` feature, they use explicit
+//! `packed_simd` vectors, and otherwise loops that the compiler vectorizes.
 
 use regex::Regex;
 use std::collections::HashMap;
@@ -32,6 +32,7 @@ use crate::compute::util::combine_option_bitmap;
 use crate::datatypes::{ArrowNumericType, BooleanType, DataType};
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
+use crate::util::cpu;
 
 /// Helper function to perform boolean lambda function on values from two arrays, this
 /// version does not attempt to use SIMD.
@@ -86,6 +87,123 @@ macro_rules! compare_op_scalar {
     }};
 }
 
+/// Packs the results of `op` on each pair of values into a bitmap
+#[inline(always)]
+fn compare_values<T: Copy, F: Fn(T, T) -> bool>(
+    left: &[T],
+    right: &[T],
+    op: F,
+) -> Buffer {
+    let len = left.len();
+    let mut result = MutableBuffer::new(bit_util::ceil(len, 64) * 8)
+        .with_bitset(bit_util::ceil(len, 64) * 8, false);
+    left.chunks(64)
+        .zip(right.chunks(64))
+        .zip(result.typed_data_mut::<u64>().iter_mut())
+        .for_each(|((left, right), bits)| {
+            *bits = left
+                .iter()
+                .zip(right.iter())
+                .enumerate()
+                .fold(0, |bits, (i, (l, r))| bits | ((op(*l, *r) as u64) << i));
+        });
+    result.resize(bit_util::ceil(len, 8)).unwrap();
+    result.freeze()
+}
+
+/// Packs the results of `op` on each value and `right` into a bitmap
+#[inline(always)]
+fn compare_values_scalar<T: Copy, F: Fn(T, T) -> bool>(
+    left: &[T],
+    right: T,
+    op: F,
+) -> Buffer {
+    let len = left.len();
+    let mut result = MutableBuffer::new(bit_util::ceil(len, 64) * 8)
+        .with_bitset(bit_util::ceil(len, 64) * 8, false);
+    left.chunks(64)
+        .zip(result.typed_data_mut::<u64>().iter_mut())
+        .for_each(|(left, bits)| {
+            *bits = left
+                .iter()
+                .enumerate()
+                .fold(0, |bits, (i, l)| bits | ((op(*l, right) as u64) << i));
+        });
+    result.resize(bit_util::ceil(len, 8)).unwrap();
+    result.freeze()
+}
+
+/// Helper function to perform boolean lambda function on values from two arrays,
+/// compiled for the SIMD instruction set detected at runtime.
+fn dispatch_compare_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+
+    let null_bit_buffer =
+        combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
+    let values = cpu::dispatch(|| {
+        compare_values(
+            left.value_slice(0, left.len()),
+            right.value_slice(0, right.len()),
+            op,
+        )
+    });
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![values],
+        vec![],
+    );
+    Ok(PrimitiveArray::<BooleanType>::from(Arc::new(data)))
+}
+
+/// Helper function to perform boolean lambda function on values from an array and a
+/// scalar value, compiled for the SIMD instruction set detected at runtime.
+fn dispatch_compare_op_scalar<T, F>(
+    left: &PrimitiveArray<T>,
+    right: T::Native,
+    op: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    let null_bit_buffer = left
+        .data()
+        .null_buffer()
+        .map(|buffer| buffer.bit_slice(left.offset(), left.len()));
+    let values = cpu::dispatch(|| {
+        compare_values_scalar(left.value_slice(0, left.len()), right, op)
+    });
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![values],
+        vec![],
+    );
+    Ok(PrimitiveArray::<BooleanType>::from(Arc::new(data)))
+}
+
 pub fn no_simd_compare_op<T, F>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -251,6 +369,7 @@ pub fn gt_eq_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray
 /// Helper function to perform boolean lambda function on values from two arrays using
 /// SIMD.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn simd_compare_op<T, F>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -312,6 +431,7 @@ where
 /// Helper function to perform boolean lambda function on values from an array and a scalar value using
 /// SIMD.
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+#[inline(always)]
 fn simd_compare_op_scalar<T, F>(
     left: &PrimitiveArray<T>,
     right: T::Native,
@@ -325,7 +445,10 @@ where
     use std::mem;
 
     let len = left.len();
-    let null_bit_buffer = left.data().null_buffer().cloned();
+    let null_bit_buffer = left
+        .data()
+        .null_buffer()
+        .map(|buffer| buffer.bit_slice(left.offset(), left.len()));
     let lanes = T::lanes();
     let mut result = MutableBuffer::new(left.len() * mem::size_of::<bool>());
     let simd_right = T::init(right);
@@ -367,13 +490,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::eq);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::eq));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a == b)
+    dispatch_compare_op(left, right, |a, b| a == b)
 }
 
 /// Perform `left == right` operation on an array and a scalar value.
@@ -382,13 +505,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::eq);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::eq));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a == b)
+    dispatch_compare_op_scalar(left, right, |a, b| a == b)
 }
 
 /// Perform `left != right` operation on two arrays.
@@ -397,13 +520,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::ne);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::ne));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a != b)
+    dispatch_compare_op(left, right, |a, b| a != b)
 }
 
 /// Perform `left != right` operation on an array and a scalar value.
@@ -412,13 +535,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::ne);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::ne));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a != b)
+    dispatch_compare_op_scalar(left, right, |a, b| a != b)
 }
 
 /// Perform `left < right` operation on two arrays. Null values are less than non-null
@@ -428,13 +551,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::lt);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::lt));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a < b)
+    dispatch_compare_op(left, right, |a, b| a < b)
 }
 
 /// Perform `left < right` operation on an array and a scalar value.
@@ -444,13 +567,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::lt);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::lt));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a < b)
+    dispatch_compare_op_scalar(left, right, |a, b| a < b)
 }
 
 /// Perform `left <= right` operation on two arrays. Null values are less than non-null
@@ -463,13 +586,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::le);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::le));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a <= b)
+    dispatch_compare_op(left, right, |a, b| a <= b)
 }
 
 /// Perform `left <= right` operation on an array and a scalar value.
@@ -479,13 +602,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::le);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::le));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a <= b)
+    dispatch_compare_op_scalar(left, right, |a, b| a <= b)
 }
 
 /// Perform `left > right` operation on two arrays. Non-null values are greater than null
@@ -495,13 +618,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::gt);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::gt));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a > b)
+    dispatch_compare_op(left, right, |a, b| a > b)
 }
 
 /// Perform `left > right` operation on an array and a scalar value.
@@ -511,13 +634,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::gt);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::gt));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a > b)
+    dispatch_compare_op_scalar(left, right, |a, b| a > b)
 }
 
 /// Perform `left >= right` operation on two arrays. Non-null values are greater than null
@@ -530,13 +653,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op(left, right, T::ge);
+    return cpu::dispatch(|| simd_compare_op(left, right, T::ge));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op!(left, right, |a, b| a >= b)
+    dispatch_compare_op(left, right, |a, b| a >= b)
 }
 
 /// Perform `left >= right` operation on an array and a scalar value.
@@ -546,13 +669,13 @@ where
     T: ArrowNumericType,
 {
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
-    return simd_compare_op_scalar(left, right, T::ge);
+    return cpu::dispatch(|| simd_compare_op_scalar(left, right, T::ge));
 
     #[cfg(any(
         not(any(target_arch = "x86", target_arch = "x86_64")),
         not(feature = "simd")
     ))]
-    compare_op_scalar!(left, right, |a, b| a >= b)
+    dispatch_compare_op_scalar(left, right, |a, b| a >= b)
 }
 
 /// Checks if a `GenericListArray` contains a value in the `PrimitiveArray`
@@ -681,8 +804,8 @@ fn new_all_set_buffer(len: usize) -> Buffer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::Int32Array;
-    use crate::datatypes::{Int8Type, ToByteSlice};
+    use crate::array::{Float16Array, Int32Array};
+    use crate::datatypes::{f16, Int8Type, ToByteSlice};
 
     #[test]
     fn test_primitive_array_eq() {
@@ -696,6 +819,23 @@ mod tests {
         assert_eq!(false, c.value(4));
     }
 
+    #[test]
+    fn test_float16_array_comparison() {
+        let a = Float16Array::from(
+            vec![0.5, 1.0, 2.0]
+                .into_iter()
+                .map(f16::from_f32)
+                .collect::<Vec<_>>(),
+        );
+        let b = Float16Array::from(vec![f16::ONE; 3]);
+        let c = lt(&a, &b).unwrap();
+        assert_eq!(BooleanArray::from(vec![true, false, false]), c);
+        let c = eq(&a, &b).unwrap();
+        assert_eq!(BooleanArray::from(vec![false, true, false]), c);
+        let c = gt_eq_scalar(&a, f16::ONE).unwrap();
+        assert_eq!(BooleanArray::from(vec![false, true, true]), c);
+    }
+
     #[test]
     fn test_primitive_array_eq_scalar() {
         let a = Int32Array::from(vec![6, 7, 8, 9, 10]);
@@ -1179,4 +1319,68 @@ mod tests {
         gt_eq_utf8_scalar,
         vec![false, false, true, true]
     );
+
+    #[test]
+    fn test_primitive_array_compare_simd_levels() {
+        use crate::util::cpu::{with_simd_level, SimdLevel};
+
+        let a_values: Vec<Option<i32>> = (0..150)
+            .map(|i| if i % 7 == 0 { None } else { Some(i % 13) })
+            .collect();
+        let b_values: Vec<Option<i32>> = (0..150)
+            .map(|i| if i % 11 == 0 { None } else { Some(i % 5 + 4) })
+            .collect();
+        let a = Int32Array::from(a_values.clone());
+        let b = Int32Array::from(b_values.clone());
+        // a sliced array exercises non-zero offsets
+        let a_slice = a.slice(3, 130);
+        let a_slice = a_slice.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b_slice = b.slice(0, 130);
+        let b_slice = b_slice.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        // every level is checked against results computed value by value
+        let expected =
+            |a: &[Option<i32>], b: &[Option<i32>], op: fn(i32, i32) -> bool| {
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| Some(op((*a)?, (*b)?)))
+                    .collect::<Vec<_>>()
+            };
+        let six = vec![Some(6); 150];
+        let expected = vec![
+            expected(&a_values, &b_values, |a, b| a == b),
+            expected(&a_values, &b_values, |a, b| a != b),
+            expected(&a_values, &b_values, |a, b| a < b),
+            expected(&a_values, &b_values, |a, b| a <= b),
+            expected(&a_values, &b_values, |a, b| a > b),
+            expected(&a_values, &b_values, |a, b| a >= b),
+            expected(&a_values, &six, |a, b| a == b),
+            expected(&a_values, &six, |a, b| a < b),
+            expected(&a_values, &six, |a, b| a >= b),
+            expected(&a_values[3..133], &b_values[..130], |a, b| a < b),
+            expected(&a_values[3..133], &six, |a, b| a != b),
+        ];
+        for level in SimdLevel::supported() {
+            let results = with_simd_level(level, || {
+                vec![
+                    eq(&a, &b).unwrap(),
+                    neq(&a, &b).unwrap(),
+                    lt(&a, &b).unwrap(),
+                    lt_eq(&a, &b).unwrap(),
+                    gt(&a, &b).unwrap(),
+                    gt_eq(&a, &b).unwrap(),
+                    eq_scalar(&a, 6).unwrap(),
+                    lt_scalar(&a, 6).unwrap(),
+                    gt_eq_scalar(&a, 6).unwrap(),
+                    lt(a_slice, b_slice).unwrap(),
+                    neq_scalar(a_slice, 6).unwrap(),
+                ]
+            });
+            let results = results
+                .iter()
+                .map(|result| result.iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(expected, results, "{:?}", level);
+        }
+    }
 }
@@ -70,6 +70,7 @@ pub fn concat(array_list: &[ArrayRef]) -> Result<ArrayRef> {
         DataType::UInt16 => concat_primitive::<UInt16Type>(array_data_list),
         DataType::UInt32 => concat_primitive::<UInt32Type>(array_data_list),
         DataType::UInt64 => concat_primitive::<UInt64Type>(array_data_list),
+        DataType::Float16 => concat_primitive::<Float16Type>(array_data_list),
         DataType::Float32 => concat_primitive::<Float32Type>(array_data_list),
         DataType::Float64 => concat_primitive::<Float64Type>(array_data_list),
         DataType::Date32(_) => concat_primitive::<Date32Type>(array_data_list),
@@ -356,6 +356,7 @@ impl FilterContext {
             DataType::Int16 => filter_primitive_array!(self, array, Int16Array),
             DataType::Int32 => filter_primitive_array!(self, array, Int32Array),
             DataType::Int64 => filter_primitive_array!(self, array, Int64Array),
+            DataType::Float16 => filter_primitive_array!(self, array, Float16Array),
             DataType::Float32 => filter_primitive_array!(self, array, Float32Array),
             DataType::Float64 => filter_primitive_array!(self, array, Float64Array),
             DataType::Boolean => {
@@ -47,7 +47,19 @@ fn partition_nan<T: ArrowPrimitiveType>(
     v: Vec<u32>,
 ) -> (Vec<u32>, Vec<u32>) {
     // partition by nan for float types
-    if T::DATA_TYPE == DataType::Float32 {
+    if T::DATA_TYPE == DataType::Float16 {
+        let array = array
+            .as_any()
+            .downcast_ref::<Float16Array>()
+            .expect("Unable to downcast array");
+        let has_nan = v.iter().any(|index| array.value(*index as usize).is_nan());
+        if has_nan {
+            v.into_iter()
+                .partition(|index| !array.value(*index as usize).is_nan())
+        } else {
+            (v, vec![])
+        }
+    } else if T::DATA_TYPE == DataType::Float32 {
         // T::Native has no `is_nan` and thus we need to downcast
         let array = array
             .as_any()
@@ -105,6 +117,10 @@ pub fn sort_to_indices(
         DataType::UInt16 => sort_primitive::<UInt16Type>(values, v, n, vec![], &options),
         DataType::UInt32 => sort_primitive::<UInt32Type>(values, v, n, vec![], &options),
         DataType::UInt64 => sort_primitive::<UInt64Type>(values, v, n, vec![], &options),
+        DataType::Float16 => {
+            let (v, nan) = partition_nan::<Float16Type>(values, v);
+            sort_primitive::<Float16Type>(values, v, n, nan, &options)
+        }
         DataType::Float32 => {
             let (v, nan) = partition_nan::<Float32Type>(values, v);
             sort_primitive::<Float32Type>(values, v, n, nan, &options)
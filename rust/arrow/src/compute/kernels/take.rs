@@ -81,6 +81,7 @@ pub fn take(
         DataType::UInt16 => take_primitive::<UInt16Type>(values, indices),
         DataType::UInt32 => take_primitive::<UInt32Type>(values, indices),
         DataType::UInt64 => take_primitive::<UInt64Type>(values, indices),
+        DataType::Float16 => take_primitive::<Float16Type>(values, indices),
         DataType::Float32 => take_primitive::<Float32Type>(values, indices),
         DataType::Float64 => take_primitive::<Float64Type>(values, indices),
         DataType::Date32(_) => take_primitive::<Date32Type>(values, indices),
@@ -125,6 +126,7 @@ pub fn take(
         DataType::Duration(TimeUnit::Nanosecond) => {
             take_primitive::<DurationNanosecondType>(values, indices)
         }
+        DataType::Decimal(_, _) => take_decimal(values, indices),
         DataType::Utf8 => take_string::<i32>(values, indices),
         DataType::LargeUtf8 => take_string::<i64>(values, indices),
         DataType::List(_) => take_list(values, indices),
@@ -261,6 +263,41 @@ fn take_boolean(values: &ArrayRef, indices: &UInt32Array) -> Result<ArrayRef> {
     Ok(Arc::new(BooleanArray::from(Arc::new(data))))
 }
 
+/// `take` implementation for decimal arrays
+fn take_decimal(values: &ArrayRef, indices: &UInt32Array) -> Result<ArrayRef> {
+    let data_len = indices.len();
+
+    let array = values.as_any().downcast_ref::<DecimalArray>().unwrap();
+
+    let num_bytes = bit_util::ceil(data_len, 8);
+    let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, true);
+    let null_slice = null_buf.data_mut();
+
+    let mut new_values: Vec<u8> = Vec::with_capacity(data_len * 16);
+    for i in 0..data_len {
+        let index = indices.value(i) as usize;
+
+        if array.is_valid(index) && indices.is_valid(i) {
+            new_values.extend_from_slice(&array.value(index).to_le_bytes());
+        } else {
+            new_values.extend_from_slice(&[0; 16]);
+            bit_util::unset_bit(null_slice, i);
+        }
+    }
+
+    let nulls = match indices.data_ref().null_buffer() {
+        Some(buffer) => buffer_bin_and(buffer, 0, &null_buf.freeze(), 0, data_len),
+        None => null_buf.freeze(),
+    };
+
+    let data = ArrayData::builder(values.data_type().clone())
+        .len(data_len)
+        .null_bit_buffer(nulls)
+        .add_buffer(Buffer::from(&new_values[..]))
+        .build();
+    Ok(Arc::new(DecimalArray::from(data)))
+}
+
 /// `take` implementation for string arrays
 fn take_string<OffsetSize>(values: &ArrayRef, indices: &UInt32Array) -> Result<ArrayRef>
 where
@@ -535,6 +572,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_take_decimal() {
+        let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(2)]);
+
+        let mut builder = DecimalBuilder::new(5, 10, 2);
+        builder.append_value(111).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-333).unwrap();
+        builder.append_value(444).unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<DecimalArray>().unwrap();
+
+        builder.append_value(444).unwrap();
+        builder.append_null().unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(444).unwrap();
+        builder.append_value(-333).unwrap();
+        let expected = builder.finish();
+
+        assert_eq!(&expected, actual);
+    }
+
     fn _test_take_string<'a, K: 'static>()
     where
         K: Array + From<Vec<Option<&'a str>>>,
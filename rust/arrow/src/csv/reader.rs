@@ -40,8 +40,10 @@
 //! let batch = csv.next().unwrap().unwrap();
 //! ```
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use lazy_static::lazy_static;
 use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
@@ -54,7 +56,7 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 use crate::{
-    array::{ArrayRef, PrimitiveArray, StringBuilder},
+    array::{make_array, ArrayData, ArrayRef, PrimitiveArray, StringBuilder},
     util::buffered_iterator::Buffered,
 };
 
@@ -67,6 +69,31 @@ lazy_static! {
         .case_insensitive(true)
         .build()
         .unwrap();
+    static ref DATE_RE: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    static ref DATETIME_RE: Regex =
+        Regex::new(r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d{1,9})?$").unwrap();
+    static ref DATETIME_TZ_RE: Regex = Regex::new(
+        r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d{1,9})?(Z|[+-]\d{2}:\d{2})$"
+    )
+    .unwrap();
+}
+
+/// The time zone of inferred timestamps with an offset, which are converted to UTC
+const UTC: &str = "UTC";
+
+/// The type inferred for dates
+fn date_type() -> DataType {
+    DataType::Date32(DateUnit::Day)
+}
+
+/// The type inferred for timestamps, with a time zone if they have an offset
+fn timestamp_type(has_offset: bool) -> DataType {
+    let tz = if has_offset {
+        Some(Arc::new(UTC.to_string()))
+    } else {
+        None
+    };
+    DataType::Timestamp(TimeUnit::Nanosecond, tz)
 }
 
 /// Infer the data type of a record
@@ -83,26 +110,177 @@ fn infer_field_schema(string: &str) -> DataType {
         DataType::Float64
     } else if INTEGER_RE.is_match(string) {
         DataType::Int64
+    } else if DATE_RE.is_match(string) {
+        date_type()
+    } else if DATETIME_RE.is_match(string) {
+        timestamp_type(false)
+    } else if DATETIME_TZ_RE.is_match(string) {
+        timestamp_type(true)
     } else {
         DataType::Utf8
     }
 }
 
+/// Infer the data type of a record of a column with a chrono format string, falling
+/// back to the default inference if the record does not match the format
+fn infer_formatted_field_schema(string: &str, format: &str) -> DataType {
+    if DateTime::parse_from_str(string, format).is_ok() {
+        timestamp_type(true)
+    } else if NaiveDateTime::parse_from_str(string, format).is_ok() {
+        timestamp_type(false)
+    } else if NaiveDate::parse_from_str(string, format).is_ok() {
+        date_type()
+    } else {
+        infer_field_schema(string)
+    }
+}
+
+/// Parses a date or timestamp, converting timestamps with an offset to UTC. Without a
+/// format, ISO 8601 dates and timestamps are accepted.
+fn parse_datetime(string: &str, format: Option<&str>) -> Option<NaiveDateTime> {
+    match format {
+        Some(format) => DateTime::parse_from_str(string, format)
+            .map(|datetime| datetime.naive_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(string, format))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(string, format)
+                    .map(|date| date.and_hms(0, 0, 0))
+            })
+            .ok(),
+        None => {
+            // accept a space instead of the `T` separating the date and the time
+            let string = if string.len() > 10 && string.as_bytes()[10] == b' ' {
+                format!("{}T{}", &string[..10], &string[11..])
+            } else {
+                string.to_string()
+            };
+            DateTime::parse_from_rfc3339(&string)
+                .map(|datetime| datetime.naive_utc())
+                .or_else(|_| {
+                    NaiveDateTime::parse_from_str(&string, "%Y-%m-%dT%H:%M:%S%.f")
+                })
+                .or_else(|_| {
+                    NaiveDate::parse_from_str(&string, "%Y-%m-%d")
+                        .map(|date| date.and_hms(0, 0, 0))
+                })
+                .ok()
+        }
+    }
+}
+
+/// Converts a datetime into a timestamp of the given unit, or `None` on overflow
+fn datetime_to_timestamp(datetime: NaiveDateTime, unit: &TimeUnit) -> Option<i64> {
+    let (per_second, nanoseconds_per_unit) = match unit {
+        TimeUnit::Second => (1, 1_000_000_000),
+        TimeUnit::Millisecond => (1_000, 1_000_000),
+        TimeUnit::Microsecond => (1_000_000, 1_000),
+        TimeUnit::Nanosecond => (1_000_000_000, 1),
+    };
+    datetime
+        .timestamp()
+        .checked_mul(per_second)?
+        .checked_add((datetime.timestamp_subsec_nanos() / nanoseconds_per_unit) as i64)
+}
+
+/// The CSV dialect, shared by schema inference and the reader
+#[derive(Debug, Clone)]
+struct Dialect {
+    /// The column delimiter. Defaults to `b','`
+    delimiter: u8,
+    /// The quote character. Defaults to `b'"'`
+    quote: u8,
+    /// An optional escape character for quotes within quoted fields
+    escape: Option<u8>,
+    /// Whether two consecutive quotes within a quoted field are an escaped quote
+    double_quote: bool,
+    /// Whether quotes are special characters
+    quoting: bool,
+    /// An optional character starting comment lines, which are skipped
+    comment: Option<u8>,
+    /// An optional record terminator. By default `\n`, `\r\n` and `\r` are accepted
+    terminator: Option<u8>,
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            double_quote: true,
+            quoting: true,
+            comment: None,
+            terminator: None,
+        }
+    }
+}
+
+impl Dialect {
+    /// The default dialect, with an optional delimiter
+    fn with_delimiter(delimiter: Option<u8>) -> Self {
+        let mut dialect = Dialect::default();
+        if let Some(delimiter) = delimiter {
+            dialect.delimiter = delimiter;
+        }
+        dialect
+    }
+
+    fn reader_builder(&self, has_header: bool) -> csv_crate::ReaderBuilder {
+        let mut builder = csv_crate::ReaderBuilder::new();
+        builder
+            .has_headers(has_header)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.double_quote)
+            .quoting(self.quoting)
+            .comment(self.comment);
+        if let Some(terminator) = self.terminator {
+            builder.terminator(csv_crate::Terminator::Any(terminator));
+        }
+        builder
+    }
+}
+
+/// The strings read as nulls, besides empty values of non-string columns
+#[derive(Debug, Clone, Default)]
+struct NullValues {
+    /// Null strings of the columns without their own list
+    values: Vec<String>,
+    /// Null strings by column name
+    columns: HashMap<String, Vec<String>>,
+}
+
+impl NullValues {
+    /// Returns the null strings of a column
+    fn column(&self, name: &str) -> &[String] {
+        self.columns.get(name).unwrap_or(&self.values)
+    }
+}
+
+/// Returns whether a value of a non-string column is null
+fn is_null(string: &str, null_values: &[String]) -> bool {
+    string.is_empty() || null_values.iter().any(|value| value == string)
+}
+
 /// Infer the schema of a CSV file by reading through the first n records of the file,
 /// with `max_read_records` controlling the maximum number of records to read.
 ///
 /// If `max_read_records` is not set, the whole file is read to infer its schema.
 ///
+/// Columns with a format in `datetime_formats` are inferred as dates or timestamps if
+/// their records match the format, and records in `null_values` are nulls.
+///
 /// Return infered schema and number of records used for inference.
 fn infer_file_schema<R: Read + Seek>(
     reader: &mut BufReader<R>,
-    delimiter: u8,
+    dialect: &Dialect,
     max_read_records: Option<usize>,
     has_header: bool,
+    datetime_formats: &HashMap<String, String>,
+    null_values: &NullValues,
 ) -> Result<(Schema, usize)> {
-    let mut csv_reader = csv_crate::ReaderBuilder::new()
-        .delimiter(delimiter)
-        .from_reader(reader);
+    let mut csv_reader = dialect.reader_builder(true).from_reader(reader);
 
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
@@ -120,6 +298,14 @@ fn infer_file_schema<R: Read + Seek>(
     let position = csv_reader.position().clone();
 
     let header_length = headers.len();
+    let formats: Vec<Option<&String>> = headers
+        .iter()
+        .map(|name| datetime_formats.get(name))
+        .collect();
+    let column_null_values: Vec<&[String]> = headers
+        .iter()
+        .map(|name| null_values.column(name))
+        .collect();
     // keep track of inferred field types
     let mut column_types: Vec<HashSet<DataType>> = vec![HashSet::new(); header_length];
     // keep track of columns with nulls
@@ -140,8 +326,10 @@ fn infer_file_schema<R: Read + Seek>(
 
         for i in 0..header_length {
             if let Some(string) = record.get(i) {
-                if string == "" {
+                if is_null(string, column_null_values[i]) {
                     nulls[i] = true;
+                } else if let Some(format) = formats[i] {
+                    column_types[i].insert(infer_formatted_field_schema(string, format));
                 } else {
                     column_types[i].insert(infer_field_schema(string));
                 }
@@ -169,6 +357,15 @@ fn infer_file_schema<R: Read + Seek>(
                 {
                     // we have an integer and double, fall down to double
                     fields.push(Field::new(&field_name, DataType::Float64, has_nulls));
+                } else if possibilities.contains(&date_type())
+                    && possibilities.contains(&timestamp_type(false))
+                {
+                    // dates are timestamps at midnight
+                    fields.push(Field::new(
+                        &field_name,
+                        timestamp_type(false),
+                        has_nulls,
+                    ));
                 } else {
                     // default to Utf8 for conflicting datatypes (e.g bool and int)
                     fields.push(Field::new(&field_name, DataType::Utf8, has_nulls));
@@ -202,9 +399,11 @@ pub fn infer_schema_from_files(
     for fname in files.iter() {
         let (schema, records_read) = infer_file_schema(
             &mut BufReader::new(File::open(fname)?),
-            delimiter,
+            &Dialect::with_delimiter(Some(delimiter)),
             Some(records_to_read),
             has_header,
+            &HashMap::new(),
+            &NullValues::default(),
         )?;
         if records_read == 0 {
             continue;
@@ -233,6 +432,10 @@ pub struct Reader<R: Read> {
         Buffered<Skip<Take<StringRecordsIntoIter<BufReader<R>>>>, StringRecord, Error>,
     /// Current line number
     line_number: usize,
+    /// Chrono format strings of date and timestamp columns, by column name
+    datetime_formats: HashMap<String, String>,
+    /// Strings read as nulls
+    null_values: NullValues,
 }
 
 impl<R> fmt::Debug for Reader<R>
@@ -244,6 +447,8 @@ where
             .field("schema", &self.schema)
             .field("projection", &self.projection)
             .field("line_number", &self.line_number)
+            .field("datetime_formats", &self.datetime_formats)
+            .field("null_values", &self.null_values)
             .finish()
     }
 }
@@ -302,14 +507,27 @@ impl<R: Read> Reader<R> {
         bounds: Bounds,
         projection: Option<Vec<usize>>,
     ) -> Self {
-        let mut reader_builder = csv_crate::ReaderBuilder::new();
-        reader_builder.has_headers(has_header);
-
-        if let Some(c) = delimiter {
-            reader_builder.delimiter(c);
-        }
+        Self::from_dialect(
+            buf_reader,
+            schema,
+            has_header,
+            &Dialect::with_delimiter(delimiter),
+            batch_size,
+            bounds,
+            projection,
+        )
+    }
 
-        let csv_reader = reader_builder.from_reader(buf_reader);
+    fn from_dialect(
+        buf_reader: BufReader<R>,
+        schema: SchemaRef,
+        has_header: bool,
+        dialect: &Dialect,
+        batch_size: usize,
+        bounds: Bounds,
+        projection: Option<Vec<usize>>,
+    ) -> Self {
+        let csv_reader = dialect.reader_builder(has_header).from_reader(buf_reader);
         let record_iter = csv_reader.into_records();
 
         let (start, end) = match bounds {
@@ -330,6 +548,8 @@ impl<R: Read> Reader<R> {
             projection,
             record_iter,
             line_number: if has_header { start + 1 } else { start + 0 },
+            datetime_formats: HashMap::new(),
+            null_values: NullValues::default(),
         }
     }
 }
@@ -361,6 +581,8 @@ impl<R: Read> Iterator for Reader<R> {
             &self.schema.fields(),
             &self.projection,
             self.line_number,
+            &self.datetime_formats,
+            &self.null_values,
         );
 
         self.line_number += rows.len();
@@ -375,6 +597,8 @@ fn parse(
     fields: &Vec<Field>,
     projection: &Option<Vec<usize>>,
     line_number: usize,
+    datetime_formats: &HashMap<String, String>,
+    null_values: &NullValues,
 ) -> Result<RecordBatch> {
     let projection: Vec<usize> = match projection {
         Some(ref v) => v.clone(),
@@ -386,45 +610,81 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            let format = datetime_formats.get(field.name()).map(|f| f.as_str());
+            let nulls = null_values.column(field.name());
             match field.data_type() {
                 &DataType::Boolean => {
-                    build_primitive_array::<BooleanType>(line_number, rows, i)
+                    build_primitive_array::<BooleanType>(line_number, rows, i, nulls)
                 }
                 &DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i)
+                    build_primitive_array::<Int8Type>(line_number, rows, i, nulls)
                 }
                 &DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i)
+                    build_primitive_array::<Int16Type>(line_number, rows, i, nulls)
                 }
                 &DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i)
+                    build_primitive_array::<Int32Type>(line_number, rows, i, nulls)
                 }
                 &DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i)
+                    build_primitive_array::<Int64Type>(line_number, rows, i, nulls)
                 }
                 &DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i)
+                    build_primitive_array::<UInt8Type>(line_number, rows, i, nulls)
                 }
                 &DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i)
+                    build_primitive_array::<UInt16Type>(line_number, rows, i, nulls)
                 }
                 &DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i)
+                    build_primitive_array::<UInt32Type>(line_number, rows, i, nulls)
                 }
                 &DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i)
+                    build_primitive_array::<UInt64Type>(line_number, rows, i, nulls)
                 }
                 &DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i)
+                    build_primitive_array::<Float32Type>(line_number, rows, i, nulls)
                 }
                 &DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i)
+                    build_primitive_array::<Float64Type>(line_number, rows, i, nulls)
+                }
+                &DataType::Date32(DateUnit::Day) => {
+                    build_datetime_array::<Date32Type, _>(
+                        line_number,
+                        rows,
+                        i,
+                        nulls,
+                        format,
+                        |datetime| {
+                            let epoch = NaiveDate::from_ymd(1970, 1, 1);
+                            Some((datetime.date() - epoch).num_days() as i32)
+                        },
+                    )
+                }
+                &DataType::Date64(DateUnit::Millisecond) => {
+                    build_datetime_array::<Date64Type, _>(
+                        line_number,
+                        rows,
+                        i,
+                        nulls,
+                        format,
+                        |datetime| Some(datetime.timestamp_millis()),
+                    )
                 }
+                DataType::Timestamp(unit, _) => build_timestamp_array(
+                    line_number,
+                    rows,
+                    i,
+                    nulls,
+                    format,
+                    unit,
+                    field.data_type(),
+                ),
                 &DataType::Utf8 => {
                     let mut builder = StringBuilder::new(rows.len());
                     for row in rows.iter() {
                         match row.get(i) {
-                            Some(s) => builder.append_value(s).unwrap(),
+                            Some(s) if !nulls.iter().any(|value| value == s) => {
+                                builder.append_value(s).unwrap()
+                            }
                             _ => builder.append(false).unwrap(),
                         }
                     }
@@ -451,13 +711,14 @@ fn build_primitive_array<T: ArrowPrimitiveType>(
     line_number: usize,
     rows: &[StringRecord],
     col_idx: usize,
+    null_values: &[String],
 ) -> Result<ArrayRef> {
     rows.iter()
         .enumerate()
         .map(|(row_index, row)| {
             match row.get(col_idx) {
                 Some(s) => {
-                    if s.is_empty() {
+                    if is_null(s, null_values) {
                         return Ok(None);
                     }
                     let parsed = if T::DATA_TYPE == DataType::Boolean {
@@ -483,6 +744,99 @@ fn build_primitive_array<T: ArrowPrimitiveType>(
         .map(|e| Arc::new(e) as ArrayRef)
 }
 
+// parses a date or timestamp column (col_idx) into an Arrow Array, converting the
+// parsed values with `convert`.
+fn build_datetime_array<T, F>(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+    null_values: &[String],
+    format: Option<&str>,
+    convert: F,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(NaiveDateTime) -> Option<T::Native>,
+{
+    rows.iter()
+        .enumerate()
+        .map(|(row_index, row)| match row.get(col_idx) {
+            Some(s) if !is_null(s, null_values) => {
+                match parse_datetime(s, format).and_then(&convert) {
+                    Some(e) => Ok(Some(e)),
+                    None => Err(ArrowError::ParseError(format!(
+                        "Error while parsing value {} for column {} at line {}",
+                        s,
+                        col_idx,
+                        line_number + row_index
+                    ))),
+                }
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<PrimitiveArray<T>>>()
+        .map(|e| Arc::new(e) as ArrayRef)
+}
+
+// parses a timestamp column (col_idx) into an Arrow Array of the given unit and
+// data type.
+fn build_timestamp_array(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+    null_values: &[String],
+    format: Option<&str>,
+    unit: &TimeUnit,
+    data_type: &DataType,
+) -> Result<ArrayRef> {
+    let convert = |datetime| datetime_to_timestamp(datetime, unit);
+    let array = match unit {
+        TimeUnit::Second => build_datetime_array::<TimestampSecondType, _>(
+            line_number,
+            rows,
+            col_idx,
+            null_values,
+            format,
+            convert,
+        ),
+        TimeUnit::Millisecond => build_datetime_array::<TimestampMillisecondType, _>(
+            line_number,
+            rows,
+            col_idx,
+            null_values,
+            format,
+            convert,
+        ),
+        TimeUnit::Microsecond => build_datetime_array::<TimestampMicrosecondType, _>(
+            line_number,
+            rows,
+            col_idx,
+            null_values,
+            format,
+            convert,
+        ),
+        TimeUnit::Nanosecond => build_datetime_array::<TimestampNanosecondType, _>(
+            line_number,
+            rows,
+            col_idx,
+            null_values,
+            format,
+            convert,
+        ),
+    }?;
+    // the timezone is not part of the primitive type
+    let data = array.data();
+    Ok(make_array(Arc::new(ArrayData::new(
+        data_type.clone(),
+        data.len(),
+        Some(data.null_count()),
+        data.null_buffer().cloned(),
+        0,
+        data.buffers().to_vec(),
+        vec![],
+    ))))
+}
+
 /// CSV file reader builder
 #[derive(Debug)]
 pub struct ReaderBuilder {
@@ -496,8 +850,8 @@ pub struct ReaderBuilder {
     /// If schema inference is run on a file with no headers, default column names
     /// are created.
     has_header: bool,
-    /// An optional column delimiter. Defaults to `b','`
-    delimiter: Option<u8>,
+    /// The CSV dialect: delimiter, quoting, escaping, comments and terminator
+    dialect: Dialect,
     /// Optional maximum number of records to read during schema inference
     ///
     /// If a number is not provided, all the records are read.
@@ -510,6 +864,10 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Chrono format strings of date and timestamp columns, by column name
+    datetime_formats: HashMap<String, String>,
+    /// Strings read as nulls
+    null_values: NullValues,
 }
 
 impl Default for ReaderBuilder {
@@ -517,11 +875,13 @@ impl Default for ReaderBuilder {
         Self {
             schema: None,
             has_header: false,
-            delimiter: None,
+            dialect: Dialect::default(),
             max_records: None,
             batch_size: 1024,
             bounds: None,
             projection: None,
+            datetime_formats: HashMap::new(),
+            null_values: NullValues::default(),
         }
     }
 }
@@ -568,7 +928,65 @@ impl ReaderBuilder {
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = Some(delimiter);
+        self.dialect.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character. Defaults to `b'"'`
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.dialect.quote = quote;
+        self
+    }
+
+    /// Set the escape character of quotes within quoted fields, such as `b'\\'`
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.dialect.escape = Some(escape);
+        self
+    }
+
+    /// Set whether two consecutive quotes within a quoted field are read as one quote.
+    /// Defaults to `true`
+    pub fn with_double_quote(mut self, double_quote: bool) -> Self {
+        self.dialect.double_quote = double_quote;
+        self
+    }
+
+    /// Set whether quotes are special characters. When disabled, quotes are read as
+    /// part of the values. Defaults to `true`
+    pub fn with_quoting(mut self, quoting: bool) -> Self {
+        self.dialect.quoting = quoting;
+        self
+    }
+
+    /// Set the character starting comment lines, which are skipped
+    pub fn with_comment(mut self, comment: u8) -> Self {
+        self.dialect.comment = Some(comment);
+        self
+    }
+
+    /// Set the record terminator. By default `\n`, `\r\n` and `\r` are all accepted
+    pub fn with_terminator(mut self, terminator: u8) -> Self {
+        self.dialect.terminator = Some(terminator);
+        self
+    }
+
+    /// Set the strings read as nulls, such as `NA` or `\N`, in the columns without
+    /// their own null strings. Empty values are always nulls, except in string columns
+    pub fn with_null_values(mut self, null_values: Vec<String>) -> Self {
+        self.null_values.values = null_values;
+        self
+    }
+
+    /// Set the strings read as nulls in a column, by name, instead of the ones set with
+    /// `with_null_values`
+    pub fn with_column_null_values(
+        mut self,
+        column: &str,
+        null_values: Vec<String>,
+    ) -> Self {
+        self.null_values
+            .columns
+            .insert(column.to_string(), null_values);
         self
     }
 
@@ -592,33 +1010,49 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the chrono format string of the dates or timestamps of a column, by name.
+    ///
+    /// During schema inference, the column is inferred as `Date32` or `Timestamp` if its
+    /// records match the format, which is also used to parse the column. Timestamps with
+    /// an offset are converted to UTC. Columns without a format accept ISO 8601 dates
+    /// and timestamps, such as `2020-10-17` or `2020-10-17T10:30:00.123+02:00`.
+    pub fn with_datetime_format(mut self, column: &str, format: &str) -> Self {
+        self.datetime_formats
+            .insert(column.to_string(), format.to_string());
+        self
+    }
+
     /// Create a new `Reader` from the `ReaderBuilder`
     pub fn build<R: Read + Seek>(self, reader: R) -> Result<Reader<R>> {
         // check if schema should be inferred
         let mut buf_reader = BufReader::new(reader);
-        let delimiter = self.delimiter.unwrap_or(b',');
         let schema = match self.schema {
             Some(schema) => schema,
             None => {
                 let (inferred_schema, _) = infer_file_schema(
                     &mut buf_reader,
-                    delimiter,
+                    &self.dialect,
                     self.max_records,
                     self.has_header,
+                    &self.datetime_formats,
+                    &self.null_values,
                 )?;
 
                 Arc::new(inferred_schema)
             }
         };
-        Ok(Reader::from_buf_reader(
+        let mut reader = Reader::from_dialect(
             buf_reader,
             schema,
             self.has_header,
-            self.delimiter,
+            &self.dialect,
             self.batch_size,
             None,
             self.projection.clone(),
-        ))
+        );
+        reader.datetime_formats = self.datetime_formats;
+        reader.null_values = self.null_values;
+        Ok(reader)
     }
 }
 
@@ -893,6 +1327,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_csv_with_dialect() {
+        let data = "# vendor export\r\n\
+            name;score;count\r\n\
+            'it\\'s';1.5;NA\r\n\
+            # skipped\r\n\
+            'a;b';\\N;7\r\n\
+            NA;-;8\r\n";
+
+        let mut csv = ReaderBuilder::new()
+            .has_header(true)
+            .infer_schema(None)
+            .with_delimiter(b';')
+            .with_quote(b'\'')
+            .with_escape(b'\\')
+            .with_comment(b'#')
+            .with_null_values(vec!["NA".to_string(), "\\N".to_string()])
+            .with_column_null_values("score", vec!["-".to_string(), "\\N".to_string()])
+            .build(Cursor::new(data))
+            .unwrap();
+
+        let schema = csv.schema();
+        assert_eq!(&DataType::Utf8, schema.field(0).data_type());
+        assert_eq!(&DataType::Float64, schema.field(1).data_type());
+        assert_eq!(&DataType::Int64, schema.field(2).data_type());
+        assert_eq!(true, schema.field(1).is_nullable());
+        assert_eq!(true, schema.field(2).is_nullable());
+
+        let batch = csv.next().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+        let name = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("it's", name.value(0));
+        assert_eq!("a;b", name.value(1));
+        assert!(name.is_null(2));
+        let score = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert!((1.5 - score.value(0)).abs() < f64::EPSILON);
+        assert!(score.is_null(1));
+        assert!(score.is_null(2));
+        let count = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(count.is_null(0));
+        assert_eq!(7, count.value(1));
+        assert_eq!(8, count.value(2));
+    }
+
+    #[test]
+    fn test_csv_without_quoting() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, false),
+            Field::new("c2", DataType::Int32, false),
+        ]);
+        let data = "\"a\"|1;\"b|2;";
+
+        let mut csv = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .with_delimiter(b'|')
+            .with_quoting(false)
+            .with_terminator(b';')
+            .build(Cursor::new(data))
+            .unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let c1 = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("\"a\"", c1.value(0));
+        assert_eq!("\"b", c1.value(1));
+        let c2 = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&Int32Array::from(vec![1, 2]), c2);
+    }
+
     #[test]
     fn test_infer_field_schema() {
         assert_eq!(infer_field_schema("A"), DataType::Utf8);
@@ -901,6 +1423,147 @@ mod tests {
         assert_eq!(infer_field_schema("10.2"), DataType::Float64);
         assert_eq!(infer_field_schema("true"), DataType::Boolean);
         assert_eq!(infer_field_schema("false"), DataType::Boolean);
+        assert_eq!(infer_field_schema("2020-10-17"), date_type());
+        assert_eq!(
+            infer_field_schema("2020-10-17T10:30:00"),
+            timestamp_type(false)
+        );
+        assert_eq!(
+            infer_field_schema("2020-10-17 10:30:00.123"),
+            timestamp_type(false)
+        );
+        assert_eq!(
+            infer_field_schema("2020-10-17T10:30:00Z"),
+            timestamp_type(true)
+        );
+        assert_eq!(
+            infer_field_schema("2020-10-17T10:30:00.5-05:00"),
+            timestamp_type(true)
+        );
+        assert_eq!(infer_field_schema("2020-10-17T10:30"), DataType::Utf8);
+        assert_eq!(
+            infer_formatted_field_schema("17/10/2020", "%d/%m/%Y"),
+            date_type()
+        );
+        assert_eq!(
+            infer_formatted_field_schema("17/10/2020 10:30", "%d/%m/%Y %H:%M"),
+            timestamp_type(false)
+        );
+        assert_eq!(
+            infer_formatted_field_schema("17/10/2020 10:30 +0200", "%d/%m/%Y %H:%M %z"),
+            timestamp_type(true)
+        );
+        assert_eq!(
+            infer_formatted_field_schema("10", "%d/%m/%Y"),
+            DataType::Int64
+        );
+    }
+
+    #[test]
+    fn test_csv_with_temporal_inference() {
+        let data = "date,naive,offset,mixed,custom\n\
+            2020-10-17,2020-10-17T10:30:00.5,2020-10-17T10:30:00+02:00,2020-10-17,17/10/2020 10:30\n\
+            1970-01-02,1970-01-01 00:00:01,1970-01-01T00:00:00Z,2020-10-17 10:30:00,\n";
+
+        let mut csv = ReaderBuilder::new()
+            .has_header(true)
+            .infer_schema(None)
+            .with_datetime_format("custom", "%d/%m/%Y %H:%M")
+            .build(Cursor::new(data))
+            .unwrap();
+
+        let schema = csv.schema();
+        assert_eq!(&date_type(), schema.field(0).data_type());
+        assert_eq!(&timestamp_type(false), schema.field(1).data_type());
+        assert_eq!(&timestamp_type(true), schema.field(2).data_type());
+        // dates and timestamps are inferred as timestamps
+        assert_eq!(&timestamp_type(false), schema.field(3).data_type());
+        assert_eq!(&timestamp_type(false), schema.field(4).data_type());
+        assert_eq!(true, schema.field(4).is_nullable());
+
+        let batch = csv.next().unwrap().unwrap();
+        let date = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(18552, date.value(0));
+        assert_eq!(1, date.value(1));
+
+        let naive = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_602_930_600_500_000_000, naive.value(0));
+        assert_eq!(1_000_000_000, naive.value(1));
+
+        // timestamps with an offset are converted to UTC
+        let offset = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_602_923_400_000_000_000, offset.value(0));
+        assert_eq!(0, offset.value(1));
+
+        let mixed = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_602_892_800_000_000_000, mixed.value(0));
+        assert_eq!(1_602_930_600_000_000_000, mixed.value(1));
+
+        let custom = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_602_930_600_000_000_000, custom.value(0));
+        assert!(custom.is_null(1));
+    }
+
+    #[test]
+    fn test_csv_with_temporal_schema() {
+        let schema = Schema::new(vec![
+            Field::new("date", DataType::Date64(DateUnit::Millisecond), false),
+            Field::new("ts", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        ]);
+        let data = "17.10.2020,2020-10-17T10:30:00.123456\n01.01.1970,\n";
+
+        let mut csv = ReaderBuilder::new()
+            .with_schema(Arc::new(schema.clone()))
+            .with_datetime_format("date", "%d.%m.%Y")
+            .build(Cursor::new(data))
+            .unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let date = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Date64Array>()
+            .unwrap();
+        assert_eq!(1_602_892_800_000, date.value(0));
+        assert_eq!(0, date.value(1));
+        let ts = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1_602_930_600_123, ts.value(0));
+        assert!(ts.is_null(1));
+
+        // without the format, the date column expects ISO dates
+        let mut csv = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .build(Cursor::new(data))
+            .unwrap();
+        let err = csv.next().unwrap().unwrap_err();
+        assert_eq!(
+            "Parser error: Error while parsing value 17.10.2020 for column 0 at line 0",
+            err.to_string()
+        );
     }
 
     #[test]
@@ -78,6 +78,48 @@ const DEFAULT_DATE_FORMAT: &str = "%F";
 const DEFAULT_TIME_FORMAT: &str = "%T";
 const DEFAULT_TIMESTAMP_FORMAT: &str = "%FT%H:%M:%S.%9f";
 
+/// When the writer quotes values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuoteStyle {
+    /// Quote all values
+    Always,
+    /// Quote values containing a delimiter, quote or line terminator
+    Necessary,
+    /// Quote all values that are not numbers
+    NonNumeric,
+    /// Never quote values, even if this makes the output invalid CSV
+    Never,
+}
+
+impl QuoteStyle {
+    fn to_csv(self) -> csv_crate::QuoteStyle {
+        match self {
+            QuoteStyle::Always => csv_crate::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv_crate::QuoteStyle::Necessary,
+            QuoteStyle::NonNumeric => csv_crate::QuoteStyle::NonNumeric,
+            QuoteStyle::Never => csv_crate::QuoteStyle::Never,
+        }
+    }
+}
+
+/// The terminator written after each record
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineTerminator {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    Crlf,
+}
+
+impl LineTerminator {
+    fn to_csv(self) -> csv_crate::Terminator {
+        match self {
+            LineTerminator::Lf => csv_crate::Terminator::Any(b'\n'),
+            LineTerminator::Crlf => csv_crate::Terminator::CRLF,
+        }
+    }
+}
+
 fn write_primitive_value<T>(array: &ArrayRef, i: usize) -> String
 where
     T: ArrowNumericType,
@@ -102,6 +144,8 @@ pub struct Writer<W: Write> {
     timestamp_format: String,
     /// The time format for time arrays
     time_format: String,
+    /// The representation of null values
+    null: String,
     /// Is the beginning-of-writer
     beginning: bool,
 }
@@ -119,6 +163,7 @@ impl<W: Write> Writer<W> {
             date_format: DEFAULT_DATE_FORMAT.to_string(),
             time_format: DEFAULT_TIME_FORMAT.to_string(),
             timestamp_format: DEFAULT_TIMESTAMP_FORMAT.to_string(),
+            null: String::new(),
             beginning: true,
         }
     }
@@ -130,8 +175,7 @@ impl<W: Write> Writer<W> {
         for col_index in 0..batch.num_columns() {
             let col = batch.column(col_index);
             if col.is_null(row_index) {
-                // write an empty value
-                record.push(String::from(""));
+                record.push(self.null.clone());
                 continue;
             }
             let string = match col.data_type() {
@@ -288,6 +332,17 @@ pub struct WriterBuilder {
     timestamp_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// Optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// Optional escape character of quotes within quoted values. Quotes are doubled
+    /// when it is not set
+    escape: Option<u8>,
+    /// Optional quote style. Defaults to `QuoteStyle::Necessary`
+    quote_style: Option<QuoteStyle>,
+    /// Optional representation of null values. Defaults to an empty value
+    null: Option<String>,
+    /// Optional record terminator. Defaults to `LineTerminator::Lf`
+    line_terminator: Option<LineTerminator>,
 }
 
 impl Default for WriterBuilder {
@@ -298,6 +353,11 @@ impl Default for WriterBuilder {
             date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
             time_format: Some(DEFAULT_TIME_FORMAT.to_string()),
             timestamp_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
+            quote: None,
+            escape: None,
+            quote_style: None,
+            null: None,
+            line_terminator: None,
         }
     }
 }
@@ -359,11 +419,50 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the quote character
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set the escape character of quotes within quoted values, such as `b'\\'`,
+    /// instead of doubling the quotes
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set when values are quoted
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = Some(quote_style);
+        self
+    }
+
+    /// Set the representation of null values, such as `NA` or `\N`
+    pub fn with_null(mut self, null: String) -> Self {
+        self.null = Some(null);
+        self
+    }
+
+    /// Set the terminator written after each record
+    pub fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = Some(line_terminator);
+        self
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let delimiter = self.delimiter.unwrap_or(b',');
         let mut builder = csv_crate::WriterBuilder::new();
-        let writer = builder.delimiter(delimiter).from_writer(writer);
+        builder
+            .delimiter(delimiter)
+            .quote(self.quote.unwrap_or(b'"'))
+            .quote_style(self.quote_style.unwrap_or(QuoteStyle::Necessary).to_csv())
+            .terminator(self.line_terminator.unwrap_or(LineTerminator::Lf).to_csv());
+        if let Some(escape) = self.escape {
+            builder.escape(escape).double_quote(false);
+        }
+        let writer = builder.from_writer(writer);
         Writer {
             writer,
             delimiter,
@@ -377,6 +476,7 @@ impl WriterBuilder {
             timestamp_format: self
                 .timestamp_format
                 .unwrap_or_else(|| DEFAULT_TIMESTAMP_FORMAT.to_string()),
+            null: self.null.unwrap_or_default(),
             beginning: true,
         }
     }
@@ -521,6 +621,46 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03
         );
     }
 
+    #[test]
+    fn test_write_csv_dialect() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Float64, true),
+            Field::new("c3", DataType::UInt32, false),
+        ]);
+        let c1 = StringArray::from(vec![Some("say \"hi\""), None, Some("a|b")]);
+        let c2 = PrimitiveArray::<Float64Type>::from(vec![Some(1.5), None, Some(-2.0)]);
+        let c3 = PrimitiveArray::<UInt32Type>::from(vec![3, 2, 1]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(c1), Arc::new(c2), Arc::new(c3)],
+        )
+        .unwrap();
+
+        let mut writer = WriterBuilder::new()
+            .with_delimiter(b'|')
+            .with_quote(b'\'')
+            .with_null("NA".to_string())
+            .with_line_terminator(LineTerminator::Crlf)
+            .build(StringWriter::new());
+        writer.write(&batch).unwrap();
+        assert_eq!(
+            "c1|c2|c3\r\nsay \"hi\"|1.5|3\r\nNA|NA|2\r\n'a|b'|-2|1\r\n",
+            writer.writer.into_inner().unwrap().to_string()
+        );
+
+        let mut writer = WriterBuilder::new()
+            .has_headers(false)
+            .with_escape(b'\\')
+            .with_quote_style(QuoteStyle::NonNumeric)
+            .build(StringWriter::new());
+        writer.write(&batch).unwrap();
+        assert_eq!(
+            "\"say \\\"hi\\\"\",1.5,3\n\"\",\"\",2\n\"a|b\",-2,1\n",
+            writer.writer.into_inner().unwrap().to_string()
+        );
+    }
+
     #[test]
     fn test_export_csv_string() {
         let schema = Schema::new(vec![
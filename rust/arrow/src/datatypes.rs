@@ -22,7 +22,7 @@
 //!  * [`Field`](crate::datatypes::Field) to describe one field within a schema.
 //!  * [`DataType`](crate::datatypes::DataType) to describe the type of a field.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::default::Default;
 use std::fmt;
 use std::mem::size_of;
@@ -30,8 +30,9 @@ use std::mem::size_of;
 use std::ops::{Add, Div, Mul, Sub};
 use std::slice::from_raw_parts;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
+use lazy_static::lazy_static;
 #[cfg(feature = "simd")]
 use packed_simd::*;
 use serde_derive::{Deserialize, Serialize};
@@ -42,6 +43,9 @@ use serde_json::{
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
 
+/// The half-precision floating point type, native type of `Float16` values
+pub use half::f16;
+
 /// The set of datatypes that are supported by this implementation of Apache Arrow.
 ///
 /// The Arrow specification on data types includes some more types.
@@ -53,6 +57,7 @@ use crate::util::bit_util;
 /// Currently the Rust implementation supports the following  nested types:
 ///  - `List<T>`
 ///  - `Struct<T, U, V, ...>`
+///  - `Map<K, V>`
 ///
 /// Nested types can themselves be nested within other arrays.
 /// For more information on these types please see
@@ -134,6 +139,14 @@ pub enum DataType {
     Struct(Vec<Field>),
     /// A nested datatype that can represent slots of differing types.
     Union(Vec<Field>),
+    /// A Map is a logical nested type that is represented as
+    ///
+    /// `List<entries: Struct<key: K, value: V>>`
+    ///
+    /// The `Field` is the non-nullable `entries` struct, whose first child is the
+    /// key and whose second child is the value. The keys must not be null.
+    /// The `bool` indicates whether the keys within each map are sorted.
+    Map(Box<Field>, bool),
     /// A dictionary encoded array (`key_type`, `value_type`), where
     /// each array element is an index of `key_type` into an
     /// associated dictionary of `value_type`.
@@ -145,6 +158,13 @@ pub enum DataType {
     /// This type mostly used to represent low cardinality string
     /// arrays or a limited set of primitive types as integers.
     Dictionary(Box<DataType>, Box<DataType>),
+    /// Exact decimal value with precision and scale
+    ///
+    /// * precision is the total number of digits
+    /// * scale is the number of digits past the decimal
+    ///
+    /// Values are stored as 128-bit little-endian two's complement integers.
+    Decimal(usize, usize),
 }
 
 /// Date is either a 32-bit or 64-bit type representing elapsed time since UNIX
@@ -191,6 +211,13 @@ pub struct Field {
     nullable: bool,
     pub(crate) dict_id: i64,
     pub(crate) dict_is_ordered: bool,
+    /// A map of key-value pairs containing additional custom meta data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<BTreeMap<String, String>>,
+    /// The custom meta data of the values of a list field, which have no `Field` of
+    /// their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    child_metadata: Option<BTreeMap<String, String>>,
 }
 
 pub trait ArrowNativeType:
@@ -369,6 +396,12 @@ impl ArrowNativeType for f64 {
     }
 }
 
+impl ArrowNativeType for f16 {
+    fn into_json_value(self) -> Option<Value> {
+        Number::from_f64(self.to_f64()).map(VNumber)
+    }
+}
+
 // BooleanType is special: its bit-width is not the size of the primitive type, and its `index`
 // operation assumes bit-packing.
 #[derive(Debug)]
@@ -410,6 +443,7 @@ make_type!(UInt8Type, u8, DataType::UInt8);
 make_type!(UInt16Type, u16, DataType::UInt16);
 make_type!(UInt32Type, u32, DataType::UInt32);
 make_type!(UInt64Type, u64, DataType::UInt64);
+make_type!(Float16Type, f16, DataType::Float16);
 make_type!(Float32Type, f32, DataType::Float32);
 make_type!(Float64Type, f64, DataType::Float64);
 make_type!(
@@ -791,6 +825,126 @@ make_numeric_type!(UInt64Type, u64, u64x8, m64x8);
 make_numeric_type!(Float32Type, f32, f32x16, m32x16);
 make_numeric_type!(Float64Type, f64, f64x8, m64x8);
 
+// There are no SIMD types of half-precision floats, `Float16` values are instead
+// converted to and from single-precision lanes.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "simd"))]
+impl ArrowNumericType for Float16Type {
+    type Simd = <Float32Type as ArrowNumericType>::Simd;
+
+    type SimdMask = <Float32Type as ArrowNumericType>::SimdMask;
+
+    #[inline]
+    fn lanes() -> usize {
+        <Float32Type as ArrowNumericType>::lanes()
+    }
+
+    #[inline]
+    fn init(value: Self::Native) -> Self::Simd {
+        <Float32Type as ArrowNumericType>::init(value.to_f32())
+    }
+
+    #[inline]
+    fn load(slice: &[Self::Native]) -> Self::Simd {
+        let mut values = [0.0_f32; 16];
+        values
+            .iter_mut()
+            .zip(slice)
+            .for_each(|(value, half)| *value = half.to_f32());
+        <Float32Type as ArrowNumericType>::load(&values)
+    }
+
+    #[inline]
+    fn mask_init(value: bool) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::mask_init(value)
+    }
+
+    #[inline]
+    fn mask_from_u64(mask: u64) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::mask_from_u64(mask)
+    }
+
+    #[inline]
+    fn mask_get(mask: &Self::SimdMask, idx: usize) -> bool {
+        <Float32Type as ArrowNumericType>::mask_get(mask, idx)
+    }
+
+    fn bitmask<T>(mask: &Self::SimdMask, action: T)
+    where
+        T: FnMut(&[u8]),
+    {
+        <Float32Type as ArrowNumericType>::bitmask(mask, action)
+    }
+
+    #[inline]
+    fn mask_set(mask: Self::SimdMask, idx: usize, value: bool) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::mask_set(mask, idx, value)
+    }
+
+    #[inline]
+    fn mask_select(mask: Self::SimdMask, a: Self::Simd, b: Self::Simd) -> Self::Simd {
+        <Float32Type as ArrowNumericType>::mask_select(mask, a, b)
+    }
+
+    #[inline]
+    fn mask_any(mask: Self::SimdMask) -> bool {
+        <Float32Type as ArrowNumericType>::mask_any(mask)
+    }
+
+    #[inline]
+    fn bin_op<F: Fn(Self::Simd, Self::Simd) -> Self::Simd>(
+        left: Self::Simd,
+        right: Self::Simd,
+        op: F,
+    ) -> Self::Simd {
+        op(left, right)
+    }
+
+    #[inline]
+    fn eq(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::eq(left, right)
+    }
+
+    #[inline]
+    fn ne(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::ne(left, right)
+    }
+
+    #[inline]
+    fn lt(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::lt(left, right)
+    }
+
+    #[inline]
+    fn le(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::le(left, right)
+    }
+
+    #[inline]
+    fn gt(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::gt(left, right)
+    }
+
+    #[inline]
+    fn ge(left: Self::Simd, right: Self::Simd) -> Self::SimdMask {
+        <Float32Type as ArrowNumericType>::ge(left, right)
+    }
+
+    #[inline]
+    fn write(simd_result: Self::Simd, slice: &mut [Self::Native]) {
+        let mut values = [0.0_f32; 16];
+        <Float32Type as ArrowNumericType>::write(simd_result, &mut values);
+        slice
+            .iter_mut()
+            .zip(values.iter())
+            .for_each(|(half, value)| *half = f16::from_f32(*value));
+    }
+}
+#[cfg(any(
+    not(any(target_arch = "x86", target_arch = "x86_64")),
+    not(feature = "simd")
+))]
+impl ArrowNumericType for Float16Type {}
+
 make_numeric_type!(TimestampSecondType, i64, i64x8, m64x8);
 make_numeric_type!(TimestampMillisecondType, i64, i64x8, m64x8);
 make_numeric_type!(TimestampMicrosecondType, i64, i64x8, m64x8);
@@ -892,6 +1046,22 @@ impl DataType {
                         ))
                     }
                 }
+                Some(s) if s == "decimal" => {
+                    let precision = match map.get("precision").and_then(|p| p.as_u64()) {
+                        Some(p) => Ok(p as usize),
+                        None => Err(ArrowError::ParseError(
+                            "Expecting an integer precision for decimal".to_string(),
+                        )),
+                    };
+                    let scale = match map.get("scale").and_then(|s| s.as_u64()) {
+                        Some(s) => Ok(s as usize),
+                        None => Err(ArrowError::ParseError(
+                            "Expecting an integer scale for decimal".to_string(),
+                        )),
+                    };
+
+                    Ok(DataType::Decimal(precision?, scale?))
+                }
                 Some(s) if s == "floatingpoint" => match map.get("precision") {
                     Some(p) if p == "HALF" => Ok(DataType::Float16),
                     Some(p) if p == "SINGLE" => Ok(DataType::Float32),
@@ -1030,6 +1200,23 @@ impl DataType {
                     // return an empty `struct` type as its children aren't defined in the map
                     Ok(DataType::Struct(vec![]))
                 }
+                Some(s) if s == "map" => {
+                    // return a map with an empty `entries` struct as its children
+                    // aren't defined in the map
+                    let keys_sorted = match map.get("keysSorted") {
+                        Some(Value::Bool(sorted)) => *sorted,
+                        Some(_) => {
+                            return Err(ArrowError::ParseError(
+                                "keysSorted should be a boolean".to_string(),
+                            ))
+                        }
+                        None => false,
+                    };
+                    Ok(DataType::Map(
+                        Box::new(Field::new("entries", DataType::Struct(vec![]), false)),
+                        keys_sorted,
+                    ))
+                }
                 Some(other) => Err(ArrowError::ParseError(format!(
                     "invalid or unsupported type name: {} in {:?}",
                     other, json
@@ -1065,8 +1252,14 @@ impl DataType {
             DataType::FixedSizeBinary(byte_width) => {
                 json!({"name": "fixedsizebinary", "byteWidth": byte_width})
             }
+            DataType::Decimal(precision, scale) => {
+                json!({"name": "decimal", "precision": precision, "scale": scale})
+            }
             DataType::Struct(_) => json!({"name": "struct"}),
             DataType::Union(_) => json!({"name": "union"}),
+            DataType::Map(_, keys_sorted) => {
+                json!({"name": "map", "keysSorted": keys_sorted})
+            }
             DataType::List(_) => json!({ "name": "list"}),
             DataType::LargeList(_) => json!({ "name": "largelist"}),
             DataType::FixedSizeList(_, length) => {
@@ -1144,6 +1337,8 @@ impl Field {
             nullable,
             dict_id: 0,
             dict_is_ordered: false,
+            metadata: None,
+            child_metadata: None,
         }
     }
 
@@ -1161,9 +1356,102 @@ impl Field {
             nullable,
             dict_id,
             dict_is_ordered,
+            metadata: None,
+            child_metadata: None,
+        }
+    }
+
+    /// Creates a new field whose data type is the storage type of the given
+    /// extension type, and whose metadata identifies the extension type.
+    pub fn new_extension(
+        name: &str,
+        extension_type: &ExtensionType,
+        nullable: bool,
+    ) -> Self {
+        let mut metadata = BTreeMap::new();
+        metadata.insert(
+            EXTENSION_TYPE_NAME_KEY.to_string(),
+            extension_type.extension_name().to_string(),
+        );
+        metadata.insert(
+            EXTENSION_TYPE_METADATA_KEY.to_string(),
+            extension_type.serialize(),
+        );
+        let mut field = Field::new(name, extension_type.storage_type().clone(), nullable);
+        field.set_metadata(Some(metadata));
+        field
+    }
+
+    /// Sets the `Field`'s optional custom metadata.
+    /// The metadata is set as `None` for empty map.
+    #[inline]
+    pub fn set_metadata(&mut self, metadata: Option<BTreeMap<String, String>>) {
+        // To make serde happy, convert Some(empty_map) to None.
+        self.metadata = None;
+        if let Some(v) = metadata {
+            if !v.is_empty() {
+                self.metadata = Some(v);
+            }
         }
     }
 
+    /// Returns the immutable reference to the `Field`'s optional custom metadata.
+    #[inline]
+    pub const fn metadata(&self) -> &Option<BTreeMap<String, String>> {
+        &self.metadata
+    }
+
+    /// Sets the optional custom metadata of the values of a list `Field`, such as the
+    /// extension type of the values. The metadata is set as `None` for empty map.
+    ///
+    /// The values of `List`, `LargeList` and `FixedSizeList` have no `Field` of their
+    /// own, so their metadata is kept in the list's `Field`.
+    #[inline]
+    pub fn set_child_metadata(&mut self, metadata: Option<BTreeMap<String, String>>) {
+        self.child_metadata = metadata.filter(|v| !v.is_empty());
+    }
+
+    /// Returns the immutable reference to the optional custom metadata of the values
+    /// of a list `Field`.
+    #[inline]
+    pub const fn child_metadata(&self) -> &Option<BTreeMap<String, String>> {
+        &self.child_metadata
+    }
+
+    /// Returns the name of the extension type this `Field` is annotated with, if any.
+    pub fn extension_name(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|md| md.get(EXTENSION_TYPE_NAME_KEY))
+            .map(|name| name.as_str())
+    }
+
+    /// Resolves the extension type this `Field` is annotated with against the
+    /// registered extension types.
+    ///
+    /// Returns `Ok(None)` if the field is not annotated with an extension type, or
+    /// if the extension type has not been registered, in which case the field should
+    /// be treated as its storage type.
+    pub fn extension_type(&self) -> Result<Option<Arc<ExtensionType>>> {
+        let name = match self.extension_name() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let registered = match get_extension_type(name) {
+            Some(registered) => registered,
+            None => return Ok(None),
+        };
+        let serialized = self
+            .metadata
+            .as_ref()
+            .and_then(|md| md.get(EXTENSION_TYPE_METADATA_KEY))
+            .map(|s| s.as_str())
+            .unwrap_or("");
+        registered
+            .deserialize(&self.data_type, serialized)
+            .map(Some)
+    }
+
     /// Returns an immutable reference to the `Field`'s name
     #[inline]
     pub const fn name(&self) -> &String {
@@ -1210,6 +1498,8 @@ impl Field {
                         ));
                     }
                 };
+                // the metadata of the values of a list, which have no field of their own
+                let mut child_metadata = None;
                 // if data_type is a struct or list, get its children
                 let data_type = match data_type {
                     DataType::List(_)
@@ -1221,16 +1511,18 @@ impl Field {
                                     "Field 'children' must have one element for a list data type".to_string(),
                                 ));
                             }
+                            let child = Self::from(&values[0])?;
+                            child_metadata = child.metadata;
                             match data_type {
                                     DataType::List(_) => DataType::List(Box::new(
-                                        Self::from(&values[0])?.data_type,
+                                        child.data_type,
                                     )),
                                     DataType::LargeList(_) => DataType::LargeList(Box::new(
-                                        Self::from(&values[0])?.data_type,
+                                        child.data_type,
                                     )),
                                     DataType::FixedSizeList(_, int) => {
                                         DataType::FixedSizeList(
-                                            Box::new(Self::from(&values[0])?.data_type),
+                                            Box::new(child.data_type),
                                             int,
                                         )
                                     }
@@ -1250,6 +1542,36 @@ impl Field {
                             ));
                         }
                     },
+                    DataType::Map(_, keys_sorted) => {
+                        match map.get("children") {
+                            Some(Value::Array(values)) => {
+                                if values.len() != 1 {
+                                    return Err(ArrowError::ParseError(
+                                    "Field 'children' must have one element for a map data type".to_string(),
+                                ));
+                                }
+                                let entries = Self::from(&values[0])?;
+                                match entries.data_type() {
+                                    DataType::Struct(fields) if fields.len() == 2 => {}
+                                    _ => return Err(ArrowError::ParseError(
+                                        "Map entries must be a struct with two children"
+                                            .to_string(),
+                                    )),
+                                }
+                                DataType::Map(Box::new(entries), keys_sorted)
+                            }
+                            Some(_) => {
+                                return Err(ArrowError::ParseError(
+                                    "Field 'children' must be an array".to_string(),
+                                ))
+                            }
+                            None => {
+                                return Err(ArrowError::ParseError(
+                                    "Field missing 'children' attribute".to_string(),
+                                ));
+                            }
+                        }
+                    }
                     DataType::Struct(mut fields) => match map.get("children") {
                         Some(Value::Array(values)) => {
                             let struct_fields: Result<Vec<Field>> =
@@ -1304,12 +1626,20 @@ impl Field {
                     }
                     _ => data_type,
                 };
+
+                let metadata = match map.get("metadata") {
+                    Some(value) => Self::from_metadata(value)?,
+                    None => None,
+                };
+
                 Ok(Field {
                     name,
                     nullable,
                     data_type,
                     dict_id,
                     dict_is_ordered,
+                    metadata,
+                    child_metadata,
                 })
             }
             _ => Err(ArrowError::ParseError(
@@ -1318,25 +1648,61 @@ impl Field {
         }
     }
 
+    /// Parse a field's `metadata` definition from a JSON representation.
+    ///
+    /// Both the integration format, a list of `{"key": .., "value": ..}` objects,
+    /// and a plain object of string values are accepted.
+    fn from_metadata(json: &Value) -> Result<Option<BTreeMap<String, String>>> {
+        let metadata = match json {
+            Value::Array(values) => values
+                .iter()
+                .map(|kv| match (kv.get("key"), kv.get("value")) {
+                    (Some(Value::String(k)), Some(Value::String(v))) => {
+                        Ok((k.to_string(), v.to_string()))
+                    }
+                    _ => Err(ArrowError::ParseError(
+                        "Field 'metadata' must have exact two string entries named 'key' and 'value'"
+                            .to_string(),
+                    )),
+                })
+                .collect::<Result<BTreeMap<_, _>>>()?,
+            Value::Object(md) => md
+                .iter()
+                .map(|(k, v)| match v {
+                    Value::String(v) => Ok((k.to_string(), v.to_string())),
+                    _ => Err(ArrowError::ParseError(
+                        "Field 'metadata' values must be strings".to_string(),
+                    )),
+                })
+                .collect::<Result<BTreeMap<_, _>>>()?,
+            _ => {
+                return Err(ArrowError::ParseError(
+                    "Field 'metadata' must be an array or an object".to_string(),
+                ))
+            }
+        };
+        if metadata.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(metadata))
+        }
+    }
+
     /// Generate a JSON representation of the `Field`
     pub fn to_json(&self) -> Value {
         let children: Vec<Value> = match self.data_type() {
             DataType::Struct(fields) => fields.iter().map(|f| f.to_json()).collect(),
-            DataType::List(dtype) => {
-                let item = Field::new("item", *dtype.clone(), self.nullable);
-                vec![item.to_json()]
-            }
-            DataType::LargeList(dtype) => {
-                let item = Field::new("item", *dtype.clone(), self.nullable);
-                vec![item.to_json()]
-            }
-            DataType::FixedSizeList(dtype, _) => {
-                let item = Field::new("item", *dtype.clone(), self.nullable);
+            DataType::List(dtype)
+            | DataType::LargeList(dtype)
+            | DataType::FixedSizeList(dtype, _) => {
+                let mut item = Field::new("item", *dtype.clone(), self.nullable);
+                item.set_metadata(self.child_metadata.clone());
                 vec![item.to_json()]
             }
+            DataType::Map(entries, _) => vec![entries.to_json()],
             _ => vec![],
         };
-        match self.data_type() {
+        let mut json = match self.data_type() {
             DataType::Dictionary(ref index_type, ref value_type) => json!({
                 "name": self.name,
                 "nullable": self.nullable,
@@ -1354,31 +1720,74 @@ impl Field {
                 "type": self.data_type.to_json(),
                 "children": children
             }),
+        };
+        if let Some(metadata) = &self.metadata {
+            json["metadata"] = metadata
+                .iter()
+                .map(|(k, v)| json!({"key": k, "value": v}))
+                .collect::<Vec<Value>>()
+                .into();
         }
+        json
     }
 
     /// Merge field into self if it is compatible. Struct will be merged recursively.
     ///
+    /// Types are widened when both can be cast to a common type without loss of
+    /// range: integers to the smallest integer holding both, integers and floats to
+    /// `Float64`, `Utf8` to `LargeUtf8` and `Binary` to `LargeBinary`. A `Null`
+    /// field takes the type of the other field and becomes nullable.
+    ///
     /// Example:
     ///
     /// ```
     /// use arrow::datatypes::*;
     ///
-    /// let mut field = Field::new("c1", DataType::Int64, false);
+    /// let mut field = Field::new("c1", DataType::Int32, false);
     /// assert!(field.try_merge(&Field::new("c1", DataType::Int64, true)).is_ok());
     /// assert!(field.is_nullable());
+    /// assert_eq!(field.data_type(), &DataType::Int64);
     /// ```
     pub fn try_merge(&mut self, from: &Field) -> Result<()> {
         if from.dict_id != self.dict_id {
-            return Err(ArrowError::SchemaError(
-                "Fail to merge schema Field due to conflicting dict_id".to_string(),
-            ));
+            return Err(ArrowError::SchemaError(format!(
+                "Fail to merge schema field '{}' because the from dict_id = {} does not equal {}",
+                self.name, from.dict_id, self.dict_id
+            )));
         }
         if from.dict_is_ordered != self.dict_is_ordered {
-            return Err(ArrowError::SchemaError(
-                "Fail to merge schema Field due to conflicting dict_is_ordered"
-                    .to_string(),
-            ));
+            return Err(ArrowError::SchemaError(format!(
+                "Fail to merge schema field '{}' because the from dict_is_ordered = {} does not equal {}",
+                self.name, from.dict_is_ordered, self.dict_is_ordered
+            )));
+        }
+        // merge metadata
+        if let Some(from_metadata) = &from.metadata {
+            let mut merged = self.metadata.clone().unwrap_or_default();
+            for (key, from_value) in from_metadata {
+                match merged.get(key) {
+                    Some(self_value) => {
+                        if self_value != from_value {
+                            return Err(ArrowError::SchemaError(format!(
+                                "Fail to merge schema field '{}' due to conflicting metadata for key {}: {} != {}",
+                                self.name, key, from_value, self_value
+                            )));
+                        }
+                    }
+                    None => {
+                        merged.insert(key.clone(), from_value.clone());
+                    }
+                }
+            }
+            self.set_metadata(Some(merged));
+        }
+        if self.child_metadata.is_none() {
+            self.child_metadata = from.child_metadata.clone();
+        }
+        if from.data_type == DataType::Null && self.data_type != DataType::Null {
+            // a field of nulls only widens the nullability
+            self.nullable = true;
+            return Ok(());
         }
         match &mut self.data_type {
             DataType::Struct(nested_fields) => match &from.data_type {
@@ -1398,10 +1807,10 @@ impl Field {
                     }
                 }
                 _ => {
-                    return Err(ArrowError::SchemaError(
-                        "Fail to merge schema Field due to conflicting datatype"
-                            .to_string(),
-                    ));
+                    return Err(ArrowError::SchemaError(format!(
+                        "Fail to merge schema field '{}' because the from data_type = {:?} is not a struct",
+                        self.name, from.data_type
+                    )));
                 }
             },
             DataType::Union(nested_fields) => match &from.data_type {
@@ -1420,14 +1829,19 @@ impl Field {
                     }
                 }
                 _ => {
-                    return Err(ArrowError::SchemaError(
-                        "Fail to merge schema Field due to conflicting datatype"
-                            .to_string(),
-                    ));
+                    return Err(ArrowError::SchemaError(format!(
+                        "Fail to merge schema field '{}' because the from data_type = {:?} is not a union",
+                        self.name, from.data_type
+                    )));
                 }
             },
-            DataType::Null
-            | DataType::Boolean
+            DataType::Null => {
+                if from.data_type != DataType::Null {
+                    self.data_type = from.data_type.clone();
+                    self.nullable = true;
+                }
+            }
+            DataType::Boolean
             | DataType::Int8
             | DataType::Int16
             | DataType::Int32
@@ -1454,12 +1868,17 @@ impl Field {
             | DataType::FixedSizeList(_, _)
             | DataType::FixedSizeBinary(_)
             | DataType::Utf8
-            | DataType::LargeUtf8 => {
+            | DataType::LargeUtf8
+            | DataType::Decimal(_, _)
+            | DataType::Map(_, _) => {
                 if self.data_type != from.data_type {
-                    return Err(ArrowError::SchemaError(
-                        "Fail to merge schema Field due to conflicting datatype"
-                            .to_string(),
-                    ));
+                    self.data_type = widen_data_types(&self.data_type, &from.data_type)
+                        .ok_or_else(|| {
+                            ArrowError::SchemaError(format!(
+                                "Fail to merge schema field '{}' because the from data_type = {:?} does not equal {:?}",
+                                self.name, from.data_type, self.data_type
+                            ))
+                        })?;
                 }
             }
         }
@@ -1471,12 +1890,160 @@ impl Field {
     }
 }
 
+/// Returns the type that values of both `left` and `right` can be cast to without
+/// loss of range, or `None` if the types conflict.
+fn widen_data_types(left: &DataType, right: &DataType) -> Option<DataType> {
+    use DataType::*;
+
+    // the signedness and byte width of integer types
+    fn integer_width(data_type: &DataType) -> Option<(bool, usize)> {
+        match data_type {
+            Int8 => Some((true, 1)),
+            Int16 => Some((true, 2)),
+            Int32 => Some((true, 4)),
+            Int64 => Some((true, 8)),
+            UInt8 => Some((false, 1)),
+            UInt16 => Some((false, 2)),
+            UInt32 => Some((false, 4)),
+            UInt64 => Some((false, 8)),
+            _ => None,
+        }
+    }
+    fn integer_type(signed: bool, width: usize) -> Option<DataType> {
+        match (signed, width) {
+            (true, 1) => Some(Int8),
+            (true, 2) => Some(Int16),
+            (true, 4) => Some(Int32),
+            (true, 8) => Some(Int64),
+            (false, 1) => Some(UInt8),
+            (false, 2) => Some(UInt16),
+            (false, 4) => Some(UInt32),
+            (false, 8) => Some(UInt64),
+            _ => None,
+        }
+    }
+    fn float_width(data_type: &DataType) -> Option<usize> {
+        match data_type {
+            Float16 => Some(2),
+            Float32 => Some(4),
+            Float64 => Some(8),
+            _ => None,
+        }
+    }
+
+    if left == right {
+        return Some(left.clone());
+    }
+    match (left, right) {
+        (Utf8, LargeUtf8) | (LargeUtf8, Utf8) => return Some(LargeUtf8),
+        (Binary, LargeBinary) | (LargeBinary, Binary) => return Some(LargeBinary),
+        _ => {}
+    }
+    if let (Some((l_signed, l_width)), Some((r_signed, r_width))) =
+        (integer_width(left), integer_width(right))
+    {
+        return if l_signed == r_signed {
+            integer_type(l_signed, l_width.max(r_width))
+        } else {
+            // a signed type needs twice the width to hold all unsigned values
+            let (signed_width, unsigned_width) = if l_signed {
+                (l_width, r_width)
+            } else {
+                (r_width, l_width)
+            };
+            integer_type(true, signed_width.max(unsigned_width * 2))
+        };
+    }
+    match (float_width(left), float_width(right)) {
+        (Some(l_width), Some(r_width)) => {
+            if l_width > r_width {
+                Some(left.clone())
+            } else {
+                Some(right.clone())
+            }
+        }
+        (Some(_), None) if integer_width(right).is_some() => Some(Float64),
+        (None, Some(_)) if integer_width(left).is_some() => Some(Float64),
+        _ => None,
+    }
+}
+
 impl fmt::Display for Field {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}: {:?}", self.name, self.data_type)
     }
 }
 
+/// The field metadata key holding the name of an extension type.
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The field metadata key holding the serialized parameters of an extension type.
+pub const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// A user-defined logical type that is physically stored as one of the built-in
+/// data types (its storage type).
+///
+/// Extension types travel through IPC as regular fields whose metadata carries
+/// the keys `EXTENSION_TYPE_NAME_KEY` and `EXTENSION_TYPE_METADATA_KEY`. Once an
+/// implementation has been registered with `register_extension_type`, fields read
+/// from IPC can be resolved back to it with `Field::extension_type`.
+pub trait ExtensionType: fmt::Debug + Send + Sync {
+    /// Returns the unique name of the extension type, e.g. `"uuid"`
+    fn extension_name(&self) -> &str;
+
+    /// Returns the data type the values of this extension type are stored as
+    fn storage_type(&self) -> &DataType;
+
+    /// Serializes the parameters of this extension type, if any
+    fn serialize(&self) -> String;
+
+    /// Creates an instance of this extension type from its storage type and
+    /// serialized parameters, as found in a field's metadata
+    fn deserialize(
+        &self,
+        storage_type: &DataType,
+        serialized: &str,
+    ) -> Result<Arc<ExtensionType>>;
+}
+
+lazy_static! {
+    static ref EXTENSION_TYPES: RwLock<HashMap<String, Arc<ExtensionType>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Registers an extension type, so that fields annotated with its name can be
+/// resolved to it. Returns an error if an extension type with the same name is
+/// already registered.
+pub fn register_extension_type(extension_type: Arc<ExtensionType>) -> Result<()> {
+    let mut registry = EXTENSION_TYPES.write().unwrap();
+    let name = extension_type.extension_name().to_string();
+    if registry.contains_key(&name) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Extension type {} is already registered",
+            name
+        )));
+    }
+    registry.insert(name, extension_type);
+    Ok(())
+}
+
+/// Unregisters the extension type with the given name. Returns an error if no
+/// extension type with that name is registered.
+pub fn unregister_extension_type(name: &str) -> Result<()> {
+    match EXTENSION_TYPES.write().unwrap().remove(name) {
+        Some(_) => Ok(()),
+        None => Err(ArrowError::InvalidArgumentError(format!(
+            "Extension type {} is not registered",
+            name
+        ))),
+    }
+}
+
+/// Returns the registered extension type with the given name, if any
+pub fn get_extension_type(name: &str) -> Option<Arc<ExtensionType>> {
+    EXTENSION_TYPES.read().unwrap().get(name).cloned()
+}
+
 /// Describes the meta-data of an ordered sequence of relative types.
 ///
 /// Note that this information is only part of the meta-data and not part of the physical
@@ -1541,6 +2108,10 @@ impl Schema {
 
     /// Merge schema into self if it is compatible. Struct fields will be merged recursively.
     ///
+    /// Fields are matched by name, and their types are widened as described in
+    /// [`Field::try_merge`]. Use [`RecordBatch::adapt`](crate::record_batch::RecordBatch::adapt)
+    /// to convert batches of the original schemas to the merged schema.
+    ///
     /// Example:
     ///
     /// ```
@@ -1576,10 +2147,10 @@ impl Schema {
                 match merged.metadata.get(key) {
                     Some(old_val) => {
                         if old_val != value {
-                            return Err(ArrowError::SchemaError(
-                                "Fail to merge schema due to conflicting metadata"
-                                    .to_string(),
-                            ));
+                            return Err(ArrowError::SchemaError(format!(
+                                "Fail to merge schema due to conflicting metadata for key {}: {} != {}",
+                                key, value, old_val
+                            )));
                         }
                     }
                     None => {
@@ -1619,6 +2190,24 @@ impl Schema {
         &self.fields[i]
     }
 
+    /// Returns a new schema with only the fields at `indices`, in the given
+    /// order. The schema metadata is preserved.
+    pub fn project(&self, indices: &[usize]) -> Result<Schema> {
+        let fields = indices
+            .iter()
+            .map(|i| {
+                self.fields.get(*i).cloned().ok_or_else(|| {
+                    ArrowError::SchemaError(format!(
+                        "project index {} out of bounds, max field {}",
+                        i,
+                        self.fields.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new_with_metadata(fields, self.metadata.clone()))
+    }
+
     /// Returns an immutable reference of a specific `Field` instance selected by name
     pub fn field_with_name(&self, name: &str) -> Result<&Field> {
         Ok(&self.fields[self.index_of(name)?])
@@ -1847,6 +2436,104 @@ mod tests {
         .unwrap();
         assert_eq!(value, f.to_json());
     }
+
+    #[test]
+    fn list_child_metadata_to_json() {
+        let mut f = Field::new(
+            "ids",
+            DataType::List(Box::new(DataType::FixedSizeBinary(16))),
+            true,
+        );
+        f.set_child_metadata(Some(
+            [("ARROW:extension:name".to_string(), "uuid".to_string())]
+                .iter()
+                .cloned()
+                .collect(),
+        ));
+        let value: Value = serde_json::from_str(
+            r#"{
+                "name": "ids",
+                "nullable": true,
+                "type": {
+                    "name": "list"
+                },
+                "children": [
+                    {
+                        "name": "item",
+                        "nullable": true,
+                        "type": {
+                            "name": "fixedsizebinary",
+                            "byteWidth": 16
+                        },
+                        "children": [],
+                        "metadata": [
+                            {
+                                "key": "ARROW:extension:name",
+                                "value": "uuid"
+                            }
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(value, f.to_json());
+        assert_eq!(f, Field::from(&value).unwrap());
+    }
+
+    #[test]
+    fn field_metadata_to_json() {
+        let mut f = Field::new("id", DataType::FixedSizeBinary(16), false);
+        f.set_metadata(Some(
+            [("ARROW:extension:name".to_string(), "uuid".to_string())]
+                .iter()
+                .cloned()
+                .collect(),
+        ));
+        let value: Value = serde_json::from_str(
+            r#"{
+                "name": "id",
+                "nullable": false,
+                "type": {
+                    "name": "fixedsizebinary",
+                    "byteWidth": 16
+                },
+                "children": [],
+                "metadata": [
+                    {
+                        "key": "ARROW:extension:name",
+                        "value": "uuid"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(value, f.to_json());
+        assert_eq!(f, Field::from(&value).unwrap());
+
+        // the metadata may also be given as an object
+        let value: Value = serde_json::from_str(
+            r#"{
+                "name": "id",
+                "nullable": false,
+                "type": {
+                    "name": "fixedsizebinary",
+                    "byteWidth": 16
+                },
+                "children": [],
+                "metadata": {
+                    "ARROW:extension:name": "uuid"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(f, Field::from(&value).unwrap());
+
+        // empty metadata is normalized to None
+        f.set_metadata(Some(BTreeMap::new()));
+        assert_eq!(f.metadata(), &None);
+    }
+
     #[test]
     fn parse_struct_from_json() {
         let json = r#"
@@ -1909,6 +2596,23 @@ mod tests {
         assert_eq!(DataType::Int32, dt);
     }
 
+    #[test]
+    fn parse_decimal_from_json() {
+        let json = "{\"name\": \"decimal\", \"precision\": 10, \"scale\": 2}";
+        let value: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(DataType::Decimal(10, 2), DataType::from(&value).unwrap());
+
+        // malformed precisions and scales are errors instead of panics
+        for json in &[
+            "{\"name\": \"decimal\", \"precision\": \"10\", \"scale\": 2}",
+            "{\"name\": \"decimal\", \"precision\": 10, \"scale\": -2}",
+            "{\"name\": \"decimal\", \"precision\": 10}",
+        ] {
+            let value: Value = serde_json::from_str(json).unwrap();
+            assert!(DataType::from(&value).is_err());
+        }
+    }
+
     #[test]
     fn schema_json() {
         // Add some custom metadata
@@ -2004,6 +2708,22 @@ mod tests {
                     )))),
                     true,
                 ),
+                Field::new("c35", DataType::Decimal(10, 2), true),
+                Field::new(
+                    "c36",
+                    DataType::Map(
+                        Box::new(Field::new(
+                            "entries",
+                            DataType::Struct(vec![
+                                Field::new("key", DataType::Utf8, false),
+                                Field::new("value", DataType::Int32, true),
+                            ]),
+                            false,
+                        )),
+                        false,
+                    ),
+                    true,
+                ),
             ],
             metadata,
         );
@@ -2398,6 +3118,53 @@ mod tests {
                                 ]
                             }
                         ]
+                    },
+                    {
+                        "name": "c35",
+                        "nullable": true,
+                        "type": {
+                          "name": "decimal",
+                          "precision": 10,
+                          "scale": 2
+                        },
+                        "children": []
+                    },
+                    {
+                        "name": "c36",
+                        "nullable": true,
+                        "type": {
+                          "name": "map",
+                          "keysSorted": false
+                        },
+                        "children": [
+                            {
+                                "name": "entries",
+                                "nullable": false,
+                                "type": {
+                                    "name": "struct"
+                                },
+                                "children": [
+                                    {
+                                        "name": "key",
+                                        "nullable": false,
+                                        "type": {
+                                            "name": "utf8"
+                                        },
+                                        "children": []
+                                    },
+                                    {
+                                        "name": "value",
+                                        "nullable": true,
+                                        "type": {
+                                            "name": "int",
+                                            "bitWidth": 32,
+                                            "isSigned": true
+                                        },
+                                        "children": []
+                                    }
+                                ]
+                            }
+                        ]
                     }
                 ],
                 "metadata" : {
@@ -2455,8 +3222,8 @@ mod tests {
         assert_eq!(schema.to_string(), "first_name: Utf8, \
         last_name: Utf8, \
         address: Struct([\
-        Field { name: \"street\", data_type: Utf8, nullable: false, dict_id: 0, dict_is_ordered: false }, \
-        Field { name: \"zip\", data_type: UInt16, nullable: false, dict_id: 0, dict_is_ordered: false }])")
+        Field { name: \"street\", data_type: Utf8, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None, child_metadata: None }, \
+        Field { name: \"zip\", data_type: UInt16, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: None, child_metadata: None }])")
     }
 
     #[test]
@@ -2659,6 +3426,45 @@ mod tests {
             ),]),
         );
 
+        // compatible types are widened
+        let merged = Schema::try_merge(&[
+            Schema::new(vec![
+                Field::new("i", DataType::Int32, false),
+                Field::new("u", DataType::UInt32, false),
+                Field::new("f", DataType::Float32, false),
+                Field::new("s", DataType::Utf8, false),
+                Field::new("n", DataType::Null, true),
+            ]),
+            Schema::new(vec![
+                Field::new("i", DataType::Int64, false),
+                Field::new("u", DataType::Int8, false),
+                Field::new("f", DataType::Int16, false),
+                Field::new("s", DataType::LargeUtf8, false),
+                Field::new("n", DataType::Boolean, false),
+            ]),
+        ])?;
+        assert_eq!(
+            merged,
+            Schema::new(vec![
+                Field::new("i", DataType::Int64, false),
+                Field::new("u", DataType::Int64, false),
+                Field::new("f", DataType::Float64, false),
+                Field::new("s", DataType::LargeUtf8, false),
+                Field::new("n", DataType::Boolean, true),
+            ])
+        );
+
+        // no signed integer type holds all UInt64 values
+        let err = Schema::try_merge(&[
+            Schema::new(vec![Field::new("c1", DataType::UInt64, false)]),
+            Schema::new(vec![Field::new("c1", DataType::Int64, false)]),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            "Schema error: Fail to merge schema field 'c1' because the from data_type = Int64 does not equal UInt64",
+            err.to_string()
+        );
+
         // incompatible field should throw error
         assert!(Schema::try_merge(&[
             Schema::new(vec![
@@ -2688,8 +3494,87 @@ mod tests {
         ])
         .is_err());
 
+        // field metadata is merged, incompatible field metadata should throw error
+        let field_with_md = |k: &str, v: &str| {
+            let mut field = Field::new("c1", DataType::Utf8, false);
+            field.set_metadata(Some(
+                [(k.to_string(), v.to_string())].iter().cloned().collect(),
+            ));
+            field
+        };
+        let mut field = field_with_md("foo", "bar");
+        field.try_merge(&field_with_md("baz", "qux"))?;
+        assert_eq!(
+            field.metadata(),
+            &Some(
+                [
+                    ("baz".to_string(), "qux".to_string()),
+                    ("foo".to_string(), "bar".to_string())
+                ]
+                .iter()
+                .cloned()
+                .collect()
+            )
+        );
+        assert!(field.try_merge(&field_with_md("foo", "baz")).is_err());
+
         Ok(())
     }
+
+    #[derive(Debug)]
+    struct TestExtensionType;
+
+    impl ExtensionType for TestExtensionType {
+        fn extension_name(&self) -> &str {
+            "test.datatypes.ext"
+        }
+
+        fn storage_type(&self) -> &DataType {
+            &DataType::Int64
+        }
+
+        fn serialize(&self) -> String {
+            "params".to_string()
+        }
+
+        fn deserialize(
+            &self,
+            storage_type: &DataType,
+            serialized: &str,
+        ) -> Result<Arc<ExtensionType>> {
+            if storage_type != &DataType::Int64 || serialized != "params" {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Invalid extension type".to_string(),
+                ));
+            }
+            Ok(Arc::new(TestExtensionType))
+        }
+    }
+
+    #[test]
+    fn extension_type_registry() {
+        let field = Field::new_extension("ext", &TestExtensionType, true);
+        assert_eq!(field.data_type(), &DataType::Int64);
+        assert_eq!(field.extension_name(), Some("test.datatypes.ext"));
+        assert!(field.extension_type().unwrap().is_none());
+
+        register_extension_type(Arc::new(TestExtensionType)).unwrap();
+        assert!(register_extension_type(Arc::new(TestExtensionType)).is_err());
+        assert!(get_extension_type("test.datatypes.ext").is_some());
+        let ext = field.extension_type().unwrap().unwrap();
+        assert_eq!(ext.extension_name(), "test.datatypes.ext");
+
+        // the stored parameters are validated by the extension type
+        let mut invalid = field.clone();
+        let mut metadata = field.metadata().clone().unwrap();
+        metadata.insert(EXTENSION_TYPE_METADATA_KEY.to_string(), "other".to_string());
+        invalid.set_metadata(Some(metadata));
+        assert!(invalid.extension_type().is_err());
+
+        unregister_extension_type("test.datatypes.ext").unwrap();
+        assert!(unregister_extension_type("test.datatypes.ext").is_err());
+        assert!(get_extension_type("test.datatypes.ext").is_none());
+    }
 }
 
 #[cfg(all(
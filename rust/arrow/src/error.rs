@@ -36,6 +36,7 @@ pub enum ArrowError {
     InvalidArgumentError(String),
     ParquetError(String),
     DictionaryKeyOverflowError,
+    CDataInterface(String),
 }
 
 impl ArrowError {
@@ -73,6 +74,12 @@ impl From<csv_crate::Error> for ArrowError {
     }
 }
 
+impl From<serde_json::Error> for ArrowError {
+    fn from(error: serde_json::Error) -> Self {
+        ArrowError::JsonError(error.to_string())
+    }
+}
+
 impl From<::std::string::FromUtf8Error> for ArrowError {
     fn from(error: std::string::FromUtf8Error) -> Self {
         ArrowError::ParseError(error.to_string())
@@ -100,6 +107,9 @@ impl Display for ArrowError {
             ArrowError::DictionaryKeyOverflowError => {
                 write!(f, "Dictionary key bigger than the key type")
             }
+            ArrowError::CDataInterface(desc) => {
+                write!(f, "C Data Interface error: {}", desc)
+            }
         }
     }
 }
@@ -0,0 +1,1559 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+//!
+//! The C Data Interface allows to share arrays with other Arrow implementations living
+//! in the same process (e.g. `pyarrow` or the C++ library) without copying them. An
+//! array is described by two C structs: [`FFI_ArrowSchema`](crate::ffi::FFI_ArrowSchema)
+//! holds its data type and [`FFI_ArrowArray`](crate::ffi::FFI_ArrowArray) its buffers.
+//! Both carry a `release` callback that the consumer calls once it no longer needs them.
+//!
+//! Exported arrays keep their buffers alive until they are released. Imported arrays are
+//! backed by buffers that reference the foreign memory, which is released once the last
+//! of them is dropped.
+//!
+//! ```rust
+//! # use arrow::array::{make_array_from_raw, Array, Int32Array};
+//! # use arrow::error::Result;
+//! # use arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+//! # fn main() -> Result<()> {
+//! let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+//!
+//! // the structs are usually allocated by the consumer, e.g. pyarrow
+//! let mut ffi_array = FFI_ArrowArray::empty();
+//! let mut ffi_schema = FFI_ArrowSchema::empty();
+//!
+//! // export the array into the structs
+//! unsafe { array.to_raw(&mut ffi_array, &mut ffi_schema)? };
+//!
+//! // import them back, moving their contents into a new array
+//! let imported = unsafe { make_array_from_raw(&mut ffi_array, &mut ffi_schema)? };
+//! assert!(imported.equals(&array));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::mem::size_of;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::array::{ArrayData, ArrayDataRef};
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, DateUnit, Field, IntervalUnit, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+
+/// The field is dictionary encoded and the order of its dictionary values is meaningful
+const ARROW_FLAG_DICTIONARY_ORDERED: i64 = 1;
+/// The field is semantically nullable
+const ARROW_FLAG_NULLABLE: i64 = 2;
+/// The keys within each value of the map field are sorted
+const ARROW_FLAG_MAP_KEYS_SORTED: i64 = 4;
+
+/// ABI-compatible struct for `ArrowSchema` from the C Data Interface.
+/// See <https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions>
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowSchema {
+    format: *const c_char,
+    name: *const c_char,
+    metadata: *const c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut FFI_ArrowSchema,
+    dictionary: *mut FFI_ArrowSchema,
+    release: Option<unsafe extern "C" fn(schema: *mut FFI_ArrowSchema)>,
+    private_data: *mut c_void,
+}
+
+/// The memory owned by an exported `FFI_ArrowSchema`
+struct SchemaPrivateData {
+    format: CString,
+    name: CString,
+    metadata: Option<Vec<u8>>,
+    children: Box<[*mut FFI_ArrowSchema]>,
+    dictionary: *mut FFI_ArrowSchema,
+}
+
+/// The `release` callback of the schemas exported by this crate
+unsafe extern "C" fn release_schema(schema: *mut FFI_ArrowSchema) {
+    if schema.is_null() {
+        return;
+    }
+    let schema = &mut *schema;
+    let private_data = Box::from_raw(schema.private_data as *mut SchemaPrivateData);
+    // the children and the dictionary were allocated on export, and are released
+    // when dropped, unless the consumer moved them out
+    for child in private_data.children.iter() {
+        drop(Box::from_raw(*child));
+    }
+    if !private_data.dictionary.is_null() {
+        drop(Box::from_raw(private_data.dictionary));
+    }
+    schema.release = None;
+}
+
+impl FFI_ArrowSchema {
+    /// Creates a new exported schema, owning all of its parts
+    fn try_new(
+        format: &str,
+        name: &str,
+        metadata: Option<Vec<u8>>,
+        flags: i64,
+        children: Vec<FFI_ArrowSchema>,
+        dictionary: Option<FFI_ArrowSchema>,
+    ) -> Result<Self> {
+        let format = CString::new(format)
+            .map_err(|e| ArrowError::CDataInterface(e.to_string()))?;
+        let name =
+            CString::new(name).map_err(|e| ArrowError::CDataInterface(e.to_string()))?;
+        let children = children
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(child)))
+            .collect::<Box<[_]>>();
+        let dictionary = dictionary
+            .map(|dictionary| Box::into_raw(Box::new(dictionary)))
+            .unwrap_or_else(ptr::null_mut);
+
+        let private_data = Box::new(SchemaPrivateData {
+            format,
+            name,
+            metadata,
+            children,
+            dictionary,
+        });
+        Ok(Self {
+            format: private_data.format.as_ptr(),
+            name: private_data.name.as_ptr(),
+            metadata: private_data
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.as_ptr() as *const c_char)
+                .unwrap_or_else(ptr::null),
+            flags,
+            n_children: private_data.children.len() as i64,
+            children: private_data.children.as_ptr() as *mut *mut Self,
+            dictionary,
+            release: Some(release_schema),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        })
+    }
+
+    /// Creates an empty, released `FFI_ArrowSchema`, to be filled by a producer
+    pub fn empty() -> Self {
+        Self {
+            format: ptr::null(),
+            name: ptr::null(),
+            metadata: ptr::null(),
+            flags: 0,
+            n_children: 0,
+            children: ptr::null_mut(),
+            dictionary: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+
+    /// Returns whether this schema has been released, i.e. it does not describe any
+    /// type anymore
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+
+    /// Returns the format string of this schema
+    pub fn format(&self) -> Result<&str> {
+        c_str(self.format, "format")
+    }
+
+    /// Returns the name of this schema
+    pub fn name(&self) -> Result<&str> {
+        if self.name.is_null() {
+            return Ok("");
+        }
+        c_str(self.name, "name")
+    }
+
+    /// Returns the flags of this schema
+    pub fn flags(&self) -> i64 {
+        self.flags
+    }
+
+    fn child(&self, index: usize) -> Result<&Self> {
+        if index >= self.n_children as usize {
+            return Err(ArrowError::CDataInterface(format!(
+                "The schema has {} children, while child {} was requested",
+                self.n_children, index
+            )));
+        }
+        unsafe { Ok(&**self.children.add(index)) }
+    }
+
+    fn children(&self) -> Result<Vec<&Self>> {
+        (0..self.n_children.max(0) as usize)
+            .map(|i| self.child(i))
+            .collect()
+    }
+
+    fn dictionary(&self) -> Option<&Self> {
+        unsafe { self.dictionary.as_ref() }
+    }
+
+    /// Decodes the metadata of this schema, in the order it is stored
+    fn metadata(&self) -> Result<Vec<(String, String)>> {
+        if self.metadata.is_null() {
+            return Ok(vec![]);
+        }
+        unsafe {
+            let mut pos = self.metadata as *const u8;
+            let len = read_i32(&mut pos);
+            (0..len)
+                .map(|_| Ok((read_string(&mut pos)?, read_string(&mut pos)?)))
+                .collect()
+        }
+    }
+}
+
+impl Drop for FFI_ArrowSchema {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) };
+        }
+    }
+}
+
+// See the equivalent implementations for `FFI_ArrowArray`
+unsafe impl Send for FFI_ArrowSchema {}
+unsafe impl Sync for FFI_ArrowSchema {}
+
+fn c_str<'a>(ptr: *const c_char, what: &str) -> Result<&'a str> {
+    if ptr.is_null() {
+        return Err(ArrowError::CDataInterface(format!(
+            "The schema's {} is null",
+            what
+        )));
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().map_err(|_| {
+        ArrowError::CDataInterface(format!("The schema's {} is not valid UTF-8", what))
+    })
+}
+
+unsafe fn read_i32(pos: &mut *const u8) -> i32 {
+    let value = ptr::read_unaligned(*pos as *const i32);
+    *pos = pos.add(size_of::<i32>());
+    value
+}
+
+unsafe fn read_string(pos: &mut *const u8) -> Result<String> {
+    let len = read_i32(pos);
+    if len < 0 {
+        return Err(ArrowError::CDataInterface(
+            "The schema's metadata contains a negative length".to_string(),
+        ));
+    }
+    let bytes = std::slice::from_raw_parts(*pos, len as usize);
+    *pos = pos.add(len as usize);
+    Ok(String::from_utf8(bytes.to_vec())?)
+}
+
+/// Encodes key-value pairs in the binary format of the schema's metadata, or `None` if
+/// there are none
+fn encode_metadata<'a, I>(entries: I) -> Option<Vec<u8>>
+where
+    I: Iterator<Item = (&'a String, &'a String)>,
+{
+    let entries = entries.collect::<Vec<_>>();
+    if entries.is_empty() {
+        return None;
+    }
+    let mut buffer = vec![];
+    buffer.extend_from_slice(&(entries.len() as i32).to_ne_bytes());
+    for (key, value) in entries {
+        for s in &[key, value] {
+            buffer.extend_from_slice(&(s.len() as i32).to_ne_bytes());
+            buffer.extend_from_slice(s.as_bytes());
+        }
+    }
+    Some(buffer)
+}
+
+fn time_unit_format(unit: &TimeUnit) -> char {
+    match unit {
+        TimeUnit::Second => 's',
+        TimeUnit::Millisecond => 'm',
+        TimeUnit::Microsecond => 'u',
+        TimeUnit::Nanosecond => 'n',
+    }
+}
+
+/// Returns the format string of a union with the given children, whose type ids are the
+/// indexes of its children
+fn union_format(fields: &[Field], dense: bool) -> String {
+    let type_ids = (0..fields.len())
+        .map(|type_id| type_id.to_string())
+        .collect::<Vec<_>>();
+    format!("+u{}:{}", if dense { "d" } else { "s" }, type_ids.join(","))
+}
+
+/// Returns whether the data of a union is laid out as a dense union, i.e. it has offsets
+fn is_dense_union(data: &ArrayData) -> bool {
+    data.buffers().len() == 2
+}
+
+/// Returns the format string of a data type. Dictionaries are formatted as their index
+/// type, and unions as sparse unions.
+fn format_of(data_type: &DataType) -> Result<String> {
+    let format = match data_type {
+        DataType::Null => "n",
+        DataType::Boolean => "b",
+        DataType::Int8 => "c",
+        DataType::UInt8 => "C",
+        DataType::Int16 => "s",
+        DataType::UInt16 => "S",
+        DataType::Int32 => "i",
+        DataType::UInt32 => "I",
+        DataType::Int64 => "l",
+        DataType::UInt64 => "L",
+        DataType::Float16 => "e",
+        DataType::Float32 => "f",
+        DataType::Float64 => "g",
+        DataType::Binary => "z",
+        DataType::LargeBinary => "Z",
+        DataType::Utf8 => "u",
+        DataType::LargeUtf8 => "U",
+        DataType::Date32(DateUnit::Day) => "tdD",
+        DataType::Date64(DateUnit::Millisecond) => "tdm",
+        DataType::Time32(TimeUnit::Second) => "tts",
+        DataType::Time32(TimeUnit::Millisecond) => "ttm",
+        DataType::Time64(TimeUnit::Microsecond) => "ttu",
+        DataType::Time64(TimeUnit::Nanosecond) => "ttn",
+        DataType::Interval(IntervalUnit::YearMonth) => "tiM",
+        DataType::Interval(IntervalUnit::DayTime) => "tiD",
+        DataType::List(_) => "+l",
+        DataType::LargeList(_) => "+L",
+        DataType::Struct(_) => "+s",
+        DataType::Map(_, _) => "+m",
+        DataType::Decimal(precision, scale) => {
+            return Ok(format!("d:{},{}", precision, scale))
+        }
+        DataType::FixedSizeBinary(size) => return Ok(format!("w:{}", size)),
+        DataType::FixedSizeList(_, size) => return Ok(format!("+w:{}", size)),
+        DataType::Timestamp(unit, tz) => {
+            return Ok(format!(
+                "ts{}:{}",
+                time_unit_format(unit),
+                tz.as_ref().map(|tz| tz.as_str()).unwrap_or("")
+            ))
+        }
+        DataType::Duration(unit) => return Ok(format!("tD{}", time_unit_format(unit))),
+        DataType::Dictionary(key_type, _) => return format_of(key_type),
+        DataType::Union(fields) => return Ok(union_format(fields, false)),
+        other => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The data type {:?} is not supported",
+                other
+            )))
+        }
+    };
+    Ok(format.to_string())
+}
+
+/// Exports a data type, with the attributes of the field it belongs to. The mode of the
+/// unions is taken from `data` when it is given, they are exported as sparse otherwise.
+fn export_schema(
+    data_type: &DataType,
+    data: Option<&ArrayData>,
+    name: &str,
+    nullable: bool,
+    dict_is_ordered: bool,
+    metadata: Option<Vec<u8>>,
+) -> Result<FFI_ArrowSchema> {
+    let format = match (data_type, data) {
+        (DataType::Union(fields), Some(data)) => {
+            union_format(fields, is_dense_union(data))
+        }
+        _ => format_of(data_type)?,
+    };
+    let child_data = |index: usize| {
+        data.and_then(|data| data.child_data().get(index))
+            .map(|child| child.as_ref())
+    };
+    let mut flags = if nullable { ARROW_FLAG_NULLABLE } else { 0 };
+    let children = match data_type {
+        DataType::List(value_type)
+        | DataType::LargeList(value_type)
+        | DataType::FixedSizeList(value_type, _) => {
+            vec![export_schema(
+                value_type,
+                child_data(0),
+                "item",
+                true,
+                false,
+                None,
+            )?]
+        }
+        DataType::Struct(fields) | DataType::Union(fields) => fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| export_field(field, child_data(index)))
+            .collect::<Result<_>>()?,
+        DataType::Map(entries, keys_sorted) => {
+            if *keys_sorted {
+                flags |= ARROW_FLAG_MAP_KEYS_SORTED;
+            }
+            vec![export_field(entries, child_data(0))?]
+        }
+        _ => vec![],
+    };
+    let dictionary = match data_type {
+        DataType::Dictionary(_, value_type) => {
+            if dict_is_ordered {
+                flags |= ARROW_FLAG_DICTIONARY_ORDERED;
+            }
+            Some(export_schema(
+                value_type,
+                child_data(0),
+                "",
+                true,
+                false,
+                None,
+            )?)
+        }
+        _ => None,
+    };
+    FFI_ArrowSchema::try_new(&format, name, metadata, flags, children, dictionary)
+}
+
+/// Exports a field, taking the mode of its unions from `data` when it is given
+fn export_field(field: &Field, data: Option<&ArrayData>) -> Result<FFI_ArrowSchema> {
+    export_schema(
+        field.data_type(),
+        data,
+        field.name(),
+        field.is_nullable(),
+        field.dict_is_ordered,
+        encode_metadata(field.metadata().iter().flat_map(|md| md.iter())),
+    )
+}
+
+impl TryFrom<&DataType> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    /// Exports a data type. Unions are exported as sparse unions, as their mode is
+    /// only known from their data.
+    fn try_from(data_type: &DataType) -> Result<Self> {
+        export_schema(data_type, None, "", true, false, None)
+    }
+}
+
+impl TryFrom<&Field> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    /// Exports a field. Unions are exported as sparse unions, as their mode is only
+    /// known from their data.
+    fn try_from(field: &Field) -> Result<Self> {
+        export_field(field, None)
+    }
+}
+
+impl TryFrom<&Schema> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    /// Exports a schema as a struct type whose children are the schema's fields
+    fn try_from(schema: &Schema) -> Result<Self> {
+        let children = schema
+            .fields()
+            .iter()
+            .map(FFI_ArrowSchema::try_from)
+            .collect::<Result<_>>()?;
+        // sort the metadata so that the export is deterministic
+        let metadata = schema.metadata().iter().collect::<BTreeMap<_, _>>();
+        FFI_ArrowSchema::try_new(
+            "+s",
+            "",
+            encode_metadata(metadata.into_iter()),
+            0,
+            children,
+            None,
+        )
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str, format: &str) -> Result<T> {
+    value.parse::<T>().map_err(|_| {
+        ArrowError::CDataInterface(format!("The format {} is not valid", format))
+    })
+}
+
+fn parse_time_unit(unit: &str, format: &str) -> Result<TimeUnit> {
+    match unit {
+        "s" => Ok(TimeUnit::Second),
+        "m" => Ok(TimeUnit::Millisecond),
+        "u" => Ok(TimeUnit::Microsecond),
+        "n" => Ok(TimeUnit::Nanosecond),
+        _ => Err(ArrowError::CDataInterface(format!(
+            "The format {} is not supported",
+            format
+        ))),
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for DataType {
+    type Error = ArrowError;
+
+    fn try_from(c_schema: &FFI_ArrowSchema) -> Result<Self> {
+        let format = c_schema.format()?;
+        let data_type = match format {
+            "n" => DataType::Null,
+            "b" => DataType::Boolean,
+            "c" => DataType::Int8,
+            "C" => DataType::UInt8,
+            "s" => DataType::Int16,
+            "S" => DataType::UInt16,
+            "i" => DataType::Int32,
+            "I" => DataType::UInt32,
+            "l" => DataType::Int64,
+            "L" => DataType::UInt64,
+            "e" => DataType::Float16,
+            "f" => DataType::Float32,
+            "g" => DataType::Float64,
+            "z" => DataType::Binary,
+            "Z" => DataType::LargeBinary,
+            "u" => DataType::Utf8,
+            "U" => DataType::LargeUtf8,
+            "tdD" => DataType::Date32(DateUnit::Day),
+            "tdm" => DataType::Date64(DateUnit::Millisecond),
+            "tts" => DataType::Time32(TimeUnit::Second),
+            "ttm" => DataType::Time32(TimeUnit::Millisecond),
+            "ttu" => DataType::Time64(TimeUnit::Microsecond),
+            "ttn" => DataType::Time64(TimeUnit::Nanosecond),
+            "tiM" => DataType::Interval(IntervalUnit::YearMonth),
+            "tiD" => DataType::Interval(IntervalUnit::DayTime),
+            "tDs" => DataType::Duration(TimeUnit::Second),
+            "tDm" => DataType::Duration(TimeUnit::Millisecond),
+            "tDu" => DataType::Duration(TimeUnit::Microsecond),
+            "tDn" => DataType::Duration(TimeUnit::Nanosecond),
+            "+l" => DataType::List(Box::new(DataType::try_from(c_schema.child(0)?)?)),
+            "+L" => {
+                DataType::LargeList(Box::new(DataType::try_from(c_schema.child(0)?)?))
+            }
+            "+s" => DataType::Struct(
+                c_schema
+                    .children()?
+                    .into_iter()
+                    .map(Field::try_from)
+                    .collect::<Result<_>>()?,
+            ),
+            "+m" => {
+                let entries = Field::try_from(c_schema.child(0)?)?;
+                match entries.data_type() {
+                    DataType::Struct(fields) if fields.len() == 2 => {}
+                    _ => {
+                        return Err(ArrowError::CDataInterface(
+                            "The entries of a map must be a struct with two children"
+                                .to_string(),
+                        ))
+                    }
+                }
+                DataType::Map(
+                    Box::new(entries),
+                    c_schema.flags & ARROW_FLAG_MAP_KEYS_SORTED != 0,
+                )
+            }
+            other => {
+                let parts = other.splitn(2, ':').collect::<Vec<_>>();
+                match parts.as_slice() {
+                    ["d", params] => {
+                        let params = params.split(',').collect::<Vec<_>>();
+                        match params.as_slice() {
+                            [precision, scale] | [precision, scale, "128"] => {
+                                DataType::Decimal(
+                                    parse_number(precision, format)?,
+                                    parse_number(scale, format)?,
+                                )
+                            }
+                            _ => {
+                                return Err(ArrowError::CDataInterface(format!(
+                                    "The format {} is not supported",
+                                    format
+                                )))
+                            }
+                        }
+                    }
+                    ["w", size] => DataType::FixedSizeBinary(parse_number(size, format)?),
+                    ["+us", type_ids] | ["+ud", type_ids] => {
+                        let fields = c_schema
+                            .children()?
+                            .into_iter()
+                            .map(Field::try_from)
+                            .collect::<Result<Vec<_>>>()?;
+                        let type_ids = type_ids
+                            .split(',')
+                            .filter(|type_id| !type_id.is_empty())
+                            .map(|type_id| parse_number::<usize>(type_id, format))
+                            .collect::<Result<Vec<_>>>()?;
+                        // the type ids of a union are the indexes of its children
+                        if type_ids != (0..fields.len()).collect::<Vec<_>>() {
+                            return Err(ArrowError::CDataInterface(format!(
+                                "The format {} is not supported, as the type ids of a union must be the indexes of its children",
+                                format
+                            )));
+                        }
+                        DataType::Union(fields)
+                    }
+                    ["+w", size] => DataType::FixedSizeList(
+                        Box::new(DataType::try_from(c_schema.child(0)?)?),
+                        parse_number(size, format)?,
+                    ),
+                    [unit, tz] if unit.len() == 3 && unit.starts_with("ts") => {
+                        let tz = if tz.is_empty() {
+                            None
+                        } else {
+                            Some(Arc::new(tz.to_string()))
+                        };
+                        DataType::Timestamp(parse_time_unit(&unit[2..], format)?, tz)
+                    }
+                    _ => {
+                        return Err(ArrowError::CDataInterface(format!(
+                            "The format {} is not supported",
+                            format
+                        )))
+                    }
+                }
+            }
+        };
+
+        match c_schema.dictionary() {
+            Some(dictionary) => Ok(DataType::Dictionary(
+                Box::new(data_type),
+                Box::new(DataType::try_from(dictionary)?),
+            )),
+            None => Ok(data_type),
+        }
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for Field {
+    type Error = ArrowError;
+
+    fn try_from(c_schema: &FFI_ArrowSchema) -> Result<Self> {
+        let data_type = DataType::try_from(c_schema)?;
+        let name = c_schema.name()?;
+        let nullable = c_schema.flags & ARROW_FLAG_NULLABLE != 0;
+        let mut field = match data_type {
+            DataType::Dictionary(_, _) => Field::new_dict(
+                name,
+                data_type,
+                nullable,
+                0,
+                c_schema.flags & ARROW_FLAG_DICTIONARY_ORDERED != 0,
+            ),
+            _ => Field::new(name, data_type, nullable),
+        };
+        field.set_metadata(Some(c_schema.metadata()?.into_iter().collect()));
+        Ok(field)
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for Schema {
+    type Error = ArrowError;
+
+    /// Imports a schema from a struct type whose children are the schema's fields
+    fn try_from(c_schema: &FFI_ArrowSchema) -> Result<Self> {
+        if c_schema.format()? != "+s" {
+            return Err(ArrowError::CDataInterface(
+                "A schema must be imported from a struct type".to_string(),
+            ));
+        }
+        let fields = c_schema
+            .children()?
+            .into_iter()
+            .map(Field::try_from)
+            .collect::<Result<_>>()?;
+        let metadata = c_schema.metadata()?.into_iter().collect::<HashMap<_, _>>();
+        Ok(Schema::new_with_metadata(fields, metadata))
+    }
+}
+
+/// ABI-compatible struct for `ArrowArray` from the C Data Interface.
+/// See <https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions>
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut FFI_ArrowArray,
+    dictionary: *mut FFI_ArrowArray,
+    release: Option<unsafe extern "C" fn(array: *mut FFI_ArrowArray)>,
+    private_data: *mut c_void,
+}
+
+/// The memory owned by an exported `FFI_ArrowArray`
+struct ArrayPrivateData {
+    /// Keeps the exported buffers alive, `None` for an absent null bitmap
+    buffers: Vec<Option<Buffer>>,
+    buffers_ptr: Box<[*const c_void]>,
+    children: Box<[*mut FFI_ArrowArray]>,
+    dictionary: *mut FFI_ArrowArray,
+}
+
+/// The `release` callback of the arrays exported by this crate
+unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
+    if array.is_null() {
+        return;
+    }
+    let array = &mut *array;
+    let private_data = Box::from_raw(array.private_data as *mut ArrayPrivateData);
+    for child in private_data.children.iter() {
+        drop(Box::from_raw(*child));
+    }
+    if !private_data.dictionary.is_null() {
+        drop(Box::from_raw(private_data.dictionary));
+    }
+    array.release = None;
+}
+
+impl FFI_ArrowArray {
+    /// Exports `data`, sharing its buffers
+    pub(crate) fn try_new(data: &ArrayData) -> Result<Self> {
+        let buffers = match data.data_type() {
+            DataType::Null => vec![],
+            // unions have no null bitmap, only their type ids and offsets
+            DataType::Union(_) if data.null_count() > 0 => {
+                return Err(ArrowError::CDataInterface(
+                    "A union with null values can not be exported, as unions have no null bitmap"
+                        .to_string(),
+                ))
+            }
+            DataType::Union(_) => data
+                .buffers()
+                .iter()
+                .map(|buffer| Some(buffer.clone()))
+                .collect(),
+            _ => std::iter::once(data.null_buffer().cloned())
+                .chain(data.buffers().iter().map(|buffer| Some(buffer.clone())))
+                .collect::<Vec<_>>(),
+        };
+        let buffers_ptr = buffers
+            .iter()
+            .map(|buffer| match buffer {
+                Some(buffer) => buffer.raw_data() as *const c_void,
+                None => ptr::null(),
+            })
+            .collect::<Box<[_]>>();
+
+        // the values of a dictionary are exported as its dictionary, not as a child
+        let (children, dictionary) = match data.data_type() {
+            DataType::Dictionary(_, _) => (
+                vec![],
+                Some(FFI_ArrowArray::try_new(&data.child_data()[0])?),
+            ),
+            _ => (
+                data.child_data()
+                    .iter()
+                    .map(|child| FFI_ArrowArray::try_new(child))
+                    .collect::<Result<Vec<_>>>()?,
+                None,
+            ),
+        };
+        let children = children
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(child)))
+            .collect::<Box<[_]>>();
+        let dictionary = dictionary
+            .map(|dictionary| Box::into_raw(Box::new(dictionary)))
+            .unwrap_or_else(ptr::null_mut);
+
+        let mut private_data = Box::new(ArrayPrivateData {
+            buffers,
+            buffers_ptr,
+            children,
+            dictionary,
+        });
+        Ok(Self {
+            length: data.len() as i64,
+            null_count: data.null_count() as i64,
+            offset: data.offset() as i64,
+            n_buffers: private_data.buffers.len() as i64,
+            n_children: private_data.children.len() as i64,
+            buffers: private_data.buffers_ptr.as_mut_ptr(),
+            children: private_data.children.as_mut_ptr(),
+            dictionary,
+            release: Some(release_array),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        })
+    }
+
+    /// Creates an empty, released `FFI_ArrowArray`, to be filled by a producer
+    pub fn empty() -> Self {
+        Self {
+            length: 0,
+            null_count: 0,
+            offset: 0,
+            n_buffers: 0,
+            n_children: 0,
+            buffers: ptr::null_mut(),
+            children: ptr::null_mut(),
+            dictionary: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+
+    /// Returns whether this array has been released, i.e. it does not own any memory
+    /// anymore
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+
+    /// Returns the number of elements of this array
+    pub fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    /// Returns whether this array has no elements
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of null elements of this array, or -1 if it is unknown
+    pub fn null_count(&self) -> i64 {
+        self.null_count
+    }
+
+    /// Returns the offset of this array into its buffers
+    pub fn offset(&self) -> usize {
+        self.offset as usize
+    }
+
+    fn child(&self, index: usize) -> Result<&Self> {
+        if index >= self.n_children as usize {
+            return Err(ArrowError::CDataInterface(format!(
+                "The array has {} children, while child {} was requested",
+                self.n_children, index
+            )));
+        }
+        unsafe { Ok(&**self.children.add(index)) }
+    }
+}
+
+impl Drop for FFI_ArrowArray {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) };
+        }
+    }
+}
+
+// The C Data Interface requires the release callback to be callable from any thread,
+// and the struct is never mutated once it has been produced.
+unsafe impl Send for FFI_ArrowArray {}
+unsafe impl Sync for FFI_ArrowArray {}
+
+/// Returns the number of buffers of arrays of the given data type, including the null
+/// bitmap. Unions, whose buffers depend on their mode, are imported by `import_union`.
+fn buffer_count(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Null => 0,
+        DataType::Struct(_) | DataType::FixedSizeList(_, _) => 1,
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => 3,
+        _ => 2,
+    }
+}
+
+/// Returns the number of children of arrays of the given data type
+fn children_count(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Map(_, _) => 1,
+        DataType::Struct(fields) => fields.len(),
+        _ => 0,
+    }
+}
+
+/// Returns the width in bytes of a single value of a fixed-width data type
+fn byte_width(data_type: &DataType) -> Result<usize> {
+    Ok(match data_type {
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        DataType::FixedSizeBinary(size) => *size as usize,
+        other => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The data type {:?} is not supported",
+                other
+            )))
+        }
+    })
+}
+
+/// Reads the last offset of a variable-sized array, i.e. the length of its values buffer
+unsafe fn last_offset<T: Copy + Into<i64>>(array: &FFI_ArrowArray) -> Result<usize> {
+    let offsets = *array.buffers.add(1) as *const T;
+    if offsets.is_null() {
+        return Ok(0);
+    }
+    let last: i64 = (*offsets.add(array.offset() + array.len())).into();
+    usize::try_from(last).map_err(|_| {
+        ArrowError::CDataInterface("The array contains a negative offset".to_string())
+    })
+}
+
+/// Returns the length in bytes of buffer `index` of an array of the given data type
+unsafe fn buffer_len(
+    array: &FFI_ArrowArray,
+    data_type: &DataType,
+    index: usize,
+) -> Result<usize> {
+    let len = array.offset() + array.len();
+    Ok(match (data_type, index) {
+        (_, 0) | (DataType::Boolean, 1) => bit_util::ceil(len, 8),
+        (DataType::Utf8, 1)
+        | (DataType::Binary, 1)
+        | (DataType::List(_), 1)
+        | (DataType::Map(_, _), 1) => (len + 1) * size_of::<i32>(),
+        (DataType::LargeUtf8, 1)
+        | (DataType::LargeBinary, 1)
+        | (DataType::LargeList(_), 1) => (len + 1) * size_of::<i64>(),
+        (DataType::Utf8, 2) | (DataType::Binary, 2) => last_offset::<i32>(array)?,
+        (DataType::LargeUtf8, 2) | (DataType::LargeBinary, 2) => {
+            last_offset::<i64>(array)?
+        }
+        (DataType::Dictionary(key_type, _), 1) => len * byte_width(key_type)?,
+        (data_type, _) => len * byte_width(data_type)?,
+    })
+}
+
+/// Imports the array `array` of type `data_type`, whose memory is kept alive by `owner`
+unsafe fn import_array(
+    array: &FFI_ArrowArray,
+    data_type: &DataType,
+    owner: &Arc<FFI_ArrowArray>,
+) -> Result<ArrayDataRef> {
+    if array.length < 0 || array.offset < 0 {
+        return Err(ArrowError::CDataInterface(
+            "The array has a negative length or offset".to_string(),
+        ));
+    }
+    if let DataType::Union(fields) = data_type {
+        return import_union(array, data_type, fields, owner);
+    }
+    let n_buffers = buffer_count(data_type);
+    if array.n_buffers != n_buffers as i64 {
+        return Err(ArrowError::CDataInterface(format!(
+            "An array of type {:?} must have {} buffers, but it has {}",
+            data_type, n_buffers, array.n_buffers
+        )));
+    }
+    let n_children = children_count(data_type);
+    if array.n_children != n_children as i64 {
+        return Err(ArrowError::CDataInterface(format!(
+            "An array of type {:?} must have {} children, but it has {}",
+            data_type, n_children, array.n_children
+        )));
+    }
+
+    let mut buffers = vec![];
+    for index in 0..n_buffers {
+        let ptr = *array.buffers.add(index) as *const u8;
+        let len = buffer_len(array, data_type, index)?;
+        buffers.push(if ptr.is_null() {
+            None
+        } else {
+            Some(Buffer::from_ffi(ptr, len, owner.clone()))
+        });
+    }
+    let mut buffers = buffers.into_iter();
+    let null_bit_buffer = buffers.next().and_then(|buffer| buffer);
+    let buffers = buffers
+        .enumerate()
+        .map(|(index, buffer)| match buffer {
+            Some(buffer) => Ok(buffer),
+            None if buffer_len(array, data_type, index + 1)? == 0 => Ok(Buffer::empty()),
+            None => Err(ArrowError::CDataInterface(format!(
+                "The buffer {} of the array is null",
+                index + 1
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let null_count = match null_bit_buffer {
+        None => Some(0),
+        Some(_) if array.null_count < 0 => None,
+        Some(_) => Some(array.null_count as usize),
+    };
+
+    let child_data = match data_type {
+        DataType::List(value_type)
+        | DataType::LargeList(value_type)
+        | DataType::FixedSizeList(value_type, _) => {
+            vec![import_array(array.child(0)?, value_type, owner)?]
+        }
+        DataType::Map(entries, _) => {
+            vec![import_array(array.child(0)?, entries.data_type(), owner)?]
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                import_array(array.child(index)?, field.data_type(), owner)
+            })
+            .collect::<Result<_>>()?,
+        DataType::Dictionary(_, value_type) => match array.dictionary.as_ref() {
+            Some(dictionary) => vec![import_array(dictionary, value_type, owner)?],
+            None => {
+                return Err(ArrowError::CDataInterface(
+                    "A dictionary array must have a dictionary".to_string(),
+                ))
+            }
+        },
+        _ => vec![],
+    };
+
+    Ok(Arc::new(ArrayData::new(
+        data_type.clone(),
+        array.len(),
+        null_count,
+        null_bit_buffer,
+        array.offset(),
+        buffers,
+        child_data,
+    )))
+}
+
+/// Imports the union array `array` of type `data_type`. Unions have no null bitmap, their
+/// buffers are their type ids and, for dense unions only, their offsets.
+unsafe fn import_union(
+    array: &FFI_ArrowArray,
+    data_type: &DataType,
+    fields: &[Field],
+    owner: &Arc<FFI_ArrowArray>,
+) -> Result<ArrayDataRef> {
+    if array.n_buffers != 1 && array.n_buffers != 2 {
+        return Err(ArrowError::CDataInterface(format!(
+            "A union array must have 1 or 2 buffers, but it has {}",
+            array.n_buffers
+        )));
+    }
+    if array.n_children != fields.len() as i64 {
+        return Err(ArrowError::CDataInterface(format!(
+            "An array of type {:?} must have {} children, but it has {}",
+            data_type,
+            fields.len(),
+            array.n_children
+        )));
+    }
+
+    // the type ids are `i8` and the offsets `i32`
+    let len = array.offset() + array.len();
+    let mut buffers = vec![];
+    for (index, width) in [size_of::<i8>(), size_of::<i32>()]
+        .iter()
+        .take(array.n_buffers as usize)
+        .enumerate()
+    {
+        let ptr = *array.buffers.add(index) as *const u8;
+        buffers.push(if !ptr.is_null() {
+            Buffer::from_ffi(ptr, len * width, owner.clone())
+        } else if len == 0 {
+            Buffer::empty()
+        } else {
+            return Err(ArrowError::CDataInterface(format!(
+                "The buffer {} of the array is null",
+                index
+            )));
+        });
+    }
+    let child_data = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| import_array(array.child(index)?, field.data_type(), owner))
+        .collect::<Result<_>>()?;
+
+    Ok(Arc::new(ArrayData::new(
+        data_type.clone(),
+        array.len(),
+        Some(0),
+        None,
+        array.offset(),
+        buffers,
+        child_data,
+    )))
+}
+
+/// An array exchanged through the C Data Interface, made of an `FFI_ArrowArray` and the
+/// `FFI_ArrowSchema` describing its type. Both are released when this struct is dropped,
+/// unless they have been moved out with `into_raw`.
+#[derive(Debug)]
+pub struct ArrowArray {
+    array: FFI_ArrowArray,
+    schema: FFI_ArrowSchema,
+}
+
+impl ArrowArray {
+    /// Exports `data` without copying its buffers
+    pub fn try_new(data: &ArrayData) -> Result<Self> {
+        Ok(Self {
+            array: FFI_ArrowArray::try_new(data)?,
+            schema: export_schema(data.data_type(), Some(data), "", true, false, None)?,
+        })
+    }
+
+    /// Takes ownership of the structs pointed to by `array` and `schema`, which are
+    /// marked as released afterwards, as mandated by the C Data Interface for moves.
+    ///
+    /// # Safety
+    ///
+    /// Both pointers must point to valid structs, e.g. ones filled by a producer.
+    pub unsafe fn try_from_raw(
+        array: *mut FFI_ArrowArray,
+        schema: *mut FFI_ArrowSchema,
+    ) -> Result<Self> {
+        if array.is_null() || schema.is_null() {
+            return Err(ArrowError::CDataInterface(
+                "The pointers to the array and its schema must not be null".to_string(),
+            ));
+        }
+        if (*array).is_released() || (*schema).is_released() {
+            return Err(ArrowError::CDataInterface(
+                "The array and its schema must not be released".to_string(),
+            ));
+        }
+        let imported = Self {
+            array: ptr::read(array),
+            schema: ptr::read(schema),
+        };
+        (*array).release = None;
+        (*schema).release = None;
+        Ok(imported)
+    }
+
+    /// Moves this array and its schema into the structs pointed to by `array` and
+    /// `schema`, handing their ownership to the consumer.
+    ///
+    /// # Safety
+    ///
+    /// Both pointers must be valid for writes. Their previous contents are overwritten
+    /// without being released.
+    pub unsafe fn into_raw(
+        self,
+        array: *mut FFI_ArrowArray,
+        schema: *mut FFI_ArrowSchema,
+    ) {
+        let ArrowArray {
+            array: c_array,
+            schema: c_schema,
+        } = self;
+        ptr::write(array, c_array);
+        ptr::write(schema, c_schema);
+    }
+
+    /// Returns the C struct of the array
+    pub fn array(&self) -> &FFI_ArrowArray {
+        &self.array
+    }
+
+    /// Returns the C struct of the array's schema
+    pub fn schema(&self) -> &FFI_ArrowSchema {
+        &self.schema
+    }
+
+    /// Returns the data type of the array
+    pub fn data_type(&self) -> Result<DataType> {
+        DataType::try_from(&self.schema)
+    }
+
+    /// Imports the array as `ArrayData` without copying it. Its buffers keep the
+    /// `FFI_ArrowArray` alive, which is released once the last of them is dropped.
+    pub fn into_data(self) -> Result<ArrayDataRef> {
+        let data_type = self.data_type()?;
+        import_array_data(self.array, &data_type)
+    }
+}
+
+/// Imports `array`, whose type is known to be `data_type`, as `ArrayData` without copying
+/// it. Its buffers keep `array` alive until the last of them is dropped.
+pub(crate) fn import_array_data(
+    array: FFI_ArrowArray,
+    data_type: &DataType,
+) -> Result<ArrayDataRef> {
+    let owner = Arc::new(array);
+    unsafe { import_array(&owner, data_type, &owner) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{
+        make_array, make_array_from_raw, Array, ArrayRef, BinaryArray, BooleanArray,
+        DecimalBuilder, DictionaryArray, FixedSizeBinaryArray, FixedSizeBinaryBuilder,
+        FixedSizeListBuilder, Int32Array, Int32Builder, LargeStringArray, ListBuilder,
+        MapBuilder, NullArray, StringArray, StringBuilder, StructArray,
+        TimestampMillisecondArray, UnionArray, UnionBuilder,
+    };
+    use crate::datatypes::{Float64Type, Int32Type, Int8Type};
+    use std::iter::FromIterator;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Exports `array` into empty structs and imports it back
+    fn round_trip(array: &Array) -> Result<ArrayRef> {
+        let mut c_array = FFI_ArrowArray::empty();
+        let mut c_schema = FFI_ArrowSchema::empty();
+        unsafe {
+            array.to_raw(&mut c_array, &mut c_schema)?;
+            let imported = make_array_from_raw(&mut c_array, &mut c_schema)?;
+            // the structs were moved out
+            assert!(c_array.is_released());
+            assert!(c_schema.is_released());
+            Ok(imported)
+        }
+    }
+
+    fn check_round_trip(array: &Array) -> Result<()> {
+        let imported = round_trip(array)?;
+        assert_eq!(array.data_type(), imported.data_type());
+        assert!(imported.equals(array));
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_primitive() -> Result<()> {
+        let array = Int32Array::from(vec![Some(2), None, Some(1), None]);
+        check_round_trip(&array)?;
+        // sliced arrays are exported with their offset
+        check_round_trip(array.slice(1, 2).as_ref())
+    }
+
+    #[test]
+    fn test_round_trip_boolean() -> Result<()> {
+        let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        check_round_trip(&array)
+    }
+
+    #[test]
+    fn test_round_trip_null() -> Result<()> {
+        check_round_trip(&NullArray::new(5))
+    }
+
+    #[test]
+    fn test_round_trip_string() -> Result<()> {
+        let array = StringArray::from(vec![Some("a"), None, Some("aaa")]);
+        check_round_trip(&array)?;
+        check_round_trip(array.slice(1, 2).as_ref())?;
+        let array = LargeStringArray::from(vec![Some("a"), None, Some("aaa")]);
+        check_round_trip(&array)
+    }
+
+    #[test]
+    fn test_round_trip_binary() -> Result<()> {
+        let array = BinaryArray::from(vec![&b"a"[..], b"", b"bb"]);
+        check_round_trip(&array)?;
+        let mut builder = FixedSizeBinaryBuilder::new(3, 2);
+        builder.append_value(&[1, 2])?;
+        builder.append_null()?;
+        builder.append_value(&[5, 6])?;
+        let array = builder.finish();
+        let imported = round_trip(&array)?;
+        let imported = imported
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(imported.data_type(), &DataType::FixedSizeBinary(2));
+        assert_eq!(imported.value(0), &[1, 2]);
+        assert!(imported.is_null(1));
+        assert_eq!(imported.value(2), &[5, 6]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_decimal() -> Result<()> {
+        let mut builder = DecimalBuilder::new(3, 10, 2);
+        builder.append_value(12345)?;
+        builder.append_null()?;
+        builder.append_value(-678)?;
+        check_round_trip(&builder.finish())
+    }
+
+    #[test]
+    fn test_round_trip_timestamp() -> Result<()> {
+        let array = TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1), None, Some(3)],
+            Some(Arc::new("UTC".to_string())),
+        );
+        check_round_trip(&array)
+    }
+
+    #[test]
+    fn test_round_trip_list() -> Result<()> {
+        let mut builder = ListBuilder::new(Int32Builder::new(4));
+        builder.values().append_value(1)?;
+        builder.append(true)?;
+        builder.append(false)?;
+        builder.values().append_value(2)?;
+        builder.values().append_null()?;
+        builder.append(true)?;
+        check_round_trip(&builder.finish())?;
+
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(4), 2);
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.values().append_slice(&[0, 0])?;
+        builder.append(false)?;
+        check_round_trip(&builder.finish())
+    }
+
+    #[test]
+    fn test_round_trip_struct() -> Result<()> {
+        let array = StructArray::from(vec![
+            (
+                Field::new("a", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec![Some("x"), None])) as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Int32, false),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            ),
+        ]);
+        check_round_trip(&array)
+    }
+
+    #[test]
+    fn test_round_trip_map() -> Result<()> {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(4), Int32Builder::new(4));
+        builder.keys().append_value("a")?;
+        builder.values().append_value(1)?;
+        builder.keys().append_value("b")?;
+        builder.values().append_null()?;
+        builder.append(true)?;
+        builder.append(false)?;
+        check_round_trip(&builder.finish())
+    }
+
+    #[test]
+    fn test_round_trip_dictionary() -> Result<()> {
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let imported = round_trip(&array)?;
+        assert_eq!(array.data_type(), imported.data_type());
+        let imported = imported
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(
+            imported.keys().collect::<Vec<_>>(),
+            vec![Some(0), None, Some(1), Some(0)]
+        );
+        assert!(imported.values().equals(array.values().as_ref()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_union() -> Result<()> {
+        for dense in &[true, false] {
+            let mut builder = if *dense {
+                UnionBuilder::new_dense(3)
+            } else {
+                UnionBuilder::new_sparse(3)
+            };
+            builder.append::<Int32Type>("a", 1)?;
+            builder.append::<Float64Type>("b", 3.0)?;
+            builder.append::<Int32Type>("a", 4)?;
+            let array = builder.build()?;
+
+            let exported = ArrowArray::try_new(&array.data())?;
+            let format = if *dense { "+ud:0,1" } else { "+us:0,1" };
+            assert_eq!(exported.schema().format()?, format);
+            assert_eq!(exported.array().n_buffers, if *dense { 2 } else { 1 });
+
+            let imported = make_array(exported.into_data()?);
+            assert_eq!(array.data_type(), imported.data_type());
+            let imported = imported.as_any().downcast_ref::<UnionArray>().unwrap();
+            for i in 0..array.len() {
+                assert_eq!(array.type_id(i), imported.type_id(i));
+                assert_eq!(array.value_offset(i), imported.value_offset(i));
+            }
+            for type_id in 0..2 {
+                assert!(imported.child(type_id).equals(&*array.child(type_id)));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_with_nulls_not_supported() -> Result<()> {
+        let mut builder = UnionBuilder::new_sparse(2);
+        builder.append::<Int32Type>("a", 1)?;
+        builder.append_null()?;
+        let array = builder.build()?;
+        assert!(ArrowArray::try_new(&array.data()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_type_ids_not_supported() {
+        let child = FFI_ArrowSchema::try_from(&DataType::Int32).unwrap();
+        let c_schema =
+            FFI_ArrowSchema::try_new("+us:5", "", None, 0, vec![child], None).unwrap();
+        assert!(DataType::try_from(&c_schema).is_err());
+    }
+
+    #[test]
+    fn test_schema_round_trip() -> Result<()> {
+        let mut field = Field::new("with_metadata", DataType::Utf8, false);
+        field.set_metadata(Some(BTreeMap::from_iter(vec![(
+            "k".to_string(),
+            "v".to_string(),
+        )])));
+        let schema = Schema::new_with_metadata(
+            vec![
+                field,
+                Field::new(
+                    "ts",
+                    DataType::Timestamp(
+                        TimeUnit::Nanosecond,
+                        Some(Arc::new("Europe/Paris".to_string())),
+                    ),
+                    true,
+                ),
+                Field::new("decimal", DataType::Decimal(10, 2), true),
+                Field::new(
+                    "fixed_list",
+                    DataType::FixedSizeList(Box::new(DataType::Int16), 3),
+                    true,
+                ),
+                Field::new_dict(
+                    "dict",
+                    DataType::Dictionary(
+                        Box::new(DataType::UInt16),
+                        Box::new(DataType::LargeUtf8),
+                    ),
+                    true,
+                    0,
+                    true,
+                ),
+                Field::new(
+                    "union",
+                    DataType::Union(vec![
+                        Field::new("a", DataType::Int32, true),
+                        Field::new("b", DataType::Utf8, true),
+                    ]),
+                    true,
+                ),
+                Field::new(
+                    "map",
+                    DataType::Map(
+                        Box::new(Field::new(
+                            "entries",
+                            DataType::Struct(vec![
+                                Field::new("key", DataType::Utf8, false),
+                                Field::new("value", DataType::Float64, true),
+                            ]),
+                            false,
+                        )),
+                        true,
+                    ),
+                    false,
+                ),
+            ],
+            HashMap::from_iter(vec![("key".to_string(), "value".to_string())]),
+        );
+
+        let c_schema = FFI_ArrowSchema::try_from(&schema)?;
+        assert_eq!(c_schema.format()?, "+s");
+        assert_eq!(c_schema.child(1)?.format()?, "tsn:Europe/Paris");
+        assert_eq!(c_schema.child(4)?.format()?, "S");
+        assert_eq!(
+            c_schema.child(4)?.flags(),
+            ARROW_FLAG_NULLABLE | ARROW_FLAG_DICTIONARY_ORDERED
+        );
+        assert_eq!(c_schema.child(5)?.format()?, "+us:0,1");
+        assert_eq!(c_schema.child(6)?.flags(), ARROW_FLAG_MAP_KEYS_SORTED);
+        assert_eq!(Schema::try_from(&c_schema)?, schema);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        let c_schema =
+            FFI_ArrowSchema::try_new("w:abc", "", None, 0, vec![], None).unwrap();
+        assert!(DataType::try_from(&c_schema).is_err());
+        let c_schema = FFI_ArrowSchema::try_new("?", "", None, 0, vec![], None).unwrap();
+        assert!(DataType::try_from(&c_schema).is_err());
+    }
+
+    static RELEASED: AtomicBool = AtomicBool::new(false);
+
+    unsafe extern "C" fn release_and_flag(array: *mut FFI_ArrowArray) {
+        release_array(array);
+        RELEASED.store(true, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_release_on_last_drop() -> Result<()> {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let mut exported = ArrowArray::try_new(&array.data())?;
+        exported.array.release = Some(release_and_flag);
+
+        let data = exported.into_data()?;
+        let imported = make_array(data.clone());
+        let sliced = imported.slice(1, 1);
+        drop(data);
+        drop(imported);
+        assert!(!RELEASED.load(Ordering::SeqCst));
+        assert_eq!(
+            sliced
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+                .value(0),
+            2
+        );
+        drop(sliced);
+        assert!(RELEASED.load(Ordering::SeqCst));
+        Ok(())
+    }
+}
@@ -0,0 +1,548 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+//!
+//! The C Stream Interface builds on the [C Data Interface](crate::ffi) to pass a stream of
+//! record batches sharing the same schema through an `FFI_ArrowArrayStream`. Each batch is
+//! exchanged as a struct array whose children are the batch's columns.
+//!
+//! ```rust
+//! # use std::sync::Arc;
+//! # use arrow::array::Int32Array;
+//! # use arrow::datatypes::{DataType, Field, Schema};
+//! # use arrow::error::Result;
+//! # use arrow::ffi_stream::{export_reader_into_raw, ArrowArrayStreamReader, FFI_ArrowArrayStream};
+//! # use arrow::ipc::reader::StreamReader;
+//! # use arrow::ipc::writer::StreamWriter;
+//! # use arrow::record_batch::{RecordBatch, RecordBatchReader};
+//! # fn main() -> Result<()> {
+//! # let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+//! # let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])?;
+//! # let mut bytes = vec![];
+//! # {
+//! #     let mut writer = StreamWriter::try_new(&mut bytes, &schema)?;
+//! #     writer.write(&batch)?;
+//! #     writer.finish()?;
+//! # }
+//! // any reader can be exported, e.g. one reading an IPC stream
+//! let reader = StreamReader::try_new(std::io::Cursor::new(bytes))?;
+//!
+//! // the struct is usually allocated by the consumer
+//! let mut stream = FFI_ArrowArrayStream::empty();
+//! unsafe { export_reader_into_raw(Box::new(reader), &mut stream) };
+//!
+//! // import it back, moving it into a new reader
+//! let reader = unsafe { ArrowArrayStreamReader::from_raw(&mut stream)? };
+//! assert_eq!(reader.schema(), schema);
+//! let batches = reader.collect::<Result<Vec<_>>>()?;
+//! assert_eq!(batches.len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+use crate::array::{Array, StructArray};
+use crate::datatypes::{DataType, Schema, SchemaRef};
+use crate::error::{ArrowError, Result};
+use crate::ffi::{import_array_data, FFI_ArrowArray, FFI_ArrowSchema};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+const EIO: c_int = 5;
+const ENOMEM: c_int = 12;
+const EINVAL: c_int = 22;
+const ENOSYS: c_int = 38;
+
+/// ABI-compatible struct for `ArrowArrayStream` from the C Stream Interface.
+/// See <https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definition>
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowArrayStream {
+    get_schema: Option<
+        unsafe extern "C" fn(
+            stream: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowSchema,
+        ) -> c_int,
+    >,
+    get_next: Option<
+        unsafe extern "C" fn(
+            stream: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowArray,
+        ) -> c_int,
+    >,
+    get_last_error:
+        Option<unsafe extern "C" fn(stream: *mut FFI_ArrowArrayStream) -> *const c_char>,
+    release: Option<unsafe extern "C" fn(stream: *mut FFI_ArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+/// The state of an exported `FFI_ArrowArrayStream`
+struct StreamPrivateData {
+    reader: Box<RecordBatchReader + Send>,
+    last_error: Option<CString>,
+}
+
+impl StreamPrivateData {
+    /// Records `error` as the last error of the stream and returns its error code
+    fn set_error(&mut self, error: ArrowError) -> c_int {
+        let code = match error {
+            ArrowError::IoError(_) => EIO,
+            ArrowError::MemoryError(_) => ENOMEM,
+            ArrowError::CDataInterface(_) => ENOSYS,
+            _ => EINVAL,
+        };
+        self.set_last_error(error.to_string());
+        code
+    }
+
+    /// Records a panic of the reader as the last error of the stream and returns
+    /// `EINVAL`
+    fn set_panic(&mut self, payload: Box<dyn Any + Send>) -> c_int {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        self.set_last_error(format!("The record batch reader panicked: {}", message));
+        EINVAL
+    }
+
+    fn set_last_error(&mut self, message: String) {
+        // the message cannot contain a nul byte, as it would end it early
+        self.last_error = Some(
+            CString::new(message.replace('\0', " ")).expect("the nul bytes were removed"),
+        );
+    }
+}
+
+unsafe fn private_data<'a>(
+    stream: *mut FFI_ArrowArrayStream,
+) -> &'a mut StreamPrivateData {
+    &mut *((*stream).private_data as *mut StreamPrivateData)
+}
+
+/// Calls `f` with the private data of `stream`, returning `EINVAL` if it panics, as
+/// unwinding into the consumer of the stream is undefined behavior
+unsafe fn catch_panic<F>(stream: *mut FFI_ArrowArrayStream, f: F) -> c_int
+where
+    F: FnOnce(&mut StreamPrivateData) -> c_int,
+{
+    match panic::catch_unwind(AssertUnwindSafe(|| f(private_data(stream)))) {
+        Ok(code) => code,
+        Err(payload) => private_data(stream).set_panic(payload),
+    }
+}
+
+/// The `get_schema` callback of the streams exported by this crate
+unsafe extern "C" fn get_schema(
+    stream: *mut FFI_ArrowArrayStream,
+    out: *mut FFI_ArrowSchema,
+) -> c_int {
+    catch_panic(stream, |private_data| {
+        let schema = private_data.reader.schema();
+        match FFI_ArrowSchema::try_from(schema.as_ref()) {
+            Ok(schema) => {
+                ptr::write(out, schema);
+                0
+            }
+            Err(error) => private_data.set_error(error),
+        }
+    })
+}
+
+/// The `get_next` callback of the streams exported by this crate
+unsafe extern "C" fn get_next(
+    stream: *mut FFI_ArrowArrayStream,
+    out: *mut FFI_ArrowArray,
+) -> c_int {
+    catch_panic(stream, |private_data| {
+        let array = match private_data.reader.next() {
+            // the end of the stream is signaled by a released array
+            None => Ok(FFI_ArrowArray::empty()),
+            Some(Ok(batch)) => {
+                let struct_array: StructArray = batch.into();
+                FFI_ArrowArray::try_new(struct_array.data_ref())
+            }
+            Some(Err(error)) => Err(error),
+        };
+        match array {
+            Ok(array) => {
+                ptr::write(out, array);
+                0
+            }
+            Err(error) => private_data.set_error(error),
+        }
+    })
+}
+
+/// The `get_last_error` callback of the streams exported by this crate
+unsafe extern "C" fn get_last_error(stream: *mut FFI_ArrowArrayStream) -> *const c_char {
+    match &private_data(stream).last_error {
+        Some(error) => error.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// The `release` callback of the streams exported by this crate
+unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+    drop(Box::from_raw(stream.private_data as *mut StreamPrivateData));
+    stream.release = None;
+}
+
+impl FFI_ArrowArrayStream {
+    /// Exports `reader` as a stream, which owns it until it is released
+    pub fn new(reader: Box<RecordBatchReader + Send>) -> Self {
+        let private_data = Box::new(StreamPrivateData {
+            reader,
+            last_error: None,
+        });
+        Self {
+            get_schema: Some(get_schema),
+            get_next: Some(get_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release_stream),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        }
+    }
+
+    /// Creates an empty, released `FFI_ArrowArrayStream`, to be filled by a producer
+    pub fn empty() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+
+    /// Returns whether this stream has been released
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+}
+
+impl Drop for FFI_ArrowArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) };
+        }
+    }
+}
+
+// A stream may be consumed from another thread than the one producing it, as long as
+// it is not used concurrently.
+unsafe impl Send for FFI_ArrowArrayStream {}
+
+/// Exports `reader` into the struct pointed to by `out_stream`, which owns the reader
+/// until the consumer releases it.
+///
+/// # Safety
+///
+/// `out_stream` must be valid for writes. Its previous contents are overwritten without
+/// being released.
+pub unsafe fn export_reader_into_raw(
+    reader: Box<RecordBatchReader + Send>,
+    out_stream: *mut FFI_ArrowArrayStream,
+) {
+    ptr::write(out_stream, FFI_ArrowArrayStream::new(reader))
+}
+
+/// A `RecordBatchReader` over a stream exchanged through the C Stream Interface. The
+/// stream is released when the reader is dropped.
+#[derive(Debug)]
+pub struct ArrowArrayStreamReader {
+    stream: FFI_ArrowArrayStream,
+    schema: SchemaRef,
+}
+
+impl ArrowArrayStreamReader {
+    /// Creates a reader over `stream`, reading its schema eagerly
+    pub fn try_new(mut stream: FFI_ArrowArrayStream) -> Result<Self> {
+        if stream.is_released() {
+            return Err(ArrowError::CDataInterface(
+                "The stream must not be released".to_string(),
+            ));
+        }
+        let get_schema = stream.get_schema.ok_or_else(|| {
+            ArrowError::CDataInterface(
+                "The stream has no get_schema callback".to_string(),
+            )
+        })?;
+
+        let mut c_schema = FFI_ArrowSchema::empty();
+        let code = unsafe { get_schema(&mut stream, &mut c_schema) };
+        if code != 0 {
+            return Err(stream_error(&mut stream, code));
+        }
+        let schema = Schema::try_from(&c_schema)?;
+        Ok(Self {
+            stream,
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Takes ownership of the stream pointed to by `stream`, which is marked as released
+    /// afterwards, as mandated by the C Stream Interface for moves.
+    ///
+    /// # Safety
+    ///
+    /// `stream` must point to a valid struct, e.g. one filled by a producer.
+    pub unsafe fn from_raw(stream: *mut FFI_ArrowArrayStream) -> Result<Self> {
+        if stream.is_null() {
+            return Err(ArrowError::CDataInterface(
+                "The pointer to the stream must not be null".to_string(),
+            ));
+        }
+        if (*stream).is_released() {
+            return Err(ArrowError::CDataInterface(
+                "The stream must not be released".to_string(),
+            ));
+        }
+        let imported = ptr::read(stream);
+        (*stream).release = None;
+        Self::try_new(imported)
+    }
+
+    fn read_next(&mut self) -> Result<Option<RecordBatch>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::CDataInterface("The stream has no get_next callback".to_string())
+        })?;
+
+        let mut c_array = FFI_ArrowArray::empty();
+        let code = unsafe { get_next(&mut self.stream, &mut c_array) };
+        if code != 0 {
+            return Err(stream_error(&mut self.stream, code));
+        }
+        if c_array.is_released() {
+            return Ok(None);
+        }
+
+        let data_type = DataType::Struct(self.schema.fields().clone());
+        let struct_array = StructArray::from(import_array_data(c_array, &data_type)?);
+        let columns = RecordBatch::from(&struct_array).columns().to_vec();
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+}
+
+/// Returns the error of a failed call to the stream, described by `get_last_error`
+fn stream_error(stream: &mut FFI_ArrowArrayStream, code: c_int) -> ArrowError {
+    let message = stream.get_last_error.and_then(|get_last_error| unsafe {
+        let message = get_last_error(stream);
+        if message.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(message).to_string_lossy().into_owned())
+        }
+    });
+    ArrowError::CDataInterface(match message {
+        Some(message) => message,
+        None => format!("The stream failed with error code {}", code),
+    })
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+impl RecordBatchReader for ArrowArrayStreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{ArrayRef, Int32Array, StringArray};
+    use crate::datatypes::Field;
+    use std::collections::HashMap;
+    use std::iter::FromIterator;
+
+    /// A reader producing the given results, with the given schema
+    struct TestReader {
+        schema: SchemaRef,
+        results: std::vec::IntoIter<Result<RecordBatch>>,
+    }
+
+    impl Iterator for TestReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.results.next()
+        }
+    }
+
+    impl RecordBatchReader for TestReader {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+    }
+
+    fn round_trip(reader: TestReader) -> Result<ArrowArrayStreamReader> {
+        let mut stream = FFI_ArrowArrayStream::empty();
+        unsafe {
+            export_reader_into_raw(Box::new(reader), &mut stream);
+            let imported = ArrowArrayStreamReader::from_raw(&mut stream)?;
+            assert!(stream.is_released());
+            Ok(imported)
+        }
+    }
+
+    fn test_batches() -> Result<(SchemaRef, Vec<RecordBatch>)> {
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, false),
+            ],
+            HashMap::from_iter(vec![("key".to_string(), "value".to_string())]),
+        ));
+        let batches = (0..3)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int32Array::from(vec![Some(i), None])) as ArrayRef,
+                        Arc::new(StringArray::from(vec!["x", "yy", "zzz"]))
+                            .slice(i as usize % 2, 2),
+                    ],
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((schema, batches))
+    }
+
+    #[test]
+    fn test_stream_round_trip() -> Result<()> {
+        let (schema, batches) = test_batches()?;
+        let reader = TestReader {
+            schema: schema.clone(),
+            results: batches
+                .iter()
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        };
+
+        let imported = round_trip(reader)?;
+        assert_eq!(imported.schema(), schema);
+        let imported = imported.collect::<Result<Vec<_>>>()?;
+        assert_eq!(imported.len(), batches.len());
+        for (imported, batch) in imported.iter().zip(batches.iter()) {
+            assert_eq!(imported.schema(), batch.schema());
+            for (imported, column) in imported.columns().iter().zip(batch.columns()) {
+                assert!(imported.equals(column.as_ref()));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_error() -> Result<()> {
+        let (schema, batches) = test_batches()?;
+        let reader = TestReader {
+            schema,
+            results: vec![
+                Ok(batches[0].clone()),
+                Err(ArrowError::IoError("unexpected end of file".to_string())),
+            ]
+            .into_iter(),
+        };
+
+        let mut imported = round_trip(reader)?;
+        assert!(imported.next().unwrap().is_ok());
+        match imported.next() {
+            Some(Err(ArrowError::CDataInterface(message))) => {
+                assert_eq!(message, "Io error: unexpected end of file")
+            }
+            other => panic!("expected an error, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    /// A reader panicking when its schema or next batch is requested
+    struct PanickingReader {
+        schema: Option<SchemaRef>,
+    }
+
+    impl Iterator for PanickingReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            panic!("no more batches")
+        }
+    }
+
+    impl RecordBatchReader for PanickingReader {
+        fn schema(&self) -> SchemaRef {
+            match &self.schema {
+                Some(schema) => schema.clone(),
+                None => panic!("no schema"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_panic() -> Result<()> {
+        let mut stream =
+            FFI_ArrowArrayStream::new(Box::new(PanickingReader { schema: None }));
+        let mut out = FFI_ArrowSchema::empty();
+        let code = unsafe { (stream.get_schema.unwrap())(&mut stream, &mut out) };
+        assert_eq!(EINVAL, code);
+        match stream_error(&mut stream, code) {
+            ArrowError::CDataInterface(message) => {
+                assert_eq!(message, "The record batch reader panicked: no schema")
+            }
+            other => panic!("expected an error, got {:?}", other),
+        }
+
+        let (schema, _) = test_batches()?;
+        let mut stream = FFI_ArrowArrayStream::new(Box::new(PanickingReader {
+            schema: Some(schema),
+        }));
+        let mut imported = unsafe { ArrowArrayStreamReader::from_raw(&mut stream)? };
+        match imported.next() {
+            Some(Err(ArrowError::CDataInterface(message))) => {
+                assert_eq!(message, "The record batch reader panicked: no more batches")
+            }
+            other => panic!("expected an error, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_released_stream() {
+        let mut stream = FFI_ArrowArrayStream::empty();
+        assert!(unsafe { ArrowArrayStreamReader::from_raw(&mut stream) }.is_err());
+    }
+}
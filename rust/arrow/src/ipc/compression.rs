@@ -0,0 +1,268 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compression of the buffers in the body of IPC record batches.
+//!
+//! Each non-empty buffer is compressed on its own, and prefixed with its uncompressed
+//! length as a little-endian `i64`. A length of `-1` marks a buffer that is stored
+//! uncompressed, for instance because compressing it would not make it smaller.
+//!
+//! The codecs require the `ipc_compression` feature.
+
+use std::convert::TryInto;
+
+use crate::error::{ArrowError, Result};
+use crate::ipc::CompressionType;
+
+/// The uncompressed length of a buffer that is stored uncompressed
+const UNCOMPRESSED_LENGTH: i64 = -1;
+
+/// Returns whether buffers can be compressed with the codecs compiled in
+pub(crate) fn is_supported() -> bool {
+    cfg!(feature = "ipc_compression")
+}
+
+/// Compress a buffer, prefixed with its uncompressed length.
+///
+/// The buffer is stored uncompressed if the codec fails, or if compressing it does
+/// not save space. Empty buffers remain empty.
+pub(crate) fn compress_buffer(codec: CompressionType, input: &[u8]) -> Vec<u8> {
+    if input.is_empty() {
+        return vec![];
+    }
+    let mut output = Vec::with_capacity(input.len() + 8);
+    output.extend_from_slice(&(input.len() as i64).to_le_bytes());
+    match compress(codec, input, &mut output) {
+        Ok(()) if output.len() < input.len() + 8 => output,
+        _ => {
+            output.clear();
+            output.extend_from_slice(&UNCOMPRESSED_LENGTH.to_le_bytes());
+            output.extend_from_slice(input);
+            output
+        }
+    }
+}
+
+/// Decompress a buffer written by `compress_buffer`
+pub(crate) fn decompress_buffer(codec: CompressionType, input: &[u8]) -> Result<Vec<u8>> {
+    if input.is_empty() {
+        return Ok(vec![]);
+    }
+    if input.len() < 8 {
+        return Err(ArrowError::IoError(format!(
+            "Compressed buffer of {} bytes is too short for its uncompressed length",
+            input.len()
+        )));
+    }
+    let length = i64::from_le_bytes(input[..8].try_into().unwrap());
+    let data = &input[8..];
+    if length == UNCOMPRESSED_LENGTH {
+        return Ok(data.to_vec());
+    }
+    if length < 0 {
+        return Err(ArrowError::IoError(format!(
+            "Invalid uncompressed buffer length {}",
+            length
+        )));
+    }
+    // the length is not trusted to reserve memory, as it may be arbitrarily large,
+    // and at most one byte more than it is decompressed to detect longer data
+    let mut output = vec![];
+    decompress(codec, data, length as u64 + 1, &mut output)?;
+    if output.len() as u64 > length as u64 {
+        return Err(ArrowError::IoError(format!(
+            "Decompressed buffer exceeds its length of {} bytes",
+            length
+        )));
+    }
+    if output.len() as u64 != length as u64 {
+        return Err(ArrowError::IoError(format!(
+            "Decompressed buffer has {} bytes, expected {}",
+            output.len(),
+            length
+        )));
+    }
+    Ok(output)
+}
+
+#[cfg(feature = "ipc_compression")]
+fn compress(codec: CompressionType, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+    use std::io::Write;
+
+    match codec {
+        CompressionType::LZ4_FRAME => {
+            let mut encoder = lz4::EncoderBuilder::new().build(output)?;
+            encoder.write_all(input)?;
+            let (_, result) = encoder.finish();
+            Ok(result?)
+        }
+        CompressionType::ZSTD => {
+            let mut encoder = zstd::Encoder::new(output, 0)?;
+            encoder.write_all(input)?;
+            encoder.finish()?;
+            Ok(())
+        }
+    }
+}
+
+/// Decompresses at most `limit` bytes of `input` into `output`
+#[cfg(feature = "ipc_compression")]
+fn decompress(
+    codec: CompressionType,
+    input: &[u8],
+    limit: u64,
+    output: &mut Vec<u8>,
+) -> Result<()> {
+    use std::io::Read;
+
+    match codec {
+        CompressionType::LZ4_FRAME => {
+            lz4::Decoder::new(input)?.take(limit).read_to_end(output)?;
+        }
+        CompressionType::ZSTD => {
+            zstd::Decoder::new(input)?.take(limit).read_to_end(output)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "ipc_compression"))]
+fn compress(codec: CompressionType, _input: &[u8], _output: &mut Vec<u8>) -> Result<()> {
+    Err(unsupported(codec))
+}
+
+#[cfg(not(feature = "ipc_compression"))]
+fn decompress(
+    codec: CompressionType,
+    _input: &[u8],
+    _limit: u64,
+    _output: &mut Vec<u8>,
+) -> Result<()> {
+    Err(unsupported(codec))
+}
+
+/// The error returned when compressing without the `ipc_compression` feature
+pub(crate) fn unsupported(codec: CompressionType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "{} compression requires the `ipc_compression` feature",
+        crate::ipc::enum_name_compression_type(codec)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uncompressed_buffer() {
+        // buffers stored uncompressed are read without a codec
+        let mut input = (-1i64).to_le_bytes().to_vec();
+        input.extend_from_slice(b"arrow");
+        let output = decompress_buffer(CompressionType::ZSTD, &input).unwrap();
+        assert_eq!(b"arrow".to_vec(), output);
+        assert!(decompress_buffer(CompressionType::ZSTD, &[])
+            .unwrap()
+            .is_empty());
+        assert!(decompress_buffer(CompressionType::ZSTD, &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        // a length larger than the available memory is not reserved up front
+        for length in &[i64::MAX, 1 << 40] {
+            let mut input = length.to_le_bytes().to_vec();
+            input.extend_from_slice(&[1, 2, 3, 4]);
+            assert!(decompress_buffer(CompressionType::ZSTD, &input).is_err());
+            assert!(decompress_buffer(CompressionType::LZ4_FRAME, &input).is_err());
+        }
+        let mut input = (-2i64).to_le_bytes().to_vec();
+        input.extend_from_slice(&[1, 2, 3, 4]);
+        assert!(decompress_buffer(CompressionType::ZSTD, &input).is_err());
+    }
+
+    /// "arrow " repeated 20 times, compressed by the reference `lz4` and `zstd`
+    /// command line tools, rather than the crates used to write buffers
+    #[cfg(feature = "ipc_compression")]
+    const LZ4_FRAME_FIXTURE: &[u8] = &[
+        0x04, 0x22, 0x4d, 0x18, 0x64, 0x40, 0xa7, 0x10, 0x00, 0x00, 0x00, 0x6f, 0x61,
+        0x72, 0x72, 0x6f, 0x77, 0x20, 0x06, 0x00, 0x5a, 0x50, 0x72, 0x72, 0x6f, 0x77,
+        0x20, 0x00, 0x00, 0x00, 0x00, 0x64, 0x6a, 0x2f, 0xe4,
+    ];
+
+    #[cfg(feature = "ipc_compression")]
+    const ZSTD_FIXTURE: &[u8] = &[
+        0x28, 0xb5, 0x2f, 0xfd, 0x24, 0x78, 0x6d, 0x00, 0x00, 0x30, 0x61, 0x72, 0x72,
+        0x6f, 0x77, 0x20, 0x01, 0x00, 0x2f, 0x69, 0x29, 0x02, 0x00, 0x2b, 0xbd, 0x9d,
+    ];
+
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_decompress_fixtures() {
+        let expected = b"arrow ".repeat(20);
+        let fixtures = [
+            (CompressionType::LZ4_FRAME, LZ4_FRAME_FIXTURE),
+            (CompressionType::ZSTD, ZSTD_FIXTURE),
+        ];
+        for (codec, fixture) in fixtures.iter() {
+            let mut input = (expected.len() as i64).to_le_bytes().to_vec();
+            input.extend_from_slice(fixture);
+            assert_eq!(expected, decompress_buffer(*codec, &input).unwrap());
+
+            // the declared length must match the decompressed data
+            input[..8].copy_from_slice(&(expected.len() as i64 + 1).to_le_bytes());
+            assert!(decompress_buffer(*codec, &input).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_decompress_beyond_length() {
+        // a small frame that inflates to far more than its declared length
+        let input = vec![0u8; 64 * 1024 * 1024];
+        for codec in &[CompressionType::LZ4_FRAME, CompressionType::ZSTD] {
+            let mut compressed = compress_buffer(*codec, &input);
+            assert!(compressed.len() < input.len() / 100);
+            compressed[..8].copy_from_slice(&16i64.to_le_bytes());
+            let err = decompress_buffer(*codec, &compressed).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Decompressed buffer exceeds its length of 16 bytes"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_compress_buffer() {
+        let input: Vec<u8> = (0..1000).map(|i| (i % 7) as u8).collect();
+        for codec in &[CompressionType::LZ4_FRAME, CompressionType::ZSTD] {
+            let compressed = compress_buffer(*codec, &input);
+            assert!(compressed.len() < input.len());
+            assert_eq!(
+                1000,
+                i64::from_le_bytes(compressed[..8].try_into().unwrap())
+            );
+            assert_eq!(input, decompress_buffer(*codec, &compressed).unwrap());
+        }
+        // incompressible buffers are stored uncompressed
+        let compressed = compress_buffer(CompressionType::LZ4_FRAME, &[1, 2, 3]);
+        assert_eq!(-1, i64::from_le_bytes(compressed[..8].try_into().unwrap()));
+        assert_eq!(&[1, 2, 3], &compressed[8..]);
+    }
+}
@@ -23,7 +23,7 @@ use crate::ipc;
 use flatbuffers::{
     FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use DataType::*;
@@ -65,7 +65,7 @@ pub fn schema_to_fb(schema: &Schema) -> FlatBufferBuilder {
 }
 
 pub fn schema_to_fb_offset<'a: 'b, 'b>(
-    fbb: &'a mut FlatBufferBuilder,
+    fbb: &mut FlatBufferBuilder<'a>,
     schema: &Schema,
 ) -> WIPOffset<ipc::Schema<'b>> {
     let mut fields = vec![];
@@ -97,7 +97,7 @@ pub fn schema_to_fb_offset<'a: 'b, 'b>(
 /// Convert an IPC Field to Arrow Field
 impl<'a> From<ipc::Field<'a>> for Field {
     fn from(field: ipc::Field) -> Field {
-        if let Some(dictionary) = field.dictionary() {
+        let mut arrow_field = if let Some(dictionary) = field_dictionary(field) {
             Field::new_dict(
                 field.name().unwrap(),
                 get_data_type(field, true),
@@ -111,10 +111,54 @@ impl<'a> From<ipc::Field<'a>> for Field {
                 get_data_type(field, true),
                 field.nullable(),
             )
+        };
+
+        arrow_field.set_metadata(Some(fb_to_field_metadata(field)));
+        if let Some(child) = list_child(field) {
+            arrow_field.set_child_metadata(Some(fb_to_field_metadata(child)));
+        }
+        arrow_field
+    }
+}
+
+/// Returns the custom metadata of an IPC Field
+fn fb_to_field_metadata(field: ipc::Field) -> BTreeMap<String, String> {
+    let mut metadata = BTreeMap::default();
+    if let Some(md_fields) = field.custom_metadata() {
+        for i in 0..md_fields.len() {
+            let kv = md_fields.get(i);
+            if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
+                metadata.insert(k.to_string(), v.to_string());
+            }
+        }
+    }
+    metadata
+}
+
+/// Returns the IPC Field of the values of a list field
+fn list_child(field: ipc::Field) -> Option<ipc::Field> {
+    match field.type_type() {
+        ipc::Type::List | ipc::Type::LargeList | ipc::Type::FixedSizeList => {
+            let children = field.children()?;
+            if children.len() == 1 {
+                Some(children.get(0))
+            } else {
+                None
+            }
         }
+        _ => None,
     }
 }
 
+/// Returns the dictionary encoding of a field, or of the values of a list field. The
+/// values of a list have no Arrow Field, so their dictionary id is kept in the list's.
+fn field_dictionary(field: ipc::Field) -> Option<ipc::DictionaryEncoding> {
+    if let Some(dictionary) = field.dictionary() {
+        return Some(dictionary);
+    }
+    list_child(field).and_then(field_dictionary)
+}
+
 /// Deserialize a Schema table from IPC format to Schema data type
 pub fn fb_to_schema(fb: ipc::Schema) -> Schema {
     let mut fields: Vec<Field> = vec![];
@@ -148,6 +192,55 @@ pub fn schema_from_bytes(bytes: &[u8]) -> Option<Schema> {
     ipc.header_as_schema().map(fb_to_schema)
 }
 
+/// Get the Arrow data type of the values of a flatbuffer Tensor table.
+/// Tensors only hold values of boolean and numeric types.
+pub(crate) fn get_tensor_data_type(tensor: ipc::Tensor) -> Option<DataType> {
+    get_tensor_value_type(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )
+}
+
+/// Get the Arrow data type of the values of a flatbuffer SparseTensor table
+pub(crate) fn get_sparse_tensor_data_type(tensor: ipc::SparseTensor) -> Option<DataType> {
+    get_tensor_value_type(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )
+}
+
+fn get_tensor_value_type(
+    type_type: ipc::Type,
+    int: Option<ipc::Int>,
+    float: Option<ipc::FloatingPoint>,
+) -> Option<DataType> {
+    match type_type {
+        ipc::Type::Bool => Some(DataType::Boolean),
+        ipc::Type::Int => {
+            let int = int?;
+            match (int.bitWidth(), int.is_signed()) {
+                (8, true) => Some(DataType::Int8),
+                (8, false) => Some(DataType::UInt8),
+                (16, true) => Some(DataType::Int16),
+                (16, false) => Some(DataType::UInt16),
+                (32, true) => Some(DataType::Int32),
+                (32, false) => Some(DataType::UInt32),
+                (64, true) => Some(DataType::Int64),
+                (64, false) => Some(DataType::UInt64),
+                _ => None,
+            }
+        }
+        ipc::Type::FloatingPoint => Some(match float?.precision() {
+            ipc::Precision::HALF => DataType::Float16,
+            ipc::Precision::SINGLE => DataType::Float32,
+            ipc::Precision::DOUBLE => DataType::Float64,
+        }),
+        _ => None,
+    }
+}
+
 /// Get the Arrow data type from the flatbuffer Field table
 pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataType {
     if let Some(dictionary) = field.dictionary() {
@@ -196,6 +289,10 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
             let fsb = field.type_as_fixed_size_binary().unwrap();
             DataType::FixedSizeBinary(fsb.byteWidth())
         }
+        ipc::Type::Decimal => {
+            let fsb = field.type_as_decimal().unwrap();
+            DataType::Decimal(fsb.precision() as usize, fsb.scale() as usize)
+        }
         ipc::Type::FloatingPoint => {
             let float = field.type_as_floating_point().unwrap();
             match float.precision() {
@@ -270,7 +367,7 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
             }
             let child_field = children.get(0);
             // returning int16 for now, to test, not sure how to get data type
-            DataType::List(Box::new(get_data_type(child_field, false)))
+            DataType::List(Box::new(get_data_type(child_field, true)))
         }
         ipc::Type::LargeList => {
             let children = field.children().unwrap();
@@ -279,7 +376,7 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
             }
             let child_field = children.get(0);
             // returning int16 for now, to test, not sure how to get data type
-            DataType::LargeList(Box::new(get_data_type(child_field, false)))
+            DataType::LargeList(Box::new(get_data_type(child_field, true)))
         }
         ipc::Type::FixedSizeList => {
             let children = field.children().unwrap();
@@ -289,10 +386,18 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
             let child_field = children.get(0);
             let fsl = field.type_as_fixed_size_list().unwrap();
             DataType::FixedSizeList(
-                Box::new(get_data_type(child_field, false)),
+                Box::new(get_data_type(child_field, true)),
                 fsl.listSize(),
             )
         }
+        ipc::Type::Map => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a map to have one child")
+            }
+            let map = field.type_as_map().unwrap();
+            DataType::Map(Box::new(children.get(0).into()), map.keysSorted())
+        }
         ipc::Type::Struct_ => {
             let mut fields = vec![];
             if let Some(children) = field.children() {
@@ -318,8 +423,15 @@ pub(crate) fn build_field<'a: 'b, 'b>(
     fbb: &mut FlatBufferBuilder<'a>,
     field: &Field,
 ) -> WIPOffset<ipc::Field<'b>> {
+    let fb_metadata = build_field_metadata(fbb, field.metadata());
     let fb_field_name = fbb.create_string(field.name().as_str());
-    let field_type = get_fb_field_type(field.data_type(), fbb);
+    let field_type = get_fb_field_type_with_dictionary(
+        field.data_type(),
+        field.dict_id,
+        field.dict_is_ordered,
+        field.child_metadata(),
+        fbb,
+    );
 
     let fb_dictionary = if let Dictionary(index_type, _) = field.data_type() {
         Some(get_fb_dictionary(
@@ -344,13 +456,84 @@ pub(crate) fn build_field<'a: 'b, 'b>(
         Some(children) => field_builder.add_children(children),
     };
     field_builder.add_type_(field_type.type_);
+    if let Some(metadata) = fb_metadata {
+        field_builder.add_custom_metadata(metadata);
+    }
     field_builder.finish()
 }
 
+/// Create the IPC custom metadata of an Arrow Field, if it has any
+fn build_field_metadata<'a: 'b, 'b>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    metadata: &Option<BTreeMap<String, String>>,
+) -> Option<WIPOffset<Vector<'b, ForwardsUOffset<ipc::KeyValue<'b>>>>> {
+    let metadata = metadata.as_ref()?;
+    let mut custom_metadata = vec![];
+    for (k, v) in metadata {
+        let fb_key_name = fbb.create_string(k.as_str());
+        let fb_val_name = fbb.create_string(v.as_str());
+
+        let mut kv_builder = ipc::KeyValueBuilder::new(fbb);
+        kv_builder.add_key(fb_key_name);
+        kv_builder.add_value(fb_val_name);
+        custom_metadata.push(kv_builder.finish());
+    }
+    Some(fbb.create_vector(&custom_metadata))
+}
+
+/// Create the IPC Field of the values of a list. A dictionary in the values takes the
+/// dictionary id of the enclosing field, and the values take the child metadata of
+/// the enclosing field, as they have no Arrow Field of their own.
+fn build_list_child<'a: 'b, 'b>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    data_type: &DataType,
+    dict_id: i64,
+    dict_is_ordered: bool,
+    metadata: &Option<BTreeMap<String, String>>,
+) -> WIPOffset<ipc::Field<'b>> {
+    let custom_metadata = build_field_metadata(fbb, metadata);
+    let inner_types = get_fb_field_type_with_dictionary(
+        data_type,
+        dict_id,
+        dict_is_ordered,
+        &None,
+        fbb,
+    );
+    let dictionary = if let Dictionary(index_type, _) = data_type {
+        Some(get_fb_dictionary(index_type, dict_id, dict_is_ordered, fbb))
+    } else {
+        None
+    };
+    ipc::Field::create(
+        fbb,
+        &ipc::FieldArgs {
+            name: None,
+            nullable: false,
+            type_type: inner_types.type_type,
+            type_: Some(inner_types.type_),
+            dictionary,
+            children: inner_types.children,
+            custom_metadata,
+        },
+    )
+}
+
 /// Get the IPC type of a data type
 pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
     data_type: &DataType,
     fbb: &mut FlatBufferBuilder<'a>,
+) -> FBFieldType<'b> {
+    get_fb_field_type_with_dictionary(data_type, 0, false, &None, fbb)
+}
+
+/// Get the IPC type of a data type, with the dictionary id and ordering used by
+/// dictionaries in the values of lists, and the custom metadata of the values of lists
+fn get_fb_field_type_with_dictionary<'a: 'b, 'b>(
+    data_type: &DataType,
+    dict_id: i64,
+    dict_is_ordered: bool,
+    child_metadata: &Option<BTreeMap<String, String>>,
+    fbb: &mut FlatBufferBuilder<'a>,
 ) -> FBFieldType<'b> {
     // some IPC implementations expect an empty list for child data, instead of a null value.
     // An empty field list is thus returned for primitive types
@@ -444,6 +627,17 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
                 children: Some(fbb.create_vector(&empty_fields[..])),
             }
         }
+        Decimal(precision, scale) => {
+            let mut builder = ipc::DecimalBuilder::new(fbb);
+            builder.add_precision(*precision as i32);
+            builder.add_scale(*scale as i32);
+            builder.add_bitWidth(128);
+            FBFieldType {
+                type_type: ipc::Type::Decimal,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&empty_fields[..])),
+            }
+        }
         Date32(_) => {
             let mut builder = ipc::DateBuilder::new(fbb);
             builder.add_unit(ipc::DateUnit::DAY);
@@ -537,18 +731,12 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
             }
         }
         List(ref list_type) => {
-            let inner_types = get_fb_field_type(list_type, fbb);
-            let child = ipc::Field::create(
+            let child = build_list_child(
                 fbb,
-                &ipc::FieldArgs {
-                    name: None,
-                    nullable: false,
-                    type_type: inner_types.type_type,
-                    type_: Some(inner_types.type_),
-                    children: inner_types.children,
-                    dictionary: None,
-                    custom_metadata: None,
-                },
+                list_type,
+                dict_id,
+                dict_is_ordered,
+                child_metadata,
             );
             FBFieldType {
                 type_type: ipc::Type::List,
@@ -557,18 +745,12 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
             }
         }
         LargeList(ref list_type) => {
-            let inner_types = get_fb_field_type(list_type, fbb);
-            let child = ipc::Field::create(
+            let child = build_list_child(
                 fbb,
-                &ipc::FieldArgs {
-                    name: None,
-                    nullable: false,
-                    type_type: inner_types.type_type,
-                    type_: Some(inner_types.type_),
-                    dictionary: None,
-                    children: inner_types.children,
-                    custom_metadata: None,
-                },
+                list_type,
+                dict_id,
+                dict_is_ordered,
+                child_metadata,
             );
             FBFieldType {
                 type_type: ipc::Type::LargeList,
@@ -577,18 +759,12 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
             }
         }
         FixedSizeList(ref list_type, len) => {
-            let inner_types = get_fb_field_type(list_type, fbb);
-            let child = ipc::Field::create(
+            let child = build_list_child(
                 fbb,
-                &ipc::FieldArgs {
-                    name: None,
-                    nullable: false,
-                    type_type: inner_types.type_type,
-                    type_: Some(inner_types.type_),
-                    dictionary: None,
-                    children: inner_types.children,
-                    custom_metadata: None,
-                },
+                list_type,
+                dict_id,
+                dict_is_ordered,
+                child_metadata,
             );
             let mut builder = ipc::FixedSizeListBuilder::new(fbb);
             builder.add_listSize(*len as i32);
@@ -602,20 +778,7 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
             // struct's fields are children
             let mut children = vec![];
             for field in fields {
-                let inner_types = get_fb_field_type(field.data_type(), fbb);
-                let field_name = fbb.create_string(field.name());
-                children.push(ipc::Field::create(
-                    fbb,
-                    &ipc::FieldArgs {
-                        name: Some(field_name),
-                        nullable: field.is_nullable(),
-                        type_type: inner_types.type_type,
-                        type_: Some(inner_types.type_),
-                        dictionary: None,
-                        children: inner_types.children,
-                        custom_metadata: None,
-                    },
-                ));
+                children.push(build_field(fbb, field));
             }
             FBFieldType {
                 type_type: ipc::Type::Struct_,
@@ -623,11 +786,28 @@ pub(crate) fn get_fb_field_type<'a: 'b, 'b>(
                 children: Some(fbb.create_vector(&children[..])),
             }
         }
+        Map(entries, keys_sorted) => {
+            // the `entries` struct is the only child
+            let child = build_field(fbb, entries);
+            let mut builder = ipc::MapBuilder::new(fbb);
+            builder.add_keysSorted(*keys_sorted);
+            FBFieldType {
+                type_type: ipc::Type::Map,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
         Dictionary(_, value_type) => {
             // In this library, the dictionary "type" is a logical construct. Here we
             // pass through to the value type, as we've already captured the index
             // type in the DictionaryEncoding metadata in the parent field
-            get_fb_field_type(value_type, fbb)
+            get_fb_field_type_with_dictionary(
+                value_type,
+                dict_id,
+                dict_is_ordered,
+                child_metadata,
+                fbb,
+            )
         }
         t => unimplemented!("Type {:?} not supported", t),
     }
@@ -671,7 +851,11 @@ pub(crate) fn get_fb_dictionary<'a: 'b, 'b>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::datatypes::{DataType, Field, Schema};
+    use crate::datatypes::{
+        register_extension_type, unregister_extension_type, DataType, ExtensionType,
+        Field, Schema,
+    };
+    use crate::error::Result;
 
     #[test]
     fn convert_schema_round_trip() {
@@ -679,6 +863,33 @@ mod tests {
             .iter()
             .cloned()
             .collect();
+        let field_md: BTreeMap<String, String> = [("k".to_string(), "v".to_string())]
+            .iter()
+            .cloned()
+            .collect();
+        let mut field_with_md = Field::new("with_metadata", DataType::Utf8, true);
+        field_with_md.set_metadata(Some(field_md.clone()));
+        let mut child_with_md = Field::new("child", DataType::Int32, false);
+        child_with_md.set_metadata(Some(field_md.clone()));
+        let mut list_with_md = Field::new(
+            "list<with_metadata>",
+            DataType::List(Box::new(DataType::Int32)),
+            true,
+        );
+        list_with_md.set_child_metadata(Some(field_md.clone()));
+        let mut large_list_with_md = Field::new(
+            "large_list<with_metadata>",
+            DataType::LargeList(Box::new(DataType::Utf8)),
+            true,
+        );
+        large_list_with_md.set_metadata(Some(field_md.clone()));
+        large_list_with_md.set_child_metadata(Some(field_md.clone()));
+        let mut fixed_size_list_with_md = Field::new(
+            "fixed_size_list<with_metadata>",
+            DataType::FixedSizeList(Box::new(DataType::Int64), 2),
+            false,
+        );
+        fixed_size_list_with_md.set_child_metadata(Some(field_md));
         let schema = Schema::new_with_metadata(
             vec![
                 Field::new("uint8", DataType::UInt8, false),
@@ -769,6 +980,21 @@ mod tests {
                     false,
                 ),
                 Field::new("struct<>", DataType::Struct(vec![]), true),
+                Field::new(
+                    "map<utf8, int32>",
+                    DataType::Map(
+                        Box::new(Field::new(
+                            "entries",
+                            DataType::Struct(vec![
+                                Field::new("key", DataType::Utf8, false),
+                                Field::new("value", DataType::Int32, true),
+                            ]),
+                            false,
+                        )),
+                        true,
+                    ),
+                    true,
+                ),
                 Field::new_dict(
                     "dictionary<int32, utf8>",
                     DataType::Dictionary(
@@ -789,6 +1015,16 @@ mod tests {
                     123,
                     true,
                 ),
+                Field::new("decimal<usize, usize>", DataType::Decimal(10, 6), false),
+                field_with_md,
+                Field::new(
+                    "struct<child_with_metadata>",
+                    DataType::Struct(vec![child_with_md]),
+                    true,
+                ),
+                list_with_md,
+                large_list_with_md,
+                fixed_size_list_with_md,
             ],
             md,
         );
@@ -835,4 +1071,60 @@ mod tests {
         assert!(ipc.custom_metadata().is_none());
         assert!(ipc2.custom_metadata().is_none());
     }
+
+    #[derive(Debug)]
+    struct UuidType {
+        storage_type: DataType,
+    }
+
+    impl ExtensionType for UuidType {
+        fn extension_name(&self) -> &str {
+            "test.ipc.uuid"
+        }
+
+        fn storage_type(&self) -> &DataType {
+            &self.storage_type
+        }
+
+        fn serialize(&self) -> String {
+            "".to_string()
+        }
+
+        fn deserialize(
+            &self,
+            storage_type: &DataType,
+            _serialized: &str,
+        ) -> Result<Arc<ExtensionType>> {
+            Ok(Arc::new(UuidType {
+                storage_type: storage_type.clone(),
+            }))
+        }
+    }
+
+    #[test]
+    fn convert_schema_extension_type() {
+        let uuid = UuidType {
+            storage_type: DataType::FixedSizeBinary(16),
+        };
+        let schema = Schema::new(vec![
+            Field::new_extension("id", &uuid, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+
+        let fb = schema_to_fb(&schema);
+        let schema2 = fb_to_schema(ipc::get_root_as_schema(fb.finished_data()));
+        assert_eq!(schema, schema2);
+
+        // not registered yet, the field is read as its storage type
+        let field = schema2.field(0);
+        assert_eq!(field.extension_name(), Some("test.ipc.uuid"));
+        assert!(field.extension_type().unwrap().is_none());
+
+        register_extension_type(Arc::new(uuid)).unwrap();
+        let ext = field.extension_type().unwrap().unwrap();
+        assert_eq!(ext.extension_name(), "test.ipc.uuid");
+        assert_eq!(ext.storage_type(), &DataType::FixedSizeBinary(16));
+        assert!(schema2.field(1).extension_type().unwrap().is_none());
+        unregister_extension_type("test.ipc.uuid").unwrap();
+    }
 }
@@ -21,26 +21,89 @@
 //! however the `FileReader` expects a reader that supports `Seek`ing
 
 use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+#[cfg(feature = "mmap")]
+use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::mem;
 use std::sync::Arc;
 
+use flatbuffers::{ForwardsUOffset, Vector};
+
 use crate::array::*;
 use crate::buffer::Buffer;
-use crate::compute::cast;
-use crate::datatypes::{DataType, Field, IntervalUnit, Schema, SchemaRef};
+use crate::compute::{cast, concat};
+use crate::datatypes::{
+    ArrowPrimitiveType, DataType, Field, IntervalUnit, Schema, SchemaRef,
+};
 use crate::error::{ArrowError, Result};
 use crate::ipc;
+use crate::ipc::compression;
 use crate::record_batch::{RecordBatch, RecordBatchReader};
+use crate::sparse_tensor::{CompressedAxis, SparseCOOTensor, SparseCSXMatrix};
+use crate::tensor::Tensor;
 
 use ipc::CONTINUATION_MARKER;
 use DataType::*;
 
+/// The alignment required to share the memory of a message body with the buffers read
+/// from it, which is the largest alignment of the values of arrays
+const SHARED_BUFFER_ALIGNMENT: usize = 8;
+
+/// The body of an IPC message, from which the buffers of arrays are read
+#[derive(Clone, Copy)]
+enum MessageBody<'a> {
+    /// A body whose buffers are copied into new memory
+    Bytes(&'a [u8]),
+    /// A body whose memory is shared by the buffers read from it, as long as they are
+    /// aligned, such as a region of a memory mapped file
+    Shared(&'a Buffer),
+}
+
+impl<'a> MessageBody<'a> {
+    fn data(&self) -> &'a [u8] {
+        match *self {
+            MessageBody::Bytes(data) => data,
+            MessageBody::Shared(buffer) => buffer.data(),
+        }
+    }
+}
+
+/// A region of a buffer, which keeps the memory of the buffer alive
+struct BufferRegion {
+    buffer: Buffer,
+    start: usize,
+    end: usize,
+}
+
+impl AsRef<[u8]> for BufferRegion {
+    fn as_ref(&self) -> &[u8] {
+        &self.buffer.data()[self.start..self.end]
+    }
+}
+
+/// Returns a buffer sharing the memory of `buffer` in the range `[start, end)`
+fn share_buffer_region(buffer: &Buffer, start: usize, end: usize) -> Buffer {
+    Buffer::from_owner(BufferRegion {
+        buffer: buffer.clone(),
+        start,
+        end,
+    })
+}
+
 /// Read a buffer based on offset and length
-fn read_buffer(buf: &ipc::Buffer, a_data: &[u8]) -> Buffer {
+fn read_buffer(buf: &ipc::Buffer, body: MessageBody) -> Buffer {
     let start_offset = buf.offset() as usize;
     let end_offset = start_offset + buf.length() as usize;
-    let buf_data = &a_data[start_offset..end_offset];
-    Buffer::from(&buf_data)
+    let buf_data = &body.data()[start_offset..end_offset];
+    match body {
+        MessageBody::Shared(buffer)
+            if buf_data.as_ptr() as usize % SHARED_BUFFER_ALIGNMENT == 0 =>
+        {
+            share_buffer_region(buffer, start_offset, end_offset)
+        }
+        _ => Buffer::from(&buf_data),
+    }
 }
 
 /// Coordinates reading arrays based on data types.
@@ -52,15 +115,24 @@ fn read_buffer(buf: &ipc::Buffer, a_data: &[u8]) -> Buffer {
 ///     - check if the bit width of non-64-bit numbers is 64, and
 ///     - read the buffer as 64-bit (signed integer or float), and
 ///     - cast the 64-bit array to the appropriate data type
+///
+/// The values of dictionary arrays are looked up in `dictionaries_by_id` with `dict_id`,
+/// the dictionary id of the field enclosing the data type.
+///
+/// If `validate` is set, the data of every array is checked with
+/// `ArrayData::validate_full` before the array is created from it.
+#[allow(clippy::too_many_arguments)]
 fn create_array(
     nodes: &[ipc::FieldNode],
     data_type: &DataType,
-    data: &[u8],
+    dict_id: i64,
+    data: MessageBody,
     buffers: &[ipc::Buffer],
-    dictionaries: &[Option<ArrayRef>],
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
     mut node_index: usize,
     mut buffer_index: usize,
-) -> (ArrayRef, usize, usize) {
+    validate: bool,
+) -> Result<(ArrayRef, usize, usize)> {
     use DataType::*;
     let array = match data_type {
         Utf8 | Binary | LargeBinary | LargeUtf8 => {
@@ -71,12 +143,13 @@ fn create_array(
                     .iter()
                     .map(|buf| read_buffer(buf, data))
                     .collect(),
-            );
+                validate,
+            )?;
             node_index += 1;
             buffer_index += 3;
             array
         }
-        FixedSizeBinary(_) => {
+        FixedSizeBinary(_) | Decimal(_, _) => {
             let array = create_primitive_array(
                 &nodes[node_index],
                 data_type,
@@ -84,11 +157,36 @@ fn create_array(
                     .iter()
                     .map(|buf| read_buffer(buf, data))
                     .collect(),
-            );
+                validate,
+            )?;
             node_index += 1;
             buffer_index += 2;
             array
         }
+        Map(ref entries, _) => {
+            let map_node = &nodes[node_index];
+            let map_buffers: Vec<Buffer> = buffers[buffer_index..buffer_index + 2]
+                .iter()
+                .map(|buf| read_buffer(buf, data))
+                .collect();
+            node_index += 1;
+            buffer_index += 2;
+            let triple = create_array(
+                nodes,
+                entries.data_type(),
+                entries.dict_id,
+                data,
+                buffers,
+                dictionaries_by_id,
+                node_index,
+                buffer_index,
+                validate,
+            )?;
+            node_index = triple.1;
+            buffer_index = triple.2;
+
+            create_list_array(map_node, data_type, &map_buffers[..], triple.0, validate)?
+        }
         List(ref list_data_type) | LargeList(ref list_data_type) => {
             let list_node = &nodes[node_index];
             let list_buffers: Vec<Buffer> = buffers[buffer_index..buffer_index + 2]
@@ -100,16 +198,24 @@ fn create_array(
             let triple = create_array(
                 nodes,
                 list_data_type,
+                dict_id,
                 data,
                 buffers,
-                dictionaries,
+                dictionaries_by_id,
                 node_index,
                 buffer_index,
-            );
+                validate,
+            )?;
             node_index = triple.1;
             buffer_index = triple.2;
 
-            create_list_array(list_node, data_type, &list_buffers[..], triple.0)
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers[..],
+                triple.0,
+                validate,
+            )?
         }
         FixedSizeList(ref list_data_type, _) => {
             let list_node = &nodes[node_index];
@@ -122,16 +228,24 @@ fn create_array(
             let triple = create_array(
                 nodes,
                 list_data_type,
+                dict_id,
                 data,
                 buffers,
-                dictionaries,
+                dictionaries_by_id,
                 node_index,
                 buffer_index,
-            );
+                validate,
+            )?;
             node_index = triple.1;
             buffer_index = triple.2;
 
-            create_list_array(list_node, data_type, &list_buffers[..], triple.0)
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers[..],
+                triple.0,
+                validate,
+            )?
         }
         Struct(struct_fields) => {
             let struct_node = &nodes[node_index];
@@ -147,28 +261,26 @@ fn create_array(
                 let triple = create_array(
                     nodes,
                     struct_field.data_type(),
+                    struct_field.dict_id,
                     data,
                     buffers,
-                    dictionaries,
+                    dictionaries_by_id,
                     node_index,
                     buffer_index,
-                );
+                    validate,
+                )?;
                 node_index = triple.1;
                 buffer_index = triple.2;
-                struct_arrays.push((struct_field.clone(), triple.0));
+                struct_arrays.push(triple.0.data());
             }
             let null_count = struct_node.null_count() as usize;
-            let struct_array = if null_count > 0 {
-                // create struct array from fields, arrays and null data
-                StructArray::from((
-                    struct_arrays,
-                    null_buffer,
-                    struct_node.null_count() as usize,
-                ))
-            } else {
-                StructArray::from(struct_arrays)
-            };
-            Arc::new(struct_array)
+            let mut builder = ArrayData::builder(data_type.clone())
+                .len(struct_node.length() as usize)
+                .child_data(struct_arrays);
+            if null_count > 0 {
+                builder = builder.null_count(null_count).null_bit_buffer(null_buffer)
+            }
+            build_array(builder.build(), validate)?
         }
         // Create dictionary array from RecordBatch
         Dictionary(_, _) => {
@@ -177,7 +289,15 @@ fn create_array(
                 .iter()
                 .map(|buf| read_buffer(buf, data))
                 .collect();
-            let value_array = dictionaries[node_index].clone().unwrap();
+            let value_array = dictionaries_by_id
+                .get(&dict_id)
+                .ok_or_else(|| {
+                    ArrowError::IoError(format!(
+                        "Dictionary with id {} not found before the record batch using it",
+                        dict_id
+                    ))
+                })?
+                .clone();
             node_index += 1;
             buffer_index += 2;
 
@@ -186,7 +306,8 @@ fn create_array(
                 data_type,
                 &index_buffers[..],
                 value_array,
-            )
+                validate,
+            )?
         }
         Null => {
             let length = nodes[node_index].length() as usize;
@@ -196,7 +317,7 @@ fn create_array(
                 .build();
             node_index += 1;
             // no buffer increases
-            make_array(data)
+            build_array(data, validate)?
         }
         _ => {
             let array = create_primitive_array(
@@ -206,13 +327,23 @@ fn create_array(
                     .iter()
                     .map(|buf| read_buffer(buf, data))
                     .collect(),
-            );
+                validate,
+            )?;
             node_index += 1;
             buffer_index += 2;
             array
         }
     };
-    (array, node_index, buffer_index)
+    Ok((array, node_index, buffer_index))
+}
+
+/// Creates an array from its data, checking the data with `ArrayData::validate_full`
+/// first if `validate` is set
+fn build_array(data: ArrayDataRef, validate: bool) -> Result<ArrayRef> {
+    if validate {
+        data.validate_full()?;
+    }
+    Ok(make_array(data))
 }
 
 /// Reads the correct number of buffers based on data type and null_count, and creates a
@@ -221,7 +352,8 @@ fn create_primitive_array(
     field_node: &ipc::FieldNode,
     data_type: &DataType,
     buffers: Vec<Buffer>,
-) -> ArrayRef {
+    validate: bool,
+) -> Result<ArrayRef> {
     let length = field_node.length() as usize;
     let null_count = field_node.null_count() as usize;
     let array_data = match data_type {
@@ -238,8 +370,8 @@ fn create_primitive_array(
             }
             builder.build()
         }
-        FixedSizeBinary(_) => {
-            // read 3 buffers
+        FixedSizeBinary(_) | Decimal(_, _) => {
+            // read 2 buffers
             let mut builder = ArrayData::builder(data_type.clone())
                 .len(length)
                 .buffers(buffers[1..2].to_vec())
@@ -271,10 +403,8 @@ fn create_primitive_array(
                         .null_count(null_count)
                         .null_bit_buffer(buffers[0].clone())
                 }
-                let values = Arc::new(Int64Array::from(builder.build())) as ArrayRef;
-                // this cast is infallible, the unwrap is safe
-                let casted = cast(&values, data_type).unwrap();
-                casted.data()
+                let values = build_array(builder.build(), validate)?;
+                cast_values(&values, data_type)?.data()
             } else {
                 let mut builder = ArrayData::builder(data_type.clone())
                     .len(length)
@@ -300,10 +430,8 @@ fn create_primitive_array(
                         .null_count(null_count)
                         .null_bit_buffer(buffers[0].clone())
                 }
-                let values = Arc::new(Float64Array::from(builder.build())) as ArrayRef;
-                // this cast is infallible, the unwrap is safe
-                let casted = cast(&values, data_type).unwrap();
-                casted.data()
+                let values = build_array(builder.build(), validate)?;
+                cast_values(&values, data_type)?.data()
             } else {
                 let mut builder = ArrayData::builder(data_type.clone())
                     .len(length)
@@ -320,6 +448,7 @@ fn create_primitive_array(
         Boolean
         | Int64
         | UInt64
+        | Float16
         | Float64
         | Time64(_)
         | Timestamp(_, _)
@@ -337,10 +466,25 @@ fn create_primitive_array(
             }
             builder.build()
         }
-        t => panic!("Data type {:?} either unsupported or not primitive", t),
+        t => {
+            return Err(ArrowError::IoError(format!(
+                "Reading arrays of type {:?} is not supported",
+                t
+            )))
+        }
     };
 
-    make_array(array_data)
+    build_array(array_data, validate)
+}
+
+/// Casts values read as 64-bit numbers to their data type
+fn cast_values(values: &ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    cast(values, data_type).map_err(|e| {
+        ArrowError::IoError(format!(
+            "Unable to read 64-bit values as {:?}: {}",
+            data_type, e
+        ))
+    })
 }
 
 /// Reads the correct number of buffers based on list type and null_count, and creates a
@@ -350,8 +494,9 @@ fn create_list_array(
     data_type: &DataType,
     buffers: &[Buffer],
     child_array: ArrayRef,
-) -> ArrayRef {
-    if let DataType::List(_) = *data_type {
+    validate: bool,
+) -> Result<ArrayRef> {
+    if matches!(*data_type, DataType::List(_) | DataType::Map(_, _)) {
         let null_count = field_node.null_count() as usize;
         let mut builder = ArrayData::builder(data_type.clone())
             .len(field_node.length() as usize)
@@ -363,7 +508,7 @@ fn create_list_array(
                 .null_count(null_count)
                 .null_bit_buffer(buffers[0].clone())
         }
-        make_array(builder.build())
+        build_array(builder.build(), validate)
     } else if let DataType::FixedSizeList(_, _) = *data_type {
         let null_count = field_node.null_count() as usize;
         let mut builder = ArrayData::builder(data_type.clone())
@@ -376,9 +521,12 @@ fn create_list_array(
                 .null_count(null_count)
                 .null_bit_buffer(buffers[0].clone())
         }
-        make_array(builder.build())
+        build_array(builder.build(), validate)
     } else {
-        panic!("Cannot create list array from {:?}", data_type)
+        Err(ArrowError::IoError(format!(
+            "Cannot create list array from {:?}",
+            data_type
+        )))
     }
 }
 
@@ -389,7 +537,8 @@ fn create_dictionary_array(
     data_type: &DataType,
     buffers: &[Buffer],
     value_array: ArrayRef,
-) -> ArrayRef {
+    validate: bool,
+) -> Result<ArrayRef> {
     if let DataType::Dictionary(_, _) = *data_type {
         let null_count = field_node.null_count() as usize;
         let mut builder = ArrayData::builder(data_type.clone())
@@ -402,25 +551,143 @@ fn create_dictionary_array(
                 .null_count(null_count)
                 .null_bit_buffer(buffers[0].clone())
         }
-        make_array(builder.build())
+        build_array(builder.build(), validate)
     } else {
-        unreachable!("Cannot create dictionary array from {:?}", data_type)
+        Err(ArrowError::IoError(format!(
+            "Cannot create dictionary array from {:?}",
+            data_type
+        )))
     }
 }
 
 /// Creates a record batch from binary data using the `ipc::RecordBatch` indexes and the `Schema`
+///
+/// `dictionaries_by_id` holds the values of the dictionaries read so far, by dictionary id.
 pub fn read_record_batch(
     buf: &[u8],
     batch: ipc::RecordBatch,
     schema: SchemaRef,
-    dictionaries: &[Option<ArrayRef>],
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+) -> Result<RecordBatch> {
+    read_record_batch_body(
+        MessageBody::Bytes(buf),
+        batch,
+        schema,
+        dictionaries_by_id,
+        false,
+    )
+}
+
+/// Reads the values of a dictionary from binary data using the `ipc::DictionaryBatch`
+/// indexes and the `Schema` of the record batches using it, adding them to
+/// `dictionaries_by_id`.
+///
+/// Delta dictionary batches append their values to the dictionary with the same id,
+/// while other dictionary batches replace it.
+pub fn read_dictionary_batch(
+    buf: &[u8],
+    batch: ipc::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+) -> Result<()> {
+    read_dictionary(
+        MessageBody::Bytes(buf),
+        batch,
+        schema,
+        dictionaries_by_id,
+        false,
+    )
+}
+
+/// The number of field nodes and buffers that `create_array` reads for a data type
+fn count_nodes_and_buffers(data_type: &DataType) -> (usize, usize) {
+    let (children, buffers) = match data_type {
+        Utf8 | Binary | LargeBinary | LargeUtf8 => ((0, 0), 3),
+        List(value_type) | LargeList(value_type) => {
+            (count_nodes_and_buffers(value_type), 2)
+        }
+        FixedSizeList(value_type, _) => (count_nodes_and_buffers(value_type), 1),
+        Map(entries, _) => (count_nodes_and_buffers(entries.data_type()), 2),
+        Struct(fields) => (count_fields_nodes_and_buffers(fields), 1),
+        Null => ((0, 0), 0),
+        _ => ((0, 0), 2),
+    };
+    (children.0 + 1, children.1 + buffers)
+}
+
+/// The number of field nodes and buffers that `create_array` reads for fields
+fn count_fields_nodes_and_buffers(fields: &[Field]) -> (usize, usize) {
+    fields.iter().fold((0, 0), |(nodes, buffers), field| {
+        let (field_nodes, field_buffers) = count_nodes_and_buffers(field.data_type());
+        (nodes + field_nodes, buffers + field_buffers)
+    })
+}
+
+/// Creates a record batch from a message body, see `read_record_batch`. If `validate`
+/// is set, the data of every array is checked with `ArrayData::validate_full` before
+/// the array is created from it.
+fn read_record_batch_body(
+    body: MessageBody,
+    batch: ipc::RecordBatch,
+    schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+    validate: bool,
 ) -> Result<RecordBatch> {
+    let buf = body.data();
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
     })?;
     let field_nodes = batch.nodes().ok_or_else(|| {
         ArrowError::IoError("Unable to get field nodes from IPC RecordBatch".to_string())
     })?;
+    // the message must have the nodes and buffers of the schema, with valid lengths
+    let (num_nodes, num_buffers) = count_fields_nodes_and_buffers(schema.fields());
+    if field_nodes.len() < num_nodes || buffers.len() < num_buffers {
+        return Err(ArrowError::IoError(format!(
+            "IPC RecordBatch has {} field nodes and {} buffers, while its schema \
+             requires {} and {}",
+            field_nodes.len(),
+            buffers.len(),
+            num_nodes,
+            num_buffers
+        )));
+    }
+    if let Some(node) = field_nodes
+        .iter()
+        .find(|node| node.length() < 0 || node.null_count() < 0)
+    {
+        return Err(ArrowError::IoError(format!(
+            "IPC RecordBatch has a field node of negative length {} or null count {}",
+            node.length(),
+            node.null_count()
+        )));
+    }
+    // the buffers must lie within the message body, or reading them would panic
+    for buffer in buffers {
+        let in_bounds = (buffer.offset() as u64)
+            .checked_add(buffer.length() as u64)
+            .map_or(false, |end| {
+                buffer.offset() >= 0 && buffer.length() >= 0 && end <= buf.len() as u64
+            });
+        if !in_bounds {
+            return Err(ArrowError::IoError(format!(
+                "Buffer at offset {} of length {} is out of the bounds of the message \
+                 body of {} bytes",
+                buffer.offset(),
+                buffer.length(),
+                buf.len()
+            )));
+        }
+    }
+    // read the decompressed buffers from a new body
+    let decompressed;
+    let (body, buffers) = match batch.compression() {
+        Some(compression) => {
+            decompressed = decompress_body(buf, buffers, compression.codec())?;
+            (MessageBody::Bytes(&decompressed.0), &decompressed.1[..])
+        }
+        None => (body, buffers),
+    };
     // keep track of buffer and node index, the functions that create arrays mutate these
     let mut buffer_index = 0;
     let mut node_index = 0;
@@ -431,12 +698,14 @@ pub fn read_record_batch(
         let triple = create_array(
             field_nodes,
             field.data_type(),
-            &buf,
+            field.dict_id,
+            body,
             buffers,
-            dictionaries,
+            dictionaries_by_id,
             node_index,
             buffer_index,
-        );
+            validate,
+        )?;
         node_index = triple.1;
         buffer_index = triple.2;
         arrays.push(triple.0);
@@ -445,225 +714,608 @@ pub fn read_record_batch(
     RecordBatch::try_new(schema, arrays)
 }
 
+/// Decompress the buffers of a message body, returning the decompressed body and the
+/// buffers locating them in it
+fn decompress_body(
+    buf: &[u8],
+    buffers: &[ipc::Buffer],
+    codec: ipc::CompressionType,
+) -> Result<(Vec<u8>, Vec<ipc::Buffer>)> {
+    let mut body = vec![];
+    let mut decompressed_buffers = Vec::with_capacity(buffers.len());
+    for buffer in buffers {
+        let start = buffer.offset() as usize;
+        let data = &buf[start..start + buffer.length() as usize];
+        let data = compression::decompress_buffer(codec, data)?;
+        decompressed_buffers.push(ipc::Buffer::new(body.len() as i64, data.len() as i64));
+        body.extend_from_slice(&data);
+    }
+    Ok((body, decompressed_buffers))
+}
+
+/// An encapsulated IPC message, split into its metadata and body.
+///
+/// Flatbuffers reads struct fields such as buffers in place, so the metadata is
+/// copied to aligned memory, and parsed from there.
+struct EncapsulatedMessage<'a> {
+    /// The whole message
+    buf: &'a [u8],
+    /// The metadata, copied to aligned memory
+    metadata: Buffer,
+    /// The offset of the metadata in `buf`
+    meta_start: usize,
+    /// The body
+    body: &'a [u8],
+}
+
+impl<'a> EncapsulatedMessage<'a> {
+    fn try_new(buf: &'a [u8]) -> Result<Self> {
+        let too_short =
+            || ArrowError::IoError("IPC tensor message is too short".to_string());
+        // a continuation marker is not present in the legacy format
+        let prefix_size = if buf.starts_with(&CONTINUATION_MARKER) {
+            8
+        } else {
+            4
+        };
+        if buf.len() < prefix_size {
+            return Err(too_short());
+        }
+        let mut meta_size = [0; 4];
+        meta_size.copy_from_slice(&buf[prefix_size - 4..prefix_size]);
+        let meta_len = i32::from_le_bytes(meta_size);
+        if meta_len < 0 || buf.len() < prefix_size + meta_len as usize {
+            return Err(too_short());
+        }
+        let body_start = prefix_size + meta_len as usize;
+        Ok(Self {
+            buf,
+            metadata: Buffer::from(&buf[prefix_size..body_start]),
+            meta_start: prefix_size,
+            body: &buf[body_start..],
+        })
+    }
+
+    fn message(&self) -> ipc::Message<'_> {
+        ipc::get_root_as_message(self.metadata.data())
+    }
+
+    /// Returns a string of the metadata, borrowed from the message rather than from
+    /// the aligned copy of its metadata
+    fn borrow_str(&self, value: &str) -> &'a str {
+        let offset = value.as_ptr() as usize - self.metadata.raw_data() as usize;
+        let start = self.meta_start + offset;
+        let bytes = &self.buf[start..start + value.len()];
+        // the bytes are a copy of `value`, which is valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+/// Copies a buffer of a tensor message out of the message body, checking its bounds
+fn read_tensor_buffer(buffer: &ipc::Buffer, body: &[u8], name: &str) -> Result<Buffer> {
+    let in_bounds = (buffer.offset() as u64)
+        .checked_add(buffer.length() as u64)
+        .map_or(false, |end| {
+            buffer.offset() >= 0 && buffer.length() >= 0 && end <= body.len() as u64
+        });
+    if !in_bounds {
+        return Err(ArrowError::IoError(format!(
+            "Tensor {} at offset {} of length {} is out of the bounds of the message \
+             body of {} bytes",
+            name,
+            buffer.offset(),
+            buffer.length(),
+            body.len()
+        )));
+    }
+    Ok(read_buffer(buffer, MessageBody::Bytes(body)))
+}
+
+/// Checks that the values of a tensor message are of type `T`
+fn check_tensor_data_type<T: ArrowPrimitiveType>(
+    data_type: Option<DataType>,
+) -> Result<()> {
+    if data_type.as_ref() != Some(&T::DATA_TYPE) {
+        return Err(ArrowError::IoError(format!(
+            "Unable to read a tensor of {:?} values as a {:?} tensor",
+            data_type,
+            T::DATA_TYPE
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that the indices of a sparse tensor message are signed 64-bit integers
+fn check_sparse_index_type(index_type: ipc::Int, name: &str) -> Result<()> {
+    if index_type.bitWidth() != 64 || !index_type.is_signed() {
+        return Err(ArrowError::IoError(format!(
+            "Reading sparse tensor {} of {}-bit {} integers is not supported",
+            name,
+            index_type.bitWidth(),
+            if index_type.is_signed() {
+                "signed"
+            } else {
+                "unsigned"
+            }
+        )));
+    }
+    Ok(())
+}
+
+/// The sizes of the dimensions of a tensor message, which must not be negative
+fn tensor_shape(dims: Vector<ForwardsUOffset<ipc::TensorDim>>) -> Result<Vec<usize>> {
+    dims.iter()
+        .map(|dim| {
+            usize::try_from(dim.size_()).map_err(|_| {
+                ArrowError::IoError(format!(
+                    "Tensor dimension has a negative size {}",
+                    dim.size_()
+                ))
+            })
+        })
+        .collect()
+}
+
+/// The number of bytes of the values of a tensor of type `T` and the given shape
+fn tensor_byte_len<T: ArrowPrimitiveType>(shape: &[usize]) -> Result<usize> {
+    shape
+        .iter()
+        .try_fold(mem::size_of::<T::Native>(), |len, size| {
+            len.checked_mul(*size)
+        })
+        .ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "The size of a tensor of shape {:?} overflows",
+                shape
+            ))
+        })
+}
+
+/// The strides of a tensor message of type `T`, in bytes. There must be one stride
+/// per dimension, each a non-negative multiple of the size of `T`, and the last
+/// value they point at must lie within the `len` bytes of the tensor's values.
+fn tensor_strides<T: ArrowPrimitiveType>(
+    strides: Vector<i64>,
+    shape: &[usize],
+    len: usize,
+) -> Result<Vec<usize>> {
+    let strides: Vec<i64> = strides.iter().collect();
+    let invalid = || {
+        ArrowError::IoError(format!(
+            "Tensor strides {:?} are not within the {} bytes of a tensor of shape {:?}",
+            strides, len, shape
+        ))
+    };
+    if strides.len() != shape.len() {
+        return Err(ArrowError::IoError(format!(
+            "Tensor has {} strides for its {} dimensions",
+            strides.len(),
+            shape.len()
+        )));
+    }
+    let element_size = mem::size_of::<T::Native>();
+    let mut end = element_size;
+    for (stride, size) in strides.iter().zip(shape) {
+        let stride = usize::try_from(*stride)
+            .ok()
+            .filter(|stride| stride % element_size == 0)
+            .ok_or_else(invalid)?;
+        // an empty tensor holds no values for its strides to point at
+        if len > 0 {
+            end = stride
+                .checked_mul(size - 1)
+                .and_then(|offset| offset.checked_add(end))
+                .ok_or_else(invalid)?;
+        }
+    }
+    if len > 0 && end > len {
+        return Err(invalid());
+    }
+    Ok(strides.iter().map(|stride| *stride as usize).collect())
+}
+
+/// The names of the dimensions of a tensor message, which are either given for every
+/// dimension or not at all. The names are borrowed from the message.
+fn tensor_names<'a>(
+    dims: Vector<ForwardsUOffset<ipc::TensorDim>>,
+    message: &EncapsulatedMessage<'a>,
+) -> Option<Vec<&'a str>> {
+    dims.iter()
+        .map(|dim| dim.name().map(|name| message.borrow_str(name)))
+        .collect()
+}
+
+/// Reads a `Tensor` from an encapsulated IPC tensor message, such as one written by
+/// [`write_tensor`](crate::ipc::writer::write_tensor).
+///
+/// The dimension names of the tensor borrow from `buf`, while its values are copied.
+pub fn read_tensor<T: ArrowPrimitiveType>(buf: &[u8]) -> Result<Tensor<T>> {
+    let encapsulated = EncapsulatedMessage::try_new(buf)?;
+    let message = encapsulated.message();
+    let body = encapsulated.body;
+    let tensor = message.header_as_tensor().ok_or_else(|| {
+        ArrowError::IoError("Unable to read IPC message as tensor".to_string())
+    })?;
+    check_tensor_data_type::<T>(ipc::convert::get_tensor_data_type(tensor))?;
+
+    // only the values of the tensor are read out of its data
+    let shape = tensor_shape(tensor.shape())?;
+    let len = tensor_byte_len::<T>(&shape)?;
+    let data = tensor.data();
+    if (data.length() as u64) < len as u64 {
+        return Err(ArrowError::IoError(format!(
+            "Tensor data of {} bytes is too short for the {} bytes of a tensor of \
+             shape {:?}",
+            data.length(),
+            len,
+            shape
+        )));
+    }
+    let data = ipc::Buffer::new(data.offset(), len as i64);
+    let buffer = read_tensor_buffer(&data, body, "data")?;
+
+    if shape.is_empty() {
+        return Tensor::try_new(buffer, None, None, None);
+    }
+    let names = tensor_names(tensor.shape(), &encapsulated);
+    let strides = match tensor.strides() {
+        Some(strides) => Some(tensor_strides::<T>(strides, &shape, len)?),
+        None => None,
+    };
+    Tensor::try_new(buffer, Some(shape), strides, names)
+}
+
+/// Reads a `SparseCOOTensor` from an encapsulated IPC sparse tensor message, such as
+/// one written by [`write_sparse_coo_tensor`](crate::ipc::writer::write_sparse_coo_tensor).
+///
+/// The coordinates must be signed 64-bit integers in row major order. The dimension
+/// names of the tensor borrow from `buf`, while its indices and values are copied.
+pub fn read_sparse_coo_tensor<T: ArrowPrimitiveType>(
+    buf: &[u8],
+) -> Result<SparseCOOTensor<T>> {
+    let encapsulated = EncapsulatedMessage::try_new(buf)?;
+    let message = encapsulated.message();
+    let body = encapsulated.body;
+    let tensor = message.header_as_sparse_tensor().ok_or_else(|| {
+        ArrowError::IoError("Unable to read IPC message as sparse tensor".to_string())
+    })?;
+    check_tensor_data_type::<T>(ipc::convert::get_sparse_tensor_data_type(tensor))?;
+    let index = tensor
+        .sparseIndex_as_sparse_tensor_index_coo()
+        .ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Unable to read a sparse tensor with a {:?} index as a COO tensor",
+                tensor.sparseIndex_type()
+            ))
+        })?;
+    check_sparse_index_type(index.indicesType(), "indices")?;
+    let shape = tensor_shape(tensor.shape())?;
+    if let Some(strides) = index.indicesStrides() {
+        let strides = strides.iter().collect::<Vec<_>>();
+        if strides != [shape.len() as i64 * 8, 8] {
+            return Err(ArrowError::IoError(
+                "Reading sparse tensor indices that are not in row major order \
+                 is not supported"
+                    .to_string(),
+            ));
+        }
+    }
+    let indices = read_tensor_buffer(index.indicesBuffer(), body, "indices")?;
+    let data = read_tensor_buffer(tensor.data(), body, "data")?;
+
+    let names = tensor_names(tensor.shape(), &encapsulated);
+    SparseCOOTensor::try_new(shape, indices, data, names)
+}
+
+/// Reads a `SparseCSXMatrix` from an encapsulated IPC sparse tensor message, such as
+/// one written by [`write_sparse_csx_matrix`](crate::ipc::writer::write_sparse_csx_matrix).
+///
+/// The compressed axis of the message must match `A`, and the index pointers and
+/// indices must be signed 64-bit integers. The dimension names of the matrix borrow
+/// from `buf`, while its indices and values are copied.
+pub fn read_sparse_csx_matrix<T: ArrowPrimitiveType, A: CompressedAxis>(
+    buf: &[u8],
+) -> Result<SparseCSXMatrix<T, A>> {
+    let encapsulated = EncapsulatedMessage::try_new(buf)?;
+    let message = encapsulated.message();
+    let body = encapsulated.body;
+    let tensor = message.header_as_sparse_tensor().ok_or_else(|| {
+        ArrowError::IoError("Unable to read IPC message as sparse tensor".to_string())
+    })?;
+    check_tensor_data_type::<T>(ipc::convert::get_sparse_tensor_data_type(tensor))?;
+    let index = tensor
+        .sparseIndex_as_sparse_matrix_index_csx()
+        .ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Unable to read a sparse tensor with a {:?} index as a CSX matrix",
+                tensor.sparseIndex_type()
+            ))
+        })?;
+    let axis = if A::IS_ROW {
+        ipc::SparseMatrixCompressedAxis::Row
+    } else {
+        ipc::SparseMatrixCompressedAxis::Column
+    };
+    if index.compressedAxis() != axis {
+        return Err(ArrowError::IoError(format!(
+            "Unable to read a sparse matrix compressed along {:?} as one compressed \
+             along {:?}",
+            index.compressedAxis(),
+            axis
+        )));
+    }
+    check_sparse_index_type(index.indptrType(), "indptr")?;
+    check_sparse_index_type(index.indicesType(), "indices")?;
+    let indptr = read_tensor_buffer(index.indptrBuffer(), body, "indptr")?;
+    let indices = read_tensor_buffer(index.indicesBuffer(), body, "indices")?;
+    let data = read_tensor_buffer(tensor.data(), body, "data")?;
+
+    let names = tensor_names(tensor.shape(), &encapsulated);
+    SparseCSXMatrix::try_new(tensor_shape(tensor.shape())?, indptr, indices, data, names)
+}
+
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries_by_field` with the resulting dictionary
+/// updating the `dictionaries_by_id` with the resulting dictionary.
+///
+/// A delta dictionary batch appends its values to the dictionary with the same id,
+/// while other dictionary batches replace it. If `validate` is set, the values are
+/// checked with `ArrayData::validate_full` before arrays are created from them.
 fn read_dictionary(
-    buf: &[u8],
+    body: MessageBody,
     batch: ipc::DictionaryBatch,
-    ipc_schema: &ipc::Schema,
     schema: &Schema,
-    dictionaries_by_field: &mut [Option<ArrayRef>],
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    validate: bool,
 ) -> Result<()> {
-    if batch.isDelta() {
-        return Err(ArrowError::IoError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
 
     // As the dictionary batch does not contain the type of the
     // values array, we need to retrieve this from the schema.
-    let first_field = find_dictionary_field(ipc_schema, id).ok_or_else(|| {
-        ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
-    })?;
+    let value_type = schema
+        .fields()
+        .iter()
+        .find_map(|field| {
+            find_dictionary_value_type(field.data_type(), field.dict_id, id)
+        })
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "dictionary id not found in schema".to_string(),
+            )
+        })?;
 
-    // Get an array representing this dictionary's values.
-    let dictionary_values: ArrayRef = match schema.field(first_field).data_type() {
-        DataType::Dictionary(_, ref value_type) => {
-            // Make a fake schema for the dictionary batch.
-            let schema = Schema {
-                fields: vec![Field::new("", value_type.as_ref().clone(), false)],
-                metadata: HashMap::new(),
-            };
-            // Read a single column
-            let record_batch = read_record_batch(
-                &buf,
-                batch.data().unwrap(),
-                Arc::new(schema),
-                &dictionaries_by_field,
-            )?;
-            Some(record_batch.column(0).clone())
-        }
-        _ => None,
-    }
-    .ok_or_else(|| {
-        ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
+    // Make a fake schema for the dictionary batch.
+    let schema = Schema {
+        fields: vec![Field::new("", value_type.clone(), false)],
+        metadata: HashMap::new(),
+    };
+    // Read a single column
+    let data = batch.data().ok_or_else(|| {
+        ArrowError::IoError(format!(
+            "Unable to get the record batch of dictionary batch with id {}",
+            id
+        ))
     })?;
+    let record_batch = read_record_batch_body(
+        body,
+        data,
+        Arc::new(schema),
+        &dictionaries_by_id,
+        validate,
+    )?;
+
+    let values = if batch.isDelta() {
+        let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Delta dictionary batch with id {} has no dictionary to extend",
+                id
+            ))
+        })?;
+        concat(&[existing.clone(), record_batch.column(0).clone()])?
+    } else {
+        record_batch.column(0).clone()
+    };
 
-    // for all fields with this dictionary id, update the dictionaries vector
-    // in the reader. Note that a dictionary batch may be shared between many fields.
+    // A dictionary batch may be shared between many fields with the same id.
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
-    let fields = ipc_schema.fields().unwrap();
-    for (i, field) in fields.iter().enumerate() {
-        if let Some(dictionary) = field.dictionary() {
-            if dictionary.id() == id {
-                // Add (possibly multiple) array refs to the dictionaries array.
-                dictionaries_by_field[i] = Some(dictionary_values.clone());
-            }
-        }
-    }
+    dictionaries_by_id.insert(id, values);
 
     Ok(())
 }
 
-// Linear search for the first dictionary field with a dictionary id.
-fn find_dictionary_field(ipc_schema: &ipc::Schema, id: i64) -> Option<usize> {
-    let fields = ipc_schema.fields().unwrap();
-    for i in 0..fields.len() {
-        let field: ipc::Field = fields.get(i);
-        if let Some(dictionary) = field.dictionary() {
-            if dictionary.id() == id {
-                return Some(i);
-            }
+/// Read a dictionary from the footer of a file, which only allows extending
+/// dictionaries with deltas. If `validate` is set, the values are checked with
+/// `ArrayData::validate_full` before arrays are created from them.
+fn read_file_dictionary(
+    body: MessageBody,
+    batch: ipc::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    validate: bool,
+) -> Result<()> {
+    if !batch.isDelta() && dictionaries_by_id.contains_key(&batch.id()) {
+        return Err(ArrowError::IoError(format!(
+            "Dictionary with id {} is replaced, which the IPC file format does not \
+             support",
+            batch.id()
+        )));
+    }
+    read_dictionary(body, batch, schema, dictionaries_by_id, validate)
+}
+
+/// Finds the value type of the dictionary with the given id in a data type, where
+/// `dict_id` is the dictionary id of the enclosing field
+fn find_dictionary_value_type(
+    data_type: &DataType,
+    dict_id: i64,
+    id: i64,
+) -> Option<&DataType> {
+    match data_type {
+        Dictionary(_, value_type) if dict_id == id => Some(value_type),
+        List(value_type) | LargeList(value_type) | FixedSizeList(value_type, _) => {
+            find_dictionary_value_type(value_type, dict_id, id)
+        }
+        Struct(fields) => fields.iter().find_map(|field| {
+            find_dictionary_value_type(field.data_type(), field.dict_id, id)
+        }),
+        Map(entries, _) => {
+            find_dictionary_value_type(entries.data_type(), entries.dict_id, id)
         }
+        _ => None,
     }
-    None
 }
 
-/// Arrow File reader
-pub struct FileReader<R: Read + Seek> {
-    /// Buffered file reader that supports reading and seeking
+/// The contents of an Arrow file, which are read by offset
+trait FileSource {
+    /// The length of the file in bytes
+    fn file_len(&self) -> usize;
+
+    /// Reads the `len` bytes at `offset`, which lie within the file
+    fn read_at(&mut self, offset: usize, len: usize) -> Result<Buffer>;
+
+    /// Returns the body of a message read with `read_at`, from which arrays are read
+    fn body(data: &Buffer) -> MessageBody<'_>;
+}
+
+/// A file read from a seekable reader, whose messages are copied out of it
+struct SeekableFile<R: Read + Seek> {
     reader: BufReader<R>,
+    len: usize,
+}
+
+impl<R: Read + Seek> FileSource for SeekableFile<R> {
+    fn file_len(&self) -> usize {
+        self.len
+    }
+
+    fn read_at(&mut self, offset: usize, len: usize) -> Result<Buffer> {
+        let mut data = vec![0; len];
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        self.reader.read_exact(&mut data)?;
+        Ok(Buffer::from_owner(data))
+    }
+
+    fn body(data: &Buffer) -> MessageBody<'_> {
+        MessageBody::Bytes(data.data())
+    }
+}
 
-    /// The schema that is read from the file header
+/// A file held in memory, whose messages share the memory of the file
+impl FileSource for Buffer {
+    fn file_len(&self) -> usize {
+        self.len()
+    }
+
+    fn read_at(&mut self, offset: usize, len: usize) -> Result<Buffer> {
+        Ok(share_buffer_region(self, offset, offset + len))
+    }
+
+    fn body(data: &Buffer) -> MessageBody<'_> {
+        MessageBody::Shared(data)
+    }
+}
+
+/// The footer, dictionaries and record batch blocks of an Arrow file, which are read
+/// the same way by the `FileReader` and the `MmapFileReader`
+struct ArrowFile<S: FileSource> {
+    /// The contents of the file
+    source: S,
+
+    /// The schema that is read from the file footer
     schema: SchemaRef,
 
-    /// The blocks in the file
-    ///
-    /// A block indicates the regions in the file to read to get data
+    /// The blocks of the record batches in the file
     blocks: Vec<ipc::Block>,
 
     /// A counter to keep track of the current block that should be read
     current_block: usize,
 
-    /// The total number of blocks, which may contain record batches and other types
-    total_blocks: usize,
-
-    /// Optional dictionaries for each schema field.
-    ///
-    /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    /// The values of the dictionaries, by dictionary id
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
 
     /// Metadata version
     metadata_version: ipc::MetadataVersion,
+
+    /// Whether record batches are fully validated when they are read
+    full_validation: bool,
 }
 
-impl<R: Read + Seek> FileReader<R> {
-    /// Try to create a new file reader
-    ///
-    /// Returns errors if the file does not meet the Arrow Format header and footer
-    /// requirements
-    pub fn try_new(reader: R) -> Result<Self> {
-        let mut reader = BufReader::new(reader);
-        // check if header and footer contain correct magic bytes
-        let mut magic_buffer: [u8; 6] = [0; 6];
-        reader.read_exact(&mut magic_buffer)?;
-        if magic_buffer != super::ARROW_MAGIC {
+impl<S: FileSource> ArrowFile<S> {
+    /// Reads the footer and the dictionaries of a file, checking that they lie within
+    /// the file. If `full_validation` is set, the dictionaries and record batches read
+    /// are checked with `ArrayData::validate_full`.
+    fn try_new(mut source: S, full_validation: bool) -> Result<Self> {
+        let len = source.file_len();
+        if len < 16 || source.read_at(0, 6)?.data() != super::ARROW_MAGIC {
             return Err(ArrowError::IoError(
                 "Arrow file does not contain correct header".to_string(),
             ));
         }
-        reader.seek(SeekFrom::End(-6))?;
-        reader.read_exact(&mut magic_buffer)?;
-        if magic_buffer != super::ARROW_MAGIC {
+        let trailer = source.read_at(len - 10, 10)?;
+        if trailer.data()[4..] != super::ARROW_MAGIC {
             return Err(ArrowError::IoError(
                 "Arrow file does not contain correct footer".to_string(),
             ));
         }
-        // read footer length
-        let mut footer_size: [u8; 4] = [0; 4];
-        reader.seek(SeekFrom::End(-10))?;
-        reader.read_exact(&mut footer_size)?;
-        let footer_len = i32::from_le_bytes(footer_size);
-
-        // read footer
-        let mut footer_data = vec![0; footer_len as usize];
-        reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
-        reader.read_exact(&mut footer_data)?;
-        let footer = ipc::get_root_as_footer(&footer_data[..]);
+        let footer_len = i32::from_le_bytes(trailer.data()[..4].try_into().unwrap());
+        if footer_len < 0 || footer_len as usize > len - 16 {
+            return Err(ArrowError::IoError(format!(
+                "Arrow file footer of {} bytes exceeds the file of {} bytes",
+                footer_len, len
+            )));
+        }
+        // flatbuffers reads the footer in place, so it is copied to aligned memory
+        let footer_data = source
+            .read_at(len - 10 - footer_len as usize, footer_len as usize)?
+            .data()
+            .to_vec();
+        let footer = ipc::get_root_as_footer(&footer_data);
 
         let blocks = footer.recordBatches().ok_or_else(|| {
             ArrowError::IoError(
                 "Unable to get record batches from IPC Footer".to_string(),
             )
         })?;
-
-        let total_blocks = blocks.len();
-
-        let ipc_schema = footer.schema().unwrap();
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
         let schema = ipc::convert::fb_to_schema(ipc_schema);
 
-        // Create an array of optional dictionary value arrays, one per field.
-        let mut dictionaries_by_field = vec![None; schema.fields().len()];
-        for block in footer.dictionaries().unwrap() {
-            // read length from end of offset
-            // TODO: ARROW-9848: dictionary metadata has not been tested
-            let meta_len = block.metaDataLength() - 4;
-
-            let mut block_data = vec![0; meta_len as usize];
-            reader.seek(SeekFrom::Start(block.offset() as u64 + 4))?;
-            reader.read_exact(&mut block_data)?;
-
-            let message = ipc::get_root_as_message(&block_data[..]);
-
-            match message.header_type() {
-                ipc::MessageHeader::DictionaryBatch => {
-                    let batch = message.header_as_dictionary_batch().unwrap();
-
-                    // read the block that makes up the dictionary batch into a buffer
-                    let mut buf = vec![0; block.bodyLength() as usize];
-                    reader.seek(SeekFrom::Start(
-                        block.offset() as u64 + block.metaDataLength() as u64,
-                    ))?;
-                    reader.read_exact(&mut buf)?;
-
-                    read_dictionary(
-                        &buf,
-                        batch,
-                        &ipc_schema,
-                        &schema,
-                        &mut dictionaries_by_field,
-                    )?;
-                }
-                _ => {
-                    return Err(ArrowError::IoError(
-                        "Expecting DictionaryBatch in dictionary blocks.".to_string(),
-                    ))
-                }
-            };
+        let mut dictionaries_by_id = HashMap::new();
+        for block in footer.dictionaries().unwrap_or(&[]) {
+            let (metadata, body) = read_block(&mut source, block)?;
+            let message = ipc::get_root_as_message(&metadata);
+            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                ArrowError::IoError(
+                    "Expecting DictionaryBatch in dictionary blocks.".to_string(),
+                )
+            })?;
+            read_file_dictionary(
+                S::body(&body),
+                batch,
+                &schema,
+                &mut dictionaries_by_id,
+                full_validation,
+            )?;
         }
 
         Ok(Self {
-            reader,
+            source,
             schema: Arc::new(schema),
             blocks: blocks.to_vec(),
             current_block: 0,
-            total_blocks,
-            dictionaries_by_field,
+            dictionaries_by_id,
             metadata_version: footer.version(),
+            full_validation,
         })
     }
 
-    /// Return the number of batches in the file
-    pub fn num_batches(&self) -> usize {
-        self.total_blocks
-    }
-
-    /// Return the schema of the file
-    pub fn schema(&self) -> SchemaRef {
-        self.schema.clone()
-    }
-
-    /// Read a specific record batch
-    ///
-    /// Sets the current block to the index, allowing random reads
-    pub fn set_index(&mut self, index: usize) -> Result<()> {
-        if index >= self.total_blocks {
+    fn set_index(&mut self, index: usize) -> Result<()> {
+        if index >= self.blocks.len() {
             Err(ArrowError::IoError(format!(
                 "Cannot set batch to index {} from {} total batches",
-                index, self.total_blocks
+                index,
+                self.blocks.len()
             )))
         } else {
             self.current_block = index;
@@ -671,24 +1323,20 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    fn next(&mut self) -> Option<Result<RecordBatch>> {
+        if self.current_block < self.blocks.len() {
+            self.maybe_next().transpose()
+        } else {
+            None
+        }
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>> {
         let block = self.blocks[self.current_block];
         self.current_block += 1;
 
-        // read length
-        self.reader.seek(SeekFrom::Start(block.offset() as u64))?;
-        let mut meta_buf = [0; 4];
-        self.reader.read_exact(&mut meta_buf)?;
-        if meta_buf == CONTINUATION_MARKER {
-            // continuation marker encountered, read message next
-            self.reader.read_exact(&mut meta_buf)?;
-        }
-        let meta_len = i32::from_le_bytes(meta_buf);
-
-        let mut block_data = vec![0; meta_len as usize];
-        self.reader.read_exact(&mut block_data)?;
-
-        let message = ipc::get_root_as_message(&block_data[..]);
+        let (metadata, body) = read_block(&mut self.source, &block)?;
+        let message = ipc::get_root_as_message(&metadata);
 
         // some old test data's footer metadata is not set, so we account for that
         if self.metadata_version != ipc::MetadataVersion::V1
@@ -709,49 +1357,260 @@ impl<R: Read + Seek> FileReader<R> {
                         "Unable to read IPC message as record batch".to_string(),
                     )
                 })?;
-                // read the block that makes up the record batch into a buffer
-                let mut buf = vec![0; block.bodyLength() as usize];
-                self.reader.seek(SeekFrom::Start(
-                    block.offset() as u64 + block.metaDataLength() as u64,
-                ))?;
-                self.reader.read_exact(&mut buf)?;
-
-                read_record_batch(
-                    &buf,
+                let batch = read_record_batch_body(
+                    S::body(&body),
                     batch,
-                    self.schema(),
-                    &self.dictionaries_by_field,
-                ).map(Some)
-            }
-            ipc::MessageHeader::NONE => {
-                Ok(None)
+                    self.schema.clone(),
+                    &self.dictionaries_by_id,
+                    self.full_validation,
+                )?;
+                Ok(Some(batch))
             }
+            ipc::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::IoError(format!(
-                "Reading types other than record batches not yet supported, unable to read {:?}", t
+                "Reading types other than record batches not yet supported, unable to \
+                 read {:?}",
+                t
             ))),
         }
     }
 }
 
+/// Reads the message in a block of a file, returning its metadata copied to aligned
+/// memory for flatbuffers, and its body
+fn read_block<S: FileSource>(
+    source: &mut S,
+    block: &ipc::Block,
+) -> Result<(Vec<u8>, Buffer)> {
+    let file_len = source.file_len();
+    let body_start = block.offset().checked_add(block.metaDataLength() as i64);
+    let body_end = body_start.and_then(|start| start.checked_add(block.bodyLength()));
+    let (start, body_start, body_end) = match (body_start, body_end) {
+        (Some(body_start), Some(body_end))
+            if block.offset() >= 0
+                && block.metaDataLength() >= 8
+                && block.bodyLength() >= 0
+                && body_end <= file_len as i64 =>
+        {
+            (
+                block.offset() as usize,
+                body_start as usize,
+                body_end as usize,
+            )
+        }
+        _ => {
+            return Err(ArrowError::IoError(format!(
+                "Block at offset {} is out of the bounds of the file of {} bytes",
+                block.offset(),
+                file_len
+            )))
+        }
+    };
+
+    // the metadata length follows a continuation marker, except in the legacy format
+    let prefix = source.read_at(start, 8)?;
+    let (meta_start, meta_len) = if prefix.data()[..4] == CONTINUATION_MARKER {
+        (start + 8, &prefix.data()[4..8])
+    } else {
+        (start + 4, &prefix.data()[..4])
+    };
+    let meta_len = i32::from_le_bytes(meta_len.try_into().unwrap());
+    if meta_len < 0 || meta_start + meta_len as usize > body_start {
+        return Err(ArrowError::IoError(format!(
+            "Message metadata of {} bytes exceeds its block at offset {}",
+            meta_len,
+            block.offset()
+        )));
+    }
+    let metadata = source
+        .read_at(meta_start, meta_len as usize)?
+        .data()
+        .to_vec();
+    let body = source.read_at(body_start, body_end - body_start)?;
+    Ok((metadata, body))
+}
+
+/// Arrow File reader
+pub struct FileReader<R: Read + Seek> {
+    file: ArrowFile<SeekableFile<R>>,
+}
+
+impl<R: Read + Seek> FileReader<R> {
+    /// Try to create a new file reader
+    ///
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements
+    pub fn try_new(reader: R) -> Result<Self> {
+        Self::try_new_with_validation(reader, false)
+    }
+
+    /// Try to create a new file reader, which checks the data of the dictionaries and
+    /// of every record batch read with `ArrayData::validate_full` before arrays are
+    /// created from it if `full_validation` is set. It then fails with an error
+    /// instead of returning arrays whose offsets, lengths or values are invalid.
+    ///
+    /// This should be enabled when reading files from untrusted sources.
+    pub fn try_new_with_validation(reader: R, full_validation: bool) -> Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let len = reader.seek(SeekFrom::End(0))?;
+        let len = usize::try_from(len).map_err(|_| {
+            ArrowError::IoError(format!("Arrow file of {} bytes is too large", len))
+        })?;
+        Ok(Self {
+            file: ArrowFile::try_new(SeekableFile { reader, len }, full_validation)?,
+        })
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.file.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.file.schema.clone()
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<()> {
+        self.file.set_index(index)
+    }
+}
+
 impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // get current block
-        if self.current_block < self.total_blocks {
-            self.maybe_next().transpose()
-        } else {
-            None
-        }
+        self.file.next()
     }
 }
 
 impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
     fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.file.schema.clone()
+    }
+}
+
+/// Arrow File reader over a file held in memory, such as a memory mapped file
+///
+/// Unlike the `FileReader`, which copies the buffers of the record batches it reads,
+/// the arrays read share the memory of the file wherever their buffers are aligned,
+/// and keep it alive for as long as they are used. Reading a record batch thus only
+/// costs parsing its metadata, and the pages of a mapped file are only loaded when the
+/// arrays are accessed.
+pub struct MmapFileReader {
+    file: ArrowFile<Buffer>,
+}
+
+impl MmapFileReader {
+    /// Memory map `file`, and try to create a reader of it. Requires the `mmap` feature.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is mapped, that is until the reader and
+    /// all the arrays read from it are dropped, as this would change the memory of the
+    /// arrays while they are used.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn try_new(file: &File) -> Result<Self> {
+        Self::try_new_with_validation(file, false)
+    }
+
+    /// Memory map `file`, and try to create a reader of it which checks the data read
+    /// if `full_validation` is set, see `FileReader::try_new_with_validation`.
+    /// Requires the `mmap` feature.
+    ///
+    /// # Safety
+    ///
+    /// See `MmapFileReader::try_new`
+    #[cfg(feature = "mmap")]
+    pub unsafe fn try_new_with_validation(
+        file: &File,
+        full_validation: bool,
+    ) -> Result<Self> {
+        let mmap = memmap2::Mmap::map(file)?;
+        Self::try_new_from_buffer_with_validation(
+            Buffer::from_owner(mmap),
+            full_validation,
+        )
+    }
+
+    /// Try to create a reader of the file held in `data`
+    ///
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements
+    pub fn try_new_from_buffer(data: Buffer) -> Result<Self> {
+        Self::try_new_from_buffer_with_validation(data, false)
+    }
+
+    /// Try to create a reader of the file held in `data`, which checks the data of the
+    /// dictionaries and of every record batch read with `ArrayData::validate_full`
+    /// if `full_validation` is set, see `FileReader::try_new_with_validation`
+    pub fn try_new_from_buffer_with_validation(
+        data: Buffer,
+        full_validation: bool,
+    ) -> Result<Self> {
+        Ok(Self {
+            file: ArrowFile::try_new(data, full_validation)?,
+        })
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.file.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.file.schema.clone()
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<()> {
+        self.file.set_index(index)
+    }
+}
+
+impl Iterator for MmapFileReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.file.next()
+    }
+}
+
+impl RecordBatchReader for MmapFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.file.schema.clone()
     }
 }
 
+/// Reads the `len` bytes of the metadata or body of a stream message. The buffer grows
+/// as the bytes are read rather than being allocated up front, as the length read from
+/// the stream may exceed the data left in it.
+fn read_stream_bytes<R: Read>(reader: &mut R, len: i64, name: &str) -> Result<Vec<u8>> {
+    if len < 0 {
+        return Err(ArrowError::IoError(format!(
+            "IPC message {} has a negative length {}",
+            name, len
+        )));
+    }
+    let mut buf = vec![];
+    reader.by_ref().take(len as u64).read_to_end(&mut buf)?;
+    if (buf.len() as u64) < len as u64 {
+        return Err(ArrowError::IoError(format!(
+            "IPC message {} of {} bytes ends after {} bytes",
+            name,
+            len,
+            buf.len()
+        )));
+    }
+    Ok(buf)
+}
+
 /// Arrow Stream reader
 pub struct StreamReader<R: Read> {
     /// Buffered stream reader
@@ -760,20 +1619,18 @@ pub struct StreamReader<R: Read> {
     /// The schema that is read from the stream's first message
     schema: SchemaRef,
 
-    /// The bytes of the IPC schema that is read from the stream's first message
-    ///
-    /// This is kept in order to interpret dictionary data
-    ipc_schema: Vec<u8>,
-
-    /// Optional dictionaries for each schema field.
+    /// The values of the dictionaries, by dictionary id
     ///
-    /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    /// Dictionaries are read from the stream before the record batches using them.
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
 
     /// An indicator of whether the stream is complete.
     ///
     /// This value is set to `true` the first time the reader's `next()` returns `None`.
     finished: bool,
+
+    /// Whether record batches are fully validated when they are read
+    full_validation: bool,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -796,8 +1653,7 @@ impl<R: Read> StreamReader<R> {
             i32::from_le_bytes(meta_size)
         };
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        reader.read_exact(&mut meta_buffer)?;
+        let meta_buffer = read_stream_bytes(&mut reader, meta_len as i64, "metadata")?;
 
         let message = ipc::get_root_as_message(meta_buffer.as_slice());
         // message header is a Schema, so read it
@@ -806,18 +1662,25 @@ impl<R: Read> StreamReader<R> {
         })?;
         let schema = ipc::convert::fb_to_schema(ipc_schema);
 
-        // Create an array of optional dictionary value arrays, one per field.
-        let dictionaries_by_field = vec![None; schema.fields().len()];
-
         Ok(Self {
             reader,
             schema: Arc::new(schema),
-            ipc_schema: meta_buffer,
             finished: false,
-            dictionaries_by_field,
+            dictionaries_by_id: HashMap::new(),
+            full_validation: false,
         })
     }
 
+    /// Sets whether the data of every record batch and dictionary read is checked with
+    /// `ArrayData::validate_full` before arrays are created from it, failing with an
+    /// error instead of returning batches whose offsets, lengths or values are invalid.
+    ///
+    /// This should be enabled when reading streams from untrusted sources.
+    pub fn with_full_validation(mut self, full_validation: bool) -> Self {
+        self.full_validation = full_validation;
+        self
+    }
+
     /// Return the schema of the stream
     pub fn schema(&self) -> SchemaRef {
         self.schema.clone()
@@ -865,11 +1728,9 @@ impl<R: Read> StreamReader<R> {
             return Ok(None);
         }
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        self.reader.read_exact(&mut meta_buffer)?;
-
-        let vecs = &meta_buffer.to_vec();
-        let message = ipc::get_root_as_message(vecs);
+        let meta_buffer =
+            read_stream_bytes(&mut self.reader, meta_len as i64, "metadata")?;
+        let message = ipc::get_root_as_message(&meta_buffer);
 
         match message.header_type() {
             ipc::MessageHeader::Schema => Err(ArrowError::IoError(
@@ -882,10 +1743,17 @@ impl<R: Read> StreamReader<R> {
                     )
                 })?;
                 // read the block that makes up the record batch into a buffer
-                let mut buf = vec![0; message.bodyLength() as usize];
-                self.reader.read_exact(&mut buf)?;
+                let buf =
+                    read_stream_bytes(&mut self.reader, message.bodyLength(), "body")?;
 
-                read_record_batch(&buf, batch, self.schema(), &self.dictionaries_by_field).map(Some)
+                let batch = read_record_batch_body(
+                    MessageBody::Bytes(&buf),
+                    batch,
+                    self.schema(),
+                    &self.dictionaries_by_id,
+                    self.full_validation,
+                )?;
+                Ok(Some(batch))
             }
             ipc::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().ok_or_else(|| {
@@ -894,18 +1762,15 @@ impl<R: Read> StreamReader<R> {
                     )
                 })?;
                 // read the block that makes up the dictionary batch into a buffer
-                let mut buf = vec![0; message.bodyLength() as usize];
-                self.reader.read_exact(&mut buf)?;
-
-                let ipc_schema = ipc::get_root_as_message(&self.ipc_schema).header_as_schema()
-                .ok_or_else(|| {
-                    ArrowError::IoError(
-                        "Unable to read schema from stored message header".to_string(),
-                    )
-                })?;
+                let buf =
+                    read_stream_bytes(&mut self.reader, message.bodyLength(), "body")?;
 
                 read_dictionary(
-                    &buf, batch, &ipc_schema, &self.schema, &mut self.dictionaries_by_field
+                    MessageBody::Bytes(&buf),
+                    batch,
+                    &self.schema,
+                    &mut self.dictionaries_by_id,
+                    self.full_validation,
                 )?;
 
                 // read the next message until we encounter a RecordBatch
@@ -1054,6 +1919,363 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_stream_full_validation() {
+        use crate::datatypes::ToByteSlice;
+
+        // a string array whose second value is not valid UTF-8
+        let data = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from(&[0i32, 2, 4].to_byte_slice()))
+            .add_buffer(Buffer::from(&[b'o', b'k', 0xff, 0xfe]))
+            .build();
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(StringArray::from(data)) as ArrayRef],
+        )
+        .unwrap();
+
+        let mut bytes = vec![];
+        {
+            let mut writer =
+                crate::ipc::writer::StreamWriter::try_new(&mut bytes, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(&bytes)).unwrap();
+        assert_eq!(2, reader.next().unwrap().unwrap().num_rows());
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(&bytes))
+            .unwrap()
+            .with_full_validation(true);
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(error.to_string().contains("not valid UTF-8"), "{}", error);
+    }
+
+    #[test]
+    fn test_file_full_validation() {
+        use crate::datatypes::{Int8Type, ToByteSlice};
+
+        // a list array whose last offset is beyond its 2 values, and a dictionary
+        // array whose second key is beyond its single value
+        let list_type = DataType::List(Box::new(DataType::Int32));
+        let list = ArrayData::builder(list_type.clone())
+            .len(2)
+            .add_buffer(Buffer::from(&[0i32, 1, 5].to_byte_slice()))
+            .add_child_data(Int32Array::from(vec![1, 2]).data())
+            .build();
+        let dictionary_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let dictionary = ArrayData::builder(dictionary_type.clone())
+            .len(2)
+            .add_buffer(Buffer::from(&[0i8, 7].to_byte_slice()))
+            .add_child_data(StringArray::from(vec!["a"]).data())
+            .build();
+
+        for (data_type, data) in vec![(list_type, list), (dictionary_type, dictionary)] {
+            let schema = Schema::new(vec![Field::new("a", data_type, false)]);
+            let array = match data.data_type() {
+                DataType::List(_) => Arc::new(ListArray::from(data)) as ArrayRef,
+                _ => Arc::new(DictionaryArray::<Int8Type>::from(data)) as ArrayRef,
+            };
+            let batch =
+                RecordBatch::try_new(Arc::new(schema.clone()), vec![array]).unwrap();
+
+            let mut bytes = vec![];
+            {
+                let mut writer =
+                    crate::ipc::writer::FileWriter::try_new(&mut bytes, &schema).unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+            }
+
+            let mut reader =
+                FileReader::try_new_with_validation(std::io::Cursor::new(&bytes), true)
+                    .unwrap();
+            let error = reader.next().unwrap().unwrap_err();
+            assert!(error.to_string().contains("is not within"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_file_dictionary_validation() {
+        use crate::datatypes::{Int8Type, ToByteSlice};
+
+        // a dictionary whose second value is not valid UTF-8
+        let values = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from(&[0i32, 2, 4].to_byte_slice()))
+            .add_buffer(Buffer::from(&[b'o', b'k', 0xff, 0xfe]))
+            .build();
+        let dictionary_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let dictionary = ArrayData::builder(dictionary_type.clone())
+            .len(2)
+            .add_buffer(Buffer::from(&[0i8, 1].to_byte_slice()))
+            .add_child_data(values)
+            .build();
+        let schema = Schema::new(vec![Field::new("a", dictionary_type, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(DictionaryArray::<Int8Type>::from(dictionary)) as ArrayRef],
+        )
+        .unwrap();
+        let mut bytes = vec![];
+        {
+            let mut writer =
+                crate::ipc::writer::FileWriter::try_new(&mut bytes, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        // the dictionaries are only validated when validation is enabled
+        assert!(FileReader::try_new(std::io::Cursor::new(&bytes)).is_ok());
+        let error =
+            FileReader::try_new_with_validation(std::io::Cursor::new(&bytes), true)
+                .err()
+                .unwrap();
+        assert!(error.to_string().contains("not valid UTF-8"), "{}", error);
+
+        let data = Buffer::from(&bytes);
+        assert!(MmapFileReader::try_new_from_buffer(data.clone()).is_ok());
+        let error = MmapFileReader::try_new_from_buffer_with_validation(data, true)
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("not valid UTF-8"), "{}", error);
+    }
+
+    #[test]
+    fn test_read_malformed_record_batch() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef],
+        )
+        .unwrap();
+        let encoded = crate::ipc::writer::record_batch_to_bytes(
+            &batch,
+            &crate::ipc::writer::IpcWriteOptions::default(),
+        );
+        let message = ipc::get_root_as_message(&encoded.ipc_message);
+        let ipc_batch = message.header_as_record_batch().unwrap();
+
+        // schemas that do not match the nodes and buffers of the message
+        let schemas = vec![
+            (
+                Schema::new(vec![
+                    Field::new("a", DataType::Int32, false),
+                    Field::new("b", DataType::Int32, false),
+                ]),
+                "while its schema requires 2 and 4",
+            ),
+            (
+                Schema::new(vec![Field::new("a", DataType::Utf8, false)]),
+                "while its schema requires 1 and 3",
+            ),
+            (
+                Schema::new(vec![Field::new(
+                    "a",
+                    DataType::Union(vec![Field::new("b", DataType::Int32, false)]),
+                    false,
+                )]),
+                "is not supported",
+            ),
+        ];
+        for (schema, expected) in schemas {
+            let error = read_record_batch(
+                &encoded.arrow_data,
+                ipc_batch,
+                Arc::new(schema),
+                &HashMap::new(),
+            )
+            .unwrap_err();
+            assert!(error.to_string().contains(expected), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_read_invalid_file_lengths() {
+        let (_, bytes) = write_test_file();
+        let len = bytes.len();
+        for footer_len in &[-1i32, len as i32, i32::MAX] {
+            let mut invalid = bytes.clone();
+            invalid[len - 10..len - 6].copy_from_slice(&footer_len.to_le_bytes());
+            let err = FileReader::try_new(std::io::Cursor::new(invalid))
+                .err()
+                .unwrap();
+            assert!(err.to_string().contains("exceeds the file"), "{}", err);
+        }
+        let err = FileReader::try_new(std::io::Cursor::new(&bytes[..20]))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("correct footer"), "{}", err);
+    }
+
+    #[test]
+    fn test_read_invalid_stream_lengths() {
+        let (batches, _) = write_test_file();
+        let mut bytes = vec![];
+        {
+            let mut writer = crate::ipc::writer::StreamWriter::try_new(
+                &mut bytes,
+                &batches[0].schema(),
+            )
+            .unwrap();
+            writer.write(&batches[0]).unwrap();
+            writer.finish().unwrap();
+        }
+
+        // metadata lengths that are negative or exceed the stream
+        for meta_len in &[-2i32, i32::MAX] {
+            let mut invalid = CONTINUATION_MARKER.to_vec();
+            invalid.extend_from_slice(&meta_len.to_le_bytes());
+            invalid.extend_from_slice(&bytes[8..]);
+            let err = StreamReader::try_new(std::io::Cursor::new(invalid))
+                .err()
+                .unwrap();
+            assert!(err.to_string().contains("IPC message metadata"), "{}", err);
+        }
+
+        // a stream that ends within the body of its record batch
+        let truncated = &bytes[..bytes.len() - 16];
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(truncated)).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("IPC message body"), "{}", err);
+    }
+
+    /// Write a file of two batches, with a primitive, a string and a dictionary column
+    fn write_test_file() -> (Vec<RecordBatch>, Vec<u8>) {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![
+            Field::new("ints", DataType::Int64, true),
+            Field::new("strings", DataType::Utf8, false),
+            Field::new_dict("dict", dict_type, false, 0, false),
+        ]);
+        let batches: Vec<RecordBatch> = (0..2)
+            .map(|i| {
+                let dict: Int8DictionaryArray = vec!["a", "b", "a"].into_iter().collect();
+                RecordBatch::try_new(
+                    Arc::new(schema.clone()),
+                    vec![
+                        Arc::new(Int64Array::from(vec![Some(i), None, Some(3)])),
+                        Arc::new(StringArray::from(vec!["x", "yy", "zzz"])),
+                        Arc::new(dict),
+                    ],
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let mut bytes = vec![];
+        {
+            let mut writer =
+                crate::ipc::writer::FileWriter::try_new(&mut bytes, &schema).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        (batches, bytes)
+    }
+
+    /// Returns whether the buffer lies within the memory of `data`
+    fn shares_memory(buffer: &Buffer, data: &Buffer) -> bool {
+        let start = data.raw_data() as usize;
+        let ptr = buffer.raw_data() as usize;
+        ptr >= start && ptr + buffer.len() <= start + data.len()
+    }
+
+    #[test]
+    fn test_read_file_from_buffer() {
+        let (batches, bytes) = write_test_file();
+        let data = Buffer::from(&bytes);
+
+        let mut reader = MmapFileReader::try_new_from_buffer(data.clone()).unwrap();
+        assert_eq!(2, reader.num_batches());
+        assert_eq!(batches[0].schema(), reader.schema());
+        reader.set_index(1).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        for (a, b) in read.columns().iter().zip(batches[1].columns()) {
+            assert!(a.equals(b.as_ref()));
+        }
+        assert!(reader.next().is_none());
+
+        // the buffers and dictionaries share the memory of the file
+        let ints = read.column(0).data();
+        assert!(shares_memory(&ints.buffers()[0], &data));
+        let strings = read.column(1).data();
+        assert!(shares_memory(&strings.buffers()[1], &data));
+        let dict = read.column(2).data();
+        assert!(shares_memory(&dict.child_data()[0].buffers()[1], &data));
+
+        // the arrays keep the memory alive
+        drop(reader);
+        drop(data);
+        assert!(read.column(1).equals(batches[1].column(1).as_ref()));
+    }
+
+    #[test]
+    fn test_read_unaligned_file_from_buffer() {
+        let (batches, bytes) = write_test_file();
+        let mut unaligned = vec![0u8];
+        unaligned.extend_from_slice(&bytes);
+        let data = Buffer::from(&unaligned).slice(1);
+
+        // unaligned buffers are copied
+        let reader = MmapFileReader::try_new_from_buffer(data.clone()).unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(2, read_batches.len());
+        for (read, batch) in read_batches.iter().zip(&batches) {
+            for (a, b) in read.columns().iter().zip(batch.columns()) {
+                assert!(a.equals(b.as_ref()));
+            }
+        }
+        assert!(!shares_memory(
+            &read_batches[0].column(0).data().buffers()[0],
+            &data
+        ));
+    }
+
+    #[test]
+    fn test_read_invalid_file_from_buffer() {
+        let (_, bytes) = write_test_file();
+        let err = MmapFileReader::try_new_from_buffer(Buffer::from(&bytes[..20]))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("correct footer"));
+
+        // a footer length past the start of the file
+        let mut invalid = bytes;
+        let len = invalid.len();
+        invalid[len - 10..len - 6].copy_from_slice(&(len as i32).to_le_bytes());
+        let err = MmapFileReader::try_new_from_buffer(Buffer::from(&invalid))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("exceeds the file"));
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn test_read_mmap_file() {
+        use std::io::Write;
+
+        let (batches, bytes) = write_test_file();
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&bytes).unwrap();
+
+        let reader = unsafe { MmapFileReader::try_new(&file) }.unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(2, read_batches.len());
+        for (read, batch) in read_batches.iter().zip(&batches) {
+            for (a, b) in read.columns().iter().zip(batch.columns()) {
+                assert!(a.equals(b.as_ref()));
+            }
+        }
+    }
+
     /// Read gzipped JSON file
     fn read_gzip_json(path: &str) -> ArrowJson {
         let testdata = env::var("ARROW_TEST_DATA").expect("ARROW_TEST_DATA not defined");
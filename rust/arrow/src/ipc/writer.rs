@@ -20,16 +20,24 @@
 //! The `FileWriter` and `StreamWriter` have similar interfaces,
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
+use std::collections::HashMap;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 
-use flatbuffers::FlatBufferBuilder;
+use flatbuffers::{
+    FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset,
+};
 
-use crate::array::ArrayDataRef;
+use crate::array::{make_array, ArrayDataRef, ArrayRef, UInt32Array};
 use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::ipc;
+use crate::ipc::compression;
 use crate::record_batch::RecordBatch;
+use crate::sparse_tensor::{CompressedAxis, SparseCOOTensor, SparseCSXMatrix};
+use crate::tensor::Tensor;
 use crate::util::bit_util;
 
 use ipc::CONTINUATION_MARKER;
@@ -44,6 +52,11 @@ pub struct IpcWriteOptions {
     write_legacy_ipc_format: bool,
     /// The metadata version to write. The Rust IPC writer supports V4+
     metadata_version: ipc::MetadataVersion,
+    /// Whether to write the new values of a grown dictionary as a delta, instead of
+    /// writing the whole dictionary again
+    emit_dictionary_deltas: bool,
+    /// The codec used to compress the buffers of record batches, if any
+    compression: Option<ipc::CompressionType>,
 }
 
 impl IpcWriteOptions {
@@ -68,6 +81,8 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                emit_dictionary_deltas: false,
+                compression: None,
             }),
             ipc::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -80,11 +95,42 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        emit_dictionary_deltas: false,
+                        compression: None,
                     })
                 }
             }
         }
     }
+
+    /// Set whether a dictionary whose values are extended by a record batch is written
+    /// as a delta dictionary batch, containing only the new values. Defaults to false,
+    /// which writes the whole dictionary again.
+    pub fn with_dictionary_deltas(mut self, emit_dictionary_deltas: bool) -> Self {
+        self.emit_dictionary_deltas = emit_dictionary_deltas;
+        self
+    }
+
+    /// Try to set the codec used to compress the buffers of record batches and
+    /// dictionaries. Compression requires metadata version 5, and the
+    /// `ipc_compression` feature.
+    pub fn try_with_compression(
+        mut self,
+        compression: Option<ipc::CompressionType>,
+    ) -> Result<Self> {
+        if let Some(codec) = compression {
+            if self.metadata_version < ipc::MetadataVersion::V5 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Compression is only supported on metadata version 5".to_string(),
+                ));
+            }
+            if !compression::is_supported() {
+                return Err(compression::unsupported(codec));
+            }
+        }
+        self.compression = compression;
+        Ok(self)
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -93,6 +139,8 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: true,
             metadata_version: ipc::MetadataVersion::V4,
+            emit_dictionary_deltas: false,
+            compression: None,
         }
     }
 }
@@ -110,6 +158,8 @@ pub struct FileWriter<W: Write> {
     dictionary_blocks: Vec<ipc::Block>,
     /// Record blocks that will be written as part of the IPC footer
     record_blocks: Vec<ipc::Block>,
+    /// The dictionaries written to the file
+    dictionary_tracker: DictionaryTracker,
     /// Whether the writer footer has been written, and the writer is finished
     finished: bool,
 }
@@ -133,8 +183,8 @@ impl<W: Write> FileWriter<W> {
         // create an 8-byte boundary after the header
         writer.write_all(&[0, 0])?;
         // write the schema, set the written bytes to the schema + header
-        let message = Message::Schema(schema, &write_options);
-        let (meta, data) = write_message(&mut writer, &message, &write_options)?;
+        let encoded = schema_to_bytes(schema, &write_options);
+        let (meta, data) = write_encoded_message(&mut writer, encoded, &write_options)?;
         Ok(Self {
             writer,
             write_options,
@@ -142,6 +192,7 @@ impl<W: Write> FileWriter<W> {
             block_offsets: meta + data + 8,
             dictionary_blocks: vec![],
             record_blocks: vec![],
+            dictionary_tracker: DictionaryTracker::new(true),
             finished: false,
         })
     }
@@ -153,9 +204,19 @@ impl<W: Write> FileWriter<W> {
                 "Cannot write record batch to file writer as it is closed".to_string(),
             ));
         }
-        let message = Message::RecordBatch(batch, &self.write_options);
+        let (encoded_dictionaries, encoded_batch) =
+            encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)?;
+        for encoded in encoded_dictionaries {
+            let (meta, data) =
+                write_encoded_message(&mut self.writer, encoded, &self.write_options)?;
+            // add a dictionary block for the footer
+            let block =
+                ipc::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+            self.dictionary_blocks.push(block);
+            self.block_offsets += meta + data;
+        }
         let (meta, data) =
-            write_message(&mut self.writer, &message, &self.write_options)?;
+            write_encoded_message(&mut self.writer, encoded_batch, &self.write_options)?;
         // add a record block for the footer
         let block = ipc::Block::new(
             self.block_offsets as i64,
@@ -175,44 +236,7 @@ impl<W: Write> FileWriter<W> {
         let mut fbb = FlatBufferBuilder::new();
         let dictionaries = fbb.create_vector(&self.dictionary_blocks);
         let record_batches = fbb.create_vector(&self.record_blocks);
-        // TODO: this is duplicated as we otherwise mutably borrow twice
-        let schema = {
-            let mut fields = vec![];
-            for field in self.schema.fields() {
-                let fb_field_name = fbb.create_string(field.name().as_str());
-                let field_type =
-                    ipc::convert::get_fb_field_type(field.data_type(), &mut fbb);
-                let mut field_builder = ipc::FieldBuilder::new(&mut fbb);
-                field_builder.add_name(fb_field_name);
-                field_builder.add_type_type(field_type.type_type);
-                field_builder.add_nullable(field.is_nullable());
-                match field_type.children {
-                    None => {}
-                    Some(children) => field_builder.add_children(children),
-                };
-                field_builder.add_type_(field_type.type_);
-                fields.push(field_builder.finish());
-            }
-
-            let mut custom_metadata = vec![];
-            for (k, v) in self.schema.metadata() {
-                let fb_key_name = fbb.create_string(k.as_str());
-                let fb_val_name = fbb.create_string(v.as_str());
-
-                let mut kv_builder = ipc::KeyValueBuilder::new(&mut fbb);
-                kv_builder.add_key(fb_key_name);
-                kv_builder.add_value(fb_val_name);
-                custom_metadata.push(kv_builder.finish());
-            }
-
-            let fb_field_list = fbb.create_vector(&fields);
-            let fb_metadata_list = fbb.create_vector(&custom_metadata);
-
-            let mut builder = ipc::SchemaBuilder::new(&mut fbb);
-            builder.add_fields(fb_field_list);
-            builder.add_custom_metadata(fb_metadata_list);
-            builder.finish()
-        };
+        let schema = ipc::convert::schema_to_fb_offset(&mut fbb, &self.schema);
         let root = {
             let mut footer_builder = ipc::FooterBuilder::new(&mut fbb);
             footer_builder.add_version(self.write_options.metadata_version);
@@ -250,6 +274,8 @@ pub struct StreamWriter<W: Write> {
     write_options: IpcWriteOptions,
     /// A reference to the schema, used in validating record batches
     schema: Schema,
+    /// The dictionaries written to the stream
+    dictionary_tracker: DictionaryTracker,
     /// Whether the writer footer has been written, and the writer is finished
     finished: bool,
 }
@@ -268,12 +294,13 @@ impl<W: Write> StreamWriter<W> {
     ) -> Result<Self> {
         let mut writer = BufWriter::new(writer);
         // write the schema, set the written bytes to the schema
-        let message = Message::Schema(schema, &write_options);
-        write_message(&mut writer, &message, &write_options)?;
+        let encoded = schema_to_bytes(schema, &write_options);
+        write_encoded_message(&mut writer, encoded, &write_options)?;
         Ok(Self {
             writer,
             write_options,
             schema: schema.clone(),
+            dictionary_tracker: DictionaryTracker::new(false),
             finished: false,
         })
     }
@@ -285,8 +312,12 @@ impl<W: Write> StreamWriter<W> {
                 "Cannot write record batch to stream writer as it is closed".to_string(),
             ));
         }
-        let message = Message::RecordBatch(batch, &self.write_options);
-        write_message(&mut self.writer, &message, &self.write_options)?;
+        let (encoded_dictionaries, encoded_batch) =
+            encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)?;
+        for encoded in encoded_dictionaries {
+            write_encoded_message(&mut self.writer, encoded, &self.write_options)?;
+        }
+        write_encoded_message(&mut self.writer, encoded_batch, &self.write_options)?;
         Ok(())
     }
 
@@ -340,34 +371,191 @@ pub fn schema_to_bytes(schema: &Schema, write_options: &IpcWriteOptions) -> Enco
     }
 }
 
-enum Message<'a> {
-    Schema(&'a Schema, &'a IpcWriteOptions),
-    RecordBatch(&'a RecordBatch, &'a IpcWriteOptions),
-    DictionaryBatch(&'a IpcWriteOptions),
+/// How a dictionary must be written, compared to the values last written for its id
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary was already written, and is not written again
+    Unchanged,
+    /// The whole dictionary is written, either for the first time or replacing the
+    /// values written before
+    Full,
+    /// The dictionary extends the values written before, and the values from the given
+    /// index are written as a delta
+    Delta(usize),
+}
+
+/// Tracks the dictionaries written to a file or stream, by dictionary id
+#[derive(Debug)]
+pub struct DictionaryTracker {
+    written: HashMap<i64, ArrayRef>,
+    error_on_replacement: bool,
+}
+
+impl DictionaryTracker {
+    /// Create a tracker of the dictionaries written to a single file or stream.
+    ///
+    /// Dictionaries that were already written with the same values are not written
+    /// again. A dictionary written with different values for the same id replaces
+    /// the one written before, unless `error_on_replacement` is set, in which case
+    /// [`DictionaryTracker::insert`] returns an error. The IPC file format does not
+    /// support replacing dictionaries, so file writers set it, while stream writers
+    /// and Flight do not.
+    pub fn new(error_on_replacement: bool) -> Self {
+        Self {
+            written: HashMap::new(),
+            error_on_replacement,
+        }
+    }
+
+    /// Records the values of the dictionary with the given id, returning how they must
+    /// be written. Deltas are only returned if `allow_delta` is set.
+    pub fn insert(
+        &mut self,
+        dict_id: i64,
+        values: &ArrayRef,
+        allow_delta: bool,
+    ) -> Result<DictionaryUpdate> {
+        let update = match self.written.get(&dict_id) {
+            None => DictionaryUpdate::Full,
+            Some(written) => {
+                if Arc::ptr_eq(&written.data(), &values.data())
+                    || written.equals(values.as_ref())
+                {
+                    return Ok(DictionaryUpdate::Unchanged);
+                }
+                if allow_delta
+                    && values.len() > written.len()
+                    && values.range_equals(written.as_ref(), 0, written.len(), 0)
+                {
+                    DictionaryUpdate::Delta(written.len())
+                } else if self.error_on_replacement {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Dictionary with id {} was already written with different \
+                         values, and the IPC file format does not support replacing it",
+                        dict_id
+                    )));
+                } else {
+                    DictionaryUpdate::Full
+                }
+            }
+        };
+        self.written.insert(dict_id, values.clone());
+        Ok(update)
+    }
 }
 
-impl<'a> Message<'a> {
-    /// Encode message to a ipc::Message and return data as bytes
-    fn encode(&'a self) -> EncodedData {
-        match self {
-            Message::Schema(schema, options) => schema_to_bytes(*schema, *options),
-            Message::RecordBatch(batch, options) => {
-                record_batch_to_bytes(*batch, *options)
+/// Collects the values of the dictionaries in array data, with their dictionary ids, in
+/// the order they are written. `dict_id` is the dictionary id of the enclosing field,
+/// which is used by dictionaries in the values of lists.
+///
+/// Returns an error for dictionaries whose values contain dictionaries, and for
+/// dictionaries in unions, as their dictionary ids cannot be recorded in the schema.
+fn collect_dictionaries(
+    array_data: &ArrayDataRef,
+    dict_id: i64,
+    dictionaries: &mut Vec<(i64, ArrayRef)>,
+) -> Result<()> {
+    match array_data.data_type() {
+        DataType::Dictionary(_, value_type) => {
+            if contains_dictionary(value_type) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Writing dictionaries with dictionary values of type {:?} is not \
+                     supported",
+                    value_type
+                )));
             }
-            Message::DictionaryBatch(_) => {
-                unimplemented!("Writing dictionary batches not implemented")
+            dictionaries.push((dict_id, make_array(array_data.child_data()[0].clone())))
+        }
+        DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
+            collect_dictionaries(&array_data.child_data()[0], dict_id, dictionaries)?
+        }
+        DataType::Struct(fields) => {
+            for (field, child_data) in fields.iter().zip(array_data.child_data()) {
+                collect_dictionaries(child_data, field.dict_id, dictionaries)?;
             }
         }
+        DataType::Map(entries, _) => collect_dictionaries(
+            &array_data.child_data()[0],
+            entries.dict_id,
+            dictionaries,
+        )?,
+        data_type @ DataType::Union(_) if contains_dictionary(data_type) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Writing dictionaries in unions is not supported, found {:?}",
+                data_type
+            )));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns whether a data type is, or has children that are, dictionaries
+fn contains_dictionary(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, _) => true,
+        DataType::List(value_type)
+        | DataType::LargeList(value_type)
+        | DataType::FixedSizeList(value_type, _) => contains_dictionary(value_type),
+        DataType::Struct(fields) | DataType::Union(fields) => fields
+            .iter()
+            .any(|field| contains_dictionary(field.data_type())),
+        DataType::Map(entries, _) => contains_dictionary(entries.data_type()),
+        _ => false,
     }
 }
 
-/// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
-fn write_message<W: Write>(
+/// Encode a `RecordBatch`, preceded by the dictionaries it uses that were not written
+/// yet according to `dictionary_tracker`
+pub fn encoded_batch(
+    batch: &RecordBatch,
+    dictionary_tracker: &mut DictionaryTracker,
+    write_options: &IpcWriteOptions,
+) -> Result<(Vec<EncodedData>, EncodedData)> {
+    let schema = batch.schema();
+    let mut dictionaries = vec![];
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        collect_dictionaries(&column.data(), field.dict_id, &mut dictionaries)?;
+    }
+
+    let mut encoded_dictionaries = vec![];
+    for (dict_id, values) in dictionaries {
+        let update = dictionary_tracker.insert(
+            dict_id,
+            &values,
+            write_options.emit_dictionary_deltas,
+        )?;
+        match update {
+            DictionaryUpdate::Unchanged => {}
+            DictionaryUpdate::Full => encoded_dictionaries.push(
+                dictionary_batch_to_bytes(dict_id, &values, false, write_options),
+            ),
+            DictionaryUpdate::Delta(offset) => {
+                let indices = UInt32Array::from(
+                    (offset as u32..values.len() as u32).collect::<Vec<_>>(),
+                );
+                let delta = take(&values, &indices, None)?;
+                encoded_dictionaries.push(dictionary_batch_to_bytes(
+                    dict_id,
+                    &delta,
+                    true,
+                    write_options,
+                ));
+            }
+        }
+    }
+    Ok((
+        encoded_dictionaries,
+        record_batch_to_bytes(batch, write_options),
+    ))
+}
+
+/// Write encoded IPC data and buffers, returning metadata and buffer data lengths written
+fn write_encoded_message<W: Write>(
     mut writer: &mut BufWriter<W>,
-    message: &Message,
+    encoded: EncodedData,
     write_options: &IpcWriteOptions,
 ) -> Result<(usize, usize)> {
-    let encoded = message.encode();
     let arrow_data_len = encoded.arrow_data.len();
     if arrow_data_len % 8 != 0 {
         return Err(ArrowError::MemoryError(
@@ -445,25 +633,79 @@ pub fn record_batch_to_bytes(
             offset,
             array.len(),
             array.null_count(),
+            write_options.compression,
         );
     }
 
     // write data
-    let buffers = fbb.create_vector(&buffers);
-    let nodes = fbb.create_vector(&nodes);
+    let root = build_record_batch(
+        &mut fbb,
+        batch.num_rows(),
+        &nodes,
+        &buffers,
+        write_options.compression,
+    )
+    .as_union_value();
+    // create an ipc::Message
+    let mut message = ipc::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(ipc::MessageHeader::RecordBatch);
+    message.add_bodyLength(arrow_data.len() as i64);
+    message.add_header(root);
+    let root = message.finish();
+    fbb.finish(root, None);
+    let finished_data = fbb.finished_data();
+
+    EncodedData {
+        ipc_message: finished_data.to_vec(),
+        arrow_data,
+    }
+}
 
+/// Write the values of a dictionary into a tuple of bytes, one for the header
+/// (ipc::Message) and the other for the dictionary's data. Delta dictionaries only
+/// contain the values appended to the dictionary.
+pub fn dictionary_batch_to_bytes(
+    dict_id: i64,
+    values: &ArrayRef,
+    is_delta: bool,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let mut nodes: Vec<ipc::FieldNode> = vec![];
+    let mut buffers: Vec<ipc::Buffer> = vec![];
+    let mut arrow_data: Vec<u8> = vec![];
+    write_array_data(
+        &values.data(),
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        0,
+        values.len(),
+        values.null_count(),
+        write_options.compression,
+    );
+
+    // write data
+    let root = build_record_batch(
+        &mut fbb,
+        values.len(),
+        &nodes,
+        &buffers,
+        write_options.compression,
+    );
     let root = {
-        let mut batch_builder = ipc::RecordBatchBuilder::new(&mut fbb);
-        batch_builder.add_length(batch.num_rows() as i64);
-        batch_builder.add_nodes(nodes);
-        batch_builder.add_buffers(buffers);
-        let b = batch_builder.finish();
-        b.as_union_value()
+        let mut dictionary_builder = ipc::DictionaryBatchBuilder::new(&mut fbb);
+        dictionary_builder.add_id(dict_id);
+        dictionary_builder.add_data(root);
+        dictionary_builder.add_isDelta(is_delta);
+        dictionary_builder.finish().as_union_value()
     };
     // create an ipc::Message
     let mut message = ipc::MessageBuilder::new(&mut fbb);
     message.add_version(write_options.metadata_version);
-    message.add_header_type(ipc::MessageHeader::RecordBatch);
+    message.add_header_type(ipc::MessageHeader::DictionaryBatch);
     message.add_bodyLength(arrow_data.len() as i64);
     message.add_header(root);
     let root = message.finish();
@@ -476,6 +718,280 @@ pub fn record_batch_to_bytes(
     }
 }
 
+/// Build the ipc::RecordBatch describing the nodes and buffers of a message body
+fn build_record_batch<'a: 'b, 'b>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    length: usize,
+    nodes: &[ipc::FieldNode],
+    buffers: &[ipc::Buffer],
+    compression: Option<ipc::CompressionType>,
+) -> WIPOffset<ipc::RecordBatch<'b>> {
+    let buffers = fbb.create_vector(buffers);
+    let nodes = fbb.create_vector(nodes);
+    let compression = compression.map(|codec| {
+        let mut compression_builder = ipc::BodyCompressionBuilder::new(fbb);
+        compression_builder.add_codec(codec);
+        compression_builder.add_method(ipc::BodyCompressionMethod::BUFFER);
+        compression_builder.finish()
+    });
+
+    let mut batch_builder = ipc::RecordBatchBuilder::new(fbb);
+    batch_builder.add_length(length as i64);
+    batch_builder.add_nodes(nodes);
+    batch_builder.add_buffers(buffers);
+    if let Some(compression) = compression {
+        batch_builder.add_compression(compression);
+    }
+    batch_builder.finish()
+}
+
+/// Write a `Tensor` into a tuple of bytes, one for the header (ipc::Message) and the other
+/// for the tensor's data
+pub fn tensor_to_bytes<T: ArrowPrimitiveType>(
+    tensor: &Tensor<T>,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let fb_type = ipc::convert::get_fb_field_type(tensor.data_type(), &mut fbb);
+    // a tensor without a shape holds a single value, and is written without dimensions
+    let shape = tensor.shape().cloned().unwrap_or_default();
+    let dims = tensor_dims(&mut fbb, &shape, tensor.names());
+    let strides = tensor
+        .strides()
+        .map(|strides| strides.iter().map(|s| *s as i64).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let strides = fbb.create_vector(&strides);
+
+    let mut arrow_data = vec![];
+    let data = append_tensor_buffer(&mut arrow_data, tensor.data());
+
+    let root = {
+        let mut tensor_builder = ipc::TensorBuilder::new(&mut fbb);
+        tensor_builder.add_type_type(fb_type.type_type);
+        tensor_builder.add_type_(fb_type.type_);
+        tensor_builder.add_shape(dims);
+        tensor_builder.add_strides(strides);
+        tensor_builder.add_data(&data);
+        tensor_builder.finish().as_union_value()
+    };
+    finish_tensor_message(
+        fbb,
+        ipc::MessageHeader::Tensor,
+        root,
+        arrow_data,
+        write_options,
+    )
+}
+
+/// Write a `SparseCOOTensor` into a tuple of bytes, one for the header (ipc::Message)
+/// and the other for the tensor's indices and values
+pub fn sparse_coo_tensor_to_bytes<T: ArrowPrimitiveType>(
+    tensor: &SparseCOOTensor<T>,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let fb_type = ipc::convert::get_fb_field_type(tensor.data_type(), &mut fbb);
+    let dims = tensor_dims(&mut fbb, tensor.shape(), tensor.names());
+    let index_type = sparse_index_type(&mut fbb);
+    // the indices are a row major matrix of shape [non_zero_length, ndim]
+    let index_strides = fbb.create_vector(&[tensor.ndim() as i64 * 8, 8]);
+
+    let mut arrow_data = vec![];
+    let indices = append_tensor_buffer(&mut arrow_data, tensor.indices());
+    let data = append_tensor_buffer(&mut arrow_data, tensor.data());
+
+    let index = {
+        let mut index_builder = ipc::SparseTensorIndexCOOBuilder::new(&mut fbb);
+        index_builder.add_indicesType(index_type);
+        index_builder.add_indicesStrides(index_strides);
+        index_builder.add_indicesBuffer(&indices);
+        index_builder.finish().as_union_value()
+    };
+    let root = {
+        let mut tensor_builder = ipc::SparseTensorBuilder::new(&mut fbb);
+        tensor_builder.add_type_type(fb_type.type_type);
+        tensor_builder.add_type_(fb_type.type_);
+        tensor_builder.add_shape(dims);
+        tensor_builder.add_non_zero_length(tensor.non_zero_length() as i64);
+        tensor_builder.add_sparseIndex_type(ipc::SparseTensorIndex::SparseTensorIndexCOO);
+        tensor_builder.add_sparseIndex(index);
+        tensor_builder.add_data(&data);
+        tensor_builder.finish().as_union_value()
+    };
+    finish_tensor_message(
+        fbb,
+        ipc::MessageHeader::SparseTensor,
+        root,
+        arrow_data,
+        write_options,
+    )
+}
+
+/// Write a `SparseCSXMatrix` into a tuple of bytes, one for the header (ipc::Message)
+/// and the other for the matrix's index pointers, indices and values
+pub fn sparse_csx_matrix_to_bytes<T: ArrowPrimitiveType, A: CompressedAxis>(
+    matrix: &SparseCSXMatrix<T, A>,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let fb_type = ipc::convert::get_fb_field_type(matrix.data_type(), &mut fbb);
+    let dims = tensor_dims(&mut fbb, matrix.shape(), matrix.names());
+    let indptr_type = sparse_index_type(&mut fbb);
+    let index_type = sparse_index_type(&mut fbb);
+
+    let mut arrow_data = vec![];
+    let indptr = append_tensor_buffer(&mut arrow_data, matrix.indptr());
+    let indices = append_tensor_buffer(&mut arrow_data, matrix.indices());
+    let data = append_tensor_buffer(&mut arrow_data, matrix.data());
+
+    let index = {
+        let mut index_builder = ipc::SparseMatrixIndexCSXBuilder::new(&mut fbb);
+        index_builder.add_compressedAxis(if matrix.is_row_compressed() {
+            ipc::SparseMatrixCompressedAxis::Row
+        } else {
+            ipc::SparseMatrixCompressedAxis::Column
+        });
+        index_builder.add_indptrType(indptr_type);
+        index_builder.add_indptrBuffer(&indptr);
+        index_builder.add_indicesType(index_type);
+        index_builder.add_indicesBuffer(&indices);
+        index_builder.finish().as_union_value()
+    };
+    let root = {
+        let mut tensor_builder = ipc::SparseTensorBuilder::new(&mut fbb);
+        tensor_builder.add_type_type(fb_type.type_type);
+        tensor_builder.add_type_(fb_type.type_);
+        tensor_builder.add_shape(dims);
+        tensor_builder.add_non_zero_length(matrix.non_zero_length() as i64);
+        tensor_builder.add_sparseIndex_type(ipc::SparseTensorIndex::SparseMatrixIndexCSX);
+        tensor_builder.add_sparseIndex(index);
+        tensor_builder.add_data(&data);
+        tensor_builder.finish().as_union_value()
+    };
+    finish_tensor_message(
+        fbb,
+        ipc::MessageHeader::SparseTensor,
+        root,
+        arrow_data,
+        write_options,
+    )
+}
+
+/// Builds the optionally named dimensions of a tensor
+fn tensor_dims<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    shape: &[usize],
+    names: Option<&Vec<&str>>,
+) -> WIPOffset<Vector<'a, ForwardsUOffset<ipc::TensorDim<'a>>>> {
+    let dims = shape
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let name = names.map(|names| fbb.create_string(names[i]));
+            let mut dim = ipc::TensorDimBuilder::new(fbb);
+            dim.add_size_(*size as i64);
+            if let Some(name) = name {
+                dim.add_name(name);
+            }
+            dim.finish()
+        })
+        .collect::<Vec<_>>();
+    fbb.create_vector(&dims)
+}
+
+/// The type of the indices of sparse tensors, which are always signed 64-bit integers
+fn sparse_index_type<'a>(fbb: &mut FlatBufferBuilder<'a>) -> WIPOffset<ipc::Int<'a>> {
+    let mut builder = ipc::IntBuilder::new(fbb);
+    builder.add_bitWidth(64);
+    builder.add_is_signed(true);
+    builder.finish()
+}
+
+/// Appends a tensor buffer to the message body, padded to 8 bytes, returning its
+/// location within the body
+fn append_tensor_buffer(arrow_data: &mut Vec<u8>, buffer: &Buffer) -> ipc::Buffer {
+    let offset = arrow_data.len();
+    arrow_data.extend_from_slice(buffer.data());
+    arrow_data.extend_from_slice(&vec![0u8; pad_to_8(buffer.len() as u32)]);
+    ipc::Buffer::new(offset as i64, buffer.len() as i64)
+}
+
+/// Finishes a tensor message whose body is `arrow_data`
+fn finish_tensor_message(
+    mut fbb: FlatBufferBuilder,
+    header_type: ipc::MessageHeader,
+    header: WIPOffset<UnionWIPOffset>,
+    arrow_data: Vec<u8>,
+    write_options: &IpcWriteOptions,
+) -> EncodedData {
+    let mut message = ipc::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(header_type);
+    message.add_bodyLength(arrow_data.len() as i64);
+    message.add_header(header);
+    let root = message.finish();
+    fbb.finish(root, None);
+    let finished_data = fbb.finished_data();
+
+    EncodedData {
+        ipc_message: finished_data.to_vec(),
+        arrow_data,
+    }
+}
+
+/// Write a `Tensor` to the writer as an encapsulated IPC message, returning the number
+/// of bytes written.
+///
+/// The message can be read back with [`read_tensor`](crate::ipc::reader::read_tensor).
+pub fn write_tensor<W: Write, T: ArrowPrimitiveType>(
+    writer: W,
+    tensor: &Tensor<T>,
+    write_options: &IpcWriteOptions,
+) -> Result<usize> {
+    let mut writer = BufWriter::new(writer);
+    let encoded = tensor_to_bytes(tensor, write_options);
+    let (meta, data) = write_encoded_message(&mut writer, encoded, write_options)?;
+    writer.flush()?;
+    Ok(meta + data)
+}
+
+/// Write a `SparseCOOTensor` to the writer as an encapsulated IPC message, returning
+/// the number of bytes written.
+///
+/// The message can be read back with
+/// [`read_sparse_coo_tensor`](crate::ipc::reader::read_sparse_coo_tensor).
+pub fn write_sparse_coo_tensor<W: Write, T: ArrowPrimitiveType>(
+    writer: W,
+    tensor: &SparseCOOTensor<T>,
+    write_options: &IpcWriteOptions,
+) -> Result<usize> {
+    let mut writer = BufWriter::new(writer);
+    let encoded = sparse_coo_tensor_to_bytes(tensor, write_options);
+    let (meta, data) = write_encoded_message(&mut writer, encoded, write_options)?;
+    writer.flush()?;
+    Ok(meta + data)
+}
+
+/// Write a `SparseCSXMatrix` to the writer as an encapsulated IPC message, returning
+/// the number of bytes written.
+///
+/// The message can be read back with
+/// [`read_sparse_csx_matrix`](crate::ipc::reader::read_sparse_csx_matrix).
+pub fn write_sparse_csx_matrix<W: Write, T: ArrowPrimitiveType, A: CompressedAxis>(
+    writer: W,
+    matrix: &SparseCSXMatrix<T, A>,
+    write_options: &IpcWriteOptions,
+) -> Result<usize> {
+    let mut writer = BufWriter::new(writer);
+    let encoded = sparse_csx_matrix_to_bytes(matrix, write_options);
+    let (meta, data) = write_encoded_message(&mut writer, encoded, write_options)?;
+    writer.flush()?;
+    Ok(meta + data)
+}
+
 /// Write a record batch to the writer, writing the message size before the message
 /// if the record batch is being written to a stream
 fn write_continuation<W: Write>(
@@ -513,6 +1029,7 @@ fn write_continuation<W: Write>(
 }
 
 /// Write array data to a vector of bytes
+#[allow(clippy::too_many_arguments)]
 fn write_array_data(
     array_data: &ArrayDataRef,
     mut buffers: &mut Vec<ipc::Buffer>,
@@ -521,6 +1038,7 @@ fn write_array_data(
     offset: i64,
     num_rows: usize,
     null_count: usize,
+    compression: Option<ipc::CompressionType>,
 ) -> i64 {
     let mut offset = offset;
     nodes.push(ipc::FieldNode::new(num_rows as i64, null_count as i64));
@@ -538,13 +1056,24 @@ fn write_array_data(
             Some(buffer) => buffer.clone(),
         };
 
-        offset = write_buffer(&null_buffer, &mut buffers, &mut arrow_data, offset);
+        offset = write_buffer(
+            &null_buffer,
+            &mut buffers,
+            &mut arrow_data,
+            offset,
+            compression,
+        );
     }
 
     array_data.buffers().iter().for_each(|buffer| {
-        offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset);
+        offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset, compression);
     });
 
+    // the values of dictionaries are written in dictionary batches
+    if let DataType::Dictionary(_, _) = array_data.data_type() {
+        return offset;
+    }
+
     // recursively write out nested structures
     array_data.child_data().iter().for_each(|data_ref| {
         // write the nested data (e.g list data)
@@ -556,6 +1085,7 @@ fn write_array_data(
             offset,
             data_ref.len(),
             data_ref.null_count(),
+            compression,
         );
     });
     offset
@@ -567,7 +1097,18 @@ fn write_buffer(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: i64,
+    compression: Option<ipc::CompressionType>,
 ) -> i64 {
+    if let Some(codec) = compression {
+        let compressed = compression::compress_buffer(codec, buffer.data());
+        let len = compressed.len();
+        let pad_len = pad_to_8(len as u32);
+        // the padding is not part of the buffer, as codecs would try to decompress it
+        buffers.push(ipc::Buffer::new(offset, len as i64));
+        arrow_data.extend_from_slice(&compressed);
+        arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
+        return offset + (len + pad_len) as i64;
+    }
     let len = buffer.len();
     let pad_len = pad_to_8(len as u32);
     let total_len: i64 = (len + pad_len) as i64;
@@ -593,6 +1134,7 @@ mod tests {
     use crate::array::*;
     use crate::datatypes::Field;
     use crate::ipc::reader::*;
+    use crate::sparse_tensor::*;
     use crate::util::integration_util::*;
     use std::env;
     use std::fs::File;
@@ -646,6 +1188,300 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_write_decimal_file() {
+        let mut builder = DecimalBuilder::new(4, 10, 3);
+        builder.append_value(12345).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-9_999_999).unwrap();
+        builder.append_value(0).unwrap();
+        let array = builder.finish();
+        let schema = Schema::new(vec![Field::new(
+            "decimals",
+            array.data_type().clone(),
+            true,
+        )]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(array) as ArrayRef],
+        )
+        .unwrap();
+        {
+            let file = File::create("target/debug/testdata/decimal.arrow_file").unwrap();
+            let mut writer = FileWriter::try_new(file, &schema).unwrap();
+
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        {
+            let file = File::open("target/debug/testdata/decimal.arrow_file").unwrap();
+            let mut reader = FileReader::try_new(file).unwrap();
+            assert_eq!(&schema, reader.schema().as_ref());
+            let read_batch = reader.next().unwrap().unwrap();
+            let expected = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<DecimalArray>()
+                .unwrap();
+            let actual = read_batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<DecimalArray>()
+                .unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_write_float16_stream() {
+        let array = Float16Array::from(vec![
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::from_f32(-0.25)),
+        ]);
+        let schema = Schema::new(vec![Field::new("f", DataType::Float16, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(array) as ArrayRef],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = StreamWriter::try_new(&mut buf, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(buf)).unwrap();
+        assert_eq!(&schema, reader.schema().as_ref());
+        let read_batch = reader.next().unwrap().unwrap();
+        let expected = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float16Array>()
+            .unwrap();
+        let actual = read_batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float16Array>()
+            .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_write_read_tensor() {
+        let values: Vec<i64> = (0..6).collect();
+        let buffer = Buffer::from(values.to_byte_slice());
+        let tensor = Tensor::<Int64Type>::new_column_major(
+            buffer,
+            Some(vec![2, 3]),
+            Some(vec!["x", "y"]),
+        )
+        .unwrap();
+
+        let options = IpcWriteOptions::default();
+        let mut buf = Vec::new();
+        let written = write_tensor(&mut buf, &tensor, &options).unwrap();
+        assert_eq!(buf.len(), written);
+        assert_eq!(0, written % 8);
+
+        let read = read_tensor::<Int64Type>(&buf).unwrap();
+        assert_eq!(tensor.shape(), read.shape());
+        assert_eq!(tensor.strides(), read.strides());
+        assert_eq!(Some(&vec!["x", "y"]), read.names());
+        assert!(read.is_column_major().unwrap());
+        assert_eq!(tensor.data(), read.data());
+
+        // the value type must match
+        assert!(read_tensor::<Int32Type>(&buf).is_err());
+        // as must the message size
+        assert!(read_tensor::<Int64Type>(&buf[..buf.len() - 8]).is_err());
+
+        // a tensor without a shape holds a single value
+        let scalar = Tensor::<Float32Type>::try_new(
+            Buffer::from(1.5f32.to_byte_slice()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let options =
+            IpcWriteOptions::try_new(8, true, ipc::MetadataVersion::V4).unwrap();
+        let mut buf = Vec::new();
+        write_tensor(&mut buf, &scalar, &options).unwrap();
+        let read = read_tensor::<Float32Type>(&buf).unwrap();
+        assert_eq!(None, read.shape());
+        assert_eq!(scalar.data(), read.data());
+    }
+
+    #[test]
+    fn test_read_invalid_tensor_shape() {
+        let values: Vec<i32> = (0..37).collect();
+        let tensor = Tensor::<Int32Type>::new_row_major(
+            Buffer::from(values.to_byte_slice()),
+            Some(vec![1, 37]),
+            None,
+        )
+        .unwrap();
+        let mut buf = Vec::new();
+        write_tensor(&mut buf, &tensor, &IpcWriteOptions::default()).unwrap();
+        assert_eq!(
+            tensor.data(),
+            read_tensor::<Int32Type>(&buf).unwrap().data()
+        );
+
+        // the size of the second dimension is the only 37 in the message
+        let size = 37i64.to_le_bytes();
+        let position = buf.windows(8).position(|w| w == size).unwrap();
+        let with_size = |size: i64| {
+            let mut buf = buf.clone();
+            buf[position..position + 8].copy_from_slice(&size.to_le_bytes());
+            buf
+        };
+        for size in &[-37, 38, i64::MAX] {
+            assert!(read_tensor::<Int32Type>(&with_size(*size)).is_err());
+        }
+        // the strides of the message no longer match a dimension of size zero
+        assert!(read_tensor::<Int32Type>(&with_size(0)).is_err());
+    }
+
+    #[test]
+    fn test_read_invalid_tensor_strides() {
+        let values: Vec<i32> = (0..15).collect();
+        let tensor = Tensor::<Int32Type>::new_row_major(
+            Buffer::from(values.to_byte_slice()),
+            Some(vec![3, 5]),
+            None,
+        )
+        .unwrap();
+        let mut buf = Vec::new();
+        write_tensor(&mut buf, &tensor, &IpcWriteOptions::default()).unwrap();
+        assert_eq!(
+            Some(&vec![20, 4]),
+            read_tensor::<Int32Type>(&buf).unwrap().strides()
+        );
+
+        // the strides are the only [20, 4] vector in the message, after its length
+        let strides = [20i64.to_le_bytes(), 4i64.to_le_bytes()].concat();
+        let position = buf.windows(16).position(|w| w == &strides[..]).unwrap();
+        let with_strides = |strides: &[i64]| {
+            let mut buf = buf.clone();
+            for (i, stride) in strides.iter().enumerate() {
+                let start = position + i * 8;
+                buf[start..start + 8].copy_from_slice(&stride.to_le_bytes());
+            }
+            buf
+        };
+        // each stride is within the values, but the last value they point at is not
+        assert!(read_tensor::<Int32Type>(&with_strides(&[24, 4])).is_err());
+        // strides that are not a multiple of the value size, or negative
+        assert!(read_tensor::<Int32Type>(&with_strides(&[20, 2])).is_err());
+        assert!(read_tensor::<Int32Type>(&with_strides(&[-20, 4])).is_err());
+        assert!(read_tensor::<Int32Type>(&with_strides(&[4, 12])).is_ok());
+
+        // a single stride for the two dimensions
+        let mut buf = buf.clone();
+        buf[position - 4..position].copy_from_slice(&1u32.to_le_bytes());
+        let err = read_tensor::<Int32Type>(&buf).err().unwrap();
+        assert!(err.to_string().contains("1 strides for its 2 dimensions"));
+    }
+
+    #[test]
+    fn test_write_read_sparse_tensor() {
+        let dense = Tensor::<Float64Type>::new_row_major(
+            Buffer::from([0.0, 1.5, 0.0, 0.0, -2.0, 3.0].to_byte_slice()),
+            Some(vec![2, 3]),
+            Some(vec!["x", "y"]),
+        )
+        .unwrap();
+        let options = IpcWriteOptions::default();
+
+        let coo = SparseCOOTensor::try_from_dense(&dense).unwrap();
+        let mut buf = Vec::new();
+        let written = write_sparse_coo_tensor(&mut buf, &coo, &options).unwrap();
+        assert_eq!(buf.len(), written);
+        assert_eq!(0, written % 8);
+        let read = read_sparse_coo_tensor::<Float64Type>(&buf).unwrap();
+        assert_eq!(coo.shape(), read.shape());
+        assert_eq!(Some(&vec!["x", "y"]), read.names());
+        assert_eq!(coo.indices(), read.indices());
+        assert_eq!(vec![1.5, -2.0, 3.0], read.values());
+        assert_eq!(dense.data(), read.to_dense().unwrap().data());
+        // a COO message is not a CSX matrix
+        assert!(read_sparse_csx_matrix::<Float64Type, RowAxis>(&buf).is_err());
+        assert!(read_sparse_coo_tensor::<Float32Type>(&buf).is_err());
+
+        let csr = SparseCSRMatrix::try_from_dense(&dense).unwrap();
+        let mut buf = Vec::new();
+        write_sparse_csx_matrix(&mut buf, &csr, &options).unwrap();
+        let read = read_sparse_csx_matrix::<Float64Type, RowAxis>(&buf).unwrap();
+        assert_eq!(csr.indptr(), read.indptr());
+        assert_eq!(csr.indices(), read.indices());
+        assert_eq!(dense.data(), read.to_dense().unwrap().data());
+        // the compressed axis must match
+        assert!(read_sparse_csx_matrix::<Float64Type, ColumnAxis>(&buf).is_err());
+        assert!(read_sparse_coo_tensor::<Float64Type>(&buf).is_err());
+
+        let csc = SparseCSCMatrix::try_from_dense(&dense).unwrap();
+        let mut buf = Vec::new();
+        write_sparse_csx_matrix(&mut buf, &csc, &options).unwrap();
+        let read = read_sparse_csx_matrix::<Float64Type, ColumnAxis>(&buf).unwrap();
+        assert_eq!(vec![1.5, -2.0, 3.0], read.values());
+        assert_eq!(csc.indptr(), read.indptr());
+        assert_eq!(dense.data(), read.to_dense().unwrap().data());
+        assert!(
+            read_sparse_csx_matrix::<Float64Type, ColumnAxis>(&buf[..buf.len() - 8])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_write_map_file() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(4), Int32Builder::new(4));
+        builder.keys().append_value("a").unwrap();
+        builder.values().append_value(1).unwrap();
+        builder.keys().append_value("b").unwrap();
+        builder.values().append_null().unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("c").unwrap();
+        builder.values().append_value(3).unwrap();
+        builder.append(true).unwrap();
+        let array = builder.finish();
+        let schema =
+            Schema::new(vec![Field::new("maps", array.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(array) as ArrayRef],
+        )
+        .unwrap();
+        {
+            let file = File::create("target/debug/testdata/map.arrow_file").unwrap();
+            let mut writer = FileWriter::try_new(file, &schema).unwrap();
+
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        {
+            let file = File::open("target/debug/testdata/map.arrow_file").unwrap();
+            let mut reader = FileReader::try_new(file).unwrap();
+            assert_eq!(&schema, reader.schema().as_ref());
+            let read_batch = reader.next().unwrap().unwrap();
+            let actual = read_batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<MapArray>()
+                .unwrap();
+            assert_eq!(3, actual.len());
+            assert!(actual.is_null(1));
+            assert!(batch.column(0).equals(actual));
+        }
+    }
+
     #[test]
     fn test_write_null_file() {
         let schema = Schema::new(vec![
@@ -694,6 +1530,429 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_write_dictionary_file() {
+        let values = Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef;
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![Field::new_dict(
+            "dict",
+            dict_type.clone(),
+            true,
+            1,
+            false,
+        )]);
+        let keys = Int8Array::from(vec![Some(0), None, Some(2), Some(1)]);
+        let array = dictionary_array(&dict_type, &keys, &values);
+        let batch1 = RecordBatch::try_new(Arc::new(schema.clone()), vec![array]).unwrap();
+        // the second batch shares the dictionary of the first one
+        let keys = Int8Array::from(vec![Some(2), Some(2)]);
+        let array = dictionary_array(&dict_type, &keys, &values);
+        let batch2 = RecordBatch::try_new(Arc::new(schema.clone()), vec![array]).unwrap();
+
+        for options in vec![
+            IpcWriteOptions::default(),
+            IpcWriteOptions::try_new(8, false, ipc::MetadataVersion::V5).unwrap(),
+        ] {
+            {
+                let file =
+                    File::create("target/debug/testdata/dictionary.arrow_file").unwrap();
+                let mut writer =
+                    FileWriter::try_new_with_options(file, &schema, options).unwrap();
+                writer.write(&batch1).unwrap();
+                writer.write(&batch2).unwrap();
+                writer.finish().unwrap();
+                // the dictionary is only written once
+                assert_eq!(1, writer.dictionary_blocks.len());
+                assert_eq!(2, writer.record_blocks.len());
+            }
+
+            let file = File::open("target/debug/testdata/dictionary.arrow_file").unwrap();
+            let reader = FileReader::try_new(file).unwrap();
+            assert_eq!(&schema, reader.schema().as_ref());
+            let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+            assert_eq!(2, read_batches.len());
+            for (read, written) in read_batches.iter().zip(&[&batch1, &batch2]) {
+                assert!(read.column(0).equals(written.column(0).as_ref()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_nested_dictionary_stream() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let list_values = Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef;
+        let keys = Int32Array::from(vec![0, 1, 1, 0, 0]);
+        let list_child = dictionary_array(&dict_type, &keys, &list_values);
+        let list_type = DataType::List(Box::new(dict_type.clone()));
+        let list_data = ArrayData::builder(list_type.clone())
+            .len(3)
+            .add_buffer(Buffer::from(&[0i32, 2, 3, 5].to_byte_slice()))
+            .add_child_data(list_child.data())
+            .build();
+        let list = make_array(list_data);
+
+        let struct_values = Arc::new(StringArray::from(vec!["p", "q", "r"])) as ArrayRef;
+        let keys = Int32Array::from(vec![2, 0, 1]);
+        let struct_child = dictionary_array(&dict_type, &keys, &struct_values);
+        let struct_field = Field::new_dict("s_dict", dict_type, false, 2, false);
+        let structs = Arc::new(StructArray::from(vec![(
+            struct_field.clone(),
+            struct_child,
+        )])) as ArrayRef;
+
+        let schema = Schema::new(vec![
+            Field::new_dict("list_dict", list_type, false, 1, false),
+            Field::new("struct", DataType::Struct(vec![struct_field]), false),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![list, structs]).unwrap();
+
+        let mut bytes = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut bytes, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let reader = StreamReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+        assert_eq!(&schema, reader.schema().as_ref());
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(2, read_batches.len());
+        for read in &read_batches {
+            assert!(read.column(0).equals(batch.column(0).as_ref()));
+            // compare the dictionary in the struct by its keys and values, as the read
+            // key buffer is padded
+            let read_dict = read
+                .column(1)
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
+            let read_dict = read_dict
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32DictionaryArray>()
+                .unwrap();
+            assert_eq!(
+                vec![Some(2), Some(0), Some(1)],
+                read_dict.keys().collect::<Vec<_>>()
+            );
+            assert!(read_dict.values().equals(struct_values.as_ref()));
+        }
+    }
+
+    #[test]
+    fn test_write_replaced_dictionary() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![Field::new_dict(
+            "dict",
+            dict_type.clone(),
+            true,
+            0,
+            false,
+        )]);
+        let keys = Int8Array::from(vec![0, 1]);
+        let values = Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef;
+        let batch1 = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![dictionary_array(&dict_type, &keys, &values)],
+        )
+        .unwrap();
+        let values = Arc::new(StringArray::from(vec!["a", "c"])) as ArrayRef;
+        let batch2 = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![dictionary_array(&dict_type, &keys, &values)],
+        )
+        .unwrap();
+
+        // streams replace the dictionary
+        let mut bytes = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut bytes, &schema).unwrap();
+            writer.write(&batch1).unwrap();
+            writer.write(&batch2).unwrap();
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(2, read_batches.len());
+        for (read, written) in read_batches.iter().zip(&[&batch1, &batch2]) {
+            assert!(read.column(0).equals(written.column(0).as_ref()));
+        }
+
+        // while files do not support replacing it
+        let mut writer = FileWriter::try_new(vec![], &schema).unwrap();
+        writer.write(&batch1).unwrap();
+        let err = writer.write(&batch2).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Dictionary with id 0 was already written with different values"));
+    }
+
+    #[test]
+    fn test_write_unsupported_dictionaries() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let keys = Int8Array::from(vec![0, 1]);
+        let values = Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef;
+        let dictionary = dictionary_array(&dict_type, &keys, &values);
+
+        // a dictionary whose values are a dictionary
+        let nested_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(dict_type.clone()));
+        let nested = dictionary_array(&nested_type, &keys, &dictionary);
+        let err = collect_dictionaries(&nested.data(), 0, &mut vec![]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Writing dictionaries with dictionary values"));
+
+        // a union with a dictionary child
+        let union_type =
+            DataType::Union(vec![Field::new_dict("a", dict_type, true, 1, false)]);
+        let union = ArrayData::builder(union_type)
+            .len(2)
+            .add_buffer(Buffer::from(&[0u8, 0]))
+            .add_child_data(dictionary.data())
+            .build();
+        let err = collect_dictionaries(&union, 0, &mut vec![]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Writing dictionaries in unions is not supported"));
+    }
+
+    #[test]
+    fn test_write_dictionary_deltas() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![Field::new_dict(
+            "dict",
+            dict_type.clone(),
+            true,
+            0,
+            false,
+        )]);
+        let batches: Vec<RecordBatch> = vec![
+            (vec![0, 1], vec!["a", "b"]),
+            (vec![2, 0], vec!["a", "b", "c"]),
+            (vec![3, 2, 1], vec!["a", "b", "c", "d"]),
+        ]
+        .into_iter()
+        .map(|(keys, values)| {
+            let keys = Int8Array::from(keys);
+            let values = Arc::new(StringArray::from(values)) as ArrayRef;
+            RecordBatch::try_new(
+                Arc::new(schema.clone()),
+                vec![dictionary_array(&dict_type, &keys, &values)],
+            )
+            .unwrap()
+        })
+        .collect();
+
+        // each delta only contains the new values
+        let mut tracker = DictionaryTracker::new(true);
+        let options = IpcWriteOptions::default().with_dictionary_deltas(true);
+        let sizes: Vec<usize> = batches
+            .iter()
+            .map(|batch| {
+                let (dictionaries, _) =
+                    encoded_batch(batch, &mut tracker, &options).unwrap();
+                assert_eq!(1, dictionaries.len());
+                let message = ipc::get_root_as_message(&dictionaries[0].ipc_message);
+                let dictionary = message.header_as_dictionary_batch().unwrap();
+                dictionary.data().unwrap().length() as usize
+            })
+            .collect();
+        assert_eq!(vec![2, 1, 1], sizes);
+
+        let mut bytes = vec![];
+        {
+            let options = IpcWriteOptions::default().with_dictionary_deltas(true);
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut bytes, &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(3, read_batches.len());
+        for (read, written) in read_batches.iter().zip(&batches) {
+            assert!(read.column(0).equals(written.column(0).as_ref()));
+        }
+
+        let mut bytes = vec![];
+        {
+            let options = IpcWriteOptions::default().with_dictionary_deltas(true);
+            let mut writer =
+                FileWriter::try_new_with_options(&mut bytes, &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+            assert_eq!(3, writer.dictionary_blocks.len());
+        }
+        // the deltas in the file footer are applied before reading the record batches,
+        // so the batches share the whole dictionary
+        let reader = FileReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(3, read_batches.len());
+        let expected = StringArray::from(vec!["a", "b", "c", "d"]);
+        for (read, written) in read_batches.iter().zip(&batches) {
+            let read = read
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int8DictionaryArray>()
+                .unwrap();
+            let written = written
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int8DictionaryArray>()
+                .unwrap();
+            assert!(read.keys_array().equals(&written.keys_array()));
+            assert!(read.values().equals(&expected));
+        }
+    }
+
+    #[test]
+    fn test_compression_options() {
+        // compression is only supported on metadata version 5
+        let err = IpcWriteOptions::default()
+            .try_with_compression(Some(ipc::CompressionType::ZSTD))
+            .unwrap_err();
+        assert!(err.to_string().contains("metadata version 5"));
+
+        let options = IpcWriteOptions::try_new(8, false, ipc::MetadataVersion::V5)
+            .unwrap()
+            .try_with_compression(Some(ipc::CompressionType::LZ4_FRAME));
+        if cfg!(feature = "ipc_compression") {
+            assert!(options.is_ok());
+        } else {
+            assert!(options
+                .unwrap_err()
+                .to_string()
+                .contains("requires the `ipc_compression` feature"));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_write_compressed_file() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![
+            Field::new("ints", DataType::Int64, true),
+            Field::new("strings", DataType::Utf8, false),
+            Field::new_dict("dict", dict_type.clone(), false, 0, false),
+        ]);
+        let ints = Int64Array::from(
+            (0..1000)
+                .map(|i| if i % 10 == 0 { None } else { Some(i % 3) })
+                .collect::<Vec<_>>(),
+        );
+        let strings =
+            StringArray::from((0..1000).map(|i| ["a", "bb"][i % 2]).collect::<Vec<_>>());
+        let keys = Int32Array::from((0..1000).map(|i| i % 2).collect::<Vec<_>>());
+        let values = Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef;
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(ints),
+                Arc::new(strings),
+                dictionary_array(&dict_type, &keys, &values),
+            ],
+        )
+        .unwrap();
+
+        let write_file = |compression| {
+            let options = IpcWriteOptions::try_new(8, false, ipc::MetadataVersion::V5)
+                .unwrap()
+                .try_with_compression(compression)
+                .unwrap();
+            let mut bytes = vec![];
+            {
+                let mut writer =
+                    FileWriter::try_new_with_options(&mut bytes, &schema, options)
+                        .unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+            }
+            bytes
+        };
+        let uncompressed = write_file(None);
+        for codec in &[ipc::CompressionType::LZ4_FRAME, ipc::CompressionType::ZSTD] {
+            let bytes = write_file(Some(*codec));
+            assert!(bytes.len() < uncompressed.len());
+
+            let reader = FileReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+            let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+            assert_eq!(1, read_batches.len());
+            for (a, b) in read_batches[0].columns().iter().zip(batch.columns()) {
+                assert!(a.equals(b.as_ref()));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_write_compressed_stream() {
+        let schema = Schema::new(vec![Field::new(
+            "lists",
+            DataType::List(Box::new(DataType::Int32)),
+            true,
+        )]);
+        let values = Int32Array::from((0..100).collect::<Vec<_>>());
+        let list_data = ArrayData::builder(schema.field(0).data_type().clone())
+            .len(4)
+            .add_buffer(Buffer::from(&[0i32, 10, 10, 60, 100].to_byte_slice()))
+            .add_child_data(values.data())
+            .null_bit_buffer(Buffer::from([0b00001101]))
+            .build();
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![make_array(list_data)])
+                .unwrap();
+
+        let options = IpcWriteOptions::try_new(64, false, ipc::MetadataVersion::V5)
+            .unwrap()
+            .try_with_compression(Some(ipc::CompressionType::ZSTD))
+            .unwrap();
+        let mut bytes = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut bytes, &schema, options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let reader = StreamReader::try_new(std::io::Cursor::new(bytes)).unwrap();
+        let read_batches: Vec<RecordBatch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(2, read_batches.len());
+        for read in &read_batches {
+            assert!(read.column(0).equals(batch.column(0).as_ref()));
+        }
+    }
+
+    /// Create a dictionary array of `data_type` from its keys and values
+    fn dictionary_array(
+        data_type: &DataType,
+        keys: &dyn Array,
+        values: &ArrayRef,
+    ) -> ArrayRef {
+        let keys = keys.data();
+        let mut builder = ArrayData::builder(data_type.clone())
+            .len(keys.len())
+            .add_buffer(keys.buffers()[0].clone())
+            .add_child_data(values.data());
+        if let Some(bitmap) = keys.null_bitmap() {
+            builder = builder.null_bit_buffer(bitmap.bits.clone());
+        }
+        make_array(builder.build())
+    }
+
     #[test]
     fn read_and_rewrite_generated_files() {
         let testdata = env::var("ARROW_TEST_DATA").expect("ARROW_TEST_DATA not defined");
@@ -701,6 +1960,7 @@ mod tests {
         let paths = vec![
             "generated_interval",
             "generated_datetime",
+            "generated_dictionary",
             "generated_nested",
             "generated_primitive_no_batches",
             "generated_primitive_zerolength",
@@ -744,6 +2004,7 @@ mod tests {
         let paths = vec![
             "generated_interval",
             "generated_datetime",
+            "generated_dictionary",
             "generated_nested",
             "generated_primitive_no_batches",
             "generated_primitive_zerolength",
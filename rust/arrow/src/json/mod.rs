@@ -16,8 +16,13 @@
 // under the License.
 
 //! Transfer data between the Arrow memory format and JSON line-delimited records.
+//! Record batches can also be written as a JSON array of records.
 
 pub mod reader;
+pub mod serde;
+pub mod writer;
 
+pub use self::reader::Decoder;
 pub use self::reader::Reader;
 pub use self::reader::ReaderBuilder;
+pub use self::writer::{ArrayWriter, LineDelimitedWriter, Writer, WriterBuilder};
@@ -19,7 +19,9 @@
 //!
 //! This JSON reader allows JSON line-delimited files to be read into the Arrow memory
 //! model. Records are loaded in batches and are then converted from row-based data to
-//! columnar data.
+//! columnar data. Nested objects are read as structs, and arrays of objects or arrays
+//! as lists of structs or lists. Strings holding RFC 3339 date-times, naive date-times
+//! or dates are inferred as timestamps and dates.
 //!
 //! Example:
 //!
@@ -45,22 +47,97 @@
 use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
 use std::cell::RefCell;
-use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::io::{BufRead, BufReader, Chain, Cursor, Read, Seek, SeekFrom};
 use std::sync::Arc;
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use serde_json::Value;
 
 use crate::array::*;
+use crate::buffer::Buffer;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 
+/// The name of the field holding the elements of nested lists while they are built
+const LIST_ELEMENT_NAME: &str = "item";
+
+/// The format of timestamps without a timezone, as written by the JSON writer
+const NAIVE_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+/// The format of dates, as written by the JSON writer
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Returns whether a data type is inferred from strings holding dates or date-times
+fn is_temporal_string(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Timestamp(_, _) | DataType::Date32(_))
+}
+
+/// Infer the data type of a string. RFC 3339 date-times are inferred as timestamps
+/// in UTC, naive date-times as timestamps without a timezone, and `%Y-%m-%d` dates
+/// as dates.
+fn infer_string_type(s: &str) -> DataType {
+    if DateTime::parse_from_rfc3339(s).is_ok() {
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(Arc::new("UTC".to_string())))
+    } else if NaiveDateTime::parse_from_str(s, NAIVE_TIMESTAMP_FORMAT).is_ok() {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    } else if NaiveDate::parse_from_str(s, DATE_FORMAT).is_ok() {
+        DataType::Date32(DateUnit::Day)
+    } else {
+        DataType::Utf8
+    }
+}
+
+/// Parse a date-time string into a timestamp of the given unit
+pub(crate) fn parse_timestamp(s: &str, unit: &TimeUnit) -> Option<i64> {
+    let datetime = DateTime::parse_from_rfc3339(s)
+        .map(|datetime| datetime.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(s, NAIVE_TIMESTAMP_FORMAT))
+        .ok()?;
+    let nanoseconds = datetime.timestamp_subsec_nanos() as i64;
+    let (per_second, nanoseconds_per_unit) = match unit {
+        TimeUnit::Second => (1, 1_000_000_000),
+        TimeUnit::Millisecond => (1_000, 1_000_000),
+        TimeUnit::Microsecond => (1_000_000, 1_000),
+        TimeUnit::Nanosecond => (1_000_000_000, 1),
+    };
+    datetime
+        .timestamp()
+        .checked_mul(per_second)?
+        .checked_add(nanoseconds / nanoseconds_per_unit)
+}
+
+/// Parse a date string into the number of days since the epoch
+pub(crate) fn parse_date(s: &str) -> Option<i64> {
+    let date = NaiveDate::parse_from_str(s, DATE_FORMAT).ok()?;
+    Some((date - NaiveDate::from_ymd(1970, 1, 1)).num_days())
+}
+
+/// Convert a JSON number to a native value, reading integers exactly
+pub(crate) fn number_value<N: num::NumCast>(value: &Value) -> Option<N> {
+    value
+        .as_i64()
+        .and_then(num::cast::cast)
+        .or_else(|| value.as_u64().and_then(num::cast::cast))
+        .or_else(|| value.as_f64().and_then(num::cast::cast))
+}
+
 /// Coerce data type during inference
 ///
 /// * `Int64` and `Float64` should be `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
+/// * Strings that do not all parse as the same temporal type are kept as `Utf8`
 /// * All other types are coerced to `Utf8`
 fn coerce_data_type(dt: Vec<&DataType>) -> Result<DataType> {
+    if dt.len() > 1 && dt.iter().any(|t| is_temporal_string(t)) {
+        let utf8 = DataType::Utf8;
+        let mut dt: Vec<&DataType> = dt
+            .into_iter()
+            .map(|t| if is_temporal_string(t) { &utf8 } else { t })
+            .collect();
+        dt.sort();
+        dt.dedup();
+        return coerce_data_type(dt);
+    }
     match dt.len() {
         1 => Ok(dt[0].clone()),
         2 => {
@@ -134,22 +211,138 @@ fn coerce_data_type(dt: Vec<&DataType>) -> Result<DataType> {
     }
 }
 
-/// Generate schema from JSON field names and inferred data types
-fn generate_schema(spec: HashMap<String, HashSet<DataType>>) -> Result<SchemaRef> {
-    let fields: Result<Vec<Field>> = spec
-        .iter()
-        .map(|(k, hs)| {
-            let v: Vec<&DataType> = hs.iter().collect();
-            coerce_data_type(v).map(|t| Field::new(k, t, true))
+/// The type of a JSON value inferred so far, merged across records
+#[derive(Debug, Clone)]
+enum InferredType {
+    /// Scalar values, with the data types seen
+    Scalar(HashSet<DataType>),
+    /// Arrays, with the type of their elements
+    Array(Box<InferredType>),
+    /// Objects, with the types of their fields
+    Object(HashMap<String, InferredType>),
+    /// Only nulls, or empty arrays, were seen
+    Any,
+}
+
+impl InferredType {
+    fn scalar(data_type: DataType) -> Self {
+        let mut types = HashSet::new();
+        types.insert(data_type);
+        InferredType::Scalar(types)
+    }
+
+    /// Merges the types of two values of the same field.
+    ///
+    /// A scalar and an array of scalars are coerced to an array, as are an object and
+    /// an array of objects.
+    fn merge(self, other: InferredType) -> Result<InferredType> {
+        Ok(match (self, other) {
+            (t, InferredType::Any) | (InferredType::Any, t) => t,
+            (InferredType::Scalar(mut types), InferredType::Scalar(other)) => {
+                types.extend(other);
+                InferredType::Scalar(types)
+            }
+            (InferredType::Array(a), InferredType::Array(b)) => {
+                InferredType::Array(Box::new(a.merge(*b)?))
+            }
+            (InferredType::Array(a), t @ InferredType::Scalar(_))
+            | (t @ InferredType::Scalar(_), InferredType::Array(a))
+            | (InferredType::Array(a), t @ InferredType::Object(_))
+            | (t @ InferredType::Object(_), InferredType::Array(a)) => {
+                InferredType::Array(Box::new(a.merge(t)?))
+            }
+            (InferredType::Object(mut fields), InferredType::Object(other)) => {
+                for (name, t) in other {
+                    let field = fields.entry(name).or_insert(InferredType::Any);
+                    *field = std::mem::replace(field, InferredType::Any).merge(t)?;
+                }
+                InferredType::Object(fields)
+            }
+            (t1, t2) => {
+                return Err(ArrowError::JsonError(format!(
+                    "Incompatible types found during schema inference: {:?} and {:?}",
+                    t1, t2
+                )))
+            }
         })
-        .collect();
-    match fields {
-        Ok(fields) => {
-            let schema = Schema::new(fields);
-            Ok(Arc::new(schema))
+    }
+}
+
+/// Infer the type of a JSON value
+fn infer_value_type(value: &Value) -> Result<InferredType> {
+    Ok(match value {
+        Value::Null => InferredType::Any,
+        Value::Bool(_) => InferredType::scalar(DataType::Boolean),
+        Value::Number(n) => {
+            if n.is_f64() {
+                InferredType::scalar(DataType::Float64)
+            } else {
+                // default to i64
+                InferredType::scalar(DataType::Int64)
+            }
+        }
+        Value::String(s) => InferredType::scalar(infer_string_type(s)),
+        Value::Array(values) => {
+            let mut element_type = InferredType::Any;
+            for value in values {
+                element_type = element_type.merge(infer_value_type(value)?)?;
+            }
+            InferredType::Array(Box::new(element_type))
+        }
+        Value::Object(map) => {
+            let mut fields = HashMap::new();
+            collect_field_types_from_object(&mut fields, map)?;
+            InferredType::Object(fields)
+        }
+    })
+}
+
+/// Merge the types of the fields of a JSON object into `field_types`
+fn collect_field_types_from_object(
+    field_types: &mut HashMap<String, InferredType>,
+    map: &serde_json::Map<String, Value>,
+) -> Result<()> {
+    for (k, v) in map {
+        let t = infer_value_type(v)?;
+        let field_type = field_types
+            .entry(k.to_string())
+            .or_insert(InferredType::Any);
+        *field_type = std::mem::replace(field_type, InferredType::Any).merge(t)?;
+    }
+    Ok(())
+}
+
+/// Generate the data type of an inferred type, or `None` if only nulls were seen
+fn generate_datatype(t: &InferredType) -> Result<Option<DataType>> {
+    Ok(match t {
+        InferredType::Scalar(types) => Some(coerce_data_type(types.iter().collect())?),
+        InferredType::Array(element_type) => {
+            match (&**element_type, generate_datatype(element_type)?) {
+                // scalars that cannot be coerced are already a list of strings
+                (InferredType::Scalar(_), Some(list @ DataType::List(_))) => Some(list),
+                (_, element) => element.map(|t| DataType::List(Box::new(t))),
+            }
+        }
+        InferredType::Object(fields) => Some(DataType::Struct(generate_fields(fields)?)),
+        InferredType::Any => None,
+    })
+}
+
+/// Generate nullable fields from JSON field names and inferred types, skipping
+/// fields that only had nulls
+fn generate_fields(spec: &HashMap<String, InferredType>) -> Result<Vec<Field>> {
+    let mut fields = Vec::with_capacity(spec.len());
+    for (name, t) in spec {
+        if let Some(data_type) = generate_datatype(t)? {
+            fields.push(Field::new(name, data_type, true));
         }
-        Err(e) => Err(e),
     }
+    Ok(fields)
+}
+
+/// Generate schema from JSON field names and inferred data types
+fn generate_schema(spec: HashMap<String, InferredType>) -> Result<SchemaRef> {
+    Ok(Arc::new(Schema::new(generate_fields(&spec)?)))
 }
 
 /// Infer the fields of a JSON file by reading the first n records of the file, with
@@ -213,7 +406,7 @@ pub fn infer_json_schema<R: Read>(
     reader: &mut BufReader<R>,
     max_read_records: Option<usize>,
 ) -> Result<SchemaRef> {
-    let mut values: HashMap<String, HashSet<DataType>> = HashMap::new();
+    let mut values: HashMap<String, InferredType> = HashMap::new();
 
     let mut line = String::new();
     for _ in 0..max_read_records.unwrap_or(std::usize::MAX) {
@@ -221,155 +414,62 @@ pub fn infer_json_schema<R: Read>(
         if line.is_empty() {
             break;
         }
-        let record: Value = serde_json::from_str(&line.trim()).expect("Not valid JSON");
+        let record: Value = serde_json::from_str(&line.trim())
+            .map_err(|e| ArrowError::JsonError(format!("Not valid JSON: {}", e)))?;
 
         line = String::new();
 
-        match record {
-            Value::Object(map) => {
-                let res = map
-                    .iter()
-                    .map(|(k, v)| {
-                        match v {
-                            Value::Array(a) => {
-                                // collect the data types in array
-                                let types: Result<Vec<Option<&DataType>>> = a
-                                    .iter()
-                                    .map(|a| match a {
-                                        Value::Null => Ok(None),
-                                        Value::Number(n) => {
-                                            if n.is_i64() {
-                                                Ok(Some(&DataType::Int64))
-                                            } else {
-                                                Ok(Some(&DataType::Float64))
-                                            }
-                                        }
-                                        Value::Bool(_) => Ok(Some(&DataType::Boolean)),
-                                        Value::String(_) => Ok(Some(&DataType::Utf8)),
-                                        Value::Array(_) | Value::Object(_) => {
-                                            Err(ArrowError::JsonError(
-                                                "Nested lists and structs not supported"
-                                                    .to_string(),
-                                            ))
-                                        }
-                                    })
-                                    .collect();
-                                match types {
-                                    Ok(types) => {
-                                        // unwrap the Option and discard None values (from
-                                        // JSON nulls)
-                                        let mut types: Vec<&DataType> =
-                                            types.into_iter().filter_map(|t| t).collect();
-                                        types.dedup();
-                                        // if a record contains only nulls, it is not
-                                        // added to values
-                                        if !types.is_empty() {
-                                            let dt = coerce_data_type(types)?;
-
-                                            if values.contains_key(k) {
-                                                let x = values.get_mut(k).unwrap();
-                                                x.insert(DataType::List(Box::new(dt)));
-                                            } else {
-                                                // create hashset and add value type
-                                                let mut hs = HashSet::new();
-                                                hs.insert(DataType::List(Box::new(dt)));
-                                                values.insert(k.to_string(), hs);
-                                            }
-                                        }
-                                        Ok(())
-                                    }
-                                    Err(e) => Err(e),
-                                }
-                            }
-                            Value::Bool(_) => {
-                                if values.contains_key(k) {
-                                    let x = values.get_mut(k).unwrap();
-                                    x.insert(DataType::Boolean);
-                                } else {
-                                    // create hashset and add value type
-                                    let mut hs = HashSet::new();
-                                    hs.insert(DataType::Boolean);
-                                    values.insert(k.to_string(), hs);
-                                }
-                                Ok(())
-                            }
-                            Value::Null => {
-                                // do nothing, we treat json as nullable by default when
-                                // inferring
-                                Ok(())
-                            }
-                            Value::Number(n) => {
-                                if n.is_f64() {
-                                    if values.contains_key(k) {
-                                        let x = values.get_mut(k).unwrap();
-                                        x.insert(DataType::Float64);
-                                    } else {
-                                        // create hashset and add value type
-                                        let mut hs = HashSet::new();
-                                        hs.insert(DataType::Float64);
-                                        values.insert(k.to_string(), hs);
-                                    }
-                                } else {
-                                    // default to i64
-                                    if values.contains_key(k) {
-                                        let x = values.get_mut(k).unwrap();
-                                        x.insert(DataType::Int64);
-                                    } else {
-                                        // create hashset and add value type
-                                        let mut hs = HashSet::new();
-                                        hs.insert(DataType::Int64);
-                                        values.insert(k.to_string(), hs);
-                                    }
-                                }
-                                Ok(())
-                            }
-                            Value::String(_) => {
-                                if values.contains_key(k) {
-                                    let x = values.get_mut(k).unwrap();
-                                    x.insert(DataType::Utf8);
-                                } else {
-                                    // create hashset and add value type
-                                    let mut hs = HashSet::new();
-                                    hs.insert(DataType::Utf8);
-                                    values.insert(k.to_string(), hs);
-                                }
-                                Ok(())
-                            }
-                            Value::Object(_) => Err(ArrowError::JsonError(
-                                "Reading nested JSON structs currently not supported"
-                                    .to_string(),
-                            )),
-                        }
-                    })
-                    .collect();
-                match res {
-                    Ok(()) => {}
-                    Err(e) => return Err(e),
-                }
-            }
-            t => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected JSON record to be an object, found {:?}",
-                    t
-                )));
-            }
-        };
+        collect_field_types_from_record(&mut values, record)?;
     }
 
     generate_schema(values)
 }
 
+/// Infer the fields of JSON records that are already parsed, such as records
+/// decoded with a [`Decoder`].
+///
+/// # Examples
+/// ```
+/// use arrow::datatypes::DataType;
+/// use arrow::json::reader::infer_json_schema_from_iterator;
+/// use serde_json::json;
+///
+/// let values = vec![json!({"a": 1}), json!({"a": 2.5, "b": "x"})];
+/// let inferred_schema = infer_json_schema_from_iterator(values.into_iter()).unwrap();
+/// assert_eq!(&DataType::Float64, inferred_schema.field(0).data_type());
+/// ```
+pub fn infer_json_schema_from_iterator<I>(value_iter: I) -> Result<SchemaRef>
+where
+    I: Iterator<Item = Value>,
+{
+    let mut values: HashMap<String, InferredType> = HashMap::new();
+    for record in value_iter {
+        collect_field_types_from_record(&mut values, record)?;
+    }
+    generate_schema(values)
+}
+
+/// Merge the types of the fields of a JSON record into `field_types`
+fn collect_field_types_from_record(
+    field_types: &mut HashMap<String, InferredType>,
+    record: Value,
+) -> Result<()> {
+    match record {
+        Value::Object(map) => collect_field_types_from_object(field_types, &map),
+        t => Err(ArrowError::JsonError(format!(
+            "Expected JSON record to be an object, found {:?}",
+            t
+        ))),
+    }
+}
+
 /// JSON file reader
 #[derive(Debug)]
 pub struct Reader<R: Read> {
-    /// Explicit schema for the JSON file
-    schema: SchemaRef,
-    /// Optional projection for which columns to load (case-sensitive names)
-    projection: Option<Vec<String>>,
     /// File reader
     reader: BufReader<R>,
-    /// Batch size (number of records to load each time)
-    batch_size: usize,
+    /// Decoder of the records read
+    decoder: Decoder,
 }
 
 impl<R: Read> Reader<R> {
@@ -389,24 +489,7 @@ impl<R: Read> Reader<R> {
     /// Returns the schema of the reader, useful for getting the schema without reading
     /// record batches
     pub fn schema(&self) -> SchemaRef {
-        match &self.projection {
-            Some(projection) => {
-                let fields = self.schema.fields();
-                let projected_fields: Vec<Field> = fields
-                    .iter()
-                    .filter_map(|field| {
-                        if projection.contains(field.name()) {
-                            Some(field.clone())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                Arc::new(Schema::new(projected_fields))
-            }
-            None => self.schema.clone(),
-        }
+        self.decoder.schema()
     }
 
     /// Create a new JSON Reader from a `BufReader<R: Read>`
@@ -419,214 +502,113 @@ impl<R: Read> Reader<R> {
         projection: Option<Vec<String>>,
     ) -> Self {
         Self {
-            schema,
-            projection,
             reader,
-            batch_size,
+            decoder: Decoder::new(schema, batch_size, projection),
         }
     }
 
     /// Read the next batch of records
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<Option<RecordBatch>> {
-        let mut rows: Vec<Value> = Vec::with_capacity(self.batch_size);
+        let mut rows: Vec<Value> = Vec::with_capacity(self.decoder.batch_size);
         let mut line = String::new();
-        for _ in 0..self.batch_size {
+        for _ in 0..self.decoder.batch_size {
             let bytes_read = self.reader.read_line(&mut line)?;
             if bytes_read > 0 {
-                rows.push(serde_json::from_str(&line).expect("Not valid JSON"));
+                let row = serde_json::from_str(&line).map_err(|e| {
+                    ArrowError::JsonError(format!("Not valid JSON: {}", e))
+                })?;
+                rows.push(row);
                 line = String::new();
             } else {
                 break;
             }
         }
 
+        self.decoder.next_batch(&mut rows.into_iter())
+    }
+}
+
+/// Decodes JSON records, already parsed as `serde_json::Value`s, into record batches
+///
+/// # Example
+///
+/// ```
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::json::reader::Decoder;
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+/// let decoder = Decoder::new(Arc::new(schema), 1024, None);
+///
+/// let mut values = vec![json!({"a": 1}), json!({"a": null})].into_iter();
+/// let batch = decoder.next_batch(&mut values).unwrap().unwrap();
+/// assert_eq!(2, batch.num_rows());
+/// assert!(decoder.next_batch(&mut values).unwrap().is_none());
+/// ```
+#[derive(Debug)]
+pub struct Decoder {
+    /// Explicit schema for the JSON records
+    schema: SchemaRef,
+    /// Optional projection for which columns to load (case-sensitive names)
+    projection: Option<Vec<String>>,
+    /// Batch size (number of records to load each time)
+    batch_size: usize,
+}
+
+impl Decoder {
+    /// Create a new JSON decoder from the schema of the records
+    pub fn new(
+        schema: SchemaRef,
+        batch_size: usize,
+        projection: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            schema,
+            projection,
+            batch_size,
+        }
+    }
+
+    /// Returns the schema of the decoded record batches
+    pub fn schema(&self) -> SchemaRef {
+        match &self.projection {
+            Some(projection) => {
+                let fields = self.schema.fields();
+                let projected_fields: Vec<Field> = fields
+                    .iter()
+                    .filter_map(|field| {
+                        if projection.contains(field.name()) {
+                            Some(field.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                Arc::new(Schema::new(projected_fields))
+            }
+            None => self.schema.clone(),
+        }
+    }
+
+    /// Decode the next batch of up to `batch_size` records from `value_iter`, or
+    /// return `None` if it has no more records
+    pub fn next_batch<I>(&self, value_iter: &mut I) -> Result<Option<RecordBatch>>
+    where
+        I: Iterator<Item = Value>,
+    {
+        let rows: Vec<Value> = value_iter.take(self.batch_size).collect();
+
         if rows.is_empty() {
-            // reached end of file
+            // reached end of records
             return Ok(None);
         }
 
         let rows = &rows[..];
         let projection = self.projection.clone().unwrap_or_else(Vec::new);
-        let arrays: Result<Vec<ArrayRef>> =
-            self.schema
-                .clone()
-                .fields()
-                .iter()
-                .filter(|field| {
-                    if projection.is_empty() {
-                        return true;
-                    }
-                    projection.contains(field.name())
-                })
-                .map(|field| {
-                    match field.data_type().clone() {
-                        DataType::Null => unimplemented!(),
-                        DataType::Boolean => self.build_boolean_array(rows, field.name()),
-                        DataType::Float64 => {
-                            self.build_primitive_array::<Float64Type>(rows, field.name())
-                        }
-                        DataType::Float32 => {
-                            self.build_primitive_array::<Float32Type>(rows, field.name())
-                        }
-                        DataType::Int64 => {
-                            self.build_primitive_array::<Int64Type>(rows, field.name())
-                        }
-                        DataType::Int32 => {
-                            self.build_primitive_array::<Int32Type>(rows, field.name())
-                        }
-                        DataType::Int16 => {
-                            self.build_primitive_array::<Int16Type>(rows, field.name())
-                        }
-                        DataType::Int8 => {
-                            self.build_primitive_array::<Int8Type>(rows, field.name())
-                        }
-                        DataType::UInt64 => {
-                            self.build_primitive_array::<UInt64Type>(rows, field.name())
-                        }
-                        DataType::UInt32 => {
-                            self.build_primitive_array::<UInt32Type>(rows, field.name())
-                        }
-                        DataType::UInt16 => {
-                            self.build_primitive_array::<UInt16Type>(rows, field.name())
-                        }
-                        DataType::UInt8 => {
-                            self.build_primitive_array::<UInt8Type>(rows, field.name())
-                        }
-                        DataType::Timestamp(unit, _) => match unit {
-                            TimeUnit::Second => self
-                                .build_primitive_array::<TimestampSecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            TimeUnit::Microsecond => self
-                                .build_primitive_array::<TimestampMicrosecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            TimeUnit::Millisecond => self
-                                .build_primitive_array::<TimestampMillisecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            TimeUnit::Nanosecond => self
-                                .build_primitive_array::<TimestampNanosecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                        },
-                        DataType::Date64(_) => {
-                            self.build_primitive_array::<Date64Type>(rows, field.name())
-                        }
-                        DataType::Date32(_) => {
-                            self.build_primitive_array::<Date32Type>(rows, field.name())
-                        }
-                        DataType::Time64(unit) => match unit {
-                            TimeUnit::Microsecond => self
-                                .build_primitive_array::<Time64MicrosecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            TimeUnit::Nanosecond => self
-                                .build_primitive_array::<Time64NanosecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            _ => unimplemented!(),
-                        },
-                        DataType::Time32(unit) => match unit {
-                            TimeUnit::Second => self
-                                .build_primitive_array::<Time32SecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            TimeUnit::Millisecond => self
-                                .build_primitive_array::<Time32MillisecondType>(
-                                    rows,
-                                    field.name(),
-                                ),
-                            _ => unimplemented!(),
-                        },
-                        DataType::Utf8 => {
-                            let mut builder = StringBuilder::new(rows.len());
-                            for row in rows {
-                                if let Some(value) = row.get(field.name()) {
-                                    if let Some(str_v) = value.as_str() {
-                                        builder.append_value(str_v)?
-                                    } else {
-                                        builder.append(false)?
-                                    }
-                                } else {
-                                    builder.append(false)?
-                                }
-                            }
-                            Ok(Arc::new(builder.finish()) as ArrayRef)
-                        }
-                        DataType::List(ref t) => {
-                            match **t {
-                                DataType::Int8 => {
-                                    self.build_list_array::<Int8Type>(rows, field.name())
-                                }
-                                DataType::Int16 => {
-                                    self.build_list_array::<Int16Type>(rows, field.name())
-                                }
-                                DataType::Int32 => {
-                                    self.build_list_array::<Int32Type>(rows, field.name())
-                                }
-                                DataType::Int64 => {
-                                    self.build_list_array::<Int64Type>(rows, field.name())
-                                }
-                                DataType::UInt8 => {
-                                    self.build_list_array::<UInt8Type>(rows, field.name())
-                                }
-                                DataType::UInt16 => self
-                                    .build_list_array::<UInt16Type>(rows, field.name()),
-                                DataType::UInt32 => self
-                                    .build_list_array::<UInt32Type>(rows, field.name()),
-                                DataType::UInt64 => self
-                                    .build_list_array::<UInt64Type>(rows, field.name()),
-                                DataType::Float32 => self
-                                    .build_list_array::<Float32Type>(rows, field.name()),
-                                DataType::Float64 => self
-                                    .build_list_array::<Float64Type>(rows, field.name()),
-                                DataType::Null => unimplemented!(),
-                                DataType::Boolean => {
-                                    self.build_boolean_list_array(rows, field.name())
-                                }
-                                ref dtype @ DataType::Utf8 => {
-                                    // UInt64Type passed down below is a fake type for dictionary builder.
-                                    // It is there to make compiler happy.
-                                    self.list_array_string_array_builder::<UInt64Type>(
-                                        &dtype,
-                                        field.name(),
-                                        rows,
-                                    )
-                                }
-                                DataType::Dictionary(ref key_ty, _) => self
-                                    .build_wrapped_list_array(rows, field.name(), key_ty),
-                                ref e => Err(ArrowError::JsonError(format!(
-                            "Data type is currently not supported in a list : {:?}",
-                            e
-                        ))),
-                            }
-                        }
-                        DataType::Dictionary(ref key_ty, ref val_ty) => self
-                            .build_string_dictionary_array(
-                                rows,
-                                field.name(),
-                                key_ty,
-                                val_ty,
-                            ),
-                        DataType::Struct(_) => Err(ArrowError::JsonError(
-                            "struct types are not yet supported".to_string(),
-                        )),
-                        _ => Err(ArrowError::JsonError(format!(
-                            "{:?} type is not supported",
-                            field.data_type()
-                        ))),
-                    }
-                })
-                .collect();
+        let arrays = self.build_struct_array(rows, self.schema.fields(), &projection);
 
         let projected_fields: Vec<Field> = if projection.is_empty() {
             self.schema.fields().to_vec()
@@ -644,6 +626,271 @@ impl<R: Read> Reader<R> {
         arrays.and_then(|arr| RecordBatch::try_new(projected_schema, arr).map(Some))
     }
 
+    /// Build the arrays of the `fields` of records, or of nested objects, skipping
+    /// fields not in a non-empty `projection`
+    fn build_struct_array(
+        &self,
+        rows: &[Value],
+        fields: &[Field],
+        projection: &[String],
+    ) -> Result<Vec<ArrayRef>> {
+        fields
+            .iter()
+            .filter(|field| projection.is_empty() || projection.contains(field.name()))
+            .map(|field| self.build_field_array(rows, field))
+            .collect()
+    }
+
+    /// Build the array of a field from the records, or nested objects, in `rows`
+    fn build_field_array(&self, rows: &[Value], field: &Field) -> Result<ArrayRef> {
+        match field.data_type().clone() {
+            DataType::Null => Err(ArrowError::JsonError(format!(
+                "Field '{}' has the Null type, which is not supported",
+                field.name()
+            ))),
+            DataType::Boolean => self.build_boolean_array(rows, field.name()),
+            DataType::Float64 => {
+                self.build_primitive_array::<Float64Type>(rows, field.name())
+            }
+            DataType::Float32 => {
+                self.build_primitive_array::<Float32Type>(rows, field.name())
+            }
+            DataType::Int64 => {
+                self.build_primitive_array::<Int64Type>(rows, field.name())
+            }
+            DataType::Int32 => {
+                self.build_primitive_array::<Int32Type>(rows, field.name())
+            }
+            DataType::Int16 => {
+                self.build_primitive_array::<Int16Type>(rows, field.name())
+            }
+            DataType::Int8 => self.build_primitive_array::<Int8Type>(rows, field.name()),
+            DataType::UInt64 => {
+                self.build_primitive_array::<UInt64Type>(rows, field.name())
+            }
+            DataType::UInt32 => {
+                self.build_primitive_array::<UInt32Type>(rows, field.name())
+            }
+            DataType::UInt16 => {
+                self.build_primitive_array::<UInt16Type>(rows, field.name())
+            }
+            DataType::UInt8 => {
+                self.build_primitive_array::<UInt8Type>(rows, field.name())
+            }
+            DataType::Timestamp(unit, _) => {
+                let parse = |s: &str| parse_timestamp(s, &unit);
+                match unit {
+                    TimeUnit::Second => self
+                        .build_temporal_array::<TimestampSecondType, _>(
+                            rows, field, parse,
+                        ),
+                    TimeUnit::Microsecond => self
+                        .build_temporal_array::<TimestampMicrosecondType, _>(
+                            rows, field, parse,
+                        ),
+                    TimeUnit::Millisecond => self
+                        .build_temporal_array::<TimestampMillisecondType, _>(
+                            rows, field, parse,
+                        ),
+                    TimeUnit::Nanosecond => self
+                        .build_temporal_array::<TimestampNanosecondType, _>(
+                            rows, field, parse,
+                        ),
+                }
+            }
+            DataType::Date64(_) => {
+                self.build_temporal_array::<Date64Type, _>(rows, field, |s| {
+                    parse_date(s)?.checked_mul(86_400_000)
+                })
+            }
+            DataType::Date32(_) => {
+                self.build_temporal_array::<Date32Type, _>(rows, field, parse_date)
+            }
+            DataType::Time64(unit) => match unit {
+                TimeUnit::Microsecond => self
+                    .build_primitive_array::<Time64MicrosecondType>(rows, field.name()),
+                TimeUnit::Nanosecond => {
+                    self.build_primitive_array::<Time64NanosecondType>(rows, field.name())
+                }
+                _ => unimplemented!(),
+            },
+            DataType::Time32(unit) => match unit {
+                TimeUnit::Second => {
+                    self.build_primitive_array::<Time32SecondType>(rows, field.name())
+                }
+                TimeUnit::Millisecond => self
+                    .build_primitive_array::<Time32MillisecondType>(rows, field.name()),
+                _ => unimplemented!(),
+            },
+            DataType::Utf8 => {
+                let mut builder = StringBuilder::new(rows.len());
+                for row in rows {
+                    if let Some(value) = row.get(field.name()) {
+                        if let Some(str_v) = value.as_str() {
+                            builder.append_value(str_v)?
+                        } else {
+                            builder.append(false)?
+                        }
+                    } else {
+                        builder.append(false)?
+                    }
+                }
+                Ok(Arc::new(builder.finish()) as ArrayRef)
+            }
+            DataType::List(ref t) => {
+                match **t {
+                    DataType::Int8 => {
+                        self.build_list_array::<Int8Type>(rows, field.name())
+                    }
+                    DataType::Int16 => {
+                        self.build_list_array::<Int16Type>(rows, field.name())
+                    }
+                    DataType::Int32 => {
+                        self.build_list_array::<Int32Type>(rows, field.name())
+                    }
+                    DataType::Int64 => {
+                        self.build_list_array::<Int64Type>(rows, field.name())
+                    }
+                    DataType::UInt8 => {
+                        self.build_list_array::<UInt8Type>(rows, field.name())
+                    }
+                    DataType::UInt16 => {
+                        self.build_list_array::<UInt16Type>(rows, field.name())
+                    }
+                    DataType::UInt32 => {
+                        self.build_list_array::<UInt32Type>(rows, field.name())
+                    }
+                    DataType::UInt64 => {
+                        self.build_list_array::<UInt64Type>(rows, field.name())
+                    }
+                    DataType::Float32 => {
+                        self.build_list_array::<Float32Type>(rows, field.name())
+                    }
+                    DataType::Float64 => {
+                        self.build_list_array::<Float64Type>(rows, field.name())
+                    }
+                    DataType::Null => Err(ArrowError::JsonError(format!(
+                        "Field '{}' is a list of the Null type, which is not supported",
+                        field.name()
+                    ))),
+                    DataType::Boolean => {
+                        self.build_boolean_list_array(rows, field.name())
+                    }
+                    ref dtype @ DataType::Utf8 => {
+                        // UInt64Type passed down below is a fake type for dictionary builder.
+                        // It is there to make compiler happy.
+                        self.list_array_string_array_builder::<UInt64Type>(
+                            &dtype,
+                            field.name(),
+                            rows,
+                        )
+                    }
+                    DataType::Dictionary(ref key_ty, _) => {
+                        self.build_wrapped_list_array(rows, field.name(), key_ty)
+                    }
+                    DataType::List(_)
+                    | DataType::Struct(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Date32(_)
+                    | DataType::Date64(_) => {
+                        self.build_nested_list_array(rows, field.name(), t)
+                    }
+                    ref e => Err(ArrowError::JsonError(format!(
+                        "Data type is currently not supported in a list : {:?}",
+                        e
+                    ))),
+                }
+            }
+            DataType::Dictionary(ref key_ty, ref val_ty) => {
+                self.build_string_dictionary_array(rows, field.name(), key_ty, val_ty)
+            }
+            DataType::Struct(ref fields) => {
+                self.build_struct_column(rows, field.name(), fields)
+            }
+            _ => Err(ArrowError::JsonError(format!(
+                "{:?} type is not supported",
+                field.data_type()
+            ))),
+        }
+    }
+
+    /// Build a struct array from the objects of a field. Rows where the field is
+    /// missing or not an object are null.
+    fn build_struct_column(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+        fields: &[Field],
+    ) -> Result<ArrayRef> {
+        let mut null_buffer = BooleanBufferBuilder::new(rows.len());
+        let mut struct_rows = Vec::with_capacity(rows.len());
+        for row in rows {
+            match row.get(col_name) {
+                Some(value @ Value::Object(_)) => {
+                    null_buffer.append(true)?;
+                    struct_rows.push(value.clone());
+                }
+                _ => {
+                    null_buffer.append(false)?;
+                    struct_rows.push(Value::Null);
+                }
+            }
+        }
+        let arrays = self.build_struct_array(&struct_rows, fields, &[])?;
+        let data = ArrayData::builder(DataType::Struct(fields.to_vec()))
+            .len(rows.len())
+            .null_bit_buffer(null_buffer.finish())
+            .child_data(arrays.into_iter().map(|array| array.data()).collect())
+            .build();
+        Ok(make_array(data))
+    }
+
+    /// Build a list array of lists or structs. Each element is wrapped in an object
+    /// so that the values can be built like a field of records. A value that is not
+    /// in an array is read as a list of one element, and rows where the field is
+    /// missing or null are null.
+    fn build_nested_list_array(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+        value_type: &DataType,
+    ) -> Result<ArrayRef> {
+        let mut offsets = Vec::with_capacity(rows.len() + 1);
+        offsets.push(0i32);
+        let mut null_buffer = BooleanBufferBuilder::new(rows.len());
+        let mut element_rows = Vec::new();
+        let element_row = |value: &Value| {
+            let mut map = serde_json::Map::with_capacity(1);
+            map.insert(LIST_ELEMENT_NAME.to_string(), value.clone());
+            Value::Object(map)
+        };
+        for row in rows {
+            match row.get(col_name) {
+                Some(Value::Array(values)) => {
+                    null_buffer.append(true)?;
+                    element_rows.extend(values.iter().map(element_row));
+                }
+                None | Some(Value::Null) => null_buffer.append(false)?,
+                Some(value) => {
+                    null_buffer.append(true)?;
+                    element_rows.push(element_row(value));
+                }
+            }
+            offsets.push(element_rows.len() as i32);
+        }
+        let values = self.build_field_array(
+            &element_rows,
+            &Field::new(LIST_ELEMENT_NAME, value_type.clone(), true),
+        )?;
+        let data = ArrayData::builder(DataType::List(Box::new(value_type.clone())))
+            .len(rows.len())
+            .add_buffer(Buffer::from(offsets.to_byte_slice()))
+            .add_child_data(values.data())
+            .null_bit_buffer(null_buffer.finish())
+            .build();
+        Ok(make_array(data))
+    }
+
     fn build_wrapped_list_array(
         &self,
         rows: &[Value],
@@ -742,79 +989,80 @@ impl<R: Read> Reader<R> {
             }
         };
 
+        // a missing value is read as an empty list, as in the other list arrays
+        let empty = Value::Array(vec![]);
         for row in rows {
-            if let Some(value) = row.get(col_name) {
-                // value can be an array or a scalar
-                let vals: Vec<Option<String>> = if let Value::String(v) = value {
-                    vec![Some(v.to_string())]
-                } else if let Value::Array(n) = value {
-                    n.iter()
-                        .map(|v: &Value| {
-                            if v.is_string() {
-                                Some(v.as_str().unwrap().to_string())
-                            } else if v.is_array() || v.is_object() || v.is_null() {
-                                // implicitly drop nested values
-                                // TODO support deep-nesting
-                                None
-                            } else {
-                                Some(v.to_string())
-                            }
-                        })
-                        .collect()
-                } else if let Value::Null = value {
-                    vec![None]
-                } else if !value.is_object() {
-                    vec![Some(value.to_string())]
-                } else {
-                    return Err(ArrowError::JsonError(
-                        "Only scalars are currently supported in JSON arrays".to_string(),
-                    ));
-                };
-
-                // TODO: ARROW-10335: APIs of dictionary arrays and others are different. Unify
-                // them.
-                match data_type {
-                    DataType::Utf8 => {
-                        let builder = &mut builder.borrow_mut();
-                        let builder = builder
-                            .as_any_mut()
-                            .downcast_mut::<ListBuilder<StringBuilder>>()
-                            .ok_or(ArrowError::JsonError(
-                                "Cast failed for ListBuilder<StringBuilder> during nested data parsing".to_string(),
-                            ))?;
-                        for val in vals {
-                            if let Some(v) = val {
-                                builder.values().append_value(&v)?
-                            } else {
-                                builder.values().append_null()?
-                            };
+            let value = row.get(col_name).unwrap_or(&empty);
+            // value can be an array or a scalar
+            let vals: Vec<Option<String>> = if let Value::String(v) = value {
+                vec![Some(v.to_string())]
+            } else if let Value::Array(n) = value {
+                n.iter()
+                    .map(|v: &Value| {
+                        if v.is_string() {
+                            Some(v.as_str().unwrap().to_string())
+                        } else if v.is_array() || v.is_object() || v.is_null() {
+                            // implicitly drop nested values
+                            // TODO support deep-nesting
+                            None
+                        } else {
+                            Some(v.to_string())
                         }
-
-                        // Append to the list
-                        builder.append(true)?;
-                    }
-                    DataType::Dictionary(_, _) => {
-                        let builder = &mut builder.borrow_mut();
-                        let builder = builder.as_any_mut().downcast_mut::<ListBuilder<StringDictionaryBuilder<DICT_TY>>>().ok_or(ArrowError::JsonError(
-                            "Cast failed for ListBuilder<StringDictionaryBuilder> during nested data parsing".to_string(),
+                    })
+                    .collect()
+            } else if let Value::Null = value {
+                vec![None]
+            } else if !value.is_object() {
+                vec![Some(value.to_string())]
+            } else {
+                return Err(ArrowError::JsonError(
+                    "Only scalars are currently supported in JSON arrays".to_string(),
+                ));
+            };
+
+            // TODO: ARROW-10335: APIs of dictionary arrays and others are different. Unify
+            // them.
+            match data_type {
+                DataType::Utf8 => {
+                    let builder = &mut builder.borrow_mut();
+                    let builder = builder
+                        .as_any_mut()
+                        .downcast_mut::<ListBuilder<StringBuilder>>()
+                        .ok_or(ArrowError::JsonError(
+                            "Cast failed for ListBuilder<StringBuilder> during nested data parsing".to_string(),
                         ))?;
-                        for val in vals {
-                            if let Some(v) = val {
-                                let _ = builder.values().append(&v)?;
-                            } else {
-                                builder.values().append_null()?
-                            };
-                        }
-
-                        // Append to the list
-                        builder.append(true)?;
+                    for val in vals {
+                        if let Some(v) = val {
+                            builder.values().append_value(&v)?
+                        } else {
+                            builder.values().append_null()?
+                        };
                     }
-                    e => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Nested list data builder type is not supported: {:?}",
-                            e
-                        )))
+
+                    // Append to the list
+                    builder.append(true)?;
+                }
+                DataType::Dictionary(_, _) => {
+                    let builder = &mut builder.borrow_mut();
+                    let builder = builder.as_any_mut().downcast_mut::<ListBuilder<StringDictionaryBuilder<DICT_TY>>>().ok_or(ArrowError::JsonError(
+                        "Cast failed for ListBuilder<StringDictionaryBuilder> during nested data parsing".to_string(),
+                    ))?;
+                    for val in vals {
+                        if let Some(v) = val {
+                            let _ = builder.values().append(&v)?;
+                        } else {
+                            builder.values().append_null()?
+                        };
                     }
+
+                    // Append to the list
+                    builder.append(true)?;
+                }
+                e => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Nested list data builder type is not supported: {:?}",
+                        e
+                    )))
                 }
             }
         }
@@ -939,15 +1187,44 @@ impl<R: Read> Reader<R> {
     {
         Ok(Arc::new(
             rows.iter()
-                .map(|row| {
-                    row.get(&col_name)
-                        .and_then(|value| value.as_f64())
-                        .and_then(num::cast::cast)
-                })
+                .map(|row| row.get(&col_name).and_then(number_value))
                 .collect::<PrimitiveArray<T>>(),
         ))
     }
 
+    /// Build a timestamp or date array from numbers, or from strings converted with
+    /// `parse`. The array has the data type of the field, including its timezone.
+    fn build_temporal_array<T, F>(
+        &self,
+        rows: &[Value],
+        field: &Field,
+        parse: F,
+    ) -> Result<ArrayRef>
+    where
+        T: ArrowNumericType,
+        T::Native: num::NumCast,
+        F: Fn(&str) -> Option<i64>,
+    {
+        let array = rows
+            .iter()
+            .map(|row| match row.get(field.name()) {
+                Some(Value::String(s)) => parse(s).and_then(num::cast::cast),
+                value => value.and_then(number_value),
+            })
+            .collect::<PrimitiveArray<T>>();
+        // the timezone is not part of the primitive type
+        let data = array.data();
+        Ok(make_array(Arc::new(ArrayData::new(
+            field.data_type().clone(),
+            data.len(),
+            Some(data.null_count()),
+            data.null_buffer().cloned(),
+            0,
+            data.buffers().to_vec(),
+            vec![],
+        ))))
+    }
+
     fn build_list_array<T: ArrowPrimitiveType>(
         &self,
         rows: &[Value],
@@ -1015,6 +1292,13 @@ impl<R: Read> Reader<R> {
     }
 }
 
+/// The source of a [`Reader`] created by [`ReaderBuilder::build_stream`]: the records
+/// buffered to infer the schema, followed by the rest of the stream
+pub type StreamSource<R> = Chain<Cursor<Vec<u8>>, BufReader<R>>;
+
+/// The number of records read to infer the schema of a stream, unless set
+const DEFAULT_STREAM_INFERENCE_RECORDS: usize = 1000;
+
 /// JSON file reader builder
 #[derive(Debug)]
 pub struct ReaderBuilder {
@@ -1117,6 +1401,50 @@ impl ReaderBuilder {
             self.projection,
         ))
     }
+
+    /// Create a new `Reader` from a source that cannot seek, such as standard input
+    /// or a socket.
+    ///
+    /// If the schema is inferred, the records read to infer it are buffered and then
+    /// replayed by the reader. At most `max_records` records are read to infer the
+    /// schema, or 1000 if it is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::json;
+    /// use std::io::stdin;
+    ///
+    /// let builder = json::ReaderBuilder::new().infer_schema(Some(100));
+    /// // reading from stdin blocks, so the reader is only created here
+    /// let _build = || builder.build_stream(stdin());
+    /// ```
+    pub fn build_stream<R: Read>(self, source: R) -> Result<Reader<StreamSource<R>>> {
+        let mut buf_reader = BufReader::new(source);
+        let mut prefix = Vec::new();
+
+        // check if schema should be inferred
+        let schema = match self.schema {
+            Some(schema) => schema,
+            None => {
+                let max_records =
+                    self.max_records.unwrap_or(DEFAULT_STREAM_INFERENCE_RECORDS);
+                for _ in 0..max_records {
+                    if buf_reader.read_until(b'\n', &mut prefix)? == 0 {
+                        break;
+                    }
+                }
+                infer_json_schema(&mut BufReader::new(&prefix[..]), None)?
+            }
+        };
+
+        Ok(Reader::new(
+            Cursor::new(prefix).chain(buf_reader),
+            schema,
+            self.batch_size,
+            self.projection,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -1125,6 +1453,7 @@ mod tests {
 
     use super::*;
     use flate2::read::GzDecoder;
+    use serde_json::json;
     use std::fs::File;
 
     #[test]
@@ -1396,13 +1725,38 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Not valid JSON")]
     fn test_invalid_file() {
         let builder = ReaderBuilder::new().infer_schema(None).with_batch_size(64);
-        let mut reader: Reader<File> = builder
+        let err = builder
             .build::<File>(File::open("test/data/uk_cities_with_headers.csv").unwrap())
+            .unwrap_err();
+        assert!(err.to_string().contains("Not valid JSON"), "{}", err);
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let json = "{\"a\": 1}\n{\"a\": 2\n{\"a\": 3}\n";
+
+        let mut reader = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .with_batch_size(1)
+            .build_stream(json.as_bytes())
             .unwrap();
-        let _batch = reader.next().unwrap().unwrap();
+        assert_eq!(1, reader.next().unwrap().unwrap().num_rows());
+        match reader.next() {
+            Err(ArrowError::JsonError(message)) => {
+                assert!(message.starts_with("Not valid JSON"), "{}", message)
+            }
+            other => panic!("expected a JSON error, got {:?}", other),
+        }
+
+        // the invalid line also fails schema inference
+        let err = ReaderBuilder::new()
+            .infer_schema(None)
+            .build_stream(json.as_bytes())
+            .unwrap_err();
+        assert!(err.to_string().contains("Not valid JSON"), "{}", err);
     }
 
     #[test]
@@ -1872,6 +2226,128 @@ mod tests {
         assert_eq!(vec![5, 5, 2], num_records);
     }
 
+    #[test]
+    fn test_json_read_stream() {
+        let data = std::fs::read("test/data/basic.json").unwrap();
+        // a slice can be read but not seeked
+        let mut reader = ReaderBuilder::new()
+            .infer_schema(Some(3))
+            .with_batch_size(5)
+            .build_stream(&data[..])
+            .unwrap();
+
+        let schema = reader.schema();
+        assert_eq!(4, schema.fields().len());
+        assert_eq!(&DataType::Boolean, schema.field(2).data_type());
+
+        let mut num_records = Vec::new();
+        let mut first_values = Vec::new();
+        while let Some(batch) = reader.next().unwrap() {
+            num_records.push(batch.num_rows());
+            first_values.push(as_primitive_array::<Int64Type>(batch.column(0)).value(0));
+        }
+        // the records buffered for inference are read again
+        assert_eq!(vec![5, 5, 2], num_records);
+        assert_eq!(vec![1, 1, 1], first_values);
+    }
+
+    #[test]
+    fn test_json_decode_values() {
+        let values = vec![
+            json!({"a": 1, "b": {"c": "x"}}),
+            json!({"a": 2, "b": null}),
+            json!({"b": {"c": "z"}}),
+        ];
+        let schema = infer_json_schema_from_iterator(values.clone().into_iter()).unwrap();
+        assert_eq!(
+            &DataType::Struct(vec![Field::new("c", DataType::Utf8, true)]),
+            schema.field(1).data_type()
+        );
+
+        let decoder = Decoder::new(schema, 2, Some(vec!["a".to_string()]));
+        assert_eq!(1, decoder.schema().fields().len());
+        let mut values = values.into_iter();
+        let batch = decoder.next_batch(&mut values).unwrap().unwrap();
+        assert_eq!(2, batch.num_rows());
+        assert_eq!(1, batch.num_columns());
+        let batch = decoder.next_batch(&mut values).unwrap().unwrap();
+        assert_eq!(1, batch.num_rows());
+        assert!(batch.column(0).is_null(0));
+        assert!(decoder.next_batch(&mut values).unwrap().is_none());
+
+        let mut values = vec![json!([1, 2])].into_iter();
+        assert!(infer_json_schema_from_iterator(&mut values).is_err());
+    }
+
+    #[test]
+    fn test_json_decode_temporal_strings() {
+        let values = vec![
+            json!({
+                "t": "2020-01-02T03:04:05Z",
+                "n": "2020-01-02T03:04:05.5",
+                "d": "2020-01-02",
+                "s": "2020-01-02"
+            }),
+            json!({"t": "2020-01-02T04:04:05+01:00", "n": null, "d": 18263, "s": "text"}),
+            json!({"l": ["2020-01-02", null]}),
+        ];
+        let schema = infer_json_schema_from_iterator(values.clone().into_iter()).unwrap();
+        let utc = Some(Arc::new("UTC".to_string()));
+        let expected = Schema::new(vec![
+            Field::new("t", DataType::Timestamp(TimeUnit::Nanosecond, utc), true),
+            Field::new("n", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+            // numbers and dates are coerced to strings
+            Field::new("d", DataType::Utf8, true),
+            Field::new("s", DataType::Utf8, true),
+            Field::new(
+                "l",
+                DataType::List(Box::new(DataType::Date32(DateUnit::Day))),
+                true,
+            ),
+        ]);
+        assert_eq!(expected, *schema);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "t",
+                DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+01:00".into()))),
+                true,
+            ),
+            Field::new("n", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("d", DataType::Date32(DateUnit::Day), true),
+            Field::new("s", DataType::Date64(DateUnit::Millisecond), true),
+            expected.field(4).clone(),
+        ]));
+        let decoder = Decoder::new(schema.clone(), 3, None);
+        let batch = decoder
+            .next_batch(&mut values.into_iter())
+            .unwrap()
+            .unwrap();
+        assert_eq!(schema, batch.schema());
+
+        let t = as_primitive_array::<TimestampSecondType>(batch.column(0));
+        assert_eq!(1_577_934_245, t.value(0));
+        assert_eq!(1_577_934_245, t.value(1));
+        let n = as_primitive_array::<TimestampMillisecondType>(batch.column(1));
+        assert_eq!(1_577_934_245_500, n.value(0));
+        assert!(n.is_null(1));
+        let d = as_primitive_array::<Date32Type>(batch.column(2));
+        assert_eq!(18263, d.value(0));
+        assert_eq!(18263, d.value(1));
+        let s = as_primitive_array::<Date64Type>(batch.column(3));
+        assert_eq!(1_577_923_200_000, s.value(0));
+        assert!(s.is_null(1));
+        let l = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert!(l.is_null(0));
+        let l = l.value(2);
+        assert_eq!(18263, as_primitive_array::<Date32Type>(&l).value(0));
+        assert!(l.is_null(1));
+    }
+
     #[test]
     fn test_json_infer_schema() {
         let schema = Schema::new(vec![
@@ -1894,6 +2370,122 @@ mod tests {
         assert_eq!(inferred_schema, Arc::new(schema));
     }
 
+    #[test]
+    fn test_json_infer_schema_nested() {
+        let address = DataType::Struct(vec![
+            Field::new("city", DataType::Utf8, true),
+            Field::new("zip", DataType::Int64, true),
+        ]);
+        let user = DataType::Struct(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("address", address, true),
+            Field::new("age", DataType::Int64, true),
+        ]);
+        let tag = DataType::Struct(vec![
+            Field::new("k", DataType::Utf8, true),
+            Field::new("v", DataType::Int64, true),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("user", user, true),
+            Field::new("tags", List(Box::new(tag)), true),
+            Field::new(
+                "matrix",
+                List(Box::new(List(Box::new(DataType::Float64)))),
+                true,
+            ),
+        ]);
+
+        let mut reader =
+            BufReader::new(File::open("test/data/nested_structs.json").unwrap());
+        let inferred_schema = infer_json_schema(&mut reader, None).unwrap();
+        assert_eq!(Arc::new(schema), inferred_schema);
+
+        // objects and scalars cannot be coerced
+        let mut reader = BufReader::new("{\"a\": {\"b\": 1}}\n{\"a\": 1}".as_bytes());
+        assert!(infer_json_schema(&mut reader, None).is_err());
+    }
+
+    #[test]
+    fn test_json_read_nested() {
+        let builder = ReaderBuilder::new().infer_schema(None).with_batch_size(64);
+        let mut reader: Reader<File> = builder
+            .build::<File>(File::open("test/data/nested_structs.json").unwrap())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(4, batch.num_rows());
+
+        let user = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(vec![true, true, false, true], validity(user));
+        let name = as_string_array(user.column_by_name("name").unwrap());
+        assert_eq!("d", name.value(3));
+        let age = as_primitive_array::<Int64Type>(user.column_by_name("age").unwrap());
+        assert_eq!(vec![false, false, false, true], validity(age));
+        assert_eq!(40, age.value(3));
+        let address = user
+            .column_by_name("address")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(vec![true, false, false, false], validity(address));
+        let city = as_string_array(address.column_by_name("city").unwrap());
+        assert_eq!("x", city.value(0));
+        let zip = as_primitive_array::<Int64Type>(address.column_by_name("zip").unwrap());
+        assert_eq!(100, zip.value(0));
+
+        // a list of structs, where a single object is read as a list of one element
+        let tags = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(vec![true, true, true, true], validity(tags));
+        assert_eq!(
+            vec![1, 0, 2, 1],
+            (0..4).map(|i| tags.value_length(i)).collect::<Vec<_>>()
+        );
+        let tag_values = tags.values();
+        let tag_values = tag_values.as_any().downcast_ref::<StructArray>().unwrap();
+        let k = as_string_array(tag_values.column_by_name("k").unwrap());
+        assert_eq!(
+            vec!["t1", "t2", "t3", "t4"],
+            (0..4).map(|i| k.value(i)).collect::<Vec<_>>()
+        );
+        let v = as_primitive_array::<Int64Type>(tag_values.column_by_name("v").unwrap());
+        assert_eq!(vec![true, false, true, true], validity(v));
+        assert_eq!(4, v.value(3));
+
+        // a list of lists
+        let matrix = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(vec![true, true, false, false], validity(matrix));
+        let rows = matrix.values();
+        let rows = rows.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(3, rows.len());
+        assert_eq!(
+            vec![2, 1, 1],
+            (0..3).map(|i| rows.value_length(i)).collect::<Vec<_>>()
+        );
+        let values = rows.values();
+        let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(
+            vec![1.0, 2.0, 3.0, 4.5],
+            (0..4).map(|i| values.value(i)).collect::<Vec<_>>()
+        );
+    }
+
+    fn validity(array: &Array) -> Vec<bool> {
+        (0..array.len()).map(|i| array.is_valid(i)).collect()
+    }
+
     #[test]
     fn test_timestamp_from_json_seconds() {
         let schema = Schema::new(vec![Field::new(
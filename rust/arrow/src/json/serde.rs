@@ -0,0 +1,670 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between Rust values implementing `serde::Serialize` and
+//! `serde::Deserialize`, and `RecordBatch`es.
+//!
+//! Each value is converted to a row through its JSON representation, so it must
+//! serialize to an object. The rows are then read by the JSON [`Decoder`], and written
+//! back by the JSON writer, so they follow the same rules: nested structs, sequences
+//! and `Option`s map to struct, list and nullable columns, and strings holding
+//! RFC 3339 date-times, naive date-times or dates, such as the serialized form of
+//! `chrono` types, are inferred as timestamp and date columns. Unlike when reading
+//! JSON, values that cannot be converted to the type of their field are an error
+//! rather than null, and a `None` sequence is a null list rather than a list holding
+//! a single null.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow::json::serde::{from_record_batch, infer_schema, to_record_batch};
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Point {
+//!     x: f64,
+//!     label: Option<String>,
+//! }
+//!
+//! let points = vec![
+//!     Point { x: 1.5, label: Some("a".to_string()) },
+//!     Point { x: -2.0, label: None },
+//! ];
+//! let schema = infer_schema(&points).unwrap();
+//! let batch = to_record_batch(&points, schema).unwrap();
+//! assert_eq!(2, batch.num_rows());
+//!
+//! let read: Vec<Point> = from_record_batch(&batch).unwrap();
+//! assert_eq!(points, read);
+//! ```
+
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::array::*;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::json::reader::{
+    infer_json_schema_from_iterator, number_value, parse_date, parse_timestamp, Decoder,
+};
+use crate::json::writer::record_batches_to_json_rows;
+use crate::record_batch::RecordBatch;
+
+/// Infers the schema of the rows that `values` serialize to.
+///
+/// All inferred fields are nullable. Fields that are always null are left out.
+pub fn infer_schema<T: Serialize>(values: &[T]) -> Result<SchemaRef> {
+    infer_json_schema_from_iterator(to_json_rows(values)?.into_iter())
+}
+
+/// Serializes `values` into a `RecordBatch` of the given schema, with one row per
+/// value.
+///
+/// Object keys that are not part of the schema are ignored, and fields that are
+/// missing from an object are null. Values that cannot be converted to the type of
+/// their field are an error. Non-nullable fields must only be null where their parent
+/// struct is null.
+pub fn to_record_batch<T: Serialize>(
+    values: &[T],
+    schema: SchemaRef,
+) -> Result<RecordBatch> {
+    let rows = to_json_rows(values)?;
+    for row in &rows {
+        for field in schema.fields() {
+            if let Some(value) = row.get(field.name()) {
+                check_value(value, field.name(), field.data_type())?;
+            }
+        }
+    }
+    let decoder = Decoder::new(schema.clone(), rows.len(), None);
+    let batch = match decoder.next_batch(&mut rows.iter().cloned())? {
+        Some(batch) => {
+            let columns = schema
+                .fields()
+                .iter()
+                .zip(batch.columns())
+                .map(|(field, column)| {
+                    let values = rows
+                        .iter()
+                        .map(|row| row.get(field.name()))
+                        .collect::<Vec<_>>();
+                    let data =
+                        set_list_validity(column.data(), field.data_type(), &values)?;
+                    Ok(make_array(data))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            RecordBatch::try_new(schema.clone(), columns)?
+        }
+        None => {
+            let columns = schema
+                .fields()
+                .iter()
+                .map(|field| new_null_array(field.data_type(), 0))
+                .collect();
+            RecordBatch::try_new(schema.clone(), columns)?
+        }
+    };
+    let valid = vec![true; batch.num_rows()];
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        check_nullability(column, field, &valid)?;
+    }
+    Ok(batch)
+}
+
+/// Deserializes each row of `batch` into a value of type `T`.
+///
+/// Timestamps are deserialized from RFC 3339 strings when they have a timezone, and
+/// from naive date-time strings otherwise. Dates are deserialized from `%Y-%m-%d`
+/// strings. Null values are left out of the rows.
+pub fn from_record_batch<T: DeserializeOwned>(batch: &RecordBatch) -> Result<Vec<T>> {
+    record_batches_to_json_rows(std::slice::from_ref(batch))?
+        .into_iter()
+        .map(|row| Ok(serde_json::from_value(Value::Object(row))?))
+        .collect()
+}
+
+/// Serializes `values` to JSON, checking that they are objects
+fn to_json_rows<T: Serialize>(values: &[T]) -> Result<Vec<Value>> {
+    values
+        .iter()
+        .map(|value| match serde_json::to_value(value)? {
+            row @ Value::Object(_) => Ok(row),
+            row => Err(ArrowError::JsonError(format!(
+                "Expected values to serialize to objects, found {}",
+                row
+            ))),
+        })
+        .collect()
+}
+
+/// Checks that a JSON value can be converted to the data type of a field, rather than
+/// being read as null by the decoder
+fn check_value(value: &Value, name: &str, data_type: &DataType) -> Result<()> {
+    if value.is_null() {
+        return Ok(());
+    }
+    let valid = match data_type {
+        DataType::Boolean => value.is_boolean(),
+        DataType::Int8 => number_value::<i8>(value).is_some(),
+        DataType::Int16 => number_value::<i16>(value).is_some(),
+        DataType::Int32 | DataType::Time32(_) => number_value::<i32>(value).is_some(),
+        DataType::Int64 | DataType::Time64(_) => number_value::<i64>(value).is_some(),
+        DataType::UInt8 => number_value::<u8>(value).is_some(),
+        DataType::UInt16 => number_value::<u16>(value).is_some(),
+        DataType::UInt32 => number_value::<u32>(value).is_some(),
+        DataType::UInt64 => number_value::<u64>(value).is_some(),
+        DataType::Float32 => number_value::<f32>(value).is_some(),
+        DataType::Float64 => number_value::<f64>(value).is_some(),
+        DataType::Timestamp(unit, _) => match value {
+            Value::String(s) => parse_timestamp(s, unit).is_some(),
+            _ => number_value::<i64>(value).is_some(),
+        },
+        DataType::Date32(_) => match value {
+            Value::String(s) => parse_date(s).and_then(num::cast::<_, i32>).is_some(),
+            _ => number_value::<i32>(value).is_some(),
+        },
+        DataType::Date64(_) => match value {
+            Value::String(s) => parse_date(s)
+                .and_then(|days| days.checked_mul(86_400_000))
+                .is_some(),
+            _ => number_value::<i64>(value).is_some(),
+        },
+        DataType::Utf8 | DataType::Dictionary(_, _) => value.is_string(),
+        DataType::List(value_type) => match value {
+            Value::Array(values) => {
+                for value in values {
+                    check_value(value, name, value_type)?;
+                }
+                true
+            }
+            _ => false,
+        },
+        DataType::Struct(fields) => match value {
+            Value::Object(_) => {
+                for field in fields {
+                    if let Some(value) = value.get(field.name()) {
+                        check_value(value, field.name(), field.data_type())?;
+                    }
+                }
+                true
+            }
+            _ => false,
+        },
+        // other types are not supported by the decoder
+        _ => true,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(ArrowError::JsonError(format!(
+            "Field '{}' of type {:?} cannot hold the value {}",
+            name, data_type, value
+        )))
+    }
+}
+
+/// Sets the validity of the lists in `data`, and in its children, from the JSON
+/// values it was decoded from, as the decoder reads a null list of scalars as a list
+/// holding a single null
+fn set_list_validity(
+    data: ArrayDataRef,
+    data_type: &DataType,
+    values: &[Option<&Value>],
+) -> Result<ArrayDataRef> {
+    let (null_buffer, child_data) = match data_type {
+        DataType::List(value_type) => {
+            let list = ListArray::from(data.clone());
+            let mut null_buffer = BooleanBufferBuilder::new(values.len());
+            let mut elements = vec![None; data.child_data()[0].len()];
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    Some(Value::Array(values)) => {
+                        null_buffer.append(data.is_valid(i))?;
+                        let offset = list.value_offset(i) as usize;
+                        if values.len() == list.value_length(i) as usize {
+                            for (j, value) in values.iter().enumerate() {
+                                elements[offset + j] = Some(value);
+                            }
+                        }
+                    }
+                    _ => null_buffer.append(false)?,
+                }
+            }
+            let child_data =
+                set_list_validity(data.child_data()[0].clone(), value_type, &elements)?;
+            (Some(null_buffer.finish()), vec![child_data])
+        }
+        DataType::Struct(fields) => {
+            let child_data = fields
+                .iter()
+                .zip(data.child_data())
+                .map(|(field, child_data)| {
+                    let values = values
+                        .iter()
+                        .map(|value| value.and_then(|value| value.get(field.name())))
+                        .collect::<Vec<_>>();
+                    set_list_validity(child_data.clone(), field.data_type(), &values)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (data.null_buffer().cloned(), child_data)
+        }
+        _ => return Ok(data),
+    };
+    Ok(Arc::new(ArrayData::new(
+        data.data_type().clone(),
+        data.len(),
+        None,
+        null_buffer,
+        data.offset(),
+        data.buffers().to_vec(),
+        child_data,
+    )))
+}
+
+/// Checks that a non-nullable field only has nulls where its parent is null, given
+/// the validity of each value of the parent
+fn check_nullability(
+    array: &ArrayRef,
+    field: &Field,
+    parent_valid: &[bool],
+) -> Result<()> {
+    let valid = parent_valid
+        .iter()
+        .enumerate()
+        .map(|(i, parent_valid)| *parent_valid && array.is_valid(i))
+        .collect::<Vec<_>>();
+    if !field.is_nullable() && valid != parent_valid {
+        return Err(ArrowError::JsonError(format!(
+            "Field '{}' is not nullable but has null values",
+            field.name()
+        )));
+    }
+    if let DataType::Struct(fields) = field.data_type() {
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        for (field, column) in fields.iter().zip(array.columns()) {
+            check_nullability(column, field, &valid)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        zip: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Person {
+        name: String,
+        age: Option<i64>,
+        score: f64,
+        tags: Vec<String>,
+        address: Option<Address>,
+        born: NaiveDate,
+        updated: DateTime<Utc>,
+        seen: Option<NaiveDateTime>,
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person {
+                name: "alice".to_string(),
+                age: Some(31),
+                score: 1.5,
+                tags: vec!["a".to_string(), "b".to_string()],
+                address: Some(Address {
+                    city: "Paris".to_string(),
+                    zip: Some(75001),
+                }),
+                born: NaiveDate::from_ymd(1989, 5, 17),
+                updated: DateTime::<Utc>::from_utc(
+                    NaiveDate::from_ymd(2020, 1, 2).and_hms_milli(3, 4, 5, 678),
+                    Utc,
+                ),
+                seen: Some(NaiveDate::from_ymd(2020, 6, 1).and_hms(12, 0, 0)),
+            },
+            Person {
+                name: "bob".to_string(),
+                age: None,
+                score: -2.0,
+                tags: vec![],
+                address: None,
+                born: NaiveDate::from_ymd(1960, 12, 31),
+                updated: DateTime::<Utc>::from_utc(
+                    NaiveDate::from_ymd(1969, 7, 20).and_hms(20, 17, 40),
+                    Utc,
+                ),
+                seen: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_infer_schema() {
+        let schema = infer_schema(&people()).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("age", DataType::Int64, true),
+            Field::new("score", DataType::Float64, true),
+            Field::new("tags", DataType::List(Box::new(DataType::Utf8)), true),
+            Field::new(
+                "address",
+                DataType::Struct(vec![
+                    Field::new("city", DataType::Utf8, true),
+                    Field::new("zip", DataType::Int64, true),
+                ]),
+                true,
+            ),
+            Field::new("born", DataType::Date32(DateUnit::Day), true),
+            Field::new(
+                "updated",
+                DataType::Timestamp(
+                    TimeUnit::Nanosecond,
+                    Some(Arc::new("UTC".to_string())),
+                ),
+                true,
+            ),
+            Field::new(
+                "seen",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                true,
+            ),
+        ]);
+        assert_eq!(expected, *schema);
+    }
+
+    #[test]
+    fn test_round_trip_inferred_schema() {
+        let people = people();
+        let schema = infer_schema(&people).unwrap();
+        let batch = to_record_batch(&people, schema).unwrap();
+        assert_eq!(2, batch.num_rows());
+        assert_eq!(8, batch.num_columns());
+
+        let ages = as_primitive_array::<Int64Type>(batch.column(1));
+        assert_eq!(31, ages.value(0));
+        assert!(ages.is_null(1));
+        let tags = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(2, tags.value_length(0));
+        assert_eq!(0, tags.value_length(1));
+        let address = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert!(address.is_valid(0));
+        assert!(address.is_null(1));
+        let updated = as_primitive_array::<TimestampNanosecondType>(batch.column(6));
+        assert_eq!(1_577_934_245_678_000_000, updated.value(0));
+        assert_eq!(-14_182_940_000_000_000, updated.value(1));
+
+        let read: Vec<Person> = from_record_batch(&batch).unwrap();
+        assert_eq!(people, read);
+    }
+
+    #[test]
+    fn test_round_trip_given_schema() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            id: u8,
+            values: Vec<Option<f32>>,
+            updated: DateTime<Utc>,
+        }
+
+        let rows = vec![
+            Row {
+                id: 1,
+                values: vec![Some(1.5), None],
+                updated: DateTime::<Utc>::from_utc(
+                    NaiveDate::from_ymd(2021, 3, 4).and_hms(5, 6, 7),
+                    Utc,
+                ),
+            },
+            Row {
+                id: 2,
+                values: vec![],
+                updated: DateTime::<Utc>::from_utc(
+                    NaiveDate::from_ymd(2021, 3, 5).and_hms(0, 0, 0),
+                    Utc,
+                ),
+            },
+        ];
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt8, false),
+            Field::new("values", DataType::List(Box::new(DataType::Float32)), false),
+            Field::new(
+                "updated",
+                DataType::Timestamp(TimeUnit::Second, Some(Arc::new("UTC".to_string()))),
+                false,
+            ),
+        ]));
+        let batch = to_record_batch(&rows, schema).unwrap();
+        let updated = as_primitive_array::<TimestampSecondType>(batch.column(2));
+        assert_eq!(1_614_834_367, updated.value(0));
+
+        let read: Vec<Row> = from_record_batch(&batch).unwrap();
+        assert_eq!(rows, read);
+    }
+
+    #[test]
+    fn test_to_record_batch_errors() {
+        #[derive(Serialize)]
+        struct Row {
+            a: Option<String>,
+        }
+
+        let rows = vec![
+            Row { a: None },
+            Row {
+                a: Some("x".to_string()),
+            },
+        ];
+        // values that cannot be converted are an error, rather than null
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let err = to_record_batch(&rows, schema).unwrap_err();
+        assert_eq!(
+            "Json error: Field 'a' of type Int32 cannot hold the value \"x\"",
+            err.to_string()
+        );
+        let rows = vec![serde_json::json!({"a": [1, 300]})];
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::List(Box::new(DataType::UInt8)),
+            true,
+        )]));
+        let err = to_record_batch(&rows, schema).unwrap_err();
+        assert_eq!(
+            "Json error: Field 'a' of type UInt8 cannot hold the value 300",
+            err.to_string()
+        );
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Null, true)]));
+        let err = to_record_batch(&rows, schema).unwrap_err();
+        assert_eq!(
+            "Json error: Field 'a' has the Null type, which is not supported",
+            err.to_string()
+        );
+
+        let rows = vec![
+            Row { a: None },
+            Row {
+                a: Some("x".to_string()),
+            },
+        ];
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, false)]));
+        let err = to_record_batch(&rows, schema).unwrap_err();
+        assert_eq!(
+            "Json error: Field 'a' is not nullable but has null values",
+            err.to_string()
+        );
+
+        let err = infer_schema(&[1, 2]).unwrap_err();
+        assert_eq!(
+            "Json error: Expected values to serialize to objects, found 1",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_null_lists() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Item {
+            ids: Option<Vec<Option<i32>>>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            names: Option<Vec<String>>,
+            flags: Option<Vec<bool>>,
+            items: Option<Vec<Item>>,
+        }
+
+        let rows = vec![
+            Row {
+                names: None,
+                flags: Some(vec![true]),
+                items: Some(vec![
+                    Item { ids: None },
+                    Item {
+                        ids: Some(vec![None, Some(1)]),
+                    },
+                ]),
+            },
+            Row {
+                names: Some(vec!["a".to_string()]),
+                flags: None,
+                items: None,
+            },
+        ];
+        let schema = infer_schema(&rows).unwrap();
+        let batch = to_record_batch(&rows, schema).unwrap();
+        for (i, column) in batch.columns().iter().enumerate() {
+            assert_eq!(1, column.null_count(), "column {}", i);
+        }
+        let items = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap()
+            .value(0);
+        let items = items.as_any().downcast_ref::<StructArray>().unwrap();
+        let ids = items.column(0);
+        assert!(ids.is_null(0));
+        assert!(ids.is_valid(1));
+
+        let read: Vec<Row> = from_record_batch(&batch).unwrap();
+        assert_eq!(rows, read);
+
+        // null lists of non-nullable fields are an error
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "names",
+            DataType::List(Box::new(DataType::Utf8)),
+            false,
+        )]));
+        assert!(to_record_batch(&rows, schema).is_err());
+
+        // missing lists are null
+        let rows = vec![serde_json::json!({}), serde_json::json!({"names": ["a"]})];
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "names",
+            DataType::List(Box::new(DataType::Utf8)),
+            true,
+        )]));
+        let batch = to_record_batch(&rows, schema).unwrap();
+        assert_eq!(2, batch.num_rows());
+        assert!(batch.column(0).is_null(0));
+    }
+
+    #[test]
+    fn test_non_nullable_struct_fields() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("x", DataType::Int32, false)]),
+            true,
+        )]));
+        // the fields of null structs may be null
+        let rows = vec![serde_json::json!({"s": {"x": 1}}), serde_json::json!({})];
+        let batch = to_record_batch(&rows, schema.clone()).unwrap();
+        assert_eq!(1, batch.column(0).null_count());
+
+        let rows = vec![serde_json::json!({"s": {"x": null}})];
+        let err = to_record_batch(&rows, schema).unwrap_err();
+        assert_eq!(
+            "Json error: Field 'x' is not nullable but has null values",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let rows: Vec<Value> = vec![];
+        let batch = to_record_batch(&rows, schema.clone()).unwrap();
+        assert_eq!(schema, batch.schema());
+        assert_eq!(0, batch.num_rows());
+    }
+
+    #[test]
+    fn test_infer_schema_merges_rows() {
+        let rows = vec![
+            serde_json::json!({"a": 1, "b": null, "c": "2020-01-01"}),
+            serde_json::json!({"a": 2.5, "b": [1], "c": "not a date", "d": {"x": true}}),
+            serde_json::json!({"b": [null, 2], "d": {"y": "z"}}),
+        ];
+        let schema = infer_schema(&rows).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::List(Box::new(DataType::Int64)), true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new(
+                "d",
+                DataType::Struct(vec![
+                    Field::new("x", DataType::Boolean, true),
+                    Field::new("y", DataType::Utf8, true),
+                ]),
+                true,
+            ),
+        ]);
+        assert_eq!(expected, *schema);
+
+        let batch = to_record_batch(&rows, schema).unwrap();
+        let read: Vec<Value> = from_record_batch(&batch).unwrap();
+        // nulls, including null lists, are left out
+        assert_eq!(serde_json::json!({"a": 1.0, "c": "2020-01-01"}), read[0]);
+        assert_eq!(
+            serde_json::json!({"b": [null, 2], "d": {"y": "z"}}),
+            read[2]
+        );
+    }
+}
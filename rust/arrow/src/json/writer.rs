@@ -0,0 +1,920 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! JSON Writer
+//!
+//! This JSON writer allows Arrow data (in record batches) to be written as
+//! line-delimited JSON objects, or as a single JSON array of objects.
+//!
+//! Each row is written as an object keyed by column name. Temporal values are
+//! written as ISO-8601 strings, binary values as hex or base64 strings, lists as
+//! arrays, and structs and maps as nested objects. Dictionary columns are written
+//! as their values. Null values are omitted from objects unless explicit nulls are
+//! requested.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow::array::Int32Array;
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::json;
+//! use arrow::record_batch::RecordBatch;
+//! use std::sync::Arc;
+//!
+//! let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+//! let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+//! let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)]).unwrap();
+//!
+//! let mut buf = Vec::new();
+//! {
+//!     let mut writer = json::LineDelimitedWriter::new(&mut buf);
+//!     writer.write_batches(&[batch]).unwrap();
+//!     writer.finish().unwrap();
+//! }
+//! assert_eq!(
+//!     String::from_utf8(buf).unwrap(),
+//!     "{\"a\":1}\n{}\n{\"a\":3}\n"
+//! );
+//! ```
+
+use std::fmt::Debug;
+use std::io::Write;
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use serde_json::{Map as JsonMap, Number, Value};
+
+use crate::array::*;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+/// The encoding of binary values, which JSON cannot represent natively
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal digits, two per byte
+    Hex,
+    /// Standard base64 with padding
+    Base64,
+}
+
+/// Options shared by the conversion functions
+#[derive(Debug, Clone, Copy)]
+struct EncoderOptions {
+    explicit_nulls: bool,
+    binary_encoding: BinaryEncoding,
+}
+
+impl Default for EncoderOptions {
+    fn default() -> Self {
+        Self {
+            explicit_nulls: false,
+            binary_encoding: BinaryEncoding::Hex,
+        }
+    }
+}
+
+/// Converts the values of a primitive array with `to_value`
+fn primitive_values<T, F>(array: &ArrayRef, to_value: F) -> Vec<Value>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Value,
+{
+    let array = as_primitive_array::<T>(array);
+    (0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                Value::Null
+            } else {
+                to_value(array.value(i))
+            }
+        })
+        .collect()
+}
+
+fn float_value(value: f64) -> Value {
+    Number::from_f64(value)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// Converts the values of a temporal array to strings with `to_string`, failing
+/// for values out of the range of `chrono`
+fn temporal_values<T, F>(array: &ArrayRef, to_string: F) -> Result<Vec<Value>>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+    F: Fn(&PrimitiveArray<T>, usize) -> Option<String>,
+{
+    let array = as_primitive_array::<T>(array);
+    (0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                return Ok(Value::Null);
+            }
+            to_string(array, i).map(Value::String).ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "Cannot convert value {:?} of type {:?} to an ISO-8601 string",
+                    array.value(i),
+                    T::DATA_TYPE
+                ))
+            })
+        })
+        .collect()
+}
+
+fn timestamp_values<T>(array: &ArrayRef, has_timezone: bool) -> Result<Vec<Value>>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+{
+    temporal_values::<T, _>(array, |array, i| {
+        array
+            .value_as_datetime(i)
+            .map(|datetime| format_timestamp(datetime, has_timezone))
+    })
+}
+
+/// Timestamps with a timezone are instants, written in UTC
+fn format_timestamp(datetime: NaiveDateTime, has_timezone: bool) -> String {
+    if has_timezone {
+        DateTime::<Utc>::from_utc(datetime, Utc)
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    } else {
+        datetime.format(TIMESTAMP_FORMAT).to_string()
+    }
+}
+
+fn date_values<T>(array: &ArrayRef) -> Result<Vec<Value>>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+{
+    temporal_values::<T, _>(array, |array, i| {
+        array
+            .value_as_date(i)
+            .map(|date| date.format(DATE_FORMAT).to_string())
+    })
+}
+
+fn time_values<T>(array: &ArrayRef) -> Result<Vec<Value>>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+{
+    temporal_values::<T, _>(array, |array, i| {
+        array
+            .value_as_time(i)
+            .map(|time| time.format(TIME_FORMAT).to_string())
+    })
+}
+
+fn binary_value(value: &[u8], options: &EncoderOptions) -> Value {
+    Value::String(match options.binary_encoding {
+        BinaryEncoding::Hex => hex::encode(value),
+        BinaryEncoding::Base64 => base64::encode(value),
+    })
+}
+
+macro_rules! binary_values {
+    ($array_type:ty, $array:ident, $options:ident) => {{
+        let array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        (0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    Value::Null
+                } else {
+                    binary_value(array.value(i), $options)
+                }
+            })
+            .collect()
+    }};
+}
+
+macro_rules! list_values {
+    ($array_type:ty, $array:ident, $options:ident) => {{
+        let array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        (0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    Ok(Value::Null)
+                } else {
+                    Ok(Value::Array(array_to_json_values(
+                        &array.value(i),
+                        $options,
+                    )?))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?
+    }};
+}
+
+/// Converts the values of a dictionary array to the JSON values of its dictionary
+fn dictionary_values<K: ArrowPrimitiveType>(
+    array: &ArrayRef,
+    options: &EncoderOptions,
+) -> Result<Vec<Value>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = array_to_json_values(&array.values(), options)?;
+    let keys = array.keys_array();
+    (0..keys.len())
+        .map(|i| {
+            if keys.is_null(i) {
+                return Ok(Value::Null);
+            }
+            let key = keys.value(i);
+            key.to_usize()
+                .and_then(|key| values.get(key).cloned())
+                .ok_or_else(|| {
+                    ArrowError::JsonError(format!(
+                        "Dictionary key {:?} is out of bounds",
+                        key
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Converts the rows of a struct, or of a record batch, to JSON objects
+fn columns_to_json_objects(
+    num_rows: usize,
+    fields: &[Field],
+    columns: &[ArrayRef],
+    options: &EncoderOptions,
+) -> Result<Vec<JsonMap<String, Value>>> {
+    let mut rows = vec![JsonMap::new(); num_rows];
+    for (field, column) in fields.iter().zip(columns) {
+        let values = array_to_json_values(column, options)?;
+        for (row, value) in rows.iter_mut().zip(values) {
+            if !value.is_null() || options.explicit_nulls {
+                row.insert(field.name().clone(), value);
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// Converts all values of an array to JSON values, with nulls as `Value::Null`
+fn array_to_json_values(
+    array: &ArrayRef,
+    options: &EncoderOptions,
+) -> Result<Vec<Value>> {
+    Ok(match array.data_type() {
+        DataType::Null => vec![Value::Null; array.len()],
+        DataType::Boolean => {
+            let array = as_boolean_array(array);
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Bool(array.value(i))
+                    }
+                })
+                .collect()
+        }
+        DataType::Int8 => primitive_values::<Int8Type, _>(array, Value::from),
+        DataType::Int16 => primitive_values::<Int16Type, _>(array, Value::from),
+        DataType::Int32 => primitive_values::<Int32Type, _>(array, Value::from),
+        DataType::Int64 => primitive_values::<Int64Type, _>(array, Value::from),
+        DataType::UInt8 => primitive_values::<UInt8Type, _>(array, Value::from),
+        DataType::UInt16 => primitive_values::<UInt16Type, _>(array, Value::from),
+        DataType::UInt32 => primitive_values::<UInt32Type, _>(array, Value::from),
+        DataType::UInt64 => primitive_values::<UInt64Type, _>(array, Value::from),
+        DataType::Float16 => {
+            primitive_values::<Float16Type, _>(array, |v| float_value(v.to_f64()))
+        }
+        DataType::Float32 => {
+            primitive_values::<Float32Type, _>(array, |v| float_value(v as f64))
+        }
+        DataType::Float64 => primitive_values::<Float64Type, _>(array, float_value),
+        DataType::Decimal(_, _) => {
+            // written as strings, as JSON numbers are usually parsed as doubles
+            let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value_as_string(i))
+                    }
+                })
+                .collect()
+        }
+        DataType::Utf8 => {
+            let array = as_string_array(array);
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect()
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect()
+        }
+        DataType::Binary => binary_values!(BinaryArray, array, options),
+        DataType::LargeBinary => binary_values!(LargeBinaryArray, array, options),
+        DataType::FixedSizeBinary(_) => {
+            binary_values!(FixedSizeBinaryArray, array, options)
+        }
+        DataType::Timestamp(unit, tz) => {
+            let has_timezone = tz.is_some();
+            match unit {
+                TimeUnit::Second => {
+                    timestamp_values::<TimestampSecondType>(array, has_timezone)?
+                }
+                TimeUnit::Millisecond => {
+                    timestamp_values::<TimestampMillisecondType>(array, has_timezone)?
+                }
+                TimeUnit::Microsecond => {
+                    timestamp_values::<TimestampMicrosecondType>(array, has_timezone)?
+                }
+                TimeUnit::Nanosecond => {
+                    timestamp_values::<TimestampNanosecondType>(array, has_timezone)?
+                }
+            }
+        }
+        DataType::Date32(_) => date_values::<Date32Type>(array)?,
+        DataType::Date64(_) => date_values::<Date64Type>(array)?,
+        DataType::Time32(TimeUnit::Second) => time_values::<Time32SecondType>(array)?,
+        DataType::Time32(TimeUnit::Millisecond) => {
+            time_values::<Time32MillisecondType>(array)?
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            time_values::<Time64MicrosecondType>(array)?
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            time_values::<Time64NanosecondType>(array)?
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            primitive_values::<DurationSecondType, _>(array, Value::from)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            primitive_values::<DurationMillisecondType, _>(array, Value::from)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            primitive_values::<DurationMicrosecondType, _>(array, Value::from)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            primitive_values::<DurationNanosecondType, _>(array, Value::from)
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            primitive_values::<IntervalYearMonthType, _>(array, Value::from)
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            primitive_values::<IntervalDayTimeType, _>(array, Value::from)
+        }
+        DataType::List(_) => list_values!(ListArray, array, options),
+        DataType::LargeList(_) => list_values!(LargeListArray, array, options),
+        DataType::FixedSizeList(_, _) => {
+            list_values!(FixedSizeListArray, array, options)
+        }
+        DataType::Struct(fields) => {
+            let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let columns = struct_array.columns_ref();
+            columns_to_json_objects(array.len(), fields, &columns, options)?
+                .into_iter()
+                .enumerate()
+                .map(|(i, object)| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Object(object)
+                    }
+                })
+                .collect()
+        }
+        DataType::Map(_, _) => {
+            let map_array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let keys = map_array.keys();
+            let keys = match keys.data_type() {
+                DataType::Utf8 => as_string_array(&keys),
+                _ => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Writing maps with {:?} keys to JSON is not supported",
+                        keys.data_type()
+                    )))
+                }
+            };
+            let values = array_to_json_values(&map_array.values(), options)?;
+            (0..map_array.len())
+                .map(|i| {
+                    if map_array.is_null(i) {
+                        return Value::Null;
+                    }
+                    let start = map_array.value_offset(i) as usize;
+                    let end = start + map_array.value_length(i) as usize;
+                    let mut object = JsonMap::new();
+                    for (j, value) in values.iter().enumerate().take(end).skip(start) {
+                        if !value.is_null() || options.explicit_nulls {
+                            object.insert(keys.value(j).to_string(), value.clone());
+                        }
+                    }
+                    Value::Object(object)
+                })
+                .collect()
+        }
+        DataType::Dictionary(key_type, _) => match **key_type {
+            DataType::Int8 => dictionary_values::<Int8Type>(array, options)?,
+            DataType::Int16 => dictionary_values::<Int16Type>(array, options)?,
+            DataType::Int32 => dictionary_values::<Int32Type>(array, options)?,
+            DataType::Int64 => dictionary_values::<Int64Type>(array, options)?,
+            DataType::UInt8 => dictionary_values::<UInt8Type>(array, options)?,
+            DataType::UInt16 => dictionary_values::<UInt16Type>(array, options)?,
+            DataType::UInt32 => dictionary_values::<UInt32Type>(array, options)?,
+            DataType::UInt64 => dictionary_values::<UInt64Type>(array, options)?,
+            _ => {
+                return Err(ArrowError::JsonError(format!(
+                    "Unsupported dictionary key type {:?}",
+                    key_type
+                )))
+            }
+        },
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "Writing {:?} arrays to JSON is not supported",
+                t
+            )))
+        }
+    })
+}
+
+fn record_batches_to_json_rows_with_options(
+    batches: &[RecordBatch],
+    options: &EncoderOptions,
+) -> Result<Vec<JsonMap<String, Value>>> {
+    let mut rows = Vec::with_capacity(batches.iter().map(|b| b.num_rows()).sum());
+    for batch in batches {
+        rows.extend(columns_to_json_objects(
+            batch.num_rows(),
+            batch.schema().fields(),
+            batch.columns(),
+            options,
+        )?);
+    }
+    Ok(rows)
+}
+
+/// Converts record batches to JSON objects, one per row, omitting null values
+pub fn record_batches_to_json_rows(
+    batches: &[RecordBatch],
+) -> Result<Vec<JsonMap<String, Value>>> {
+    record_batches_to_json_rows_with_options(batches, &EncoderOptions::default())
+}
+
+/// The layout of the rows written by a [`Writer`]
+pub trait JsonFormat: Debug + Default {
+    /// Writes any bytes needed before the first row
+    fn start_stream<W: Write>(&self, _writer: &mut W) -> Result<()> {
+        Ok(())
+    }
+
+    /// Writes any bytes needed before each row
+    fn start_row<W: Write>(&self, _writer: &mut W, _is_first_row: bool) -> Result<()> {
+        Ok(())
+    }
+
+    /// Writes any bytes needed after each row
+    fn end_row<W: Write>(&self, _writer: &mut W) -> Result<()> {
+        Ok(())
+    }
+
+    /// Writes any bytes needed after the last row
+    fn end_stream<W: Write>(&self, _writer: &mut W) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes one JSON object per line
+#[derive(Debug, Default)]
+pub struct LineDelimited {}
+
+impl JsonFormat for LineDelimited {
+    fn end_row<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Writes all rows as the objects of a single JSON array
+#[derive(Debug, Default)]
+pub struct JsonArray {}
+
+impl JsonFormat for JsonArray {
+    fn start_stream<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(b"[")?;
+        Ok(())
+    }
+
+    fn start_row<W: Write>(&self, writer: &mut W, is_first_row: bool) -> Result<()> {
+        if !is_first_row {
+            writer.write_all(b",")?;
+        }
+        Ok(())
+    }
+
+    fn end_stream<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(b"]")?;
+        Ok(())
+    }
+}
+
+/// A JSON writer writing line-delimited objects
+pub type LineDelimitedWriter<W> = Writer<W, LineDelimited>;
+
+/// A JSON writer writing a single array of objects
+pub type ArrayWriter<W> = Writer<W, JsonArray>;
+
+/// A JSON writer, writing record batches as JSON objects in the layout of `F`.
+///
+/// [`Writer::finish`] must be called after the last batch to complete the output.
+#[derive(Debug)]
+pub struct Writer<W: Write, F: JsonFormat> {
+    /// The object to write to
+    writer: W,
+    /// Whether any row was written
+    started: bool,
+    /// Whether the stream was completed
+    finished: bool,
+    /// The layout of the rows
+    format: F,
+    /// How values are converted
+    options: EncoderOptions,
+}
+
+impl<W: Write, F: JsonFormat> Writer<W, F> {
+    /// Create a new JSON writer from a writable object, with default options
+    pub fn new(writer: W) -> Self {
+        WriterBuilder::new().build(writer)
+    }
+
+    /// Writes a single JSON object as a row
+    pub fn write_row(&mut self, row: &Value) -> Result<()> {
+        let is_first_row = !self.started;
+        if !self.started {
+            self.format.start_stream(&mut self.writer)?;
+            self.started = true;
+        }
+        self.format.start_row(&mut self.writer, is_first_row)?;
+        serde_json::to_writer(&mut self.writer, row)?;
+        self.format.end_row(&mut self.writer)?;
+        Ok(())
+    }
+
+    /// Writes the rows of a record batch
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.write_batches(std::slice::from_ref(batch))
+    }
+
+    /// Writes the rows of several record batches
+    pub fn write_batches(&mut self, batches: &[RecordBatch]) -> Result<()> {
+        for row in record_batches_to_json_rows_with_options(batches, &self.options)? {
+            self.write_row(&Value::Object(row))?;
+        }
+        Ok(())
+    }
+
+    /// Completes the output, for instance closing the array of an [`ArrayWriter`].
+    /// An empty array is written if no row was written.
+    pub fn finish(&mut self) -> Result<()> {
+        if !self.started {
+            self.format.start_stream(&mut self.writer)?;
+            self.started = true;
+        }
+        if !self.finished {
+            self.format.end_stream(&mut self.writer)?;
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    /// Unwraps the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A JSON writer builder
+#[derive(Debug)]
+pub struct WriterBuilder {
+    /// Whether null values are written as `null` instead of being omitted.
+    /// Defaults to `false`
+    explicit_nulls: bool,
+    /// The encoding of binary values. Defaults to hex
+    binary_encoding: BinaryEncoding,
+}
+
+impl Default for WriterBuilder {
+    fn default() -> Self {
+        let options = EncoderOptions::default();
+        Self {
+            explicit_nulls: options.explicit_nulls,
+            binary_encoding: options.binary_encoding,
+        }
+    }
+}
+
+impl WriterBuilder {
+    /// Create a new builder for configuring JSON writing options.
+    ///
+    /// To convert a builder into a writer, call `WriterBuilder::build`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate arrow;
+    ///
+    /// use arrow::json;
+    /// use std::fs::File;
+    ///
+    /// fn example() -> json::LineDelimitedWriter<File> {
+    ///     let file = File::create("target/out.json").unwrap();
+    ///
+    ///     // create a builder that writes null values and base64 binary values
+    ///     let builder = json::WriterBuilder::new()
+    ///         .with_explicit_nulls(true)
+    ///         .with_binary_encoding(json::writer::BinaryEncoding::Base64);
+    ///
+    ///     builder.build(file)
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether null values are written as `null`, instead of being omitted
+    pub fn with_explicit_nulls(mut self, explicit_nulls: bool) -> Self {
+        self.explicit_nulls = explicit_nulls;
+        self
+    }
+
+    /// Set the encoding of binary values
+    pub fn with_binary_encoding(mut self, binary_encoding: BinaryEncoding) -> Self {
+        self.binary_encoding = binary_encoding;
+        self
+    }
+
+    /// Create a new `Writer`
+    pub fn build<W: Write, F: JsonFormat>(self, writer: W) -> Writer<W, F> {
+        Writer {
+            writer,
+            started: false,
+            finished: false,
+            format: F::default(),
+            options: EncoderOptions {
+                explicit_nulls: self.explicit_nulls,
+                binary_encoding: self.binary_encoding,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use crate::buffer::Buffer;
+
+    fn write_line_delimited(batch: RecordBatch, builder: WriterBuilder) -> String {
+        let mut buf = Vec::new();
+        {
+            let mut writer: LineDelimitedWriter<_> = builder.build(&mut buf);
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn write_primitives_and_nulls() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, true),
+            Field::new("d", DataType::Boolean, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(StringArray::from(vec![Some("a\"b"), None])),
+                Arc::new(Float64Array::from(vec![Some(1.5), Some(f64::NAN)])),
+                Arc::new(BooleanArray::from(vec![Some(true), None])),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            "{\"a\":1,\"b\":\"a\\\"b\",\"c\":1.5,\"d\":true}\n{}\n",
+            write_line_delimited(batch.clone(), WriterBuilder::new())
+        );
+        assert_eq!(
+            "{\"a\":1,\"b\":\"a\\\"b\",\"c\":1.5,\"d\":true}\n\
+             {\"a\":null,\"b\":null,\"c\":null,\"d\":null}\n",
+            write_line_delimited(batch, WriterBuilder::new().with_explicit_nulls(true))
+        );
+    }
+
+    #[test]
+    fn write_temporal_and_binary() {
+        let schema = Schema::new(vec![
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+            Field::new(
+                "ts_utc",
+                DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+02:00".into()))),
+                false,
+            ),
+            Field::new("date", DataType::Date32(DateUnit::Day), false),
+            Field::new("time", DataType::Time32(TimeUnit::Second), false),
+            Field::new("bin", DataType::Binary, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(TimestampMillisecondArray::from_vec(
+                    vec![1_599_572_549_190],
+                    None,
+                )),
+                Arc::new(TimestampSecondArray::from_vec(
+                    vec![1_599_572_549],
+                    Some(Arc::new("+02:00".into())),
+                )),
+                Arc::new(Date32Array::from(vec![18513])),
+                Arc::new(Time32SecondArray::from(vec![49349])),
+                Arc::new(BinaryArray::from(vec![b"\x01\xff".as_ref()])),
+            ],
+        )
+        .unwrap();
+
+        let expected = "{\"ts\":\"2020-09-08T13:42:29.190\",\
+                        \"ts_utc\":\"2020-09-08T13:42:29Z\",\
+                        \"date\":\"2020-09-08\",\
+                        \"time\":\"13:42:29\",\
+                        \"bin\":\"01ff\"}\n";
+        assert_eq!(
+            expected,
+            write_line_delimited(batch.clone(), WriterBuilder::new())
+        );
+
+        let expected = expected.replace("01ff", "Af8=");
+        assert_eq!(
+            expected,
+            write_line_delimited(
+                batch,
+                WriterBuilder::new().with_binary_encoding(BinaryEncoding::Base64)
+            )
+        );
+    }
+
+    #[test]
+    fn write_nested() {
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(4)
+            .add_buffer(Buffer::from(&[1, 2, 3, 4].to_byte_slice()))
+            .build();
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(3)
+            .add_buffer(Buffer::from(&[0, 2, 2, 4].to_byte_slice()))
+            .add_child_data(value_data)
+            .null_bit_buffer(Buffer::from([0b00000101]))
+            .build();
+        let list = ListArray::from(list_data);
+
+        let structs = StructArray::from(vec![
+            (
+                Field::new("x", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+            ),
+            (
+                Field::new("y", DataType::Utf8, false),
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ),
+        ]);
+
+        let mut builder =
+            StringDictionaryBuilder::new(Int8Builder::new(3), StringBuilder::new(2));
+        builder.append("bar").unwrap();
+        builder.append_null().unwrap();
+        builder.append("foo").unwrap();
+        let dict = builder.finish();
+
+        let schema = Schema::new(vec![
+            Field::new("list", list.data_type().clone(), true),
+            Field::new("struct", structs.data_type().clone(), false),
+            Field::new("dict", dict.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(list), Arc::new(structs), Arc::new(dict)],
+        )
+        .unwrap();
+
+        let rows = record_batches_to_json_rows(&[batch.clone()]).unwrap();
+        let rows: Vec<Value> = rows.into_iter().map(Value::Object).collect();
+        assert_eq!(
+            vec![
+                json!({"list": [1, 2], "struct": {"x": 1, "y": "a"}, "dict": "bar"}),
+                json!({"struct": {"y": "b"}}),
+                json!({"list": [3, 4], "struct": {"x": 3, "y": "c"}, "dict": "foo"}),
+            ],
+            rows
+        );
+
+        // sliced batches only write their rows
+        let sliced = batch.slice(1, 2);
+        let rows = record_batches_to_json_rows(&[sliced]).unwrap();
+        assert_eq!(2, rows.len());
+        assert_eq!(
+            json!({"struct": {"y": "b"}}),
+            Value::Object(rows[0].clone())
+        );
+    }
+
+    #[test]
+    fn write_map() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(3), Int32Builder::new(3));
+        builder.keys().append_value("a").unwrap();
+        builder.values().append_value(1).unwrap();
+        builder.keys().append_value("b").unwrap();
+        builder.values().append_null().unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("c").unwrap();
+        builder.values().append_value(3).unwrap();
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let schema = Schema::new(vec![Field::new("m", map.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(map)]).unwrap();
+
+        assert_eq!(
+            "{\"m\":{\"a\":1,\"b\":null}}\n{\"m\":null}\n{\"m\":{\"c\":3}}\n",
+            write_line_delimited(batch, WriterBuilder::new().with_explicit_nulls(true))
+        );
+    }
+
+    #[test]
+    fn write_json_array() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(vec![1, 2]))])
+                .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = ArrayWriter::new(&mut buf);
+            writer.write_batches(&[batch.clone(), batch]).unwrap();
+            writer.finish().unwrap();
+        }
+        assert_eq!(
+            "[{\"a\":1},{\"a\":2},{\"a\":1},{\"a\":2}]",
+            String::from_utf8(buf).unwrap()
+        );
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = ArrayWriter::new(&mut buf);
+            writer.finish().unwrap();
+        }
+        assert_eq!("[]", String::from_utf8(buf).unwrap());
+    }
+}
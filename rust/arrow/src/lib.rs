@@ -124,6 +124,7 @@
 //!
 //! The parquet implementation is on a [separate crate](https://crates.io/crates/parquet)
 
+#![cfg_attr(feature = "avx512", feature(avx512_target_feature))]
 #![allow(dead_code)]
 #![allow(non_camel_case_types)]
 #![allow(bare_trait_objects)]
@@ -137,9 +138,12 @@ pub mod compute;
 pub mod csv;
 pub mod datatypes;
 pub mod error;
+pub mod ffi;
+pub mod ffi_stream;
 pub mod ipc;
 pub mod json;
 pub mod memory;
 pub mod record_batch;
+pub mod sparse_tensor;
 pub mod tensor;
 pub mod util;
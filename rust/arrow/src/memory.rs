@@ -17,10 +17,37 @@
 
 //! Defines memory-related functions, such as allocate/deallocate/reallocate memory
 //! regions, cache and allocation alignments.
+//!
+//! Memory can also be allocated through a [`MemoryPool`], which allows controlling and
+//! observing how much memory buffers use. [`SystemMemoryPool`] allocates directly
+//! from the system allocator, while [`TrackingMemoryPool`] keeps count of the bytes
+//! allocated through it and can refuse allocations above a configured limit.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::{Array, Int32Builder};
+//! use arrow::memory::TrackingMemoryPool;
+//!
+//! let pool = Arc::new(TrackingMemoryPool::with_limit(1024));
+//! let mut builder = Int32Builder::try_new_in(16, pool.clone()).unwrap();
+//! builder.append_slice(&[1, 2, 3]).unwrap();
+//! let array = builder.finish();
+//! assert!(pool.bytes_allocated() > 0);
+//!
+//! drop(array);
+//! assert_eq!(pool.bytes_allocated(), 0);
+//! ```
 
 use std::alloc::Layout;
+use std::fmt::Debug;
 use std::mem::align_of;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+
+use crate::error::{ArrowError, Result};
 
 // NOTE: Below code is written for spatial/temporal prefetcher optimizations. Memory allocation
 // should align well with usage pattern of cache access and block sizes on layers of storage levels from
@@ -231,6 +258,225 @@ pub fn is_ptr_aligned<T>(p: *const T) -> bool {
     p.align_offset(align_of::<T>()) == 0
 }
 
+/// A source of the memory regions backing Arrow buffers.
+///
+/// Regions handed out by a pool are zero-initialized and aligned to [ALIGNMENT]. A
+/// region must be reallocated and released through the same pool that allocated it,
+/// possibly from a different thread than the one that allocated it.
+pub trait MemoryPool: Debug + Send + Sync {
+    /// Allocates a zeroed region of `size` bytes, `size` being greater than zero.
+    fn allocate(&self, size: usize) -> Result<*mut u8>;
+
+    /// Resizes the region at `ptr` from `old_size` to `new_size` bytes, zeroing the
+    /// bytes past `old_size` when growing it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must denote a region currently allocated by this pool with a size of
+    /// `old_size`, and both sizes must be greater than zero. On success `ptr` must
+    /// no longer be used.
+    unsafe fn reallocate(
+        &self,
+        ptr: *mut u8,
+        old_size: usize,
+        new_size: usize,
+    ) -> Result<*mut u8>;
+
+    /// Releases the region at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must denote a region currently allocated by this pool with a size of
+    /// `size`.
+    unsafe fn free(&self, ptr: *mut u8, size: usize);
+}
+
+/// A [`MemoryPool`] allocating directly from the system allocator, through the
+/// functions of this module.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemMemoryPool;
+
+impl MemoryPool for SystemMemoryPool {
+    fn allocate(&self, size: usize) -> Result<*mut u8> {
+        let ptr = allocate_aligned(size);
+        if ptr.is_null() {
+            return Err(ArrowError::MemoryError(format!(
+                "Failed to allocate {} bytes",
+                size
+            )));
+        }
+        Ok(ptr)
+    }
+
+    unsafe fn reallocate(
+        &self,
+        ptr: *mut u8,
+        old_size: usize,
+        new_size: usize,
+    ) -> Result<*mut u8> {
+        let new_ptr = reallocate(ptr, old_size, new_size);
+        if new_ptr.is_null() {
+            return Err(ArrowError::MemoryError(format!(
+                "Failed to reallocate {} bytes to {} bytes",
+                old_size, new_size
+            )));
+        }
+        Ok(new_ptr)
+    }
+
+    unsafe fn free(&self, ptr: *mut u8, size: usize) {
+        free_aligned(ptr, size)
+    }
+}
+
+lazy_static! {
+    static ref DEFAULT_MEMORY_POOL: Arc<MemoryPool> = Arc::new(SystemMemoryPool);
+}
+
+/// Returns the process-wide [`SystemMemoryPool`].
+pub fn default_memory_pool() -> Arc<MemoryPool> {
+    DEFAULT_MEMORY_POOL.clone()
+}
+
+/// A [`MemoryPool`] that keeps count of the bytes currently allocated through it and
+/// of their peak, delegating the allocations themselves to another pool.
+///
+/// When created with a limit, allocations that would bring the bytes allocated above
+/// it fail with `ArrowError::MemoryError`.
+#[derive(Debug)]
+pub struct TrackingMemoryPool {
+    pool: Arc<MemoryPool>,
+    limit: Option<usize>,
+    allocated: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl TrackingMemoryPool {
+    /// Creates an unlimited pool allocating from the system allocator.
+    pub fn new() -> Self {
+        Self::new_with_pool(default_memory_pool(), None)
+    }
+
+    /// Creates a pool allocating from the system allocator that refuses to hold more
+    /// than `limit` bytes at a time.
+    pub fn with_limit(limit: usize) -> Self {
+        Self::new_with_pool(default_memory_pool(), Some(limit))
+    }
+
+    /// Creates a pool allocating from `pool`, with an optional limit in bytes.
+    pub fn new_with_pool(pool: Arc<MemoryPool>, limit: Option<usize>) -> Self {
+        Self {
+            pool,
+            limit,
+            allocated: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of bytes currently allocated through this pool.
+    pub fn bytes_allocated(&self) -> usize {
+        self.allocated.load(Ordering::SeqCst)
+    }
+
+    /// Returns the highest number of bytes allocated through this pool at any time.
+    pub fn peak_bytes_allocated(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    /// Returns the maximum number of bytes this pool allows to be allocated, if any.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Accounts for `size` more bytes, failing if this would exceed the limit.
+    fn reserve(&self, size: usize) -> Result<()> {
+        let mut current = self.allocated.load(Ordering::SeqCst);
+        let new = loop {
+            let new = current.checked_add(size).ok_or_else(|| {
+                ArrowError::MemoryError(format!("Failed to allocate {} bytes", size))
+            })?;
+            if let Some(limit) = self.limit {
+                if new > limit {
+                    return Err(ArrowError::MemoryError(format!(
+                        "Failed to allocate {} bytes: {} of the limit of {} bytes \
+                         are already allocated",
+                        size, current, limit
+                    )));
+                }
+            }
+            match self.allocated.compare_exchange_weak(
+                current,
+                new,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break new,
+                Err(actual) => current = actual,
+            }
+        };
+
+        let mut peak = self.peak.load(Ordering::SeqCst);
+        while new > peak {
+            match self.peak.compare_exchange_weak(
+                peak,
+                new,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(_) => break,
+                Err(actual) => peak = actual,
+            }
+        }
+        Ok(())
+    }
+
+    /// Accounts for `size` bytes being released.
+    fn release(&self, size: usize) {
+        self.allocated.fetch_sub(size, Ordering::SeqCst);
+    }
+}
+
+impl Default for TrackingMemoryPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryPool for TrackingMemoryPool {
+    fn allocate(&self, size: usize) -> Result<*mut u8> {
+        self.reserve(size)?;
+        self.pool.allocate(size).map_err(|e| {
+            self.release(size);
+            e
+        })
+    }
+
+    unsafe fn reallocate(
+        &self,
+        ptr: *mut u8,
+        old_size: usize,
+        new_size: usize,
+    ) -> Result<*mut u8> {
+        if new_size > old_size {
+            let grown = new_size - old_size;
+            self.reserve(grown)?;
+            self.pool.reallocate(ptr, old_size, new_size).map_err(|e| {
+                self.release(grown);
+                e
+            })
+        } else {
+            let new_ptr = self.pool.reallocate(ptr, old_size, new_size)?;
+            self.release(old_size - new_size);
+            Ok(new_ptr)
+        }
+    }
+
+    unsafe fn free(&self, ptr: *mut u8, size: usize) {
+        self.pool.free(ptr, size);
+        self.release(size);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,4 +504,53 @@ mod tests {
         assert_eq!(false, is_aligned::<u8>(ptr, 2));
         assert_eq!(false, is_aligned::<u8>(ptr, 4));
     }
+
+    #[test]
+    fn test_tracking_memory_pool() {
+        let pool = TrackingMemoryPool::new();
+        let ptr = pool.allocate(128).unwrap();
+        assert!(is_aligned(ptr, ALIGNMENT));
+        assert_eq!(128, pool.bytes_allocated());
+
+        let ptr = unsafe { pool.reallocate(ptr, 128, 512).unwrap() };
+        assert_eq!(512, pool.bytes_allocated());
+        let ptr = unsafe { pool.reallocate(ptr, 512, 256).unwrap() };
+        assert_eq!(256, pool.bytes_allocated());
+        assert_eq!(512, pool.peak_bytes_allocated());
+
+        unsafe { pool.free(ptr, 256) };
+        assert_eq!(0, pool.bytes_allocated());
+        assert_eq!(512, pool.peak_bytes_allocated());
+        assert_eq!(None, pool.limit());
+    }
+
+    #[test]
+    fn test_tracking_memory_pool_limit() {
+        let pool = TrackingMemoryPool::with_limit(256);
+        let ptr = pool.allocate(192).unwrap();
+        assert!(pool.allocate(128).is_err());
+        assert!(unsafe { pool.reallocate(ptr, 192, 320) }.is_err());
+        assert_eq!(192, pool.bytes_allocated());
+
+        let other = pool.allocate(64).unwrap();
+        assert_eq!(256, pool.bytes_allocated());
+        unsafe {
+            pool.free(ptr, 192);
+            pool.free(other, 64);
+        }
+        assert_eq!(0, pool.bytes_allocated());
+        assert_eq!(256, pool.peak_bytes_allocated());
+    }
+
+    #[test]
+    fn test_nested_tracking_memory_pools() {
+        let parent = Arc::new(TrackingMemoryPool::with_limit(1024));
+        let child = TrackingMemoryPool::new_with_pool(parent.clone(), Some(512));
+        let ptr = child.allocate(256).unwrap();
+        assert_eq!(256, parent.bytes_allocated());
+        assert!(child.allocate(512).is_err());
+        assert_eq!(256, parent.bytes_allocated());
+        unsafe { child.free(ptr, 256) };
+        assert_eq!(0, parent.bytes_allocated());
+    }
 }
@@ -21,6 +21,8 @@
 use std::sync::Arc;
 
 use crate::array::*;
+use crate::compute::kernels::cast::cast;
+use crate::compute::kernels::concat::concat;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
@@ -184,6 +186,193 @@ impl RecordBatch {
     pub fn columns(&self) -> &[ArrayRef] {
         &self.columns[..]
     }
+
+    /// Returns a zero-copy slice of this record batch, slicing each column
+    /// with the indicated offset and length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let id_array = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])) as ArrayRef;
+    /// let batch = RecordBatch::try_from_iter(vec![("id", id_array)])?;
+    ///
+    /// let slice = batch.slice(1, 3);
+    /// assert_eq!(slice.num_rows(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slice(&self, offset: usize, length: usize) -> RecordBatch {
+        assert!(
+            offset + length <= self.num_rows(),
+            "the offset and length of the slice exceed the number of rows"
+        );
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.slice(offset, length))
+            .collect();
+        RecordBatch {
+            schema: self.schema.clone(),
+            columns,
+        }
+    }
+
+    /// Returns a new record batch containing only the columns at `indices`,
+    /// in the given order.
+    ///
+    /// An error is returned if any index is out of bounds.
+    pub fn project(&self, indices: &[usize]) -> Result<RecordBatch> {
+        let schema = self.schema.project(indices)?;
+        let columns = indices.iter().map(|i| self.columns[*i].clone()).collect();
+        RecordBatch::try_new(Arc::new(schema), columns)
+    }
+
+    /// Converts this record batch to `schema`, such as one created by
+    /// [`Schema::try_merge`](crate::datatypes::Schema::try_merge).
+    ///
+    /// Columns are matched by name and cast to the type of their field, and the
+    /// fields of struct columns are adapted in the same way. Fields missing from this
+    /// batch are filled with nulls, which requires them to be nullable. An error is
+    /// returned if a column of this batch is not in `schema`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array};
+    /// use arrow::datatypes::{DataType, Field, Schema};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let a = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a)])?;
+    ///
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("a", DataType::Int64, false),
+    ///     Field::new("b", DataType::Utf8, true),
+    /// ]));
+    /// let adapted = batch.adapt(schema.clone())?;
+    ///
+    /// assert_eq!(adapted.schema(), schema);
+    /// assert_eq!(adapted.column(1).null_count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn adapt(&self, schema: SchemaRef) -> Result<RecordBatch> {
+        let columns = adapt_columns(
+            self.schema.fields(),
+            &self.columns,
+            schema.fields(),
+            self.num_rows(),
+            "record batch",
+        )?;
+        RecordBatch::try_new(schema, columns)
+    }
+
+    /// Creates a `RecordBatch` from an iterator of `(name, column)` pairs.
+    ///
+    /// A field is marked as nullable if its column contains any nulls. Use
+    /// [`try_from_iter_with_nullable`](Self::try_from_iter_with_nullable) to
+    /// set the nullability explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array, StringArray};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None]));
+    ///
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)])?;
+    ///
+    /// assert!(!batch.schema().field(0).is_nullable());
+    /// assert!(batch.schema().field(1).is_nullable());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_iter<I, F>(value: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (F, ArrayRef)>,
+        F: AsRef<str>,
+    {
+        let iter = value.into_iter().map(|(name, array)| {
+            let nullable = array.null_count() > 0;
+            (name, array, nullable)
+        });
+        Self::try_from_iter_with_nullable(iter)
+    }
+
+    /// Creates a `RecordBatch` from an iterator of `(name, column, nullable)`
+    /// tuples.
+    pub fn try_from_iter_with_nullable<I, F>(value: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (F, ArrayRef, bool)>,
+        F: AsRef<str>,
+    {
+        let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = value
+            .into_iter()
+            .map(|(name, array, nullable)| {
+                let field =
+                    Field::new(name.as_ref(), array.data_type().clone(), nullable);
+                (field, array)
+            })
+            .unzip();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+    }
+
+    /// Concatenates `batches` into a single record batch with the given schema.
+    ///
+    /// Every batch must have a schema equal to `schema`. At least one batch
+    /// is required.
+    pub fn concat(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<Self> {
+        if batches.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "at least one batch must be provided to concatenate".to_string(),
+            ));
+        }
+        if let Some((i, _)) = batches
+            .iter()
+            .enumerate()
+            .find(|&(_, batch)| batch.schema() != *schema)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] schema is different from the provided schema",
+                i
+            )));
+        }
+        let columns = (0..schema.fields().len())
+            .map(|i| {
+                let arrays = batches
+                    .iter()
+                    .map(|batch| batch.column(i).clone())
+                    .collect::<Vec<_>>();
+                concat(&arrays)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(schema.clone(), columns)
+    }
+}
+
+/// Concatenates `batches` into a single record batch with the given schema.
+///
+/// See [`RecordBatch::concat`].
+pub fn concat_batches(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+) -> Result<RecordBatch> {
+    RecordBatch::concat(schema, batches)
 }
 
 impl From<&StructArray> for RecordBatch {
@@ -204,18 +393,99 @@ impl From<&StructArray> for RecordBatch {
     }
 }
 
-impl Into<StructArray> for RecordBatch {
-    fn into(self) -> StructArray {
-        self.schema
+impl From<StructArray> for RecordBatch {
+    fn from(struct_array: StructArray) -> Self {
+        RecordBatch::from(&struct_array)
+    }
+}
+
+impl From<RecordBatch> for StructArray {
+    fn from(batch: RecordBatch) -> Self {
+        batch
+            .schema
             .fields
             .iter()
-            .zip(self.columns.iter())
+            .zip(batch.columns.iter())
             .map(|t| (t.0.clone(), t.1.clone()))
             .collect::<Vec<(Field, ArrayRef)>>()
             .into()
     }
 }
 
+/// Adapts the `columns` of `fields` to `target_fields`, filling missing fields with
+/// `len` nulls. `parent` names the record batch or struct of the columns in errors.
+fn adapt_columns(
+    fields: &[Field],
+    columns: &[ArrayRef],
+    target_fields: &[Field],
+    len: usize,
+    parent: &str,
+) -> Result<Vec<ArrayRef>> {
+    if let Some(field) = fields
+        .iter()
+        .find(|field| !target_fields.iter().any(|f| f.name() == field.name()))
+    {
+        return Err(ArrowError::SchemaError(format!(
+            "column '{}' of the {} is not in the target schema",
+            field.name(),
+            parent
+        )));
+    }
+    target_fields
+        .iter()
+        .map(
+            |target| match fields.iter().position(|f| f.name() == target.name()) {
+                Some(i) => adapt_column(&columns[i], target),
+                None if target.is_nullable() => {
+                    Ok(new_null_array(target.data_type(), len))
+                }
+                None => Err(ArrowError::SchemaError(format!(
+                    "non-nullable field '{}' is missing from the {}",
+                    target.name(),
+                    parent
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Adapts a column to the type of `field`. Struct columns are adapted field by field,
+/// as they can not be cast to another struct type.
+fn adapt_column(array: &ArrayRef, field: &Field) -> Result<ArrayRef> {
+    match (array.data_type(), field.data_type()) {
+        (DataType::Struct(fields), DataType::Struct(target_fields))
+            if fields != target_fields =>
+        {
+            let data = array.data();
+            let columns = data
+                .child_data()
+                .iter()
+                .cloned()
+                .map(make_array)
+                .collect::<Vec<_>>();
+            // the children are not sliced with the struct
+            let len = data.offset() + data.len();
+            let columns = adapt_columns(
+                fields,
+                &columns,
+                target_fields,
+                len,
+                &format!("struct '{}'", field.name()),
+            )?;
+            Ok(make_array(Arc::new(ArrayData::new(
+                field.data_type().clone(),
+                data.len(),
+                None,
+                data.null_buffer().cloned(),
+                data.offset(),
+                vec![],
+                columns.iter().map(|column| column.data()).collect(),
+            ))))
+        }
+        _ => cast(array, field.data_type()),
+    }
+}
+
 /// Trait for types that can read `RecordBatch`'s.
 pub trait RecordBatchReader: Iterator<Item = Result<RecordBatch>> {
     /// Returns the schema of this `RecordBatchReader`.
@@ -238,6 +508,8 @@ pub trait RecordBatchReader: Iterator<Item = Result<RecordBatch>> {
 mod tests {
     use super::*;
 
+    use std::convert::TryFrom;
+
     use crate::buffer::*;
 
     #[test]
@@ -329,4 +601,226 @@ mod tests {
         assert_eq!(batch.column(0).data(), boolean_data);
         assert_eq!(batch.column(1).data(), int_data);
     }
+
+    #[test]
+    fn create_record_batch_try_from_iter() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+
+        let batch =
+            RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b.clone())]).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        assert_eq!(&expected, batch.schema().as_ref());
+        assert_eq!(3, batch.num_rows());
+
+        let batch = RecordBatch::try_from_iter_with_nullable(vec![
+            ("a", a, false),
+            ("b", b, true),
+        ])
+        .unwrap();
+        assert!(!batch.schema().field(0).is_nullable());
+        assert!(batch.schema().field(1).is_nullable());
+
+        let c: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let d: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        assert!(RecordBatch::try_from_iter(vec![("c", c), ("d", d)]).is_err());
+    }
+
+    #[test]
+    fn record_batch_slice() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        let sliced = batch.slice(1, 2);
+        assert_eq!(2, sliced.num_rows());
+        assert_eq!(batch.schema(), sliced.schema());
+        let a = sliced
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&Int32Array::from(vec![2, 3]), a);
+        let b = sliced
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("b", b.value(0));
+        assert_eq!("c", b.value(1));
+
+        assert_eq!(0, batch.slice(4, 0).num_rows());
+    }
+
+    #[test]
+    #[should_panic(expected = "exceed the number of rows")]
+    fn record_batch_slice_out_of_bounds() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+        batch.slice(2, 3);
+    }
+
+    #[test]
+    fn record_batch_project() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b"]));
+        let c: ArrayRef = Arc::new(BooleanArray::from(vec![true, false]));
+        let batch =
+            RecordBatch::try_from_iter(vec![("a", a), ("b", b), ("c", c)]).unwrap();
+
+        let projected = batch.project(&[2, 0]).unwrap();
+        assert_eq!(2, projected.num_columns());
+        assert_eq!("c", projected.schema().field(0).name());
+        assert_eq!("a", projected.schema().field(1).name());
+        assert!(Arc::ptr_eq(batch.column(2), projected.column(0)));
+        assert!(Arc::ptr_eq(batch.column(0), projected.column(1)));
+
+        assert!(batch.project(&[3]).is_err());
+    }
+
+    #[test]
+    fn record_batch_concat() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![3])),
+                Arc::new(StringArray::from(vec!["c"])),
+            ],
+        )
+        .unwrap();
+
+        let batch = concat_batches(&schema, &[batch1.clone(), batch2]).unwrap();
+        assert_eq!(schema, batch.schema());
+        assert_eq!(3, batch.num_rows());
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&Int32Array::from(vec![1, 2, 3]), a);
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(&StringArray::from(vec!["a", "b", "c"]), b);
+
+        assert!(concat_batches(&schema, &[]).is_err());
+
+        let other_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        assert!(concat_batches(&other_schema, &[batch1]).is_err());
+    }
+
+    #[test]
+    fn record_batch_struct_array_round_trip() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        let struct_array = StructArray::from(batch.clone());
+        assert_eq!(2, struct_array.len());
+        assert_eq!(
+            &DataType::Struct(batch.schema().fields().clone()),
+            struct_array.data_type()
+        );
+
+        let round_trip = RecordBatch::from(struct_array);
+        assert_eq!(batch.schema(), round_trip.schema());
+        assert_eq!(batch.column(0).data(), round_trip.column(0).data());
+        assert_eq!(batch.column(1).data(), round_trip.column(1).data());
+    }
+
+    #[test]
+    fn record_batch_adapt() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("c", c)]).unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::LargeUtf8, false),
+        ]));
+        let adapted = batch.adapt(schema.clone()).unwrap();
+        assert_eq!(schema, adapted.schema());
+        assert_eq!(2, adapted.num_rows());
+        let a = adapted
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(&Int64Array::from(vec![Some(1), None]), a);
+        assert_eq!(2, adapted.column(1).null_count());
+        let c = adapted
+            .column(2)
+            .as_any()
+            .downcast_ref::<LargeStringArray>()
+            .unwrap();
+        assert_eq!("y", c.value(1));
+
+        // missing fields must be nullable
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Float64, false),
+            Field::new("c", DataType::Utf8, false),
+        ]));
+        assert!(batch.adapt(schema).is_err());
+
+        // every column must be in the target schema
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        assert!(batch.adapt(schema).is_err());
+    }
+
+    #[test]
+    fn record_batch_adapt_struct() {
+        let x: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let s = StructArray::try_from(vec![("x", x)]).unwrap();
+        let s = Arc::new(s).slice(1, 2);
+        let batch = RecordBatch::try_from_iter(vec![("s", s)]).unwrap();
+
+        // the struct gains a field, and its existing field is widened
+        let target = DataType::Struct(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(Schema::new(vec![Field::new("s", target.clone(), false)]));
+        let adapted = batch.adapt(schema.clone()).unwrap();
+        assert_eq!(schema, adapted.schema());
+        let s = adapted
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(&target, s.data_type());
+        assert_eq!(2, s.len());
+        let x = s.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert!(x.is_null(0));
+        assert_eq!(3, x.value(1));
+        assert_eq!(2, s.column(1).null_count());
+
+        // fields of the struct must be in the target struct
+        let target = DataType::Struct(vec![Field::new("y", DataType::Utf8, true)]);
+        let schema = Arc::new(Schema::new(vec![Field::new("s", target, false)]));
+        let err = batch.adapt(schema).unwrap_err();
+        assert_eq!(
+            "Schema error: column 'x' of the struct 's' is not in the target schema",
+            err.to_string()
+        );
+    }
 }
@@ -0,0 +1,631 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arrow Sparse Tensor Types, defined in
+//! [`format/SparseTensor.fbs`](https://github.com/apache/arrow/blob/master/format/SparseTensor.fbs).
+//!
+//! Indices are always stored as signed 64-bit integers. An element equal to
+//! `T::Native::default()` is considered to be zero.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::buffer::Buffer;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::tensor::Tensor;
+
+/// Reads the native value located at `byte_offset` in `buffer`
+fn read_value<T: ArrowPrimitiveType>(buffer: &Buffer, byte_offset: usize) -> T::Native {
+    let bytes = &buffer.data()[byte_offset..byte_offset + mem::size_of::<T::Native>()];
+    unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const T::Native) }
+}
+
+/// Returns the shape and strides of a dense tensor, failing for scalars
+fn dense_layout<'b, T: ArrowPrimitiveType>(
+    tensor: &'b Tensor<T>,
+) -> Result<(&'b Vec<usize>, &'b Vec<usize>)> {
+    match (tensor.shape(), tensor.strides()) {
+        (Some(shape), Some(strides)) => Ok((shape, strides)),
+        _ => Err(ArrowError::InvalidArgumentError(
+            "cannot create a sparse tensor from a tensor without a shape".to_string(),
+        )),
+    }
+}
+
+/// Reads the `i`th value of a buffer of 64-bit indices
+fn index_value(buffer: &Buffer, i: usize) -> i64 {
+    let bytes = &buffer.data()[i * 8..(i + 1) * 8];
+    let mut value = [0u8; 8];
+    value.copy_from_slice(bytes);
+    i64::from_le_bytes(value)
+}
+
+/// Checks that `buffer` holds exactly `len` 64-bit indices
+fn check_index_buffer(buffer: &Buffer, len: usize, name: &str) -> Result<()> {
+    if buffer.len() != len * mem::size_of::<i64>() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "expected {} buffer of {} bytes, got {}",
+            name,
+            len * mem::size_of::<i64>(),
+            buffer.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that the optional dimension names match the shape
+fn check_names(shape: &[usize], names: &Option<Vec<&str>>) -> Result<()> {
+    match names {
+        Some(n) if n.len() != shape.len() => Err(ArrowError::InvalidArgumentError(
+            "number of dimensions and number of dimension names differ".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Sparse tensor in coordinate (COO) format.
+///
+/// The indices are a row major matrix of shape `[non_zero_length, ndim]` holding the
+/// coordinates of each non-zero value stored in the data buffer.
+#[derive(Debug)]
+pub struct SparseCOOTensor<'a, T: ArrowPrimitiveType> {
+    data_type: DataType,
+    shape: Vec<usize>,
+    names: Option<Vec<&'a str>>,
+    indices: Buffer,
+    data: Buffer,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: ArrowPrimitiveType> SparseCOOTensor<'a, T> {
+    /// Creates a new `SparseCOOTensor`
+    pub fn try_new(
+        shape: Vec<usize>,
+        indices: Buffer,
+        data: Buffer,
+        names: Option<Vec<&'a str>>,
+    ) -> Result<Self> {
+        if shape.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "sparse tensor must have at least one dimension".to_string(),
+            ));
+        }
+        check_names(&shape, &names)?;
+        if data.len() % mem::size_of::<T::Native>() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "data buffer length is not a multiple of the value size".to_string(),
+            ));
+        }
+        let non_zero_length = data.len() / mem::size_of::<T::Native>();
+        check_index_buffer(&indices, non_zero_length * shape.len(), "indices")?;
+        for i in 0..non_zero_length {
+            for (axis, dim) in shape.iter().enumerate() {
+                let index = index_value(&indices, i * shape.len() + axis);
+                if index < 0 || index as usize >= *dim {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "index {} of value {} is out of bounds for dimension {} of size {}",
+                        index, i, axis, dim
+                    )));
+                }
+            }
+        }
+
+        Ok(Self {
+            data_type: T::DATA_TYPE,
+            shape,
+            names,
+            indices,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a `SparseCOOTensor` holding the non-zero values of a dense tensor,
+    /// with coordinates in row major order
+    pub fn try_from_dense(tensor: &Tensor<'a, T>) -> Result<Self> {
+        let (shape, strides) = dense_layout(tensor)?;
+        let mut indices: Vec<i64> = Vec::new();
+        let mut values: Vec<T::Native> = Vec::new();
+        if shape.iter().all(|dim| *dim > 0) {
+            let mut coordinate = vec![0; shape.len()];
+            loop {
+                let offset = coordinate
+                    .iter()
+                    .zip(strides.iter())
+                    .map(|(c, s)| c * s)
+                    .sum();
+                let value = read_value::<T>(tensor.data(), offset);
+                if value != T::default_value() {
+                    indices.extend(coordinate.iter().map(|c| *c as i64));
+                    values.push(value);
+                }
+                // advance to the next coordinate, last dimension varying fastest
+                let mut axis = shape.len();
+                while axis > 0 {
+                    axis -= 1;
+                    coordinate[axis] += 1;
+                    if coordinate[axis] < shape[axis] {
+                        break;
+                    }
+                    coordinate[axis] = 0;
+                }
+                if coordinate.iter().all(|c| *c == 0) {
+                    break;
+                }
+            }
+        }
+        Self::try_new(
+            shape.clone(),
+            Buffer::from(indices.to_byte_slice()),
+            Buffer::from(values.to_byte_slice()),
+            tensor.names().cloned(),
+        )
+    }
+
+    /// Converts this tensor into a dense row major `Tensor`
+    pub fn to_dense(&self) -> Result<Tensor<'a, T>> {
+        let ndim = self.ndim();
+        let strides: Vec<usize> = (0..ndim)
+            .map(|axis| self.shape[axis + 1..].iter().product())
+            .collect();
+        let mut values = vec![T::default_value(); self.shape.iter().product()];
+        let data = self.values();
+        for (i, value) in data.iter().enumerate() {
+            let position: usize = (0..ndim)
+                .map(|axis| {
+                    index_value(&self.indices, i * ndim + axis) as usize * strides[axis]
+                })
+                .sum();
+            values[position] = *value;
+        }
+        Tensor::new_row_major(
+            Buffer::from(values.to_byte_slice()),
+            Some(self.shape.clone()),
+            self.names.clone(),
+        )
+    }
+
+    /// The data type of the `SparseCOOTensor`
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The sizes of the dimensions
+    pub fn shape(&self) -> &Vec<usize> {
+        &self.shape
+    }
+
+    /// The names of the dimensions
+    pub fn names(&self) -> Option<&Vec<&'a str>> {
+        self.names.as_ref()
+    }
+
+    /// The number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// The number of non-zero values
+    pub fn non_zero_length(&self) -> usize {
+        self.data.len() / mem::size_of::<T::Native>()
+    }
+
+    /// Returns a reference to the buffer of 64-bit coordinates
+    pub fn indices(&self) -> &Buffer {
+        &self.indices
+    }
+
+    /// Returns a reference to the buffer of non-zero values
+    pub fn data(&self) -> &Buffer {
+        &self.data
+    }
+
+    /// The coordinates of the `i`th non-zero value
+    pub fn index(&self, i: usize) -> Vec<usize> {
+        assert!(i < self.non_zero_length());
+        (0..self.ndim())
+            .map(|axis| index_value(&self.indices, i * self.ndim() + axis) as usize)
+            .collect()
+    }
+
+    /// The non-zero values
+    pub fn values(&self) -> Vec<T::Native> {
+        (0..self.non_zero_length())
+            .map(|i| read_value::<T>(&self.data, i * mem::size_of::<T::Native>()))
+            .collect()
+    }
+}
+
+/// The axis compressed by a [`SparseCSXMatrix`]
+pub trait CompressedAxis: Debug + Send + Sync + 'static {
+    /// Whether the rows, rather than the columns, are compressed
+    const IS_ROW: bool;
+}
+
+/// Marker for matrices in compressed sparse row (CSR) format
+#[derive(Debug)]
+pub struct RowAxis {}
+
+impl CompressedAxis for RowAxis {
+    const IS_ROW: bool = true;
+}
+
+/// Marker for matrices in compressed sparse column (CSC) format
+#[derive(Debug)]
+pub struct ColumnAxis {}
+
+impl CompressedAxis for ColumnAxis {
+    const IS_ROW: bool = false;
+}
+
+/// Sparse matrix in compressed sparse row or column format.
+///
+/// For a CSR matrix, the values of row `i` are stored at positions
+/// `indptr[i]..indptr[i + 1]` and `indices` holds their column; a CSC matrix
+/// swaps the roles of rows and columns.
+#[derive(Debug)]
+pub struct SparseCSXMatrix<'a, T: ArrowPrimitiveType, A: CompressedAxis> {
+    data_type: DataType,
+    shape: Vec<usize>,
+    names: Option<Vec<&'a str>>,
+    indptr: Buffer,
+    indices: Buffer,
+    data: Buffer,
+    _marker: PhantomData<(T, A)>,
+}
+
+/// Sparse matrix in compressed sparse row (CSR) format
+pub type SparseCSRMatrix<'a, T> = SparseCSXMatrix<'a, T, RowAxis>;
+/// Sparse matrix in compressed sparse column (CSC) format
+pub type SparseCSCMatrix<'a, T> = SparseCSXMatrix<'a, T, ColumnAxis>;
+
+impl<'a, T: ArrowPrimitiveType, A: CompressedAxis> SparseCSXMatrix<'a, T, A> {
+    /// Creates a new `SparseCSXMatrix` of shape `[rows, columns]`
+    pub fn try_new(
+        shape: Vec<usize>,
+        indptr: Buffer,
+        indices: Buffer,
+        data: Buffer,
+        names: Option<Vec<&'a str>>,
+    ) -> Result<Self> {
+        if shape.len() != 2 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "sparse matrix must have two dimensions, got {}",
+                shape.len()
+            )));
+        }
+        check_names(&shape, &names)?;
+        if data.len() % mem::size_of::<T::Native>() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "data buffer length is not a multiple of the value size".to_string(),
+            ));
+        }
+        let non_zero_length = data.len() / mem::size_of::<T::Native>();
+        let (major, minor) = Self::major_minor(&shape);
+        check_index_buffer(&indptr, major + 1, "indptr")?;
+        check_index_buffer(&indices, non_zero_length, "indices")?;
+        if index_value(&indptr, 0) != 0
+            || index_value(&indptr, major) != non_zero_length as i64
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "indptr must start at 0 and end at the number of non-zero values {}",
+                non_zero_length
+            )));
+        }
+        for i in 0..major {
+            if index_value(&indptr, i) > index_value(&indptr, i + 1) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "indptr must be non-decreasing".to_string(),
+                ));
+            }
+        }
+        for i in 0..non_zero_length {
+            let index = index_value(&indices, i);
+            if index < 0 || index as usize >= minor {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "index {} of value {} is out of bounds for dimension of size {}",
+                    index, i, minor
+                )));
+            }
+        }
+
+        Ok(Self {
+            data_type: T::DATA_TYPE,
+            shape,
+            names,
+            indptr,
+            indices,
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a `SparseCSXMatrix` holding the non-zero values of a two-dimensional
+    /// dense tensor
+    pub fn try_from_dense(tensor: &Tensor<'a, T>) -> Result<Self> {
+        let (shape, strides) = dense_layout(tensor)?;
+        if shape.len() != 2 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot create a sparse matrix from a tensor with {} dimensions",
+                shape.len()
+            )));
+        }
+        let (major, minor) = Self::major_minor(shape);
+        let mut indptr: Vec<i64> = Vec::with_capacity(major + 1);
+        let mut indices: Vec<i64> = Vec::new();
+        let mut values: Vec<T::Native> = Vec::new();
+        indptr.push(0);
+        for i in 0..major {
+            for j in 0..minor {
+                let (row, column) = if A::IS_ROW { (i, j) } else { (j, i) };
+                let value = read_value::<T>(
+                    tensor.data(),
+                    row * strides[0] + column * strides[1],
+                );
+                if value != T::default_value() {
+                    indices.push(j as i64);
+                    values.push(value);
+                }
+            }
+            indptr.push(values.len() as i64);
+        }
+        Self::try_new(
+            shape.clone(),
+            Buffer::from(indptr.to_byte_slice()),
+            Buffer::from(indices.to_byte_slice()),
+            Buffer::from(values.to_byte_slice()),
+            tensor.names().cloned(),
+        )
+    }
+
+    /// Converts this matrix into a dense row major `Tensor`
+    pub fn to_dense(&self) -> Result<Tensor<'a, T>> {
+        let (major, _) = Self::major_minor(&self.shape);
+        let columns = self.shape[1];
+        let mut values = vec![T::default_value(); self.shape[0] * columns];
+        for i in 0..major {
+            let start = index_value(&self.indptr, i) as usize;
+            let end = index_value(&self.indptr, i + 1) as usize;
+            for k in start..end {
+                let j = index_value(&self.indices, k) as usize;
+                let (row, column) = if A::IS_ROW { (i, j) } else { (j, i) };
+                values[row * columns + column] =
+                    read_value::<T>(&self.data, k * mem::size_of::<T::Native>());
+            }
+        }
+        Tensor::new_row_major(
+            Buffer::from(values.to_byte_slice()),
+            Some(self.shape.clone()),
+            self.names.clone(),
+        )
+    }
+
+    /// Returns the length of the compressed and of the other dimension
+    fn major_minor(shape: &[usize]) -> (usize, usize) {
+        if A::IS_ROW {
+            (shape[0], shape[1])
+        } else {
+            (shape[1], shape[0])
+        }
+    }
+
+    /// The data type of the `SparseCSXMatrix`
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The sizes of the dimensions
+    pub fn shape(&self) -> &Vec<usize> {
+        &self.shape
+    }
+
+    /// The names of the dimensions
+    pub fn names(&self) -> Option<&Vec<&'a str>> {
+        self.names.as_ref()
+    }
+
+    /// Whether the rows, rather than the columns, are compressed
+    pub fn is_row_compressed(&self) -> bool {
+        A::IS_ROW
+    }
+
+    /// The number of non-zero values
+    pub fn non_zero_length(&self) -> usize {
+        self.data.len() / mem::size_of::<T::Native>()
+    }
+
+    /// Returns a reference to the buffer of 64-bit offsets into `indices` and `data`
+    pub fn indptr(&self) -> &Buffer {
+        &self.indptr
+    }
+
+    /// Returns a reference to the buffer of 64-bit indices along the
+    /// uncompressed dimension
+    pub fn indices(&self) -> &Buffer {
+        &self.indices
+    }
+
+    /// Returns a reference to the buffer of non-zero values
+    pub fn data(&self) -> &Buffer {
+        &self.data
+    }
+
+    /// The non-zero values
+    pub fn values(&self) -> Vec<T::Native> {
+        (0..self.non_zero_length())
+            .map(|i| read_value::<T>(&self.data, i * mem::size_of::<T::Native>()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Float64Tensor, Int32Tensor};
+
+    fn dense_2x3() -> Tensor<'static, Int32Type> {
+        Tensor::new_row_major(
+            Buffer::from([0, 1, 0, 2, 0, 3].to_byte_slice()),
+            Some(vec![2, 3]),
+            Some(vec!["x", "y"]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coo_from_dense() {
+        let sparse = SparseCOOTensor::try_from_dense(&dense_2x3()).unwrap();
+        assert_eq!(&vec![2, 3], sparse.shape());
+        assert_eq!(3, sparse.non_zero_length());
+        assert_eq!(vec![1, 2, 3], sparse.values());
+        assert_eq!(vec![0, 1], sparse.index(0));
+        assert_eq!(vec![1, 0], sparse.index(1));
+        assert_eq!(vec![1, 2], sparse.index(2));
+        assert_eq!(Some(&vec!["x", "y"]), sparse.names());
+
+        let dense = sparse.to_dense().unwrap();
+        assert_eq!(dense_2x3().data(), dense.data());
+        assert_eq!(Some(&vec![2, 3]), dense.shape());
+        assert!(dense.is_row_major().unwrap());
+    }
+
+    #[test]
+    fn test_coo_from_column_major_dense() {
+        // [[1, 0], [0, 2], [3, 0]] stored column by column
+        let tensor = Int32Tensor::new_column_major(
+            Buffer::from([1, 0, 3, 0, 2, 0].to_byte_slice()),
+            Some(vec![3, 2]),
+            None,
+        )
+        .unwrap();
+        let sparse = SparseCOOTensor::try_from_dense(&tensor).unwrap();
+        assert_eq!(vec![1, 2, 3], sparse.values());
+        assert_eq!(vec![1, 1], sparse.index(1));
+
+        let dense = sparse.to_dense().unwrap();
+        assert_eq!(
+            &Buffer::from([1, 0, 0, 2, 3, 0].to_byte_slice()),
+            dense.data()
+        );
+    }
+
+    #[test]
+    fn test_coo_three_dimensions() {
+        let mut values = [0.0f64; 24];
+        values[5] = 1.5;
+        values[23] = -2.0;
+        let tensor = Float64Tensor::new_row_major(
+            Buffer::from(values.to_byte_slice()),
+            Some(vec![2, 3, 4]),
+            None,
+        )
+        .unwrap();
+        let sparse = SparseCOOTensor::try_from_dense(&tensor).unwrap();
+        assert_eq!(vec![1.5, -2.0], sparse.values());
+        assert_eq!(vec![0, 1, 1], sparse.index(0));
+        assert_eq!(vec![1, 2, 3], sparse.index(1));
+        assert_eq!(tensor.data(), sparse.to_dense().unwrap().data());
+    }
+
+    #[test]
+    fn test_coo_try_new_invalid() {
+        let indices = Buffer::from([0i64, 3].to_byte_slice());
+        let data = Buffer::from([1i32].to_byte_slice());
+        let err = SparseCOOTensor::<Int32Type>::try_new(vec![2, 3], indices, data, None)
+            .unwrap_err();
+        assert_eq!(
+            "Invalid argument error: index 3 of value 0 is out of bounds for dimension 1 of size 3",
+            err.to_string()
+        );
+
+        let indices = Buffer::from([0i64].to_byte_slice());
+        let data = Buffer::from([1i32].to_byte_slice());
+        assert!(
+            SparseCOOTensor::<Int32Type>::try_new(vec![2, 3], indices, data, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_csr_from_dense() {
+        let sparse = SparseCSRMatrix::try_from_dense(&dense_2x3()).unwrap();
+        assert!(sparse.is_row_compressed());
+        assert_eq!(3, sparse.non_zero_length());
+        assert_eq!(vec![1, 2, 3], sparse.values());
+        assert_eq!(&Buffer::from([0i64, 1, 3].to_byte_slice()), sparse.indptr());
+        assert_eq!(
+            &Buffer::from([1i64, 0, 2].to_byte_slice()),
+            sparse.indices()
+        );
+        assert_eq!(dense_2x3().data(), sparse.to_dense().unwrap().data());
+    }
+
+    #[test]
+    fn test_csc_from_dense() {
+        let sparse = SparseCSCMatrix::try_from_dense(&dense_2x3()).unwrap();
+        assert!(!sparse.is_row_compressed());
+        assert_eq!(vec![2, 1, 3], sparse.values());
+        assert_eq!(
+            &Buffer::from([0i64, 1, 2, 3].to_byte_slice()),
+            sparse.indptr()
+        );
+        assert_eq!(
+            &Buffer::from([1i64, 0, 1].to_byte_slice()),
+            sparse.indices()
+        );
+        assert_eq!(dense_2x3().data(), sparse.to_dense().unwrap().data());
+    }
+
+    #[test]
+    fn test_csx_invalid() {
+        let tensor = Int32Tensor::new_row_major(
+            Buffer::from([1, 2, 3, 4, 5, 6, 7, 8].to_byte_slice()),
+            Some(vec![2, 2, 2]),
+            None,
+        )
+        .unwrap();
+        assert!(SparseCSRMatrix::try_from_dense(&tensor).is_err());
+
+        let err = SparseCSRMatrix::<Int32Type>::try_new(
+            vec![2, 2],
+            Buffer::from([0i64, 2, 1].to_byte_slice()),
+            Buffer::from([0i64].to_byte_slice()),
+            Buffer::from([1i32].to_byte_slice()),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            "Invalid argument error: indptr must be non-decreasing",
+            err.to_string()
+        );
+
+        let err = SparseCSRMatrix::<Int32Type>::try_new(
+            vec![2, 2],
+            Buffer::from([0i64, 1, 2].to_byte_slice()),
+            Buffer::from([0i64].to_byte_slice()),
+            Buffer::from([1i32].to_byte_slice()),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            "Invalid argument error: indptr must start at 0 and end at the number of non-zero values 1",
+            err.to_string()
+        );
+    }
+}
@@ -21,8 +21,10 @@
 use std::marker::PhantomData;
 use std::mem;
 
+use crate::array::{Array, FixedSizeListArray, PrimitiveArray};
 use crate::buffer::Buffer;
 use crate::datatypes::*;
+use crate::record_batch::RecordBatch;
 
 use crate::error::{ArrowError, Result};
 
@@ -31,8 +33,13 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
     shape: &[usize],
 ) -> Result<Vec<usize>> {
     let mut remaining_bytes = mem::size_of::<T::Native>();
+    let mut strides = Vec::<usize>::new();
+
+    // the strides are computed from the last dimension, so that a dimension of size
+    // zero does not lead to a division by zero
+    for i in shape.iter().rev() {
+        strides.push(remaining_bytes);
 
-    for i in shape {
         if let Some(val) = remaining_bytes.checked_mul(*i) {
             remaining_bytes = val;
         } else {
@@ -41,12 +48,7 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
             ));
         }
     }
-
-    let mut strides = Vec::<usize>::new();
-    for i in shape {
-        remaining_bytes /= *i;
-        strides.push(remaining_bytes);
-    }
+    strides.reverse();
 
     Ok(strides)
 }
@@ -223,6 +225,118 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
         }
     }
 
+    /// Creates a two-dimensional tensor of shape `[array.len(), value_length]` from
+    /// the values of a `FixedSizeListArray`, laid out in row or column major order.
+    ///
+    /// The values must be of type `T` and the array must not contain nulls.
+    pub fn try_from_fixed_size_list(
+        array: &FixedSizeListArray,
+        row_major: bool,
+    ) -> Result<Self> {
+        let values = array.values();
+        let values = values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "cannot create a {:?} tensor from a list of {:?} values",
+                    T::DATA_TYPE,
+                    array.value_type()
+                ))
+            })?;
+        if array.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "cannot create a tensor from a list array with nulls".to_string(),
+            ));
+        }
+        let rows = array.len();
+        let columns = array.value_length() as usize;
+        Self::try_from_values(rows, columns, row_major, |row, column| {
+            let i = array.value_offset(row) as usize + column;
+            if values.is_null(i) {
+                None
+            } else {
+                Some(values.value(i))
+            }
+        })
+    }
+
+    /// Creates a two-dimensional tensor of shape `[num_rows, num_columns]` from the
+    /// columns of a `RecordBatch`, laid out in row or column major order.
+    ///
+    /// All columns must be of type `T` and must not contain nulls.
+    pub fn try_from_record_batch(batch: &RecordBatch, row_major: bool) -> Result<Self> {
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(batch.schema().fields())
+            .map(|(column, field)| {
+                column
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<T>>()
+                    .ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "cannot create a {:?} tensor from column '{}' of type {:?}",
+                            T::DATA_TYPE,
+                            field.name(),
+                            field.data_type()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_from_values(batch.num_rows(), columns.len(), row_major, |row, i| {
+            let column = columns[i];
+            if column.is_null(row) {
+                None
+            } else {
+                Some(column.value(row))
+            }
+        })
+    }
+
+    /// Creates a tensor of shape `[rows, columns]`, failing if `value` returns
+    /// `None` for any element
+    fn try_from_values<F>(
+        rows: usize,
+        columns: usize,
+        row_major: bool,
+        value: F,
+    ) -> Result<Self>
+    where
+        F: Fn(usize, usize) -> Option<T::Native>,
+    {
+        if rows == 0 || columns == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "cannot create a tensor with an empty dimension".to_string(),
+            ));
+        }
+        let mut values = Vec::with_capacity(rows * columns);
+        let (outer, inner) = if row_major {
+            (rows, columns)
+        } else {
+            (columns, rows)
+        };
+        for i in 0..outer {
+            for j in 0..inner {
+                let (row, column) = if row_major { (i, j) } else { (j, i) };
+                let v = value(row, column).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "cannot create a tensor from a null value at row {} column {}",
+                        row, column
+                    ))
+                })?;
+                values.push(v);
+            }
+        }
+        let buffer = Buffer::from(values.to_byte_slice());
+        let shape = Some(vec![rows, columns]);
+        if row_major {
+            Self::new_row_major(buffer, shape, None)
+        } else {
+            Self::new_column_major(buffer, shape, None)
+        }
+    }
+
     /// The data type of the `Tensor`
     pub fn data_type(&self) -> &DataType {
         &self.data_type
@@ -299,6 +413,7 @@ mod tests {
 
     use crate::array::*;
     use crate::buffer::Buffer;
+    use std::sync::Arc;
 
     #[test]
     fn test_compute_row_major_strides() {
@@ -492,4 +607,64 @@ mod tests {
             panic!("the input stride does not match the selected shape")
         }
     }
+
+    #[test]
+    fn test_from_fixed_size_list() {
+        let values = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let list_data =
+            ArrayData::builder(DataType::FixedSizeList(Box::new(DataType::Int32), 3))
+                .len(2)
+                .offset(1)
+                .add_child_data(values.data())
+                .build();
+        let list = FixedSizeListArray::from(list_data);
+
+        let tensor = Int32Tensor::try_from_fixed_size_list(&list, true).unwrap();
+        assert_eq!(Some(&vec![2, 3]), tensor.shape());
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(
+            &Buffer::from([3, 4, 5, 6, 7, 8].to_byte_slice()),
+            tensor.data()
+        );
+
+        let tensor = Int32Tensor::try_from_fixed_size_list(&list, false).unwrap();
+        assert_eq!(Some(&vec![2, 3]), tensor.shape());
+        assert!(tensor.is_column_major().unwrap());
+        assert_eq!(
+            &Buffer::from([3, 6, 4, 7, 5, 8].to_byte_slice()),
+            tensor.data()
+        );
+
+        assert!(Int64Tensor::try_from_fixed_size_list(&list, true).is_err());
+    }
+
+    #[test]
+    fn test_from_record_batch() {
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![1.0, 2.0]));
+        let b: ArrayRef = Arc::new(Float64Array::from(vec![3.0, 4.0]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        let tensor = Float64Tensor::try_from_record_batch(&batch, true).unwrap();
+        assert_eq!(Some(&vec![2, 2]), tensor.shape());
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(
+            &Buffer::from([1.0, 3.0, 2.0, 4.0].to_byte_slice()),
+            tensor.data()
+        );
+
+        let tensor = Float64Tensor::try_from_record_batch(&batch, false).unwrap();
+        assert!(tensor.is_column_major().unwrap());
+        assert_eq!(
+            &Buffer::from([1.0, 2.0, 3.0, 4.0].to_byte_slice()),
+            tensor.data()
+        );
+
+        // nulls and other types are rejected
+        let c: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0), None]));
+        let batch = RecordBatch::try_from_iter(vec![("c", c)]).unwrap();
+        assert!(Float64Tensor::try_from_record_batch(&batch, true).is_err());
+        let d: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let batch = RecordBatch::try_from_iter(vec![("d", d)]).unwrap();
+        assert!(Float64Tensor::try_from_record_batch(&batch, true).is_err());
+    }
 }
@@ -72,9 +72,11 @@ impl<'a> BitChunks<'a> {
         if bit_len == 0 {
             0
         } else {
-            let byte_len = ceil(bit_len, 8);
+            // the remainder starts `offset` bits into its first byte, and may span
+            // one more byte than its length alone
+            let byte_len = ceil(bit_len + self.offset, 8);
 
-            let mut bits = 0;
+            let mut bits = 0u128;
             for i in 0..byte_len {
                 let byte = unsafe {
                     std::ptr::read(
@@ -82,12 +84,10 @@ impl<'a> BitChunks<'a> {
                             .add(self.chunk_len * std::mem::size_of::<u64>() + i),
                     )
                 };
-                bits |= (byte as u64) << (i * 8);
+                bits |= (byte as u128) << (i * 8);
             }
 
-            let offset = self.offset as u64;
-
-            (bits >> offset) & ((1 << bit_len) - 1)
+            ((bits >> self.offset) as u64) & ((1 << bit_len) - 1)
         }
     }
 
@@ -220,4 +220,19 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_iter_unaligned_remainder_2_bytes() {
+        let input: &[u8] = &[
+            0b00000000, 0b00000001, 0b00000010, 0b00000100, 0b00001000, 0b00010000,
+            0b00100000, 0b01000000, 0b11111111, 0b00000010,
+        ];
+        let buffer: Buffer = Buffer::from(input);
+
+        // the remainder starts at bit 70 and ends in the last byte
+        let bitchunks = buffer.bit_chunks(6, 68);
+
+        assert_eq!(4, bitchunks.remainder_len());
+        assert_eq!(0b00001011, bitchunks.remainder_bits());
+    }
 }
@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Runtime detection of the SIMD instruction sets supported by the CPU.
+//!
+//! The inner loops of the arithmetic, comparison and aggregate kernels, and of the
+//! bitwise operations on buffers, are compiled once for each instruction set, and the
+//! variant matching the best instruction set of the CPU running the program is picked
+//! when the kernel is called. A single binary thus uses AVX2 where available, while
+//! still running on older CPUs.
+//!
+//! Each loop is inlined into a function compiled with the target features of the
+//! instruction set, so the compiler generates that instruction set for it. Without
+//! the `simd` feature, the loops are plain Rust loops that the compiler vectorizes.
+//! With it, they use the explicit `packed_simd` vectors of the kernels, which the
+//! compiler lowers to the widest registers of the instruction set.
+//!
+//! AVX-512 variants require the `avx512` feature, which needs a nightly compiler.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// A SIMD instruction set that kernels can be compiled for, ordered from the least
+/// to the most capable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SimdLevel {
+    /// No SIMD instructions beyond the compilation target's baseline
+    Scalar,
+    /// SSE up to SSE4.2
+    Sse42,
+    /// AVX2
+    Avx2,
+    /// AVX-512 foundation instructions
+    Avx512,
+}
+
+const LEVELS: [SimdLevel; 4] = [
+    SimdLevel::Scalar,
+    SimdLevel::Sse42,
+    SimdLevel::Avx2,
+    SimdLevel::Avx512,
+];
+
+/// The detected level as an index into `LEVELS`, or `u8::MAX` before detection
+static DETECTED_LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+
+thread_local! {
+    static LEVEL_LIMIT: Cell<Option<SimdLevel>> = Cell::new(None);
+}
+
+impl SimdLevel {
+    /// Returns the most capable level supported by the CPU. The CPU is only queried
+    /// on the first call.
+    pub fn detect() -> SimdLevel {
+        let index = DETECTED_LEVEL.load(Ordering::Relaxed);
+        if index != u8::MAX {
+            return LEVELS[index as usize];
+        }
+        let level = detect_level();
+        let index = LEVELS.iter().position(|l| *l == level).unwrap();
+        DETECTED_LEVEL.store(index as u8, Ordering::Relaxed);
+        level
+    }
+
+    /// Returns all levels supported by the CPU, from `Scalar` up to the detected level
+    pub fn supported() -> Vec<SimdLevel> {
+        let detected = SimdLevel::detect();
+        LEVELS.iter().copied().filter(|l| *l <= detected).collect()
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn detect_level() -> SimdLevel {
+    if cfg!(feature = "avx512") && is_x86_feature_detected!("avx512f") {
+        SimdLevel::Avx512
+    } else if is_x86_feature_detected!("avx2") {
+        SimdLevel::Avx2
+    } else if is_x86_feature_detected!("sse4.2") {
+        SimdLevel::Sse42
+    } else {
+        SimdLevel::Scalar
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn detect_level() -> SimdLevel {
+    SimdLevel::Scalar
+}
+
+/// Returns the level that kernels called from the current thread use: the detected
+/// level, unless limited by [`with_simd_level`]
+pub fn simd_level() -> SimdLevel {
+    let detected = SimdLevel::detect();
+    LEVEL_LIMIT.with(|limit| match limit.get() {
+        Some(limit) if limit < detected => limit,
+        _ => detected,
+    })
+}
+
+/// Restores the previous level limit of the thread when dropped
+struct LevelLimitGuard(Option<SimdLevel>);
+
+impl Drop for LevelLimitGuard {
+    fn drop(&mut self) {
+        let previous = self.0;
+        LEVEL_LIMIT.with(|limit| limit.set(previous));
+    }
+}
+
+/// Calls `f` with the kernels it calls on the current thread limited to `level`,
+/// for instance to compare the results or the performance of the different variants.
+///
+/// Levels above the one supported by the CPU fall back to the detected level.
+pub fn with_simd_level<R, F: FnOnce() -> R>(level: SimdLevel, f: F) -> R {
+    let _guard = LevelLimitGuard(LEVEL_LIMIT.with(|limit| limit.replace(Some(level))));
+    f()
+}
+
+/// Calls `f` compiled for the instruction set of [`simd_level`].
+///
+/// `f` and the functions it calls should be inlined for the instruction set to
+/// apply to them, so kernels mark their inner loops with `#[inline(always)]`.
+#[inline(always)]
+pub(crate) fn dispatch<R, F: FnOnce() -> R>(f: F) -> R {
+    match simd_level() {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+        SimdLevel::Avx512 => unsafe { call_avx512(f) },
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        SimdLevel::Avx2 => unsafe { call_avx2(f) },
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        SimdLevel::Sse42 => unsafe { call_sse42(f) },
+        _ => f(),
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx512"))]
+#[target_feature(enable = "avx512f")]
+unsafe fn call_avx512<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn call_avx2<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn call_sse42<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_levels() {
+        let supported = SimdLevel::supported();
+        assert_eq!(SimdLevel::Scalar, supported[0]);
+        assert_eq!(SimdLevel::detect(), *supported.last().unwrap());
+        assert_eq!(SimdLevel::detect(), simd_level());
+    }
+
+    #[test]
+    fn test_with_simd_level() {
+        let detected = SimdLevel::detect();
+        with_simd_level(SimdLevel::Scalar, || {
+            assert_eq!(SimdLevel::Scalar, simd_level());
+            // nested limits are restored on exit
+            with_simd_level(SimdLevel::Avx512, || {
+                assert_eq!(detected, simd_level());
+            });
+            assert_eq!(SimdLevel::Scalar, simd_level());
+        });
+        assert_eq!(detected, simd_level());
+
+        // the limit is also restored when `f` panics
+        let result = std::panic::catch_unwind(|| {
+            with_simd_level(SimdLevel::Scalar, || panic!("kernel failed"))
+        });
+        assert!(result.is_err());
+        assert_eq!(detected, simd_level());
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let values: Vec<u32> = (0..1000).collect();
+        for level in SimdLevel::supported() {
+            let sum = with_simd_level(level, || dispatch(|| values.iter().sum::<u32>()));
+            assert_eq!(499_500, sum, "{:?}", level);
+        }
+    }
+}
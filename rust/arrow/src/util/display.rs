@@ -19,102 +19,422 @@
 //! purposes. See the `pretty` crate for additional functions for
 //! record batch pretty printing.
 
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
 use crate::array;
 use crate::array::Array;
 use crate::datatypes::{
-    ArrowNativeType, ArrowPrimitiveType, DataType, Int16Type, Int32Type, Int64Type,
-    Int8Type, TimeUnit, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    ArrowNativeType, ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType,
+    Int16Type, Int32Type, Int64Type, Int8Type, IntervalUnit, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
 };
 
 use array::DictionaryArray;
 
 use crate::error::{ArrowError, Result};
 
-macro_rules! make_string {
+/// Get the value at the given row in an array as a String.
+///
+/// Note this function is quite inefficient and is unlikely to be
+/// suitable for converting large arrays or record batches.
+pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<String> {
+    array_value_to_string_with_options(column, row, &FormatOptions::default())
+}
+
+const DEFAULT_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const DEFAULT_TIMESTAMP_TZ_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+/// Options controlling how array values are converted to strings by
+/// [`array_value_to_string_with_options`] and the `pretty` module.
+///
+/// Temporal values are rendered using `chrono` format strings, see
+/// [`chrono::format::strftime`].
+///
+/// # Example
+///
+/// ```
+/// use arrow::array::{ArrayRef, Float64Array};
+/// use arrow::util::display::{array_value_to_string_with_options, FormatOptions};
+/// use std::sync::Arc;
+///
+/// let array: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0 / 3.0), None]));
+/// let options = FormatOptions::new().with_float_precision(2).with_null("NULL");
+///
+/// assert_eq!("0.33", array_value_to_string_with_options(&array, 0, &options).unwrap());
+/// assert_eq!("NULL", array_value_to_string_with_options(&array, 1, &options).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatOptions {
+    max_rows: Option<usize>,
+    max_width: Option<usize>,
+    null: String,
+    float_precision: Option<usize>,
+    timestamp_format: Option<String>,
+    date_format: String,
+    time_format: String,
+    timezone: Option<FixedOffset>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: None,
+            max_width: None,
+            null: "".to_string(),
+            float_precision: None,
+            timestamp_format: None,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            timezone: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Create options that print every row and value in full
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows printed in a table. Larger tables print their
+    /// first and last rows, separated by a row of ellipses.
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Set the maximum number of characters of a value. Longer values are truncated
+    /// and end with an ellipsis.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Set the string printed for null values, empty by default
+    pub fn with_null(mut self, null: &str) -> Self {
+        self.null = null.to_string();
+        self
+    }
+
+    /// Set the number of digits printed after the decimal point of floating point
+    /// values
+    pub fn with_float_precision(mut self, precision: usize) -> Self {
+        self.float_precision = Some(precision);
+        self
+    }
+
+    /// Set the format of timestamps.
+    ///
+    /// By default, timestamps are printed as `%Y-%m-%dT%H:%M:%S%.f`, followed by
+    /// `%:z` for timestamps with a timezone.
+    pub fn with_timestamp_format(mut self, format: &str) -> Self {
+        self.timestamp_format = Some(format.to_string());
+        self
+    }
+
+    /// Set the format of dates, `%Y-%m-%d` by default
+    pub fn with_date_format(mut self, format: &str) -> Self {
+        self.date_format = format.to_string();
+        self
+    }
+
+    /// Set the format of times of day, `%H:%M:%S%.f` by default
+    pub fn with_time_format(mut self, format: &str) -> Self {
+        self.time_format = format.to_string();
+        self
+    }
+
+    /// Set the timezone that timestamps with a timezone are printed in, instead of
+    /// the timezone of their data type. Timestamps without a timezone are printed
+    /// as they are.
+    pub fn with_timezone(mut self, timezone: FixedOffset) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Returns the maximum number of rows printed in a table, if any
+    pub fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// Returns the maximum number of characters of a value, if any
+    pub fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Returns the string printed for null values
+    pub fn null(&self) -> &str {
+        &self.null
+    }
+}
+
+/// Get the value at the given row in an array as a String, formatted according to
+/// `options`.
+///
+/// Nested values are printed recursively: lists as `[1, 2]`, structs as
+/// `{a: 1, b: x}` and maps as `{key: value}`. Dictionaries print their values.
+pub fn array_value_to_string_with_options(
+    column: &array::ArrayRef,
+    row: usize,
+    options: &FormatOptions,
+) -> Result<String> {
+    let value = format_value(column.as_ref(), row, options)?;
+    Ok(match options.max_width {
+        Some(max_width) => truncate(value, max_width),
+        None => value,
+    })
+}
+
+/// Truncates `value` to `max_width` characters, ending it with an ellipsis
+pub(crate) fn truncate(value: String, max_width: usize) -> String {
+    if value.chars().count() <= max_width {
+        return value;
+    }
+    let ellipsis = "...";
+    if max_width <= ellipsis.len() {
+        return value.chars().take(max_width).collect();
+    }
+    let mut truncated: String = value.chars().take(max_width - ellipsis.len()).collect();
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+macro_rules! format_primitive {
     ($array_type:ty, $column: ident, $row: ident) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+        Ok(array.value($row).to_string())
+    }};
+}
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            array.value($row).to_string()
-        };
+macro_rules! format_float {
+    ($array_type:ty, $column: ident, $row: ident, $options: ident) => {{
+        let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+        let value = array.value($row);
+        Ok(match $options.float_precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        })
+    }};
+}
 
-        Ok(s)
+macro_rules! format_binary {
+    ($array_type:ty, $column: ident, $row: ident) => {{
+        let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+        Ok(array
+            .value($row)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>())
     }};
 }
 
-macro_rules! make_string_from_list {
-    ($column: ident, $row: ident) => {{
-        let list = $column
-            .as_any()
-            .downcast_ref::<array::ListArray>()
-            .ok_or(ArrowError::InvalidArgumentError(format!(
-                "Repl error: could not convert list column to list array."
-            )))?
-            .value($row);
-        let string_values = (0..list.len())
-            .map(|i| array_value_to_string(&list.clone(), i))
+macro_rules! format_list {
+    ($array_type:ty, $column: ident, $row: ident, $options: ident) => {{
+        let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+        let values = array.value($row);
+        let values = (0..values.len())
+            .map(|i| format_value(values.as_ref(), i, $options))
             .collect::<Result<Vec<String>>>()?;
-        Ok(format!("[{}]", string_values.join(", ")))
+        Ok(format!("[{}]", values.join(", ")))
     }};
 }
 
-/// Get the value at the given row in an array as a String.
-///
-/// Note this function is quite inefficient and is unlikely to be
-/// suitable for converting large arrays or record batches.
-pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<String> {
+/// Formats the value at `row` without truncating it
+fn format_value(column: &Array, row: usize, options: &FormatOptions) -> Result<String> {
+    if column.data_type() == &DataType::Null || column.is_null(row) {
+        return Ok(options.null.clone());
+    }
     match column.data_type() {
-        DataType::Utf8 => make_string!(array::StringArray, column, row),
-        DataType::Boolean => make_string!(array::BooleanArray, column, row),
-        DataType::Int8 => make_string!(array::Int8Array, column, row),
-        DataType::Int16 => make_string!(array::Int16Array, column, row),
-        DataType::Int32 => make_string!(array::Int32Array, column, row),
-        DataType::Int64 => make_string!(array::Int64Array, column, row),
-        DataType::UInt8 => make_string!(array::UInt8Array, column, row),
-        DataType::UInt16 => make_string!(array::UInt16Array, column, row),
-        DataType::UInt32 => make_string!(array::UInt32Array, column, row),
-        DataType::UInt64 => make_string!(array::UInt64Array, column, row),
-        DataType::Float16 => make_string!(array::Float32Array, column, row),
-        DataType::Float32 => make_string!(array::Float32Array, column, row),
-        DataType::Float64 => make_string!(array::Float64Array, column, row),
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Second => {
-            make_string!(array::TimestampSecondArray, column, row)
-        }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Millisecond => {
-            make_string!(array::TimestampMillisecondArray, column, row)
-        }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Microsecond => {
-            make_string!(array::TimestampMicrosecondArray, column, row)
-        }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Nanosecond => {
-            make_string!(array::TimestampNanosecondArray, column, row)
-        }
-        DataType::Date32(_) => make_string!(array::Date32Array, column, row),
-        DataType::Date64(_) => make_string!(array::Date64Array, column, row),
-        DataType::Time32(unit) if *unit == TimeUnit::Second => {
-            make_string!(array::Time32SecondArray, column, row)
-        }
-        DataType::Time32(unit) if *unit == TimeUnit::Millisecond => {
-            make_string!(array::Time32MillisecondArray, column, row)
-        }
-        DataType::Time32(unit) if *unit == TimeUnit::Microsecond => {
-            make_string!(array::Time64MicrosecondArray, column, row)
-        }
-        DataType::Time64(unit) if *unit == TimeUnit::Nanosecond => {
-            make_string!(array::Time64NanosecondArray, column, row)
-        }
-        DataType::List(_) => make_string_from_list!(column, row),
+        DataType::Boolean => format_primitive!(array::BooleanArray, column, row),
+        DataType::Int8 => format_primitive!(array::Int8Array, column, row),
+        DataType::Int16 => format_primitive!(array::Int16Array, column, row),
+        DataType::Int32 => format_primitive!(array::Int32Array, column, row),
+        DataType::Int64 => format_primitive!(array::Int64Array, column, row),
+        DataType::UInt8 => format_primitive!(array::UInt8Array, column, row),
+        DataType::UInt16 => format_primitive!(array::UInt16Array, column, row),
+        DataType::UInt32 => format_primitive!(array::UInt32Array, column, row),
+        DataType::UInt64 => format_primitive!(array::UInt64Array, column, row),
+        DataType::Float16 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Float16Array>()
+                .unwrap();
+            let value = array.value(row).to_f32();
+            Ok(match options.float_precision {
+                Some(precision) => format!("{:.*}", precision, value),
+                None => array.value(row).to_string(),
+            })
+        }
+        DataType::Float32 => format_float!(array::Float32Array, column, row, options),
+        DataType::Float64 => format_float!(array::Float64Array, column, row, options),
+        DataType::Decimal(..) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::DecimalArray>()
+                .unwrap();
+            Ok(array.value_as_string(row))
+        }
+        DataType::Utf8 => format_primitive!(array::StringArray, column, row),
+        DataType::LargeUtf8 => format_primitive!(array::LargeStringArray, column, row),
+        DataType::Binary => format_binary!(array::BinaryArray, column, row),
+        DataType::LargeBinary => format_binary!(array::LargeBinaryArray, column, row),
+        DataType::FixedSizeBinary(_) => {
+            format_binary!(array::FixedSizeBinaryArray, column, row)
+        }
+        DataType::Timestamp(unit, tz) => match unit {
+            TimeUnit::Second => {
+                format_timestamp::<TimestampSecondType>(column, row, tz, options)
+            }
+            TimeUnit::Millisecond => {
+                format_timestamp::<TimestampMillisecondType>(column, row, tz, options)
+            }
+            TimeUnit::Microsecond => {
+                format_timestamp::<TimestampMicrosecondType>(column, row, tz, options)
+            }
+            TimeUnit::Nanosecond => {
+                format_timestamp::<TimestampNanosecondType>(column, row, tz, options)
+            }
+        },
+        DataType::Date32(_) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Date32Array>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_date(row)
+                    .map(|v| v.format(&options.date_format).to_string()),
+            )
+        }
+        DataType::Date64(_) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Date64Array>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_date(row)
+                    .map(|v| v.format(&options.date_format).to_string()),
+            )
+        }
+        DataType::Time32(TimeUnit::Second) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Time32SecondArray>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_time(row)
+                    .map(|v| v.format(&options.time_format).to_string()),
+            )
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Time32MillisecondArray>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_time(row)
+                    .map(|v| v.format(&options.time_format).to_string()),
+            )
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Time64MicrosecondArray>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_time(row)
+                    .map(|v| v.format(&options.time_format).to_string()),
+            )
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Time64NanosecondArray>()
+                .unwrap();
+            temporal_value(
+                array
+                    .value_as_time(row)
+                    .map(|v| v.format(&options.time_format).to_string()),
+            )
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            format_primitive!(array::DurationSecondArray, column, row)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            format_primitive!(array::DurationMillisecondArray, column, row)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            format_primitive!(array::DurationMicrosecondArray, column, row)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            format_primitive!(array::DurationNanosecondArray, column, row)
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            format_primitive!(array::IntervalYearMonthArray, column, row)
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            format_primitive!(array::IntervalDayTimeArray, column, row)
+        }
+        DataType::List(_) => format_list!(array::ListArray, column, row, options),
+        DataType::LargeList(_) => {
+            format_list!(array::LargeListArray, column, row, options)
+        }
+        DataType::FixedSizeList(_, _) => {
+            format_list!(array::FixedSizeListArray, column, row, options)
+        }
+        DataType::Struct(_) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::StructArray>()
+                .unwrap();
+            let fields = array
+                .column_names()
+                .iter()
+                .zip(array.columns())
+                .map(|(name, child)| {
+                    Ok(format!(
+                        "{}: {}",
+                        name,
+                        format_value(child.as_ref(), row, options)?
+                    ))
+                })
+                .collect::<Result<Vec<String>>>()?;
+            Ok(format!("{{{}}}", fields.join(", ")))
+        }
+        DataType::Map(_, _) => {
+            let array = column.as_any().downcast_ref::<array::MapArray>().unwrap();
+            let start = array.value_offset(row) as usize;
+            let end = start + array.value_length(row) as usize;
+            let (keys, values) = (array.keys(), array.values());
+            let entries = (start..end)
+                .map(|i| {
+                    Ok(format!(
+                        "{}: {}",
+                        format_value(keys.as_ref(), i, options)?,
+                        format_value(values.as_ref(), i, options)?
+                    ))
+                })
+                .collect::<Result<Vec<String>>>()?;
+            Ok(format!("{{{}}}", entries.join(", ")))
+        }
         DataType::Dictionary(index_type, _value_type) => match **index_type {
-            DataType::Int8 => dict_array_value_to_string::<Int8Type>(column, row),
-            DataType::Int16 => dict_array_value_to_string::<Int16Type>(column, row),
-            DataType::Int32 => dict_array_value_to_string::<Int32Type>(column, row),
-            DataType::Int64 => dict_array_value_to_string::<Int64Type>(column, row),
-            DataType::UInt8 => dict_array_value_to_string::<UInt8Type>(column, row),
-            DataType::UInt16 => dict_array_value_to_string::<UInt16Type>(column, row),
-            DataType::UInt32 => dict_array_value_to_string::<UInt32Type>(column, row),
-            DataType::UInt64 => dict_array_value_to_string::<UInt64Type>(column, row),
+            DataType::Int8 => format_dictionary::<Int8Type>(column, row, options),
+            DataType::Int16 => format_dictionary::<Int16Type>(column, row, options),
+            DataType::Int32 => format_dictionary::<Int32Type>(column, row, options),
+            DataType::Int64 => format_dictionary::<Int64Type>(column, row, options),
+            DataType::UInt8 => format_dictionary::<UInt8Type>(column, row, options),
+            DataType::UInt16 => format_dictionary::<UInt16Type>(column, row, options),
+            DataType::UInt32 => format_dictionary::<UInt32Type>(column, row, options),
+            DataType::UInt64 => format_dictionary::<UInt64Type>(column, row, options),
             _ => Err(ArrowError::InvalidArgumentError(format!(
                 "Pretty printing not supported for {:?} due to index type",
                 column.data_type()
@@ -127,17 +447,100 @@ pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<Str
     }
 }
 
-/// Converts the value of the dictionary array at `row` to a String
-fn dict_array_value_to_string<K: ArrowPrimitiveType>(
-    colum: &array::ArrayRef,
+/// Returns a formatted date or time, or an error if the value is out of range
+fn temporal_value(value: Option<String>) -> Result<String> {
+    value.ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "Value cannot be represented as a date or time".to_string(),
+        )
+    })
+}
+
+/// Formats a timestamp, converting timestamps with a timezone to the timezone of
+/// `options`, or of their data type
+fn format_timestamp<T>(
+    column: &Array,
     row: usize,
-) -> Result<String> {
-    let dict_array = colum.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    tz: &Option<Arc<String>>,
+    options: &FormatOptions,
+) -> Result<String>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+{
+    let array = column
+        .as_any()
+        .downcast_ref::<array::PrimitiveArray<T>>()
+        .unwrap();
+    let datetime: NaiveDateTime = array.value_as_datetime(row).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "Value cannot be represented as a timestamp".to_string(),
+        )
+    })?;
+    match tz {
+        None => {
+            let format = options
+                .timestamp_format
+                .as_deref()
+                .unwrap_or(DEFAULT_TIMESTAMP_FORMAT);
+            Ok(datetime.format(format).to_string())
+        }
+        Some(tz) => {
+            let offset = match options.timezone {
+                Some(offset) => offset,
+                None => parse_timezone(tz)?,
+            };
+            let format = options
+                .timestamp_format
+                .as_deref()
+                .unwrap_or(DEFAULT_TIMESTAMP_TZ_FORMAT);
+            Ok(DateTime::<FixedOffset>::from_utc(datetime, offset)
+                .format(format)
+                .to_string())
+        }
+    }
+}
+
+/// Parses a timezone given as `UTC`, `Z` or a fixed offset such as `+05:30`
+fn parse_timezone(tz: &str) -> Result<FixedOffset> {
+    let error = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Pretty printing not supported for timezone {}, only UTC and fixed \
+             offsets are",
+            tz
+        ))
+    };
+    if tz == "UTC" || tz == "Z" || tz == "+00:00" {
+        return Ok(FixedOffset::east(0));
+    }
+    let (sign, rest) = match tz.chars().next() {
+        Some('+') => (1, &tz[1..]),
+        Some('-') => (-1, &tz[1..]),
+        _ => return Err(error()),
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(error());
+    }
+    let hours: i32 = digits[0..2].parse().map_err(|_| error())?;
+    let minutes: i32 = digits[2..4].parse().map_err(|_| error())?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(error)
+}
 
+/// Formats the value of the dictionary array at `row`
+fn format_dictionary<K: ArrowPrimitiveType>(
+    column: &Array,
+    row: usize,
+    options: &FormatOptions,
+) -> Result<String> {
+    let dict_array = column
+        .as_any()
+        .downcast_ref::<DictionaryArray<K>>()
+        .unwrap();
     let keys_array = dict_array.keys_array();
 
     if keys_array.is_null(row) {
-        return Ok(String::from(""));
+        return Ok(options.null.clone());
     }
 
     let dict_index = keys_array.value(row).to_usize().ok_or_else(|| {
@@ -148,5 +551,151 @@ fn dict_array_value_to_string<K: ArrowPrimitiveType>(
         ))
     })?;
 
-    array_value_to_string(&dict_array.values(), dict_index)
+    format_value(dict_array.values().as_ref(), dict_index, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{
+        ArrayRef, Date32Array, Float64Array, Int32Array, Int32Builder, ListBuilder,
+        MapBuilder, StringArray, StringBuilder, StringDictionaryBuilder, StructArray,
+        Time64MicrosecondArray, TimestampMillisecondArray,
+    };
+    use crate::datatypes::Field;
+
+    fn format_all(array: ArrayRef, options: &FormatOptions) -> Vec<String> {
+        (0..array.len())
+            .map(|row| array_value_to_string_with_options(&array, row, options).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_format_primitives() {
+        let array: ArrayRef =
+            Arc::new(Float64Array::from(vec![Some(1.0), None, Some(-2.126)]));
+        assert_eq!(
+            vec!["1", "", "-2.126"],
+            format_all(array.clone(), &FormatOptions::new())
+        );
+        let options = FormatOptions::new()
+            .with_float_precision(2)
+            .with_null("null");
+        assert_eq!(vec!["1.00", "null", "-2.13"], format_all(array, &options));
+
+        let array: ArrayRef = Arc::new(StringArray::from(vec!["arrow", "datafusion"]));
+        let options = FormatOptions::new().with_max_width(6);
+        assert_eq!(vec!["arrow", "dat..."], format_all(array, &options));
+    }
+
+    #[test]
+    fn test_format_temporal() {
+        // 2020-09-08T13:42:29.190 UTC
+        let millis = 1_599_572_549_190;
+        let array: ArrayRef =
+            Arc::new(TimestampMillisecondArray::from_vec(vec![millis], None));
+        assert_eq!(
+            vec!["2020-09-08T13:42:29.190"],
+            format_all(array, &FormatOptions::new())
+        );
+
+        let array: ArrayRef = Arc::new(TimestampMillisecondArray::from_vec(
+            vec![millis],
+            Some(Arc::new("+02:00".to_string())),
+        ));
+        assert_eq!(
+            vec!["2020-09-08T15:42:29.190+02:00"],
+            format_all(array.clone(), &FormatOptions::new())
+        );
+        let options = FormatOptions::new()
+            .with_timestamp_format("%Y-%m-%d %H:%M %z")
+            .with_timezone(FixedOffset::west(5 * 3600));
+        assert_eq!(vec!["2020-09-08 08:42 -0500"], format_all(array, &options));
+
+        let array: ArrayRef = Arc::new(TimestampMillisecondArray::from_vec(
+            vec![millis],
+            Some(Arc::new("Europe/Paris".to_string())),
+        ));
+        assert!(
+            array_value_to_string_with_options(&array, 0, &FormatOptions::new()).is_err()
+        );
+
+        let array: ArrayRef = Arc::new(Date32Array::from(vec![18513]));
+        assert_eq!(
+            vec!["2020-09-08"],
+            format_all(array.clone(), &FormatOptions::new())
+        );
+        let options = FormatOptions::new().with_date_format("%d/%m/%Y");
+        assert_eq!(vec!["08/09/2020"], format_all(array, &options));
+
+        let array: ArrayRef = Arc::new(Time64MicrosecondArray::from(vec![
+            (13 * 3600 + 42 * 60 + 29) * 1_000_000 + 190_000,
+        ]));
+        assert_eq!(
+            vec!["13:42:29.190"],
+            format_all(array.clone(), &FormatOptions::new())
+        );
+        let options = FormatOptions::new().with_time_format("%H:%M");
+        assert_eq!(vec!["13:42"], format_all(array, &options));
+    }
+
+    #[test]
+    fn test_format_nested() {
+        let mut builder = ListBuilder::new(Int32Builder::new(4));
+        builder.values().append_value(1).unwrap();
+        builder.values().append_null().unwrap();
+        builder.values().append_value(3).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+        let options = FormatOptions::new().with_null("null");
+        assert_eq!(
+            vec!["[1, null, 3]", "null", "[]"],
+            format_all(array, &options)
+        );
+
+        let array: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Field::new("a", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Utf8, false),
+                Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+            ),
+        ]));
+        assert_eq!(
+            vec!["{a: 1, b: x}", "{a: null, b: y}"],
+            format_all(array, &options)
+        );
+
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(4), Int32Builder::new(4));
+        builder.keys().append_value("a").unwrap();
+        builder.values().append_value(1).unwrap();
+        builder.keys().append_value("b").unwrap();
+        builder.values().append_null().unwrap();
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+        assert_eq!(vec!["{a: 1, b: null}", "{}"], format_all(array, &options));
+
+        let mut builder =
+            StringDictionaryBuilder::new(Int32Builder::new(3), StringBuilder::new(3));
+        builder.append("one").unwrap();
+        builder.append_null().unwrap();
+        builder.append("one").unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+        assert_eq!(vec!["one", "null", "one"], format_all(array, &options));
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(FixedOffset::east(0), parse_timezone("UTC").unwrap());
+        assert_eq!(FixedOffset::east(19800), parse_timezone("+05:30").unwrap());
+        assert_eq!(FixedOffset::west(3600), parse_timezone("-0100").unwrap());
+        assert!(parse_timezone("America/New_York").is_err());
+        assert!(parse_timezone("+5").is_err());
+    }
 }
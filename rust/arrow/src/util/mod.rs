@@ -18,6 +18,7 @@
 pub mod bit_chunk_iterator;
 pub mod bit_util;
 pub mod buffered_iterator;
+pub mod cpu;
 pub mod display;
 pub mod integration_util;
 #[cfg(feature = "prettyprint")]
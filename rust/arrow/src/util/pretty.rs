@@ -25,21 +25,41 @@ use prettytable::{Cell, Row, Table};
 
 use crate::error::Result;
 
-use super::display::array_value_to_string;
+use super::display::{array_value_to_string_with_options, FormatOptions};
 
 ///! Create a visual representation of record batches
 pub fn pretty_format_batches(results: &[RecordBatch]) -> Result<String> {
-    Ok(create_table(results)?.to_string())
+    pretty_format_batches_with_options(results, &FormatOptions::default())
+}
+
+/// Create a visual representation of record batches, formatted according to
+/// `options`
+pub fn pretty_format_batches_with_options(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+) -> Result<String> {
+    Ok(create_table(results, options)?.to_string())
 }
 
 ///! Prints a visual representation of record batches to stdout
 pub fn print_batches(results: &[RecordBatch]) -> Result<()> {
-    create_table(results)?.printstd();
+    print_batches_with_options(results, &FormatOptions::default())
+}
+
+/// Prints a visual representation of record batches to stdout, formatted
+/// according to `options`
+pub fn print_batches_with_options(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+) -> Result<()> {
+    create_table(results, options)?.printstd();
     Ok(())
 }
 
-///! Convert a series of record batches into a table
-fn create_table(results: &[RecordBatch]) -> Result<Table> {
+/// Convert a series of record batches into a table. When there are more rows than
+/// the maximum of `options`, only the first and last rows are added, separated by
+/// a row of ellipses.
+fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Table> {
     let mut table = Table::new();
     table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
 
@@ -55,15 +75,36 @@ fn create_table(results: &[RecordBatch]) -> Result<Table> {
     }
     table.set_titles(Row::new(header));
 
+    let num_rows: usize = results.iter().map(|batch| batch.num_rows()).sum();
+    // rows in `head..tail` are elided
+    let (head, tail) = match options.max_rows() {
+        Some(max_rows) if max_rows < num_rows => {
+            (max_rows - max_rows / 2, num_rows - max_rows / 2)
+        }
+        _ => (num_rows, num_rows),
+    };
+
+    let mut offset = 0;
     for batch in results {
         for row in 0..batch.num_rows() {
+            let index = offset + row;
+            if index == head && head < tail {
+                let cells = (0..batch.num_columns()).map(|_| Cell::new("...")).collect();
+                table.add_row(Row::new(cells));
+            }
+            if index >= head && index < tail {
+                continue;
+            }
             let mut cells = Vec::new();
             for col in 0..batch.num_columns() {
                 let column = batch.column(col);
-                cells.push(Cell::new(&array_value_to_string(&column, row)?));
+                cells.push(Cell::new(&array_value_to_string_with_options(
+                    &column, row, options,
+                )?));
             }
             table.add_row(Row::new(cells));
         }
+        offset += batch.num_rows();
     }
 
     Ok(table)
@@ -71,10 +112,13 @@ fn create_table(results: &[RecordBatch]) -> Result<Table> {
 
 #[cfg(test)]
 mod tests {
-    use crate::array::{self, PrimitiveBuilder, StringBuilder, StringDictionaryBuilder};
+    use crate::array::{
+        self, Array, DecimalBuilder, Int32Builder, ListBuilder, PrimitiveBuilder,
+        StringBuilder, StringDictionaryBuilder,
+    };
 
     use super::*;
-    use crate::datatypes::{DataType, Field, Int32Type, Schema};
+    use crate::datatypes::{f16, DataType, Field, Int32Type, Schema};
     use std::sync::Arc;
 
     #[test]
@@ -124,6 +168,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_pretty_format_float16() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "f16",
+            DataType::Float16,
+            true,
+        )]));
+        let array = array::Float16Array::from(vec![
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::from_f32(-0.25)),
+        ]);
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(array)])?;
+
+        let table = pretty_format_batches(&[batch])?;
+
+        let expected = vec![
+            "+-------+",
+            "| f16   |",
+            "+-------+",
+            "| 1.5   |",
+            "|       |",
+            "| -0.25 |",
+            "+-------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
     #[test]
     fn test_pretty_format_dictionary() -> Result<()> {
         // define a schema.
@@ -160,4 +237,113 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pretty_format_decimal() -> Result<()> {
+        let mut builder = DecimalBuilder::new(10, 6, 2);
+        builder.append_value(101)?;
+        builder.append_null()?;
+        builder.append_value(-200)?;
+        builder.append_value(4455)?;
+
+        let array = Arc::new(builder.finish());
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "f",
+            array.data_type().clone(),
+            true,
+        )]));
+
+        let batch = RecordBatch::try_new(schema, vec![array])?;
+
+        let table = pretty_format_batches(&[batch])?;
+
+        let expected = vec![
+            "+-------+",
+            "| f     |",
+            "+-------+",
+            "| 1.01  |",
+            "|       |",
+            "| -2.00 |",
+            "| 44.55 |",
+            "+-------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_max_rows() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "value",
+            DataType::Int32,
+            false,
+        )]));
+        let batches = vec![
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(array::Int32Array::from(vec![1, 2, 3]))],
+            )?,
+            RecordBatch::try_new(
+                schema,
+                vec![Arc::new(array::Int32Array::from(vec![4, 5]))],
+            )?,
+        ];
+
+        let options = FormatOptions::new().with_max_rows(3);
+        let table = pretty_format_batches_with_options(&batches, &options)?;
+
+        let expected = vec![
+            "+-------+",
+            "| value |",
+            "+-------+",
+            "| 1     |",
+            "| 2     |",
+            "| ...   |",
+            "| 5     |",
+            "+-------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_list_with_options() -> Result<()> {
+        let mut builder = ListBuilder::new(Int32Builder::new(3));
+        builder.values().append_slice(&[1, 2, 3])?;
+        builder.append(true)?;
+        builder.append(false)?;
+        let array = Arc::new(builder.finish());
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "l",
+            array.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![array])?;
+
+        let options = FormatOptions::new().with_null("null").with_max_width(7);
+        let table = pretty_format_batches_with_options(&[batch], &options)?;
+
+        let expected = vec![
+            "+---------+",
+            "| l       |",
+            "+---------+",
+            "| [1, ... |",
+            "| null    |",
+            "+---------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
 }
@@ -15,15 +15,17 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
 use arrow::datatypes::Schema;
+use arrow::ipc::MessageHeader;
 use arrow::util::pretty;
 
 use arrow_flight::flight_descriptor;
 use arrow_flight::flight_service_client::FlightServiceClient;
-use arrow_flight::utils::flight_data_to_arrow_batch;
+use arrow_flight::utils::{flight_data_to_arrow_batch, flight_data_to_dictionary};
 use arrow_flight::{FlightDescriptor, Ticket};
 
 /// This example shows how to wrap DataFusion with `FlightService` to support looking up schema information for
@@ -63,11 +65,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // all the remaining stream messages should be dictionary and record batches
     let mut results = vec![];
+    let mut dictionaries_by_id = HashMap::new();
     while let Some(flight_data) = stream.message().await? {
-        // the unwrap is infallible and thus safe
-        let record_batch =
-            flight_data_to_arrow_batch(&flight_data, schema.clone()).unwrap()?;
-        results.push(record_batch);
+        let message = arrow::ipc::get_root_as_message(&flight_data.data_header[..]);
+        if message.header_type() == MessageHeader::DictionaryBatch {
+            flight_data_to_dictionary(&flight_data, &schema, &mut dictionaries_by_id)?;
+        } else {
+            // the unwrap is infallible and thus safe
+            let record_batch = flight_data_to_arrow_batch(
+                &flight_data,
+                schema.clone(),
+                &dictionaries_by_id,
+            )
+            .unwrap()?;
+            results.push(record_batch);
+        }
     }
 
     // print the results
@@ -21,10 +21,13 @@ use futures::Stream;
 use tonic::transport::Server;
 use tonic::{Request, Response, Status, Streaming};
 
+use arrow::ipc::writer::{DictionaryTracker, IpcWriteOptions};
+
 use datafusion::datasource::parquet::ParquetTable;
 use datafusion::datasource::TableProvider;
 use datafusion::prelude::*;
 
+use arrow_flight::utils::flight_data_from_arrow_batch;
 use arrow_flight::{
     flight_service_server::FlightService, flight_service_server::FlightServiceServer,
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
@@ -112,13 +115,19 @@ impl FlightService for FlightServiceImpl {
                 let mut flights: Vec<Result<FlightData, Status>> =
                     vec![Ok(FlightData::from(schema.as_ref()))];
 
-                let mut batches: Vec<Result<FlightData, Status>> = results
-                    .iter()
-                    .map(|batch| Ok(FlightData::from(batch)))
-                    .collect();
-
-                // append batch vector to schema vector, so that the first message sent is the schema
-                flights.append(&mut batches);
+                // followed by the dictionaries of each batch, before the batch itself
+                let mut dictionary_tracker = DictionaryTracker::new(false);
+                let options = IpcWriteOptions::default();
+                for batch in &results {
+                    let (dictionaries, batch) = flight_data_from_arrow_batch(
+                        batch,
+                        &mut dictionary_tracker,
+                        &options,
+                    )
+                    .map_err(|e| Status::internal(format!("{:?}", e)))?;
+                    flights.extend(dictionaries.into_iter().map(Ok));
+                    flights.push(Ok(batch));
+                }
 
                 let output = futures::stream::iter(flights);
 
@@ -404,6 +404,25 @@ fn record_batch_from_json(
                 }
                 Arc::new(b.finish())
             }
+            DataType::Decimal(precision, scale) => {
+                let mut b = DecimalBuilder::new(json_col.count, *precision, *scale);
+                for (is_valid, value) in json_col
+                    .validity
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .zip(json_col.data.unwrap())
+                {
+                    match is_valid {
+                        1 => b.append_value(
+                            value.as_str().unwrap().parse::<i128>().unwrap(),
+                        ),
+                        _ => b.append_null(),
+                    }
+                    .unwrap();
+                }
+                Arc::new(b.finish())
+            }
             t => {
                 return Err(ArrowError::JsonError(format!(
                     "data type {:?} not supported",
@@ -27,11 +27,12 @@ use std::vec::Vec;
 use arrow::array::{
     Array, ArrayData, ArrayDataBuilder, ArrayDataRef, ArrayRef, BinaryArray,
     BinaryBuilder, BooleanBufferBuilder, BufferBuilderTrait, FixedSizeBinaryArray,
-    FixedSizeBinaryBuilder, GenericListArray, Int16BufferBuilder, ListBuilder,
+    FixedSizeBinaryBuilder, GenericListArray, Int16BufferBuilder, ListBuilder, MapArray,
     OffsetSizeTrait, PrimitiveArray, PrimitiveBuilder, StringArray, StringBuilder,
-    StructArray,
+    StructArray, UInt32Array,
 };
 use arrow::buffer::{Buffer, MutableBuffer};
+use arrow::compute::take;
 use arrow::datatypes::{
     ArrowPrimitiveType, BooleanType as ArrowBooleanType, DataType as ArrowType,
     Date32Type as ArrowDate32Type, Date64Type as ArrowDate64Type,
@@ -39,10 +40,10 @@ use arrow::datatypes::{
     DurationMillisecondType as ArrowDurationMillisecondType,
     DurationNanosecondType as ArrowDurationNanosecondType,
     DurationSecondType as ArrowDurationSecondType, Field,
-    Float32Type as ArrowFloat32Type, Float64Type as ArrowFloat64Type,
-    Int16Type as ArrowInt16Type, Int32Type as ArrowInt32Type,
-    Int64Type as ArrowInt64Type, Int8Type as ArrowInt8Type, Schema,
-    Time32MillisecondType as ArrowTime32MillisecondType,
+    Float16Type as ArrowFloat16Type, Float32Type as ArrowFloat32Type,
+    Float64Type as ArrowFloat64Type, Int16Type as ArrowInt16Type,
+    Int32Type as ArrowInt32Type, Int64Type as ArrowInt64Type, Int8Type as ArrowInt8Type,
+    Schema, Time32MillisecondType as ArrowTime32MillisecondType,
     Time32SecondType as ArrowTime32SecondType,
     Time64MicrosecondType as ArrowTime64MicrosecondType,
     Time64NanosecondType as ArrowTime64NanosecondType, TimeUnit as ArrowTimeUnit,
@@ -56,10 +57,10 @@ use arrow::datatypes::{
 use arrow::util::bit_util;
 
 use crate::arrow::converter::{
-    BinaryArrayConverter, BinaryConverter, Converter, FixedLenBinaryConverter,
-    FixedSizeArrayConverter, Int96ArrayConverter, Int96Converter,
-    LargeBinaryArrayConverter, LargeBinaryConverter, LargeUtf8ArrayConverter,
-    LargeUtf8Converter, Utf8ArrayConverter, Utf8Converter,
+    BinaryArrayConverter, BinaryConverter, Converter, DecimalArrayConverter,
+    DecimalConverter, FixedLenBinaryConverter, FixedSizeArrayConverter,
+    Int96ArrayConverter, Int96Converter, LargeBinaryArrayConverter, LargeBinaryConverter,
+    LargeUtf8ArrayConverter, LargeUtf8Converter, Utf8ArrayConverter, Utf8Converter,
 };
 use crate::arrow::record_reader::RecordReader;
 use crate::arrow::schema::parquet_to_arrow_field;
@@ -67,8 +68,8 @@ use crate::basic::{LogicalType, Repetition, Type as PhysicalType};
 use crate::column::page::PageIterator;
 use crate::column::reader::ColumnReaderImpl;
 use crate::data_type::{
-    BoolType, ByteArrayType, DataType, DoubleType, FixedLenByteArrayType, FloatType,
-    Int32Type, Int64Type, Int96Type,
+    BoolType, ByteArray, ByteArrayType, DataType, DoubleType, FixedLenByteArrayType,
+    FloatType, Int32Type, Int64Type, Int96Type,
 };
 use crate::errors::{ParquetError, ParquetError::ArrowError, Result};
 use crate::file::reader::{FilePageIterator, FileReader};
@@ -621,6 +622,9 @@ fn build_empty_list_array(item_type: ArrowType) -> Result<ArrayRef> {
         ArrowType::Int16 => build_empty_list_array_with_primitive_items!(ArrowInt16Type),
         ArrowType::Int32 => build_empty_list_array_with_primitive_items!(ArrowInt32Type),
         ArrowType::Int64 => build_empty_list_array_with_primitive_items!(ArrowInt64Type),
+        ArrowType::Float16 => {
+            build_empty_list_array_with_primitive_items!(ArrowFloat16Type)
+        }
         ArrowType::Float32 => {
             build_empty_list_array_with_primitive_items!(ArrowFloat32Type)
         }
@@ -766,6 +770,9 @@ fn remove_indices(
         ArrowType::Int16 => remove_primitive_array_indices!(arr, ArrowInt16Type, indices),
         ArrowType::Int32 => remove_primitive_array_indices!(arr, ArrowInt32Type, indices),
         ArrowType::Int64 => remove_primitive_array_indices!(arr, ArrowInt64Type, indices),
+        ArrowType::Float16 => {
+            remove_primitive_array_indices!(arr, ArrowFloat16Type, indices)
+        }
         ArrowType::Float32 => {
             remove_primitive_array_indices!(arr, ArrowFloat32Type, indices)
         }
@@ -947,6 +954,163 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
     }
 }
 
+/// Implementation of map array reader.
+///
+/// A parquet map is a repeated group of a required key and a value. The keys and values
+/// are read by their own leaf readers, whose levels delimit the maps.
+pub struct MapArrayReader {
+    key_reader: Box<dyn ArrayReader>,
+    value_reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+    map_def_level: i16,
+    map_rep_level: i16,
+    def_level_buffer: Option<Buffer>,
+    rep_level_buffer: Option<Buffer>,
+}
+
+impl MapArrayReader {
+    /// Construct map array reader.
+    ///
+    /// `def_level` and `rep_level` are the levels of the map itself, i.e. a map is
+    /// present when its definition level is at least `def_level`, and a repetition
+    /// level of at most `rep_level` starts a new map.
+    pub fn new(
+        key_reader: Box<dyn ArrayReader>,
+        value_reader: Box<dyn ArrayReader>,
+        data_type: ArrowType,
+        def_level: i16,
+        rep_level: i16,
+    ) -> Self {
+        Self {
+            key_reader,
+            value_reader,
+            data_type,
+            map_def_level: def_level,
+            map_rep_level: rep_level,
+            def_level_buffer: None,
+            rep_level_buffer: None,
+        }
+    }
+}
+
+impl ArrayReader for MapArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns data type.
+    /// This must be a map.
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef> {
+        let keys = self.key_reader.next_batch(batch_size)?;
+        let values = self.value_reader.next_batch(batch_size)?;
+        if keys.len() != values.len() {
+            return Err(general_err!(
+                "Map key and value arrays should have the same length, got {} and {}",
+                keys.len(),
+                values.len()
+            ));
+        }
+
+        let entries_field = match &self.data_type {
+            ArrowType::Map(entries, _) => (**entries).clone(),
+            _ => return Err(general_err!("Map array reader should have map type")),
+        };
+        let (key_field, value_field) = match entries_field.data_type() {
+            ArrowType::Struct(fields) if fields.len() == 2 => {
+                (fields[0].clone(), fields[1].clone())
+            }
+            _ => {
+                return Err(general_err!(
+                    "Map entries should be a struct with a key and a value field"
+                ))
+            }
+        };
+
+        let (def_levels, rep_levels) = match (
+            self.key_reader.get_def_levels(),
+            self.key_reader.get_rep_levels(),
+        ) {
+            (Some(def_levels), Some(rep_levels)) => (def_levels, rep_levels),
+            _ => {
+                return Err(ArrowError(
+                    "key_reader def levels and rep levels should not be None."
+                        .to_string(),
+                ))
+            }
+        };
+        if def_levels.len() != keys.len() || rep_levels.len() != keys.len() {
+            return Err(ArrowError(
+                "Expected key_reader def_levels and rep_levels to be same length as batch"
+                    .to_string(),
+            ));
+        }
+
+        // A slot with def_level > map_def_level holds an entry. A slot with a lower
+        // definition level marks a null or empty map and holds no entry.
+        // The first slot of each map has rep_level <= map_rep_level.
+        let mut entry_indices = Vec::with_capacity(keys.len());
+        let mut offsets: Vec<i32> = Vec::new();
+        let mut map_def_levels = Int16BufferBuilder::new(keys.len());
+        let mut map_rep_levels = Int16BufferBuilder::new(keys.len());
+        let mut bitmap_builder = BooleanBufferBuilder::new(keys.len());
+        let mut null_count = 0;
+        for i in 0..keys.len() {
+            if rep_levels[i] <= self.map_rep_level {
+                offsets.push(entry_indices.len() as i32);
+                let is_valid = def_levels[i] >= self.map_def_level;
+                if !is_valid {
+                    null_count += 1;
+                }
+                bitmap_builder.append(is_valid)?;
+                map_def_levels.append(min(def_levels[i], self.map_def_level))?;
+                map_rep_levels.append(rep_levels[i])?;
+            }
+            if def_levels[i] > self.map_def_level {
+                entry_indices.push(i as u32);
+            }
+        }
+        offsets.push(entry_indices.len() as i32);
+
+        let entries: ArrayRef = Arc::new(StructArray::from(vec![
+            (key_field, keys),
+            (value_field, values),
+        ]));
+        let entries = if entry_indices.len() == entries.len() {
+            entries
+        } else {
+            take(&entries, &UInt32Array::from(entry_indices), None)?
+        };
+
+        let map_data = ArrayData::builder(self.data_type.clone())
+            .len(offsets.len() - 1)
+            .add_buffer(Buffer::from(&offsets.to_byte_slice()))
+            .add_child_data(entries.data())
+            .null_bit_buffer(bitmap_builder.finish())
+            .null_count(null_count)
+            .build();
+
+        self.def_level_buffer = Some(map_def_levels.finish());
+        self.rep_level_buffer = Some(map_rep_levels.finish());
+        Ok(Arc::new(MapArray::from(map_data)))
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.def_level_buffer
+            .as_ref()
+            .map(|buf| unsafe { buf.typed_data() })
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.rep_level_buffer
+            .as_ref()
+            .map(|buf| unsafe { buf.typed_data() })
+    }
+}
+
 /// Implementation of struct array reader.
 pub struct StructArrayReader {
     children: Vec<Box<dyn ArrayReader>>,
@@ -1278,15 +1442,103 @@ impl<'a> TypeVisitor<Option<Box<dyn ArrayReader>>, &'a ArrayReaderBuilderContext
     }
 
     /// Build array reader for map type.
-    /// Currently this is not supported.
     fn visit_map(
         &mut self,
-        _cur_type: Rc<Type>,
-        _context: &'a ArrayReaderBuilderContext,
+        map_type: Rc<Type>,
+        context: &'a ArrayReaderBuilderContext,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
-        Err(ArrowError(
-            "Reading parquet map array into arrow is not supported yet!".to_string(),
-        ))
+        let key_value_type = match map_type.get_fields() {
+            [key_value] if !key_value.is_primitive() => key_value.clone(),
+            _ => {
+                return Err(ArrowError(
+                    "Map field must have a single key-value group child.".to_string(),
+                ))
+            }
+        };
+        let (key_type, value_type) = match key_value_type.get_fields() {
+            [key, value] => (key.clone(), value.clone()),
+            _ => {
+                return Err(ArrowError(
+                    "Key-value group of a map must contain a key and a value."
+                        .to_string(),
+                ))
+            }
+        };
+
+        let mut new_context = context.clone();
+        new_context.path.append(vec![map_type.name().to_string()]);
+
+        match map_type.get_basic_info().repetition() {
+            Repetition::REPEATED => {
+                return Err(ArrowError(
+                    "Reading repeated field is not supported yet!".to_string(),
+                ))
+            }
+            Repetition::OPTIONAL => {
+                new_context.def_level += 1;
+            }
+            _ => (),
+        }
+        let map_def_level = new_context.def_level;
+        let map_rep_level = new_context.rep_level;
+
+        new_context
+            .path
+            .append(vec![key_value_type.name().to_string()]);
+        if key_value_type.get_basic_info().repetition() != Repetition::REPEATED {
+            return Err(ArrowError(
+                "Key-value group of a map must be repeated.".to_string(),
+            ));
+        }
+        new_context.def_level += 1;
+        new_context.rep_level += 1;
+
+        let key_reader = self.dispatch(key_type.clone(), &new_context)?;
+        let value_reader = self.dispatch(value_type.clone(), &new_context)?;
+        let (key_reader, value_reader) = match (key_reader, value_reader) {
+            (Some(key_reader), Some(value_reader)) => (key_reader, value_reader),
+            (None, None) => return Ok(None),
+            _ => return Err(ArrowError(
+                "Reading only the keys or only the values of a map is not supported yet!"
+                    .to_string(),
+            )),
+        };
+
+        let arrow_type = match self
+            .arrow_schema
+            .field_with_name(map_type.name())
+            .ok()
+            .map(|f| f.data_type().to_owned())
+        {
+            Some(t @ ArrowType::Map(_, _)) => t,
+            _ => ArrowType::Map(
+                Box::new(Field::new(
+                    key_value_type.name(),
+                    ArrowType::Struct(vec![
+                        Field::new(
+                            key_type.name(),
+                            key_reader.get_data_type().clone(),
+                            false,
+                        ),
+                        Field::new(
+                            value_type.name(),
+                            value_reader.get_data_type().clone(),
+                            true,
+                        ),
+                    ]),
+                    false,
+                )),
+                false,
+            ),
+        };
+
+        Ok(Some(Box::new(MapArrayReader::new(
+            key_reader,
+            value_reader,
+            arrow_type,
+            map_def_level,
+            map_rep_level,
+        ))))
     }
 
     /// Build array reader for list type.
@@ -1450,6 +1702,19 @@ impl<'a> ArrayReaderBuilder {
                         page_iterator,
                         column_desc,
                     )?))
+                } else if let Some(ArrowType::Decimal(precision, scale)) = arrow_type {
+                    let converter = DecimalConverter::new(DecimalArrayConverter::new(
+                        precision, scale,
+                    ));
+                    Ok(Box::new(ComplexObjectArrayReader::<
+                        Int32Type,
+                        DecimalConverter<i32>,
+                    >::new(
+                        page_iterator,
+                        column_desc,
+                        converter,
+                        arrow_type,
+                    )?))
                 } else {
                     Ok(Box::new(PrimitiveArrayReader::<Int32Type>::new(
                         page_iterator,
@@ -1458,11 +1723,28 @@ impl<'a> ArrayReaderBuilder {
                     )?))
                 }
             }
-            PhysicalType::INT64 => Ok(Box::new(PrimitiveArrayReader::<Int64Type>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?)),
+            PhysicalType::INT64 => {
+                if let Some(ArrowType::Decimal(precision, scale)) = arrow_type {
+                    let converter = DecimalConverter::new(DecimalArrayConverter::new(
+                        precision, scale,
+                    ));
+                    Ok(Box::new(ComplexObjectArrayReader::<
+                        Int64Type,
+                        DecimalConverter<i64>,
+                    >::new(
+                        page_iterator,
+                        column_desc,
+                        converter,
+                        arrow_type,
+                    )?))
+                } else {
+                    Ok(Box::new(PrimitiveArrayReader::<Int64Type>::new(
+                        page_iterator,
+                        column_desc,
+                        arrow_type,
+                    )?))
+                }
+            }
             PhysicalType::INT96 => {
                 let converter = Int96Converter::new(Int96ArrayConverter {});
                 Ok(Box::new(ComplexObjectArrayReader::<
@@ -1513,6 +1795,19 @@ impl<'a> ArrayReaderBuilder {
                             arrow_type,
                         )?))
                     }
+                } else if let Some(ArrowType::Decimal(precision, scale)) = arrow_type {
+                    let converter = DecimalConverter::new(DecimalArrayConverter::new(
+                        precision, scale,
+                    ));
+                    Ok(Box::new(ComplexObjectArrayReader::<
+                        ByteArrayType,
+                        DecimalConverter<ByteArray>,
+                    >::new(
+                        page_iterator,
+                        column_desc,
+                        converter,
+                        arrow_type,
+                    )?))
                 } else if let Some(ArrowType::LargeBinary) = arrow_type {
                     let converter =
                         LargeBinaryConverter::new(LargeBinaryArrayConverter {});
@@ -1539,6 +1834,20 @@ impl<'a> ArrayReaderBuilder {
                 }
             }
             PhysicalType::FIXED_LEN_BYTE_ARRAY => {
+                if let Some(ArrowType::Decimal(precision, scale)) = arrow_type {
+                    let converter = DecimalConverter::new(DecimalArrayConverter::new(
+                        precision, scale,
+                    ));
+                    return Ok(Box::new(ComplexObjectArrayReader::<
+                        FixedLenByteArrayType,
+                        DecimalConverter<ByteArray>,
+                    >::new(
+                        page_iterator,
+                        column_desc,
+                        converter,
+                        arrow_type,
+                    )?));
+                }
                 let byte_width = match *cur_type {
                     Type::PrimitiveType {
                         ref type_length, ..
@@ -1620,8 +1929,8 @@ mod tests {
     };
     use crate::util::test_common::{get_test_file, make_pages};
     use arrow::array::{
-        Array, ArrayRef, LargeListArray, ListArray, PrimitiveArray, StringArray,
-        StructArray,
+        Array, ArrayRef, LargeListArray, ListArray, MapArray, PrimitiveArray,
+        StringArray, StructArray,
     };
     use arrow::datatypes::{
         ArrowPrimitiveType, DataType as ArrowType, Date32Type as ArrowDate32, Field,
@@ -2397,4 +2706,91 @@ mod tests {
             &PrimitiveArray::<ArrowInt32>::from(vec![Some(3), Some(4)])
         );
     }
+
+    #[test]
+    fn test_map_array_reader() {
+        // [{"a": 1, "b": null}, null, {}, {"c": 3}]
+        let keys = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            None,
+            Some("c"),
+        ]));
+        let key_array_reader = InMemoryArrayReader::new(
+            ArrowType::Utf8,
+            keys,
+            Some(vec![2, 2, 0, 1, 2]),
+            Some(vec![0, 1, 0, 0, 0]),
+        );
+        let values = Arc::new(PrimitiveArray::<ArrowInt32>::from(vec![
+            Some(1),
+            None,
+            None,
+            None,
+            Some(3),
+        ]));
+        let value_array_reader = InMemoryArrayReader::new(
+            ArrowType::Int32,
+            values,
+            Some(vec![3, 2, 0, 1, 3]),
+            Some(vec![0, 1, 0, 0, 0]),
+        );
+
+        let map_type = ArrowType::Map(
+            Box::new(Field::new(
+                "key_value",
+                ArrowType::Struct(vec![
+                    Field::new("key", ArrowType::Utf8, false),
+                    Field::new("value", ArrowType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        );
+        let mut map_array_reader = MapArrayReader::new(
+            Box::new(key_array_reader),
+            Box::new(value_array_reader),
+            map_type.clone(),
+            1,
+            0,
+        );
+
+        let next_batch = map_array_reader.next_batch(1024).unwrap();
+        let map_array = next_batch.as_any().downcast_ref::<MapArray>().unwrap();
+
+        assert_eq!(&map_type, map_array.data_type());
+        assert_eq!(4, map_array.len());
+        assert_eq!(1, map_array.null_count());
+        assert_eq!(2, map_array.value_length(0));
+        assert!(map_array.is_null(1));
+        assert!(map_array.is_valid(2));
+        assert_eq!(0, map_array.value_length(2));
+        assert_eq!(1, map_array.value_length(3));
+
+        assert_eq!(
+            map_array
+                .keys()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap(),
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            map_array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<ArrowInt32>>()
+                .unwrap(),
+            &PrimitiveArray::<ArrowInt32>::from(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(
+            Some(vec![1, 0, 1, 1].as_slice()),
+            map_array_reader.get_def_levels()
+        );
+        assert_eq!(
+            Some(vec![0, 0, 0, 0].as_slice()),
+            map_array_reader.get_rep_levels()
+        );
+    }
 }
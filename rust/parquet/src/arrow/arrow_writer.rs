@@ -24,7 +24,9 @@ use arrow::datatypes::{DataType as ArrowDataType, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use arrow_array::Array;
 
-use super::schema::add_encoded_arrow_schema_to_metadata;
+use super::schema::{
+    add_encoded_arrow_schema_to_metadata, decimal_length_from_precision,
+};
 use crate::column::writer::{ColumnWriter, ColumnWriterImpl};
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::WriterProperties;
@@ -50,13 +52,24 @@ impl<W: 'static + ParquetWriter> ArrowWriter<W> {
     ///
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
-    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * the Arrow schema contains unsupported datatypes such as Unions or Maps
     pub fn try_new(
         writer: W,
         arrow_schema: SchemaRef,
         props: Option<WriterProperties>,
     ) -> Result<Self> {
         let schema = crate::arrow::arrow_to_parquet_schema(&arrow_schema)?;
+        // the levels of map columns can not be computed yet
+        if let Some(field) = arrow_schema
+            .fields()
+            .iter()
+            .find(|field| contains_map(field.data_type()))
+        {
+            return Err(ParquetError::ArrowError(format!(
+                "Writing the map column {} is not supported yet",
+                field.name()
+            )));
+        }
         // add serialized arrow schema
         let mut props = props.unwrap_or_else(|| WriterProperties::builder().build());
         add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
@@ -109,6 +122,21 @@ impl<W: 'static + ParquetWriter> ArrowWriter<W> {
     }
 }
 
+/// Returns whether a data type is or contains a map
+fn contains_map(data_type: &ArrowDataType) -> bool {
+    match data_type {
+        ArrowDataType::Map(_, _) => true,
+        ArrowDataType::List(value)
+        | ArrowDataType::LargeList(value)
+        | ArrowDataType::FixedSizeList(value, _)
+        | ArrowDataType::Dictionary(_, value) => contains_map(value),
+        ArrowDataType::Struct(fields) | ArrowDataType::Union(fields) => {
+            fields.iter().any(|field| contains_map(field.data_type()))
+        }
+        _ => false,
+    }
+}
+
 /// Convenience method to get the next ColumnWriter from the RowGroupWriter
 #[inline]
 #[allow(clippy::borrowed_box)]
@@ -149,7 +177,8 @@ fn write_leaves(
         | ArrowDataType::LargeBinary
         | ArrowDataType::Binary
         | ArrowDataType::Utf8
-        | ArrowDataType::LargeUtf8 => {
+        | ArrowDataType::LargeUtf8
+        | ArrowDataType::Decimal(_, _) => {
             let mut col_writer = get_col_writer(&mut row_group_writer)?;
             write_leaf(
                 &mut col_writer,
@@ -264,12 +293,22 @@ fn write_leaves(
 
             Ok(())
         }
-        ArrowDataType::Float16 => Err(ParquetError::ArrowError(
-            "Float16 arrays not supported".to_string(),
-        )),
+        ArrowDataType::Float16 => {
+            // Parquet has no half-precision type, write the values as FLOAT
+            let array = arrow::compute::cast(array, &ArrowDataType::Float32)?;
+            let mut col_writer = get_col_writer(&mut row_group_writer)?;
+            write_leaf(
+                &mut col_writer,
+                &array,
+                levels.pop().expect("Levels exhausted"),
+            )?;
+            row_group_writer.close_column(col_writer)?;
+            Ok(())
+        }
         ArrowDataType::FixedSizeList(_, _)
         | ArrowDataType::Boolean
         | ArrowDataType::FixedSizeBinary(_)
+        | ArrowDataType::Map(_, _)
         | ArrowDataType::Union(_) => Err(ParquetError::NYI(
             "Attempting to write an Arrow type that is not yet implemented".to_string(),
         )),
@@ -428,8 +467,20 @@ fn write_leaf(
             }
             _ => unreachable!("Currently unreachable because data type not supported"),
         },
-        ColumnWriter::FixedLenByteArrayColumnWriter(ref mut _typed) => {
-            unreachable!("Currently unreachable because data type not supported")
+        ColumnWriter::FixedLenByteArrayColumnWriter(ref mut typed) => {
+            match column.data_type() {
+                ArrowDataType::Decimal(_, _) => {
+                    let array = arrow_array::DecimalArray::from(column.data());
+                    typed.write_batch(
+                        get_decimal_array(&array).as_slice(),
+                        Some(levels.definition.as_slice()),
+                        levels.repetition.as_deref(),
+                    )?
+                }
+                _ => {
+                    unreachable!("Currently unreachable because data type not supported")
+                }
+            }
         }
     };
     Ok(written as i64)
@@ -477,7 +528,8 @@ fn get_levels(
         | ArrowDataType::Duration(_)
         | ArrowDataType::Interval(_)
         | ArrowDataType::Binary
-        | ArrowDataType::LargeBinary => vec![Levels {
+        | ArrowDataType::LargeBinary
+        | ArrowDataType::Decimal(_, _) => vec![Levels {
             definition: get_primitive_def_levels(array, parent_def_levels),
             repetition: None,
         }],
@@ -554,7 +606,8 @@ fn get_levels(
                 | ArrowDataType::Time32(_)
                 | ArrowDataType::Time64(_)
                 | ArrowDataType::Duration(_)
-                | ArrowDataType::Interval(_) => {
+                | ArrowDataType::Interval(_)
+                | ArrowDataType::Decimal(_, _) => {
                     let def_levels =
                         get_primitive_def_levels(&child_array, &list_def_levels[..]);
                     vec![Levels {
@@ -578,6 +631,9 @@ fn get_levels(
                     &list_def_levels[..],
                     Some(&list_rep_levels[..]),
                 ),
+                ArrowDataType::Map(_, _) => {
+                    unreachable!("Map columns are rejected by ArrowWriter::try_new")
+                }
                 ArrowDataType::Union(_) => unimplemented!(),
                 ArrowDataType::Dictionary(_, _) => unimplemented!(),
             }
@@ -601,6 +657,9 @@ fn get_levels(
             });
             struct_levels
         }
+        ArrowDataType::Map(_, _) => {
+            unreachable!("Map columns are rejected by ArrowWriter::try_new")
+        }
         ArrowDataType::Union(_) => unimplemented!(),
         ArrowDataType::Dictionary(_, _) => {
             // Need to check for these cases not implemented in C++:
@@ -657,6 +716,20 @@ def_get_binary_array_fn!(get_string_array, arrow_array::StringArray);
 def_get_binary_array_fn!(get_large_binary_array, arrow_array::LargeBinaryArray);
 def_get_binary_array_fn!(get_large_string_array, arrow_array::LargeStringArray);
 
+/// Get the decimal values as big-endian two's complement byte arrays, skipping any
+/// null values. Each value is truncated to the length of the parquet type.
+fn get_decimal_array(array: &arrow_array::DecimalArray) -> Vec<ByteArray> {
+    let size = decimal_length_from_precision(array.precision()) as usize;
+    let mut values = Vec::with_capacity(array.len() - array.null_count());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let bytes = array.value(i).to_be_bytes();
+            values.push(ByteArray::from(bytes[(16 - size)..].to_vec()));
+        }
+    }
+    values
+}
+
 /// Get the underlying numeric array slice, skipping any null values.
 /// If there are no null values, it might be quicker to get the slice directly instead of
 /// calling this function.
@@ -684,7 +757,7 @@ mod tests {
 
     use arrow::array::*;
     use arrow::datatypes::ToByteSlice;
-    use arrow::datatypes::{DataType, Field, Schema, UInt32Type, UInt8Type};
+    use arrow::datatypes::{f16, DataType, Field, Schema, UInt32Type, UInt8Type};
     use arrow::record_batch::RecordBatch;
 
     use crate::arrow::{ArrowReader, ParquetFileArrowReader};
@@ -1046,6 +1119,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn f16_single_column() {
+        required_and_optional::<Float16Array, _>(
+            (0..SMALL_SIZE).map(|i| f16::from_f32(i as f32)),
+            "f16_single_column",
+        );
+    }
+
     #[test]
     fn f32_single_column() {
         required_and_optional::<Float32Array, _>(
@@ -1226,6 +1307,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decimal_single_column() {
+        let mut builder = DecimalBuilder::new(SMALL_SIZE, 10, 3);
+        for i in 0..SMALL_SIZE as i128 {
+            if i % 2 == 0 {
+                builder.append_null().unwrap();
+            } else {
+                builder.append_value(i * 1_000 - 123_456).unwrap();
+            }
+        }
+        let values = Arc::new(builder.finish());
+
+        one_column_roundtrip("decimal_single_column", values, true);
+    }
+
     #[test]
     fn string_single_column() {
         let raw_values: Vec<_> = (0..SMALL_SIZE).map(|i| i.to_string()).collect();
@@ -1298,6 +1394,36 @@ mod tests {
         one_column_roundtrip("struct_single_column", values, false);
     }
 
+    #[test]
+    fn arrow_writer_map_column() {
+        let map_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        );
+        let schemas = vec![
+            Schema::new(vec![Field::new("map", map_type.clone(), true)]),
+            Schema::new(vec![Field::new(
+                "struct",
+                DataType::Struct(vec![Field::new("map", map_type, true)]),
+                true,
+            )]),
+        ];
+        for schema in schemas {
+            let file = get_temp_file("test_arrow_writer_map_column.parquet", &[]);
+            let err = ArrowWriter::try_new(file, Arc::new(schema), None)
+                .err()
+                .expect("writing map columns should fail");
+            assert!(err.to_string().contains("is not supported yet"), "{}", err);
+        }
+    }
+
     #[test]
     fn arrow_writer_string_dictionary() {
         // define schema
@@ -18,9 +18,9 @@
 use crate::data_type::{ByteArray, DataType, Int96};
 // TODO: clean up imports (best done when there are few moving parts)
 use arrow::array::{
-    Array, ArrayRef, BinaryBuilder, FixedSizeBinaryBuilder, LargeBinaryBuilder,
-    LargeStringBuilder, PrimitiveBuilder, PrimitiveDictionaryBuilder, StringBuilder,
-    StringDictionaryBuilder, TimestampNanosecondBuilder,
+    Array, ArrayRef, BinaryBuilder, DecimalBuilder, FixedSizeBinaryBuilder,
+    LargeBinaryBuilder, LargeStringBuilder, PrimitiveBuilder, PrimitiveDictionaryBuilder,
+    StringBuilder, StringDictionaryBuilder, TimestampNanosecondBuilder,
 };
 use arrow::compute::cast;
 use std::convert::From;
@@ -30,7 +30,7 @@ use crate::errors::Result;
 use arrow::datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType};
 
 use arrow::array::{
-    BinaryArray, DictionaryArray, FixedSizeBinaryArray, LargeBinaryArray,
+    BinaryArray, DecimalArray, DictionaryArray, FixedSizeBinaryArray, LargeBinaryArray,
     LargeStringArray, PrimitiveArray, StringArray, TimestampNanosecondArray,
 };
 use std::marker::PhantomData;
@@ -71,6 +71,64 @@ impl Converter<Vec<Option<ByteArray>>, FixedSizeBinaryArray> for FixedSizeArrayC
     }
 }
 
+pub struct DecimalArrayConverter {
+    precision: usize,
+    scale: usize,
+}
+
+impl DecimalArrayConverter {
+    pub fn new(precision: usize, scale: usize) -> Self {
+        Self { precision, scale }
+    }
+
+    /// Interprets `b` as a big-endian two's complement integer.
+    fn from_bytes_to_i128(b: &[u8]) -> i128 {
+        assert!(b.len() <= 16, "DecimalArray supports only up to size 16");
+        let first_bit = !b.is_empty() && b[0] & 128u8 == 128u8;
+        let mut result = if first_bit { [255u8; 16] } else { [0u8; 16] };
+        result[16 - b.len()..].copy_from_slice(b);
+        i128::from_be_bytes(result)
+    }
+}
+
+impl Converter<Vec<Option<ByteArray>>, DecimalArray> for DecimalArrayConverter {
+    fn convert(&self, source: Vec<Option<ByteArray>>) -> Result<DecimalArray> {
+        let mut builder = DecimalBuilder::new(source.len(), self.precision, self.scale);
+        for v in source {
+            match v {
+                Some(array) => {
+                    builder.append_value(Self::from_bytes_to_i128(array.data()))
+                }
+                None => builder.append_null(),
+            }?
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+macro_rules! decimal_from_int_converter {
+    ($native_ty:ty) => {
+        impl Converter<Vec<Option<$native_ty>>, DecimalArray> for DecimalArrayConverter {
+            fn convert(&self, source: Vec<Option<$native_ty>>) -> Result<DecimalArray> {
+                let mut builder =
+                    DecimalBuilder::new(source.len(), self.precision, self.scale);
+                for v in source {
+                    match v {
+                        Some(value) => builder.append_value(value as i128),
+                        None => builder.append_null(),
+                    }?
+                }
+
+                Ok(builder.finish())
+            }
+        }
+    };
+}
+
+decimal_from_int_converter!(i32);
+decimal_from_int_converter!(i64);
+
 pub struct Int96ArrayConverter {}
 
 impl Converter<Vec<Option<Int96>>, TimestampNanosecondArray> for Int96ArrayConverter {
@@ -281,6 +339,8 @@ pub type FixedLenBinaryConverter = ArrayRefConverter<
     FixedSizeBinaryArray,
     FixedSizeArrayConverter,
 >;
+pub type DecimalConverter<T> =
+    ArrayRefConverter<Vec<Option<T>>, DecimalArray, DecimalArrayConverter>;
 
 pub struct FromConverter<S, T> {
     _source: PhantomData<S>,
@@ -298,6 +298,33 @@ pub fn parquet_to_arrow_field(parquet_column: &ColumnDescriptor) -> Result<Field
         .map(|opt| opt.unwrap())
 }
 
+/// The largest precision of a decimal, whose values are stored in 16 bytes
+const MAX_DECIMAL_PRECISION: i64 = 38;
+
+/// Checks that a decimal's precision is within 1 and 38, and its scale within 0 and
+/// its precision
+fn validate_decimal(precision: i64, scale: i64) -> Result<()> {
+    if precision < 1 || precision > MAX_DECIMAL_PRECISION {
+        return Err(ArrowError(format!(
+            "Decimal precision {} is not within 1 and {}",
+            precision, MAX_DECIMAL_PRECISION
+        )));
+    }
+    if scale < 0 || scale > precision {
+        return Err(ArrowError(format!(
+            "Decimal scale {} is not within 0 and the precision {}",
+            scale, precision
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the minimum number of bytes needed to store a decimal of `precision`
+/// digits as a two's complement integer
+pub(crate) fn decimal_length_from_precision(precision: usize) -> i32 {
+    (((10.0_f64.powi(precision as i32) + 1.0).log2() + 1.0) / 8.0).ceil() as i32
+}
+
 /// Convert an arrow field to a parquet `Type`
 fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
     let name = field.name().as_str();
@@ -345,10 +372,12 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
             .with_logical_type(LogicalType::UINT_64)
             .with_repetition(repetition)
             .build(),
-        DataType::Float16 => Err(ArrowError("Float16 arrays not supported".to_string())),
-        DataType::Float32 => Type::primitive_type_builder(name, PhysicalType::FLOAT)
-            .with_repetition(repetition)
-            .build(),
+        DataType::Float16 | DataType::Float32 => {
+            // half-precision values are widened to single precision on write
+            Type::primitive_type_builder(name, PhysicalType::FLOAT)
+                .with_repetition(repetition)
+                .build()
+        }
         DataType::Float64 => Type::primitive_type_builder(name, PhysicalType::DOUBLE)
             .with_repetition(repetition)
             .build(),
@@ -400,6 +429,16 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .with_length(*length)
                 .build()
         }
+        DataType::Decimal(precision, scale) => {
+            validate_decimal(*precision as i64, *scale as i64)?;
+            Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .with_logical_type(LogicalType::DECIMAL)
+                .with_repetition(repetition)
+                .with_length(decimal_length_from_precision(*precision))
+                .with_precision(*precision as i32)
+                .with_scale(*scale as i32)
+                .build()
+        }
         DataType::Utf8 | DataType::LargeUtf8 => {
             Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
                 .with_logical_type(LogicalType::UTF8)
@@ -438,6 +477,28 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .with_repetition(repetition)
                 .build()
         }
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                let key_field =
+                    Field::new(fields[0].name(), fields[0].data_type().clone(), false);
+                Type::group_type_builder(name)
+                    .with_fields(&mut vec![Rc::new(
+                        Type::group_type_builder(entries.name())
+                            .with_fields(&mut vec![
+                                Rc::new(arrow_to_parquet_type(&key_field)?),
+                                Rc::new(arrow_to_parquet_type(&fields[1])?),
+                            ])
+                            .with_repetition(Repetition::REPEATED)
+                            .build()?,
+                    )])
+                    .with_logical_type(LogicalType::MAP)
+                    .with_repetition(repetition)
+                    .build()
+            }
+            _ => Err(ArrowError(
+                "Map entries must be a struct with a key and a value field".to_string(),
+            )),
+        },
         DataType::Union(_) => unimplemented!("See ARROW-8817."),
         DataType::Dictionary(_, ref value) => {
             // Dictionary encoding not handled at the schema level
@@ -571,6 +632,7 @@ impl ParquetTypeConverter<'_> {
             LogicalType::INT_32 => Ok(DataType::Int32),
             LogicalType::DATE => Ok(DataType::Date32(DateUnit::Day)),
             LogicalType::TIME_MILLIS => Ok(DataType::Time32(TimeUnit::Millisecond)),
+            LogicalType::DECIMAL => self.to_decimal(),
             other => Err(ArrowError(format!(
                 "Unable to convert parquet INT32 logical type {}",
                 other
@@ -590,6 +652,7 @@ impl ParquetTypeConverter<'_> {
             LogicalType::TIMESTAMP_MICROS => {
                 Ok(DataType::Timestamp(TimeUnit::Microsecond, None))
             }
+            LogicalType::DECIMAL => self.to_decimal(),
             other => Err(ArrowError(format!(
                 "Unable to convert parquet INT64 logical type {}",
                 other
@@ -598,6 +661,10 @@ impl ParquetTypeConverter<'_> {
     }
 
     fn from_fixed_len_byte_array(&self) -> Result<DataType> {
+        if self.schema.get_basic_info().logical_type() == LogicalType::DECIMAL {
+            return self.to_decimal();
+        }
+
         let byte_width = match self.schema {
             Type::PrimitiveType {
                 ref type_length, ..
@@ -616,6 +683,7 @@ impl ParquetTypeConverter<'_> {
         match self.schema.get_basic_info().logical_type() {
             LogicalType::NONE => Ok(DataType::Binary),
             LogicalType::UTF8 => Ok(DataType::Utf8),
+            LogicalType::DECIMAL => self.to_decimal(),
             other => Err(ArrowError(format!(
                 "Unable to convert parquet BYTE_ARRAY logical type {}",
                 other
@@ -623,6 +691,22 @@ impl ParquetTypeConverter<'_> {
         }
     }
 
+    fn to_decimal(&self) -> Result<DataType> {
+        match self.schema {
+            Type::PrimitiveType {
+                ref precision,
+                ref scale,
+                ..
+            } => {
+                validate_decimal(*precision as i64, *scale as i64)?;
+                Ok(DataType::Decimal(*precision as usize, *scale as usize))
+            }
+            _ => Err(ArrowError(
+                "Expected a physical type, not a group type".to_string(),
+            )),
+        }
+    }
+
     // Functions for group types.
 
     /// Entry point for converting parquet group type.
@@ -635,6 +719,7 @@ impl ParquetTypeConverter<'_> {
         } else {
             match self.schema.get_basic_info().logical_type() {
                 LogicalType::LIST => self.to_list(),
+                LogicalType::MAP | LogicalType::MAP_KEY_VALUE => self.to_map(),
                 _ => self.to_struct(),
             }
         }
@@ -723,6 +808,67 @@ impl ParquetTypeConverter<'_> {
             )),
         }
     }
+
+    /// Converts a parquet map to arrow map.
+    ///
+    /// The map group must contain a single repeated group of a required key and a
+    /// value, as described in the
+    /// [parquet doc](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#maps).
+    fn to_map(&self) -> Result<Option<DataType>> {
+        match self.schema {
+            Type::PrimitiveType { .. } => panic!(
+                "{:?} is a map type and can't be processed as primitive.",
+                self.schema
+            ),
+            Type::GroupType {
+                basic_info: _,
+                fields,
+            } if fields.len() == 1 => {
+                let key_value = fields.first().unwrap();
+                let key_value_converter = self.clone_with_schema(key_value);
+                if !key_value_converter.is_repeated() {
+                    return Err(ArrowError(
+                        "Key-value group of a map must be repeated.".to_string(),
+                    ));
+                }
+                match key_value.as_ref() {
+                    Type::GroupType { fields, .. } if fields.len() == 2 => {
+                        let key = self.clone_with_schema(&fields[0]).to_field()?;
+                        let value = self.clone_with_schema(&fields[1]).to_field()?;
+                        match (key, value) {
+                            (Some(key), Some(value)) => {
+                                if key.is_nullable() {
+                                    return Err(ArrowError(
+                                        "Map keys must be required.".to_string(),
+                                    ));
+                                }
+                                Ok(Some(DataType::Map(
+                                    Box::new(Field::new(
+                                        key_value.name(),
+                                        DataType::Struct(vec![key, value]),
+                                        false,
+                                    )),
+                                    false,
+                                )))
+                            }
+                            (None, None) => Ok(None),
+                            _ => Err(ArrowError(
+                                "Reading only the keys or only the values of a map is not supported."
+                                    .to_string(),
+                            )),
+                        }
+                    }
+                    _ => Err(ArrowError(
+                        "Key-value group of a map must contain a key and a value."
+                            .to_string(),
+                    )),
+                }
+            }
+            _ => Err(ArrowError(
+                "Group type of map can only contain one field.".to_string(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -796,6 +942,104 @@ mod tests {
         assert_eq!(&arrow_fields, converted_arrow_schema.fields());
     }
 
+    #[test]
+    fn test_decimal_fields() {
+        let message_type = "
+        message test_schema {
+            REQUIRED INT32 decimal1 (DECIMAL(4,2));
+            REQUIRED INT64 decimal2 (DECIMAL(12,2));
+            REQUIRED FIXED_LEN_BYTE_ARRAY (16) decimal3 (DECIMAL(30,2));
+            REQUIRED BYTE_ARRAY decimal4 (DECIMAL(33,2));
+        }
+        ";
+
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+
+        let parquet_schema = SchemaDescriptor::new(Rc::new(parquet_group_type));
+        let converted_arrow_schema =
+            parquet_to_arrow_schema(&parquet_schema, &None).unwrap();
+
+        let arrow_fields = vec![
+            Field::new("decimal1", DataType::Decimal(4, 2), false),
+            Field::new("decimal2", DataType::Decimal(12, 2), false),
+            Field::new("decimal3", DataType::Decimal(30, 2), false),
+            Field::new("decimal4", DataType::Decimal(33, 2), false),
+        ];
+        assert_eq!(&arrow_fields, converted_arrow_schema.fields());
+    }
+
+    #[test]
+    fn test_invalid_decimal_fields() {
+        for (precision, scale) in &[(0, 0), (39, 2), (10, 11)] {
+            let schema = Schema::new(vec![Field::new(
+                "decimal",
+                DataType::Decimal(*precision, *scale),
+                false,
+            )]);
+            assert!(arrow_to_parquet_schema(&schema).is_err());
+        }
+    }
+
+    #[test]
+    fn test_map_fields() {
+        let message_type = "
+        message test_schema {
+            OPTIONAL GROUP my_map (MAP) {
+                REPEATED GROUP key_value {
+                    REQUIRED BINARY key (UTF8);
+                    OPTIONAL INT32 value;
+                }
+            }
+            REQUIRED GROUP legacy_map (MAP) {
+                REPEATED GROUP map (MAP_KEY_VALUE) {
+                    REQUIRED INT64 key;
+                    REQUIRED BOOLEAN value;
+                }
+            }
+        }
+        ";
+
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+
+        let parquet_schema = SchemaDescriptor::new(Rc::new(parquet_group_type));
+        let converted_arrow_schema =
+            parquet_to_arrow_schema(&parquet_schema, &None).unwrap();
+
+        let arrow_fields = vec![
+            Field::new(
+                "my_map",
+                DataType::Map(
+                    Box::new(Field::new(
+                        "key_value",
+                        DataType::Struct(vec![
+                            Field::new("key", DataType::Utf8, false),
+                            Field::new("value", DataType::Int32, true),
+                        ]),
+                        false,
+                    )),
+                    false,
+                ),
+                true,
+            ),
+            Field::new(
+                "legacy_map",
+                DataType::Map(
+                    Box::new(Field::new(
+                        "map",
+                        DataType::Struct(vec![
+                            Field::new("key", DataType::Int64, false),
+                            Field::new("value", DataType::Boolean, false),
+                        ]),
+                        false,
+                    )),
+                    false,
+                ),
+                false,
+            ),
+        ];
+        assert_eq!(&arrow_fields, converted_arrow_schema.fields());
+    }
+
     #[test]
     fn test_duplicate_fields() {
         let message_type = "
@@ -1312,6 +1556,7 @@ mod tests {
                 }
             }
             REQUIRED BINARY  dictionary_strings (UTF8);
+            OPTIONAL FIXED_LEN_BYTE_ARRAY (5) decimal (DECIMAL(10,2));
         }
         ";
         let parquet_group_type = parse_message_type(message_type).unwrap();
@@ -1355,6 +1600,7 @@ mod tests {
                 DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
                 false,
             ),
+            Field::new("decimal", DataType::Decimal(10, 2), true),
         ];
         let arrow_schema = Schema::new(arrow_fields);
         let converted_arrow_schema = arrow_to_parquet_schema(&arrow_schema).unwrap();
@@ -1505,6 +1751,7 @@ mod tests {
                 //     true,
                 // ),
                 Field::new("c35", DataType::Null, true),
+                Field::new("c36", DataType::Decimal(10, 2), false),
             ],
             metadata,
         );